@@ -1,23 +1,203 @@
-use std::sync::Arc;
+use std::{collections::BTreeMap, path::Path, sync::Arc};
+
+use disma::{
+    api::{
+        params::{guild::GuildParams, snapshot::GuildSnapshot},
+        ComparedGuild,
+    },
+    guild::{GuildFilter, GuildQuerier, GuildSections},
+};
 
 use super::{
+    args::{GuildFilterArgs, TemplateVarsArgs},
     commands::{
-        apply_changes::ApplyChanges, compile_config::CompileConfig, list_guilds::ListGuilds,
-        save_guild::SaveExistingGuild,
+        apply_changes::ApplyChanges, backup_guild::BackupGuild, clone_guild::CloneGuild,
+        compare_guilds::CompareGuilds, compile_config::CompileConfig,
+        generate_schema::GenerateSchema, lint_config::LintConfig, list_guilds::ListGuilds,
+        restore_guild::RestoreGuild, save_guild::SaveExistingGuild,
+        show_permissions::ShowPermissions,
     },
     injector::{Get, Injector},
+    utils::{
+        environment::Environments,
+        error::exit_with_error,
+        io::{awaiting_guild, Deserializer},
+        template::{compiled_path, TemplateCompiler},
+    },
 };
 
-pub fn apply_changes(guild_id: &str, file_path: &str, dry_run: bool, force: bool) {
-    let injector = Injector::new(Some(guild_id.to_string()));
+#[allow(clippy::too_many_arguments)]
+pub fn apply_changes(
+    guild_id: Option<&str>,
+    env: Option<&str>,
+    input_file: Option<&str>,
+    template_file: Option<&str>,
+    vars: &TemplateVarsArgs,
+    policy_file: Option<&str>,
+    dry_run: bool,
+    impact: bool,
+    force: bool,
+) {
+    let file_path = match (input_file, template_file) {
+        (Some(input_file), _) => Path::new(input_file).to_path_buf(),
+        (None, Some(template_file)) => compiled_path(Path::new(template_file)),
+        (None, None) => panic!("Missing config. Use --input or --template."),
+    };
+
+    let guild_id = match (guild_id, env) {
+        (Some(guild_id), _) => guild_id.to_string(),
+        (None, Some(env)) => Environments::load(&file_path, &Deserializer())
+            .guild_id(env)
+            .unwrap_or_else(|error| error.exit()),
+        (None, None) => {
+            panic!("Missing guild id. Use --guild, or --env with an environments file.")
+        }
+    };
+
+    let injector = Injector::new(Some(guild_id.clone()));
+    let content = template_file.map(|template_file| {
+        let compiler: Arc<TemplateCompiler> = injector.get();
+        compiler
+            .compile(
+                Path::new(template_file),
+                &vars.vars,
+                &vars.assignments,
+                vars.strict,
+            )
+            .unwrap_or_else(|error| error.exit())
+    });
+
     let service: Arc<ApplyChanges> = injector.get();
-    service.run(guild_id, file_path, dry_run, force);
+    service.run(
+        &guild_id,
+        &file_path,
+        content.as_deref(),
+        env,
+        policy_file.map(Path::new),
+        dry_run,
+        impact,
+        force,
+    );
 }
 
-pub fn save_existing_guild(guild_id: &str, file: &str, force: bool) {
+pub fn save_existing_guild(
+    guild_id: &str,
+    file: &str,
+    filter: &GuildFilterArgs,
+    presets: bool,
+    merge: bool,
+    force: bool,
+) {
     let injector = Injector::new(Some(guild_id.to_string()));
     let service: Arc<SaveExistingGuild> = injector.get();
-    service.run(guild_id, file, force);
+    service.run(guild_id, file, &guild_filter(filter), presets, merge, force);
+}
+
+pub fn clone_guild(
+    source_guild_id: &str,
+    target_guild_id: &str,
+    filter: &GuildFilterArgs,
+    renames: &[(String, String)],
+    dry_run: bool,
+    force: bool,
+) {
+    let injector = Injector::new(Some(target_guild_id.to_string()));
+    let service: Arc<CloneGuild> = injector.get();
+    let names: BTreeMap<String, String> = renames.iter().cloned().collect();
+    service.run(
+        source_guild_id,
+        target_guild_id,
+        &guild_filter(filter),
+        &names,
+        dry_run,
+        force,
+    );
+}
+
+pub fn backup_guild(guild_id: &str, directory: &str) {
+    let injector = Injector::new(Some(guild_id.to_string()));
+    let service: Arc<BackupGuild> = injector.get();
+    service.run(guild_id, directory);
+}
+
+pub fn restore_guild(snapshot_file: &str, guild_id: Option<&str>, dry_run: bool, force: bool) {
+    let deserializer: Arc<Deserializer> = Injector::new(None).get();
+    let snapshot: GuildSnapshot = deserializer
+        .deserialize(Path::new(snapshot_file))
+        .unwrap_or_else(|error| error.exit());
+    let guild_id = guild_id.unwrap_or(&snapshot.guild_id).to_string();
+
+    let injector = Injector::new(Some(guild_id.clone()));
+    let service: Arc<RestoreGuild> = injector.get();
+    service.run(&guild_id, snapshot_file, snapshot, dry_run, force);
+}
+
+pub fn compare_guilds(source: &str, target: &str, json: bool) {
+    let injector = Injector::new(None);
+    let service: Arc<CompareGuilds> = injector.get();
+    service.run(
+        compared_guild(&injector, source),
+        compared_guild(&injector, target),
+        json,
+    );
+}
+
+/// A config file when `side` is an existing path, otherwise a guild ID.
+fn compared_guild(injector: &Injector, side: &str) -> ComparedGuild {
+    let path = Path::new(side);
+    if path.is_file() {
+        let deserializer: Arc<Deserializer> = injector.get();
+        let guild_params: GuildParams = deserializer
+            .deserialize(path)
+            .unwrap_or_else(|error| error.exit());
+        return ComparedGuild::Config(
+            awaiting_guild(guild_params, path).unwrap_or_else(|error| error.exit()),
+        );
+    }
+
+    if !side.chars().all(|char| char.is_ascii_digit()) {
+        panic!("No config file found at '{side}'.");
+    }
+    let guild_querier: Arc<dyn GuildQuerier> = injector.get();
+    ComparedGuild::Existing(
+        guild_querier
+            .get_guild_with(side, &GuildSections::saved())
+            .unwrap_or_else(|error| exit_with_error(error)),
+    )
+}
+
+fn guild_filter(filter: &GuildFilterArgs) -> GuildFilter {
+    GuildFilter {
+        roles: filter.roles,
+        categories: filter.categories.clone(),
+        channels_in: filter.channels_in.clone(),
+        exclude: filter.exclude.clone(),
+    }
+}
+
+pub fn show_permissions(
+    guild_id: Option<&str>,
+    input_file: Option<&str>,
+    roles: &[String],
+    channel: Option<&str>,
+) {
+    let injector = Injector::new(guild_id.map(str::to_string));
+    let service: Arc<ShowPermissions> = injector.get();
+
+    match (guild_id, input_file) {
+        (_, Some(input_file)) => service.run_for_config(Path::new(input_file), roles, channel),
+        (Some(guild_id), None) => {
+            let guild_querier: Arc<dyn GuildQuerier> = injector.get();
+            service.run_for_guild(&guild_querier.get_guild(guild_id), roles, channel)
+        }
+        (None, None) => panic!("A guild id or an input file is required."),
+    }
+}
+
+pub fn lint_config(input_file: &str, policy_file: Option<&str>) {
+    let injector = Injector::new(None);
+    let service: Arc<LintConfig> = injector.get();
+    service.run(Path::new(input_file), policy_file.map(Path::new));
 }
 
 pub fn list_guilds() {
@@ -26,8 +206,26 @@ pub fn list_guilds() {
     service.run();
 }
 
-pub fn compile_config(template_file: &str, vars_file: &str, output_file: &str, force: bool) {
+pub fn compile_config(
+    template_file: &str,
+    vars: &TemplateVarsArgs,
+    output_file: &str,
+    force: bool,
+) {
     let injector = Injector::new(None);
     let service: Arc<CompileConfig> = injector.get();
-    service.run(template_file, vars_file, output_file, force)
+    service.run(
+        template_file,
+        &vars.vars,
+        &vars.assignments,
+        vars.strict,
+        output_file,
+        force,
+    )
+}
+
+pub fn generate_schema(output_file: &str, force: bool) {
+    let injector = Injector::new(None);
+    let service: Arc<GenerateSchema> = injector.get();
+    service.run(output_file, force)
 }
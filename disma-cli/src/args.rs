@@ -15,11 +15,41 @@ pub enum Command {
     #[clap(name = "apply", about = "Apply guild changes from config")]
     Apply(ApplyArgs),
 
+    #[clap(name = "backup", about = "Save a snapshot of the full guild structure")]
+    Backup(BackupArgs),
+
+    #[clap(name = "restore", about = "Restore a guild from a snapshot")]
+    Restore(RestoreArgs),
+
+    #[clap(name = "lint", about = "Check guild config against a policy")]
+    Lint(LintArgs),
+
+    #[clap(
+        name = "clone",
+        about = "Apply the structure of a guild to another one"
+    )]
+    Clone(CloneArgs),
+
+    #[clap(
+        name = "compare",
+        about = "Show the differences between two guilds or configs"
+    )]
+    Compare(CompareArgs),
+
     #[clap(name = "list", about = "List guilds accessible by bot")]
     ListGuilds,
 
     #[clap(name = "compile", about = "Compile YAML config")]
     CompileConfig(CompileConfigArgs),
+
+    #[clap(name = "schema", about = "Generate JSON Schema of the config")]
+    GenerateSchema(GenerateSchemaArgs),
+
+    #[clap(
+        name = "permissions",
+        about = "Show effective permissions of roles in each channel"
+    )]
+    ShowPermissions(ShowPermissionsArgs),
 }
 
 #[derive(Debug, Args)]
@@ -30,21 +60,216 @@ pub struct SaveArgs {
     #[clap(short, long)]
     pub output: String,
 
+    #[clap(flatten)]
+    pub filter: GuildFilterArgs,
+
+    #[clap(long, help = "Factor repeated permissions into presets")]
+    pub presets: bool,
+
+    #[clap(
+        long,
+        help = "Update the existing YAML output in place, preserving its comments and ordering"
+    )]
+    pub merge: bool,
+
     #[clap(short, long, help = "Bypass user input confirmation")]
     pub force: bool,
 }
 
 #[derive(Debug, Args)]
-pub struct ApplyArgs {
+pub struct BackupArgs {
     #[clap(short, long, help = "Guild ID")]
     pub guild: String,
 
-    #[clap(short, long, help = "Input config file")]
-    pub input: String,
+    #[clap(short, long, help = "Directory of the snapshots")]
+    pub directory: String,
+}
+
+#[derive(Debug, Args)]
+pub struct RestoreArgs {
+    #[clap(short, long, help = "Snapshot file")]
+    pub snapshot: String,
+
+    #[clap(short, long, help = "Guild ID. Defaults to the guild of the snapshot")]
+    pub guild: Option<String>,
+
+    #[clap(long, help = "Do not execute any action")]
+    pub dry_run: bool,
+
+    #[clap(short, long, help = "Bypass user input confirmation")]
+    pub force: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct CloneArgs {
+    #[clap(long, help = "Source guild ID")]
+    pub from: String,
+
+    #[clap(long, help = "Target guild ID")]
+    pub to: String,
+
+    #[clap(flatten)]
+    pub filter: GuildFilterArgs,
+
+    #[clap(
+        long,
+        value_name = "OLD=NEW",
+        value_parser = parse_rename,
+        help = "Rename a role, category or channel, along with its references"
+    )]
+    pub rename: Vec<(String, String)>,
+
+    #[clap(long, help = "Do not execute any action")]
+    pub dry_run: bool,
+
+    #[clap(short, long, help = "Bypass user input confirmation")]
+    pub force: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct CompareArgs {
+    #[clap(help = "Guild ID or config file to compare from")]
+    pub source: String,
+
+    #[clap(help = "Guild ID or config file to compare to")]
+    pub target: String,
+
+    #[clap(long, help = "Print the differences as JSON")]
+    pub json: bool,
+}
+
+fn parse_rename(value: &str) -> Result<(String, String), String> {
+    match value.split_once('=') {
+        Some((old, new)) if !old.is_empty() && !new.is_empty() => {
+            Ok((old.to_string(), new.to_string()))
+        }
+        _ => Err(format!("invalid rename '{value}', expected OLD=NEW")),
+    }
+}
+
+#[derive(Debug, Args)]
+pub struct GuildFilterArgs {
+    #[clap(long, help = "Select all roles")]
+    pub roles: bool,
+
+    #[clap(
+        long,
+        value_name = "NAME",
+        num_args = 1..,
+        help = "Select only these categories"
+    )]
+    pub categories: Vec<String>,
+
+    #[clap(
+        long,
+        value_name = "CATEGORY",
+        help = "Select the channels of this category (and the category itself)"
+    )]
+    pub channels_in: Vec<String>,
+
+    #[clap(
+        long,
+        value_name = "GLOB",
+        help = "Leave out the roles, categories and channels matching this pattern"
+    )]
+    pub exclude: Vec<String>,
+}
+
+#[derive(Debug, Args)]
+pub struct ShowPermissionsArgs {
+    #[clap(
+        short,
+        long,
+        required_unless_present = "input",
+        help = "Guild ID, to use the existing guild"
+    )]
+    pub guild: Option<String>,
+
+    #[clap(
+        short,
+        long,
+        conflicts_with = "guild",
+        help = "Config file, to use the guild it describes"
+    )]
+    pub input: Option<String>,
+
+    #[clap(
+        short,
+        long = "role",
+        required = true,
+        help = "Role of the member. Can be repeated. @everyone is always included"
+    )]
+    pub roles: Vec<String>,
+
+    #[clap(short, long, help = "Show all permissions of this channel only")]
+    pub channel: Option<String>,
+}
+
+#[derive(Debug, Args)]
+pub struct ApplyArgs {
+    #[clap(
+        short,
+        long,
+        help = "Guild ID. Defaults to the guild of the environment, if any"
+    )]
+    pub guild: Option<String>,
+
+    #[clap(short, long, help = "Environment overlay to apply")]
+    pub env: Option<String>,
+
+    #[clap(
+        short,
+        long,
+        help = "Input config file",
+        required_unless_present = "template"
+    )]
+    pub input: Option<String>,
+
+    #[clap(
+        short,
+        long,
+        help = "Input config template file, compiled before being applied",
+        conflicts_with = "input"
+    )]
+    pub template: Option<String>,
+
+    #[clap(flatten)]
+    pub vars: TemplateVarsArgs,
+
+    #[clap(
+        short,
+        long,
+        help = "Policy file the config must follow. Changes are not applied on errors"
+    )]
+    pub policy: Option<String>,
 
     #[clap(long, help = "Do not execute any action")]
     pub dry_run: bool,
 
+    #[clap(
+        long,
+        help = "Show the effective permissions each role gains or loses in channels"
+    )]
+    pub impact: bool,
+
+    #[clap(short, long, help = "Bypass user input confirmation")]
+    pub force: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct LintArgs {
+    #[clap(short, long, help = "Input config file")]
+    pub input: String,
+
+    #[clap(short, long, help = "Policy file. Defaults to the built-in rules only")]
+    pub policy: Option<String>,
+}
+
+#[derive(Debug, Args)]
+pub struct GenerateSchemaArgs {
+    #[clap(short, long, help = "JSON Schema output path")]
+    pub output: String,
+
     #[clap(short, long, help = "Bypass user input confirmation")]
     pub force: bool,
 }
@@ -54,8 +279,8 @@ pub struct CompileConfigArgs {
     #[clap(short, long, help = "Input config template file")]
     pub template: String,
 
-    #[clap(short, long, help = "Template variables")]
-    pub vars: String,
+    #[clap(flatten)]
+    pub vars: TemplateVarsArgs,
 
     #[clap(short, long, help = "Compiled config output path")]
     pub output: String,
@@ -63,3 +288,23 @@ pub struct CompileConfigArgs {
     #[clap(short, long, help = "Bypass user input confirmation")]
     pub force: bool,
 }
+
+#[derive(Debug, Args)]
+pub struct TemplateVarsArgs {
+    #[clap(
+        short,
+        long,
+        help = "Template variables files, by increasing precedence. Overridden by DISMA_VAR_* environment variables"
+    )]
+    pub vars: Vec<String>,
+
+    #[clap(
+        long = "set",
+        value_name = "KEY=VALUE",
+        help = "Template variable, overriding the variables files and environment. Use KEY:=VALUE for a YAML value"
+    )]
+    pub assignments: Vec<String>,
+
+    #[clap(long, help = "Fail on undefined template variables")]
+    pub strict: bool,
+}
@@ -1,15 +1,22 @@
 use colored::Colorize;
 use disma::{
     api::{params::guild::GuildParams, ApplyChangesUseCase, ListChangesUseCase},
-    core::changes::Change,
+    core::changes::{
+        permission::{PermissionImpact, PermissionImpactKind},
+        Change,
+    },
+    guild::AwaitingGuild,
 };
 use std::{path::Path, sync::Arc};
 
+use super::lint_config::LintConfig;
 use crate::{
     infra::diff::formatter::DiffFormaterRef,
     utils::{
+        environment::Environments,
+        error::exit_with_error,
         input::{abort, ask_user_confirmation},
-        io::Deserializer,
+        io::{awaiting_guild, Deserializer},
     },
 };
 
@@ -18,6 +25,7 @@ pub struct ApplyChanges {
     apply_changes: Arc<ApplyChangesUseCase>,
     deserializer: Arc<Deserializer>,
     formatter: DiffFormaterRef,
+    lint_config: Arc<LintConfig>,
 }
 
 impl ApplyChanges {
@@ -26,27 +34,102 @@ impl ApplyChanges {
         apply_changes: Arc<ApplyChangesUseCase>,
         deserializer: Arc<Deserializer>,
         formatter: DiffFormaterRef,
+        lint_config: Arc<LintConfig>,
     ) -> Self {
         Self {
             list_changes,
             apply_changes,
             deserializer,
             formatter,
+            lint_config,
         }
     }
 
-    pub fn run(&self, guild_id: &str, file: &str, dry_run: bool, force: bool) {
-        let file_path = Path::new(file);
-
+    /// Applies the config of `file_path`. Its content is `content` when given
+    /// (ex: compiled from a template). When `policy_path` is given, the
+    /// config must follow its policy.
+    #[allow(clippy::too_many_arguments)]
+    pub fn run(
+        &self,
+        guild_id: &str,
+        file_path: &Path,
+        content: Option<&str>,
+        env: Option<&str>,
+        policy_path: Option<&Path>,
+        dry_run: bool,
+        impact: bool,
+        force: bool,
+    ) {
         println!();
-        println!(
-            "{}",
-            format!("➜ 🛠️  Loading guild config from '{}'...", &file).bold()
-        );
-        let guild_params = self.deserializer.deserialize::<GuildParams>(file_path);
+        match content {
+            Some(_) => println!("{}", "➜ 🛠️  Loading compiled guild config...".bold()),
+            None => println!(
+                "{}",
+                format!(
+                    "➜ 🛠️  Loading guild config from '{}'...",
+                    file_path.display()
+                )
+                .bold()
+            ),
+        }
+        let guild_params = match env {
+            Some(env) => {
+                let overlay_path = Environments::load(file_path, &self.deserializer)
+                    .overlay_path(env)
+                    .unwrap_or_else(|error| error.exit());
+                println!(
+                    "{}",
+                    format!(
+                        "➜ 🧩 Applying '{env}' overlay from '{}'...",
+                        overlay_path.display()
+                    )
+                    .bold()
+                );
+                self.deserializer.deserialize_with_overlay::<GuildParams>(
+                    file_path,
+                    content,
+                    &overlay_path,
+                )
+            }
+            None => match content {
+                Some(content) => self
+                    .deserializer
+                    .deserialize_content::<GuildParams>(content, file_path),
+                None => self.deserializer.deserialize::<GuildParams>(file_path),
+            },
+        }
+        .unwrap_or_else(|error| error.exit());
+        let awaiting_guild =
+            awaiting_guild(guild_params, file_path).unwrap_or_else(|error| error.exit());
+
+        if policy_path.is_some() && !self.lint_config.check(&awaiting_guild, policy_path) {
+            abort();
+        }
+
+        self.apply(guild_id, awaiting_guild, dry_run, impact, force);
+    }
 
+    /// Lists the changes needed for the guild to match `awaiting_guild` (ex:
+    /// a config), then applies them once confirmed.
+    pub fn apply(
+        &self,
+        guild_id: &str,
+        awaiting_guild: impl Into<AwaitingGuild> + Clone,
+        dry_run: bool,
+        impact: bool,
+        force: bool,
+    ) {
         println!("{}", "➜ 🔎 Looking for changes...".bold());
-        let changes = self.list_changes.execute(guild_id, guild_params.clone());
+        let (changes, impacts) = match impact {
+            true => self
+                .list_changes
+                .execute_with_impacts(guild_id, awaiting_guild.clone()),
+            false => self
+                .list_changes
+                .execute(guild_id, awaiting_guild.clone())
+                .map(|changes| (changes, Vec::new())),
+        }
+        .unwrap_or_else(|error| exit_with_error(error));
 
         if changes.is_empty() {
             println!("{}", "➜ ✨ No change to be applied.".bold());
@@ -55,25 +138,18 @@ impl ApplyChanges {
 
         println!("{}", "➜ 📜 Found the following changes :".bold());
 
-        for change in changes {
-            match change {
-                Change::Create(entity, name) => {
-                    println!("\n● 🆕 Adding {:?} {}", entity, name.bold().on_black())
-                }
-                Change::Delete(entity, name) => {
-                    println!("\n● 🗑️  Removing {:?} {}", entity, name.bold().on_black())
-                }
-                Change::Update(entity, name, diffs) => {
-                    println!(
-                        "\n● 🔄 Updating {:?} {} with diffs:",
-                        entity,
-                        name.bold().on_black()
-                    );
-                    for diff in diffs {
-                        print!("{}", self.formatter.format(&diff));
-                    }
-                }
-            }
+        for change in changes.iter() {
+            print!("{}", self.formatter.format_change(change));
+        }
+
+        if impact {
+            self.print_impacts(&impacts);
+        }
+
+        // Only warnings were listed
+        if !changes.iter().any(Change::is_change) {
+            println!("{}", "➜ ✨ No change to be applied.".bold());
+            return;
         }
 
         if dry_run {
@@ -85,6 +161,96 @@ impl ApplyChanges {
         }
 
         println!("{}", "➜ 🚀 Applying changes...\n".bold());
-        self.apply_changes.execute(guild_id, guild_params);
+        self.apply_changes
+            .execute(guild_id, awaiting_guild)
+            .unwrap_or_else(|error| exit_with_error(error));
+    }
+
+    fn print_impacts(&self, impacts: &[PermissionImpact]) {
+        if impacts.is_empty() {
+            println!(
+                "\n{}",
+                "➜ 🔐 No effective permission is gained or lost.".bold()
+            );
+            return;
+        }
+
+        println!("\n{}", "➜ 🔐 Effective permissions impact :".bold());
+        for impact in impacts {
+            println!("{}", format_impact(impact));
+        }
+    }
+}
+
+fn format_impact(impact: &PermissionImpact) -> String {
+    let count = impact.channels.len();
+    let channels = match count {
+        1 => "1 channel".to_string(),
+        _ => format!("{count} channels"),
+    };
+
+    match impact.kind {
+        PermissionImpactKind::Gain => format!(
+            " + Role {} gains {} in {channels}",
+            impact.role, impact.permission
+        )
+        .green()
+        .to_string(),
+        PermissionImpactKind::Loss => format!(
+            " - Role {} loses {} in {channels}",
+            impact.role, impact.permission
+        )
+        .red()
+        .to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use colored::Colorize;
+    use disma::{
+        core::changes::permission::{PermissionImpact, PermissionImpactKind},
+        permission::Permission,
+    };
+
+    use super::format_impact;
+
+    #[test]
+    fn can_format_losses() {
+        let impact = PermissionImpact {
+            role: "Member".to_string(),
+            kind: PermissionImpactKind::Loss,
+            permission: Permission::SEND_MESSAGES,
+            channels: vec![
+                "a".to_string(),
+                "b".to_string(),
+                "c".to_string(),
+                "d".to_string(),
+            ],
+        };
+
+        assert_eq!(
+            format_impact(&impact),
+            " - Role Member loses SEND_MESSAGES in 4 channels"
+                .red()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn can_format_gains_in_one_channel() {
+        let impact = PermissionImpact {
+            role: "Member".to_string(),
+            kind: PermissionImpactKind::Gain,
+            permission: Permission::VIEW_CHANNEL,
+            channels: vec!["a".to_string()],
+        };
+
+        assert_eq!(
+            format_impact(&impact),
+            " + Role Member gains VIEW_CHANNEL in 1 channel"
+                .green()
+                .to_string()
+        );
     }
 }
@@ -0,0 +1,102 @@
+use colored::Colorize;
+use disma::{
+    api::params::snapshot::GuildSnapshot,
+    guild::{GuildQuerier, GuildSections},
+};
+use std::{
+    fs,
+    path::Path,
+    sync::Arc,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::utils::{error::exit_with_error, io::Serializer};
+
+pub struct BackupGuild {
+    guild_querier: Arc<dyn GuildQuerier>,
+    serializer: Arc<Serializer>,
+}
+
+impl BackupGuild {
+    pub fn new(guild_querier: Arc<dyn GuildQuerier>, serializer: Arc<Serializer>) -> Self {
+        Self {
+            guild_querier,
+            serializer,
+        }
+    }
+
+    /// Saves a snapshot of the guild in `directory`, named after the guild
+    /// and the time it was taken.
+    pub fn run(&self, guild_id: &str, directory: &str) {
+        let created_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("System time is before the Unix epoch.")
+            .as_secs();
+        let file_path =
+            Path::new(directory).join(format!("{guild_id}-{}.json", format_timestamp(created_at)));
+
+        println!();
+        println!(
+            "{}",
+            format!("➜ 📸 Taking a snapshot of guild {guild_id}...").bold()
+        );
+        let guild = self
+            .guild_querier
+            .get_guild_with(guild_id, &GuildSections::saved())
+            .unwrap_or_else(|error| exit_with_error(error));
+        let snapshot = GuildSnapshot::new(guild_id, created_at, &guild);
+
+        println!(
+            "{}",
+            format!("➜ 💾 Saving snapshot to '{}'...", file_path.display()).bold()
+        );
+        if let Err(error) = fs::create_dir_all(directory) {
+            exit_with_error(format!("Could not create directory '{directory}': {error}"));
+        }
+        self.serializer.serialize(&snapshot, &file_path);
+
+        println!("{}", "➜ ✨ DONE.".bold());
+    }
+}
+
+/// UTC time of `timestamp` (seconds since the Unix epoch), as
+/// `YYYYMMDD-HHMMSS`, for snapshots to be sorted by name.
+fn format_timestamp(timestamp: u64) -> String {
+    let days = (timestamp / 86400) as i64;
+    let seconds = timestamp % 86400;
+
+    // Civil date from days since the Unix epoch (Howard Hinnant's algorithm)
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let day_of_era = z.rem_euclid(146097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{year:04}{month:02}{day:02}-{:02}{:02}{:02}",
+        seconds / 3600,
+        seconds % 3600 / 60,
+        seconds % 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::format_timestamp;
+
+    #[test]
+    fn can_format_timestamps() {
+        assert_eq!(format_timestamp(0), "19700101-000000");
+        assert_eq!(format_timestamp(951827696), "20000229-123456");
+        assert_eq!(format_timestamp(1792461600), "20261020-020000");
+    }
+}
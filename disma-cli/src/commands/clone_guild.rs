@@ -0,0 +1,55 @@
+use colored::Colorize;
+use disma::{
+    api::params::guild::GuildParams,
+    guild::{GuildFilter, GuildQuerier, GuildSections},
+};
+use std::{collections::BTreeMap, sync::Arc};
+
+use super::apply_changes::ApplyChanges;
+use crate::utils::error::exit_with_error;
+
+pub struct CloneGuild {
+    querier: Arc<dyn GuildQuerier>,
+    apply_changes: Arc<ApplyChanges>,
+}
+
+impl CloneGuild {
+    pub fn new(querier: Arc<dyn GuildQuerier>, apply_changes: Arc<ApplyChanges>) -> Self {
+        Self {
+            querier,
+            apply_changes,
+        }
+    }
+
+    /// Applies the structure of the guild `source_guild_id` (or the part of
+    /// it selected by `filter`) to the guild `target_guild_id`, renaming the
+    /// entities named as keys of `names`.
+    pub fn run(
+        &self,
+        source_guild_id: &str,
+        target_guild_id: &str,
+        filter: &GuildFilter,
+        names: &BTreeMap<String, String>,
+        dry_run: bool,
+        force: bool,
+    ) {
+        println!();
+        println!(
+            "{}",
+            format!("➜ 📥 Reading source guild {source_guild_id}...").bold()
+        );
+        let source_guild = self
+            .querier
+            .get_guild_with(source_guild_id, &GuildSections::saved())
+            .unwrap_or_else(|error| exit_with_error(error));
+        let source_guild = filter.apply(&source_guild);
+        let guild_params = GuildParams::from(&source_guild).rename(names);
+
+        println!(
+            "{}",
+            format!("➜ 📤 Cloning into guild {target_guild_id}...").bold()
+        );
+        self.apply_changes
+            .apply(target_guild_id, guild_params, dry_run, false, force);
+    }
+}
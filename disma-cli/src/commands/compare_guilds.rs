@@ -0,0 +1,122 @@
+use colored::Colorize;
+use disma::{
+    api::{CompareGuildsUseCase, ComparedGuild},
+    core::{changes::Change, diffs::Diff},
+};
+use serde_json::{json, Value};
+use std::sync::Arc;
+
+use crate::infra::diff::formatter::DiffFormaterRef;
+
+pub struct CompareGuilds {
+    compare_guilds: Arc<CompareGuildsUseCase>,
+    formatter: DiffFormaterRef,
+}
+
+impl CompareGuilds {
+    pub fn new(compare_guilds: Arc<CompareGuildsUseCase>, formatter: DiffFormaterRef) -> Self {
+        Self {
+            compare_guilds,
+            formatter,
+        }
+    }
+
+    /// Shows the changes needed for `source` to match `target`. With `json`,
+    /// only the changes are printed, as a JSON array.
+    pub fn run(&self, source: ComparedGuild, target: ComparedGuild, json: bool) {
+        let changes = self.compare_guilds.execute(source, target);
+
+        if json {
+            let changes: Vec<Value> = changes.iter().map(change_to_json).collect();
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&changes).expect("Could not serialize changes.")
+            );
+            return;
+        }
+
+        println!();
+        if changes.is_empty() {
+            println!("{}", "➜ ✨ No difference found.".bold());
+            return;
+        }
+
+        println!("{}", "➜ 📜 Found the following differences :".bold());
+        for change in changes {
+            print!("{}", self.formatter.format_change(&change));
+        }
+    }
+}
+
+fn change_to_json(change: &Change) -> Value {
+    match change {
+        Change::Create(entity, name) => json!({
+            "action": "create",
+            "entity": format!("{entity:?}"),
+            "name": name,
+        }),
+        Change::Delete(entity, name) => json!({
+            "action": "delete",
+            "entity": format!("{entity:?}"),
+            "name": name,
+        }),
+        Change::Update(entity, name, diffs) => json!({
+            "action": "update",
+            "entity": format!("{entity:?}"),
+            "name": name,
+            "diffs": diffs.iter().map(diff_to_json).collect::<Vec<Value>>(),
+        }),
+    }
+}
+
+fn diff_to_json(diff: &Diff) -> Value {
+    match diff {
+        Diff::Add(value) => json!({ "add": value.text() }),
+        Diff::Remove(value) => json!({ "remove": value.text() }),
+        Diff::Replace(origin, target) => json!({
+            "replace": origin.text(),
+            "with": target.text(),
+        }),
+        Diff::Warning(message) => json!({ "warning": message }),
+        Diff::Update(field, diffs) => json!({
+            "update": field,
+            "diffs": diffs.iter().map(diff_to_json).collect::<Vec<Value>>(),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use disma::core::{
+        changes::{Change, ChangeEntity},
+        diffs::Diff,
+    };
+    use serde_json::json;
+
+    use super::change_to_json;
+
+    #[test]
+    fn can_convert_updates_to_json() {
+        let change = Change::Update(
+            ChangeEntity::Channel,
+            ":general (TEXT)".to_string(),
+            vec![Diff::Update(
+                "topic".to_string(),
+                vec![Diff::Remove("Hello".into()), Diff::Add("Welcome".into())],
+            )],
+        );
+
+        assert_eq!(
+            change_to_json(&change),
+            json!({
+                "action": "update",
+                "entity": "Channel",
+                "name": ":general (TEXT)",
+                "diffs": [{
+                    "update": "topic",
+                    "diffs": [{ "remove": "Hello" }, { "add": "Welcome" }],
+                }],
+            })
+        );
+    }
+}
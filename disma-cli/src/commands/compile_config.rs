@@ -1,34 +1,35 @@
 use colored::Colorize;
 use std::{fs, path::Path, sync::Arc};
 
-use handlebars::Handlebars;
-use serde_yaml::Value;
-
 use crate::utils::{
     input::{abort, ask_user_confirmation},
-    io::Deserializer,
+    template::TemplateCompiler,
 };
 
 pub struct CompileConfig {
-    deserializer: Arc<Deserializer>,
+    compiler: Arc<TemplateCompiler>,
 }
 
 impl CompileConfig {
-    pub fn new(deserializer: Arc<Deserializer>) -> Self {
-        Self { deserializer }
+    pub fn new(compiler: Arc<TemplateCompiler>) -> Self {
+        Self { compiler }
     }
 
-    pub fn run(&self, template_file: &str, vars_file: &str, output_file: &str, force: bool) {
-        let template = fs::read_to_string(template_file).unwrap();
-        // TODO context should probably be a Hash instead of a serde_yaml::Value
-        let context: Value = self.deserializer.deserialize(Path::new(vars_file));
-
+    pub fn run(
+        &self,
+        template_file: &str,
+        vars_files: &[String],
+        assignments: &[String],
+        strict: bool,
+        output_file: &str,
+        force: bool,
+    ) {
         println!();
         println!("{}", "➜ ⚙️ Compiling guild config...".bold());
-        let mut renderer = Handlebars::new();
-        renderer.register_escape_fn(|s| s.to_string()); // preventing default HTML escaping
-
-        let rendered = renderer.render_template(&template, &context).unwrap();
+        let rendered = self
+            .compiler
+            .compile(Path::new(template_file), vars_files, assignments, strict)
+            .unwrap_or_else(|error| error.exit());
 
         let output_path = Path::new(output_file);
         println!(
@@ -0,0 +1,67 @@
+use colored::Colorize;
+use std::{path::Path, sync::Arc};
+
+use disma::api::params::guild::GuildParams;
+
+use crate::utils::{
+    input::{abort, ask_user_confirmation},
+    io::Serializer,
+};
+
+pub struct GenerateSchema {
+    serializer: Arc<Serializer>,
+}
+
+impl GenerateSchema {
+    pub fn new(serializer: Arc<Serializer>) -> Self {
+        Self { serializer }
+    }
+
+    pub fn run(&self, output_file: &str, force: bool) {
+        let output_path = Path::new(output_file);
+        println!();
+        println!(
+            "{}",
+            format!(
+                "➜ 💾 Saving config JSON Schema to '{}'...",
+                output_path.as_os_str().to_str().unwrap()
+            )
+            .bold()
+        );
+
+        if !force && output_path.exists() {
+            println!(
+                "{}",
+                format!("➜ ❗ A file named '{output_file}' already exists.").bold()
+            );
+
+            if !ask_user_confirmation("Do you still want to proceeed?") {
+                abort();
+            }
+        }
+
+        self.serializer
+            .serialize(&GuildParams::json_schema(), output_path);
+
+        println!("{}", "➜ ✨ DONE.".bold());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use disma::api::params::guild::GuildParams;
+    use serde_json::Value;
+
+    #[test]
+    fn checked_in_schema_is_up_to_date() {
+        let checked_in_schema: Value =
+            serde_json::from_str(include_str!("../../../schema.json")).unwrap();
+
+        let schema = serde_json::to_value(GuildParams::json_schema()).unwrap();
+
+        assert!(
+            checked_in_schema == schema,
+            "schema.json is outdated. Run `disma schema --output schema.json --force` to update it."
+        );
+    }
+}
@@ -0,0 +1,107 @@
+use colored::Colorize;
+use disma::{
+    api::params::{guild::GuildParams, policy::PolicyParams},
+    guild::AwaitingGuild,
+    policy::{Policy, PolicySeverity, PolicyViolation},
+};
+use std::{path::Path, process::exit, sync::Arc};
+
+use crate::utils::io::{awaiting_guild, Deserializer};
+
+pub struct LintConfig {
+    deserializer: Arc<Deserializer>,
+}
+
+impl LintConfig {
+    pub fn new(deserializer: Arc<Deserializer>) -> Self {
+        Self { deserializer }
+    }
+
+    /// Lints the config of `file_path` against the policy of `policy_path`
+    /// (or the built-in rules only), exiting with an error on violations.
+    pub fn run(&self, file_path: &Path, policy_path: Option<&Path>) {
+        println!();
+        println!(
+            "{}",
+            format!(
+                "➜ 🛠️  Loading guild config from '{}'...",
+                file_path.display()
+            )
+            .bold()
+        );
+        let guild_params: GuildParams = self
+            .deserializer
+            .deserialize(file_path)
+            .unwrap_or_else(|error| error.exit());
+        let guild = awaiting_guild(guild_params, file_path).unwrap_or_else(|error| error.exit());
+
+        if !self.check(&guild, policy_path) {
+            exit(1);
+        }
+    }
+
+    /// Prints the policy violations of `guild`. Returns `false` when some are
+    /// errors.
+    pub fn check(&self, guild: &AwaitingGuild, policy_path: Option<&Path>) -> bool {
+        let policy_params = match policy_path {
+            Some(policy_path) => {
+                println!(
+                    "{}",
+                    format!("➜ 📏 Loading policy from '{}'...", policy_path.display()).bold()
+                );
+                self.deserializer
+                    .deserialize::<PolicyParams>(policy_path)
+                    .unwrap_or_else(|error| error.exit())
+            }
+            None => PolicyParams::default(),
+        };
+        let policy: Policy = policy_params.into();
+
+        println!("{}", "➜ 🔎 Checking policy rules...".bold());
+        let violations = policy.evaluate(guild);
+
+        if violations.is_empty() {
+            println!("{}", "➜ ✨ No policy violation.".bold());
+            return true;
+        }
+
+        for violation in &violations {
+            println!("{}", format_violation(violation));
+        }
+
+        let errors = violations
+            .iter()
+            .filter(|violation| violation.severity == PolicySeverity::Error)
+            .count();
+        let warnings = violations.len() - errors;
+        println!(
+            "{}",
+            format!(
+                "➜ 📏 Found {} and {}.",
+                plural(errors, "error"),
+                plural(warnings, "warning")
+            )
+            .bold()
+        );
+
+        errors == 0
+    }
+}
+
+fn format_violation(violation: &PolicyViolation) -> String {
+    match violation.severity {
+        PolicySeverity::Error => format!(" ✖ [{}] {}", violation.rule, violation.message)
+            .red()
+            .to_string(),
+        PolicySeverity::Warning => format!(" ! [{}] {}", violation.rule, violation.message)
+            .yellow()
+            .to_string(),
+    }
+}
+
+fn plural(count: usize, word: &str) -> String {
+    match count {
+        1 => format!("1 {word}"),
+        _ => format!("{count} {word}s"),
+    }
+}
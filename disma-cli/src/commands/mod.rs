@@ -1,4 +1,11 @@
 pub mod apply_changes;
+pub mod backup_guild;
+pub mod clone_guild;
+pub mod compare_guilds;
 pub mod compile_config;
+pub mod generate_schema;
+pub mod lint_config;
 pub mod list_guilds;
+pub mod restore_guild;
 pub mod save_guild;
+pub mod show_permissions;
@@ -0,0 +1,61 @@
+use colored::Colorize;
+use disma::{
+    api::params::snapshot::{GuildSnapshot, GUILD_SNAPSHOT_VERSION},
+    guild::AwaitingGuild,
+};
+use std::{process::exit, sync::Arc};
+
+use super::apply_changes::ApplyChanges;
+use crate::utils::error::exit_with_error;
+
+pub struct RestoreGuild {
+    apply_changes: Arc<ApplyChanges>,
+}
+
+impl RestoreGuild {
+    pub fn new(apply_changes: Arc<ApplyChanges>) -> Self {
+        Self { apply_changes }
+    }
+
+    /// Recreates the entities of `snapshot` missing from the guild, and
+    /// reverts the ones that changed since, renamed ones included. Newer
+    /// entities are kept.
+    pub fn run(
+        &self,
+        guild_id: &str,
+        snapshot_file: &str,
+        snapshot: GuildSnapshot,
+        dry_run: bool,
+        force: bool,
+    ) {
+        println!();
+        println!(
+            "{}",
+            format!("➜ 📥 Loading snapshot from '{snapshot_file}'...").bold()
+        );
+        if snapshot.version > GUILD_SNAPSHOT_VERSION {
+            println!(
+                "{}",
+                format!(
+                    "➜ ❌ Snapshot version {} is not supported, the latest being {GUILD_SNAPSHOT_VERSION}. Please update disma.",
+                    snapshot.version
+                )
+                .bold()
+            );
+            exit(1);
+        }
+
+        println!(
+            "{}",
+            format!(
+                "➜ ⏪ Restoring guild {guild_id} from snapshot of guild {}...",
+                snapshot.guild_id
+            )
+            .bold()
+        );
+        let awaiting_guild =
+            AwaitingGuild::try_from(snapshot).unwrap_or_else(|error| exit_with_error(error));
+        self.apply_changes
+            .apply(guild_id, awaiting_guild, dry_run, false, force);
+    }
+}
@@ -2,10 +2,14 @@ use colored::Colorize;
 use std::{path::Path, sync::Arc};
 
 use crate::utils::{
+    error::exit_with_error,
     input::{abort, ask_user_confirmation},
     io::Serializer,
 };
-use disma::{api::params::guild::GuildParams, guild::GuildQuerier};
+use disma::{
+    api::params::guild::GuildParams,
+    guild::{GuildFilter, GuildQuerier, GuildSections},
+};
 
 pub struct SaveExistingGuild {
     guild_querier: Arc<dyn GuildQuerier>,
@@ -20,10 +24,25 @@ impl SaveExistingGuild {
         }
     }
 
-    pub fn run(&self, guild_id: &str, file: &str, force: bool) {
-        let guild = self.guild_querier.get_guild(guild_id);
+    pub fn run(
+        &self,
+        guild_id: &str,
+        file: &str,
+        filter: &GuildFilter,
+        presets: bool,
+        merge: bool,
+        force: bool,
+    ) {
+        let guild = self
+            .guild_querier
+            .get_guild_with(guild_id, &GuildSections::saved())
+            .unwrap_or_else(|error| exit_with_error(error));
+        let guild = filter.apply(&guild);
 
-        let guild_params = GuildParams::from(&guild);
+        let mut guild_params = GuildParams::from(&guild);
+        if presets {
+            guild_params = guild_params.factor_presets();
+        }
         let file_path = Path::new(file);
 
         println!();
@@ -32,6 +51,15 @@ impl SaveExistingGuild {
             format!("➜ 💾 Saving current guild config to '{file}'...").bold()
         );
 
+        if merge && file_path.exists() {
+            self.serializer
+                .serialize_merged(&guild_params, file_path)
+                .unwrap_or_else(|error| error.exit());
+
+            println!("{}", "➜ ✨ DONE.".bold());
+            return;
+        }
+
         if !force && file_path.exists() {
             println!(
                 "{}",
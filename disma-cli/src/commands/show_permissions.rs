@@ -0,0 +1,115 @@
+use colored::Colorize;
+use disma::{
+    api::params::guild::GuildParams,
+    guild::ExistingGuild,
+    permission::{ChannelPermissions, EffectivePermissions, PermissionsList},
+};
+use std::{path::Path, process::exit, sync::Arc};
+
+use crate::utils::{
+    error::ConfigError,
+    io::{awaiting_guild, Deserializer},
+};
+
+pub struct ShowPermissions {
+    deserializer: Arc<Deserializer>,
+}
+
+impl ShowPermissions {
+    pub fn new(deserializer: Arc<Deserializer>) -> Self {
+        Self { deserializer }
+    }
+
+    /// Shows the effective permissions of the roles in the existing guild.
+    pub fn run_for_guild(&self, guild: &ExistingGuild, roles: &[String], channel: Option<&str>) {
+        let permissions = EffectivePermissions::new(guild.roles(), roles)
+            .map(|permissions| permissions.base())
+            .and_then(|base| Ok((base, guild.effective_permissions(roles)?)));
+
+        match permissions {
+            Ok((base, channels)) => self.show(roles, base, channels, channel),
+            Err(error) => {
+                println!("{}", format!("➜ ❌ {error}").bold());
+                exit(1);
+            }
+        }
+    }
+
+    /// Shows the effective permissions of the roles in the guild described by
+    /// the config of `file_path`.
+    pub fn run_for_config(&self, file_path: &Path, roles: &[String], channel: Option<&str>) {
+        let guild_params: GuildParams = self
+            .deserializer
+            .deserialize(file_path)
+            .unwrap_or_else(|error| error.exit());
+        let guild = awaiting_guild(guild_params, file_path).unwrap_or_else(|error| error.exit());
+        let permissions = EffectivePermissions::new(&guild.roles.items, roles)
+            .map(|permissions| permissions.base())
+            .and_then(|base| Ok((base, guild.effective_permissions(roles)?)));
+
+        match permissions {
+            Ok((base, channels)) => self.show(roles, base, channels, channel),
+            Err(error) => ConfigError::new(file_path, error).exit(),
+        }
+    }
+
+    fn show(
+        &self,
+        roles: &[String],
+        base: PermissionsList,
+        channels: Vec<ChannelPermissions>,
+        channel: Option<&str>,
+    ) {
+        println!();
+        println!(
+            "{}",
+            format!("➜ 🔐 Effective permissions of {}...", roles.join(", ")).bold()
+        );
+
+        match channel {
+            Some(channel) => {
+                let channels: Vec<ChannelPermissions> = channels
+                    .into_iter()
+                    .filter(|permissions| permissions.channel.channel_name() == channel)
+                    .collect();
+
+                if channels.is_empty() {
+                    panic!("No channel found for name {channel}.");
+                }
+
+                for permissions in channels {
+                    println!("   {}:", permissions.channel);
+                    for permission in PermissionsList::all().to_list() {
+                        match permissions.permissions.contains(permission.clone()) {
+                            true => println!("{}", format!("   ✓ {permission}").green()),
+                            false => println!("{}", format!("   ✗ {permission}").red()),
+                        }
+                    }
+                }
+            }
+            None => {
+                println!("   (guild): {}", format_list(&base));
+                for permissions in channels {
+                    println!(
+                        "   {}: {}",
+                        permissions.channel,
+                        format_list(&permissions.permissions)
+                    );
+                }
+            }
+        }
+    }
+}
+
+fn format_list(permissions: &PermissionsList) -> String {
+    let permissions: Vec<String> = permissions
+        .to_list()
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+
+    match permissions.is_empty() {
+        true => "-".to_string(),
+        false => permissions.join(", "),
+    }
+}
@@ -1,32 +1,80 @@
-use std::sync::Arc;
+use std::{cmp::max, env, sync::Arc};
 
-use colored::Colorize;
-use disma::core::diffs::Diff;
+use colored::{Color, Colorize};
+use disma::core::{
+    changes::Change,
+    diffs::{Diff, DiffValue},
+};
 
 #[derive(Default)]
-pub struct DiffFormater {}
+pub struct DiffFormater {
+    /// Whether the terminal supports 24-bit colors, to show color swatches.
+    truecolor: bool,
+}
 pub type DiffFormaterRef = Arc<DiffFormater>;
 
 impl DiffFormater {
     pub fn new() -> Self {
-        Self {}
+        Self {
+            truecolor: env::var("COLORTERM")
+                .map(|colorterm| colorterm == "truecolor" || colorterm == "24bit")
+                .unwrap_or(false),
+        }
     }
 
     pub fn format(&self, diff: &Diff) -> String {
         self.format_with_indent(0, diff)
     }
 
+    pub fn format_change(&self, change: &Change) -> String {
+        match change {
+            Change::Create(entity, name) => {
+                format!("\n● 🆕 Adding {:?} {}\n", entity, name.bold().on_black())
+            }
+            Change::Delete(entity, name) => {
+                format!("\n● 🗑️  Removing {:?} {}\n", entity, name.bold().on_black())
+            }
+            Change::Update(entity, name, diffs) => {
+                let mut text = format!(
+                    "\n● 🔄 Updating {:?} {} with diffs:\n",
+                    entity,
+                    name.bold().on_black()
+                );
+                for diff in diffs {
+                    text.push_str(&self.format(diff));
+                }
+                text
+            }
+        }
+    }
+
     fn format_with_indent(&self, indent: usize, diff: &Diff) -> String {
         let mut text = String::new();
 
         match diff {
-            Diff::Add(desc) => {
-                let string = self.indent_lines(" + ", indent, desc).green().to_string();
-                text.push_str(&string);
+            Diff::Add(value) => {
+                text.push_str(&self.format_value(" + ", indent, value, Color::Green));
             }
-            Diff::Remove(desc) => {
-                let string = self.indent_lines(" - ", indent, desc).red().to_string();
-                text.push_str(&string);
+            Diff::Remove(value) => {
+                text.push_str(&self.format_value(" - ", indent, value, Color::Red));
+            }
+            Diff::Replace(DiffValue::Multiline(origin), DiffValue::Multiline(target)) => {
+                for line in diff_lines(origin, target) {
+                    let string = match line {
+                        LineDiff::Same(line) => self.indent_lines("   ", indent, line),
+                        LineDiff::Remove(line) => {
+                            self.indent_lines(" - ", indent, line).red().to_string()
+                        }
+                        LineDiff::Add(line) => {
+                            self.indent_lines(" + ", indent, line).green().to_string()
+                        }
+                    };
+                    text.push_str(&string);
+                }
+            }
+            Diff::Replace(origin, target) => {
+                text.push_str(&self.format_value(" - ", indent, origin, Color::Red));
+                text.push_str(&self.format_value(" + ", indent, target, Color::Green));
             }
             Diff::Update(desc, diffs) => {
                 text.push_str(&self.indent_lines("   ", indent, &format!("{desc}:")));
@@ -34,11 +82,47 @@ impl DiffFormater {
                     text.push_str(&self.format_with_indent(indent + 2, diff));
                 }
             }
+            Diff::Warning(desc) => {
+                let string = self.indent_lines(" ! ", indent, desc).yellow().to_string();
+                text.push_str(&string);
+            }
         }
 
         text
     }
 
+    fn format_value(&self, prefix: &str, indent: usize, value: &DiffValue, color: Color) -> String {
+        let swatch = match value {
+            DiffValue::Color(hex) => self.swatch(hex),
+            _ => None,
+        };
+
+        match swatch {
+            Some(swatch) => format!(
+                "{} {swatch}\n",
+                format!("{prefix}{}{value}", " ".repeat(indent)).color(color)
+            ),
+            None => self
+                .indent_lines(prefix, indent, value.text())
+                .color(color)
+                .to_string(),
+        }
+    }
+
+    /// A block of the color of `hex`, if the terminal can show it.
+    fn swatch(&self, hex: &str) -> Option<String> {
+        if !self.truecolor || hex.len() != 6 {
+            return None;
+        }
+
+        let component = |index: usize| u8::from_str_radix(hex.get(index..index + 2)?, 16).ok();
+        Some(
+            "██"
+                .truecolor(component(0)?, component(2)?, component(4)?)
+                .to_string(),
+        )
+    }
+
     fn indent_lines(&self, prefix: &str, indent: usize, text: &str) -> String {
         let indent_text = " ".repeat(indent);
 
@@ -48,10 +132,57 @@ impl DiffFormater {
     }
 }
 
+enum LineDiff<'a> {
+    Same(&'a str),
+    Remove(&'a str),
+    Add(&'a str),
+}
+
+/// Line-level diff of two texts, from their longest common subsequence of
+/// lines.
+fn diff_lines<'a>(origin: &'a str, target: &'a str) -> Vec<LineDiff<'a>> {
+    let origin: Vec<&str> = origin.split('\n').collect();
+    let target: Vec<&str> = target.split('\n').collect();
+
+    // Length of the longest common subsequence of origin[i..] and target[j..]
+    let mut lengths = vec![vec![0; target.len() + 1]; origin.len() + 1];
+    for i in (0..origin.len()).rev() {
+        for j in (0..target.len()).rev() {
+            lengths[i][j] = match origin[i] == target[j] {
+                true => lengths[i + 1][j + 1] + 1,
+                false => max(lengths[i + 1][j], lengths[i][j + 1]),
+            };
+        }
+    }
+
+    let mut diffs = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < origin.len() && j < target.len() {
+        if origin[i] == target[j] {
+            diffs.push(LineDiff::Same(origin[i]));
+            i += 1;
+            j += 1;
+        } else if lengths[i + 1][j] >= lengths[i][j + 1] {
+            diffs.push(LineDiff::Remove(origin[i]));
+            i += 1;
+        } else {
+            diffs.push(LineDiff::Add(target[j]));
+            j += 1;
+        }
+    }
+    diffs.extend(origin[i..].iter().map(|line| LineDiff::Remove(line)));
+    diffs.extend(target[j..].iter().map(|line| LineDiff::Add(line)));
+
+    diffs
+}
+
 #[cfg(test)]
 mod tests {
     use colored::Colorize;
-    use disma::core::diffs::Diff;
+    use disma::core::{
+        changes::{Change, ChangeEntity},
+        diffs::{Diff, DiffValue},
+    };
 
     use super::DiffFormater;
 
@@ -85,6 +216,16 @@ mod tests {
         assert_eq!(formatted, " - Something\n".red().to_string());
     }
 
+    #[test]
+    fn can_format_warnings() {
+        let formatter = DiffFormater::new();
+        let diff = Diff::Warning("Something".into());
+
+        let formatted = formatter.format(&diff);
+
+        assert_eq!(formatted, " ! Something\n".yellow().to_string());
+    }
+
     #[test]
     fn can_format_removals_multiline() {
         let formatter = DiffFormater::new();
@@ -163,4 +304,73 @@ mod tests {
         );
         assert_eq!(formatted, expected_text);
     }
+
+    #[test]
+    fn can_format_change_updates() {
+        let formatter = DiffFormater::new();
+        let change = Change::Update(
+            ChangeEntity::Role,
+            "Member".into(),
+            vec![Diff::Add("Something".into())],
+        );
+
+        let formatted = formatter.format_change(&change);
+
+        let expected_text = format!(
+            "\n● 🔄 Updating Role {} with diffs:\n{}",
+            "Member".bold().on_black(),
+            " + Something\n".green()
+        );
+        assert_eq!(formatted, expected_text);
+    }
+
+    #[test]
+    fn can_format_multiline_replacements_by_line() {
+        let formatter = DiffFormater::new();
+        let diff = Diff::Replace(
+            DiffValue::Multiline("Welcome!\nBe nice\nHave fun".into()),
+            DiffValue::Multiline("Welcome!\nBe kind\nHave fun".into()),
+        );
+
+        let formatted = formatter.format(&diff);
+
+        let expected_text = format!(
+            "{}{}{}{}",
+            "   Welcome!\n",
+            " - Be nice\n".red(),
+            " + Be kind\n".green(),
+            "   Have fun\n"
+        );
+        assert_eq!(formatted, expected_text);
+    }
+
+    #[test]
+    fn can_format_color_replacements_with_swatches() {
+        let formatter = DiffFormater { truecolor: true };
+        let diff = Diff::Replace(
+            DiffValue::Color("ff0000".into()),
+            DiffValue::Color("00ff00".into()),
+        );
+
+        let formatted = formatter.format(&diff);
+
+        let expected_text = format!(
+            "{} {}\n{} {}\n",
+            " - ff0000".red(),
+            "██".truecolor(255, 0, 0),
+            " + 00ff00".green(),
+            "██".truecolor(0, 255, 0)
+        );
+        assert_eq!(formatted, expected_text);
+    }
+
+    #[test]
+    fn given_no_truecolor_it_formats_colors_without_swatches() {
+        let formatter = DiffFormater { truecolor: false };
+        let diff = Diff::Add(DiffValue::Color("ff0000".into()));
+
+        let formatted = formatter.format(&diff);
+
+        assert_eq!(formatted, " + ff0000\n".green().to_string());
+    }
 }
@@ -1,11 +1,14 @@
 use std::sync::Arc;
 
 use disma::{
-    api::{ApplyChangesUseCase, ListChangesUseCase},
+    api::{ApplyChangesUseCase, CompareGuildsUseCase, ListChangesUseCase},
     core::{
         changes::{
-            category::CategoryChangesService, channel::ChannelChangesService,
-            role::RoleChangesService,
+            automod::AutoModRuleChangesService, category::CategoryChangesService,
+            channel::ChannelChangesService, member::MemberChangesService,
+            onboarding::OnboardingChangesService, permission::PermissionImpactService,
+            role::RoleChangesService, scheduled_event::ScheduledEventChangesService,
+            welcome_screen::WelcomeScreenChangesService,
         },
         events::ChangeEventListenerRef,
     },
@@ -15,8 +18,11 @@ use disma::{
 
 use crate::{
     commands::{
-        apply_changes::ApplyChanges, compile_config::CompileConfig, list_guilds::ListGuilds,
-        save_guild::SaveExistingGuild,
+        apply_changes::ApplyChanges, backup_guild::BackupGuild, clone_guild::CloneGuild,
+        compare_guilds::CompareGuilds, compile_config::CompileConfig,
+        generate_schema::GenerateSchema, lint_config::LintConfig, list_guilds::ListGuilds,
+        restore_guild::RestoreGuild, save_guild::SaveExistingGuild,
+        show_permissions::ShowPermissions,
     },
     infra::diff::{
         event::CliChangeEventListener,
@@ -25,6 +31,7 @@ use crate::{
     utils::{
         env::required_env,
         io::{Deserializer, Serializer},
+        template::TemplateCompiler,
     },
 };
 
@@ -93,6 +100,7 @@ impl Get<Arc<ApplyChanges>> for Injector {
             self.get(),
             self.get(),
             self.get(),
+            self.get(),
         ))
     }
 }
@@ -105,18 +113,66 @@ impl Get<Arc<SaveExistingGuild>> for Injector {
     }
 }
 
+impl Get<Arc<BackupGuild>> for Injector {
+    fn get(&self) -> Arc<BackupGuild> {
+        Arc::from(BackupGuild::new(self.get(), self.get()))
+    }
+}
+
+impl Get<Arc<RestoreGuild>> for Injector {
+    fn get(&self) -> Arc<RestoreGuild> {
+        Arc::from(RestoreGuild::new(self.get()))
+    }
+}
+
+impl Get<Arc<ShowPermissions>> for Injector {
+    fn get(&self) -> Arc<ShowPermissions> {
+        Arc::from(ShowPermissions::new(self.get()))
+    }
+}
+
+impl Get<Arc<CloneGuild>> for Injector {
+    fn get(&self) -> Arc<CloneGuild> {
+        Arc::from(CloneGuild::new(self.get(), self.get()))
+    }
+}
+
+impl Get<Arc<CompareGuilds>> for Injector {
+    fn get(&self) -> Arc<CompareGuilds> {
+        Arc::from(CompareGuilds::new(self.get(), self.get()))
+    }
+}
+
+impl Get<Arc<LintConfig>> for Injector {
+    fn get(&self) -> Arc<LintConfig> {
+        Arc::from(LintConfig::new(self.get()))
+    }
+}
+
 impl Get<Arc<ListGuilds>> for Injector {
     fn get(&self) -> Arc<ListGuilds> {
         Arc::from(ListGuilds::new(self.get()))
     }
 }
 
+impl Get<Arc<TemplateCompiler>> for Injector {
+    fn get(&self) -> Arc<TemplateCompiler> {
+        Arc::from(TemplateCompiler::new(self.get()))
+    }
+}
+
 impl Get<Arc<CompileConfig>> for Injector {
     fn get(&self) -> Arc<CompileConfig> {
         Arc::from(CompileConfig::new(self.get()))
     }
 }
 
+impl Get<Arc<GenerateSchema>> for Injector {
+    fn get(&self) -> Arc<GenerateSchema> {
+        Arc::from(GenerateSchema::new(self.get()))
+    }
+}
+
 impl Get<Arc<ListChangesUseCase>> for Injector {
     fn get(&self) -> Arc<ListChangesUseCase> {
         Arc::from(ListChangesUseCase::new(
@@ -124,6 +180,12 @@ impl Get<Arc<ListChangesUseCase>> for Injector {
             self.get(),
             self.get(),
             self.get(),
+            self.get(),
+            self.get(),
+            self.get(),
+            self.get(),
+            self.get(),
+            self.get(),
         ))
     }
 }
@@ -137,6 +199,25 @@ impl Get<Arc<ApplyChangesUseCase>> for Injector {
             self.get(),
             self.get(),
             self.get(),
+            self.get(),
+            self.get(),
+            self.get(),
+            self.get(),
+            self.get(),
+        ))
+    }
+}
+
+impl Get<Arc<CompareGuildsUseCase>> for Injector {
+    fn get(&self) -> Arc<CompareGuildsUseCase> {
+        Arc::from(CompareGuildsUseCase::new(
+            self.get(),
+            self.get(),
+            self.get(),
+            self.get(),
+            self.get(),
+            self.get(),
+            self.get(),
         ))
     }
 }
@@ -158,3 +239,39 @@ impl Get<Arc<ChannelChangesService>> for Injector {
         Arc::from(ChannelChangesService {})
     }
 }
+
+impl Get<Arc<AutoModRuleChangesService>> for Injector {
+    fn get(&self) -> Arc<AutoModRuleChangesService> {
+        Arc::from(AutoModRuleChangesService {})
+    }
+}
+
+impl Get<Arc<ScheduledEventChangesService>> for Injector {
+    fn get(&self) -> Arc<ScheduledEventChangesService> {
+        Arc::from(ScheduledEventChangesService {})
+    }
+}
+
+impl Get<Arc<MemberChangesService>> for Injector {
+    fn get(&self) -> Arc<MemberChangesService> {
+        Arc::from(MemberChangesService {})
+    }
+}
+
+impl Get<Arc<OnboardingChangesService>> for Injector {
+    fn get(&self) -> Arc<OnboardingChangesService> {
+        Arc::from(OnboardingChangesService {})
+    }
+}
+
+impl Get<Arc<WelcomeScreenChangesService>> for Injector {
+    fn get(&self) -> Arc<WelcomeScreenChangesService> {
+        Arc::from(WelcomeScreenChangesService {})
+    }
+}
+
+impl Get<Arc<PermissionImpactService>> for Injector {
+    fn get(&self) -> Arc<PermissionImpactService> {
+        Arc::from(PermissionImpactService {})
+    }
+}
@@ -8,7 +8,10 @@ mod utils;
 use clap::Parser;
 
 use crate::{
-    actions::{apply_changes, compile_config, list_guilds, save_existing_guild},
+    actions::{
+        apply_changes, backup_guild, clone_guild, compare_guilds, compile_config, generate_schema,
+        lint_config, list_guilds, restore_guild, save_existing_guild, show_permissions,
+    },
     args::{ArgParser, Command},
 };
 
@@ -16,11 +19,52 @@ fn main() {
     let args = ArgParser::parse();
 
     match &args.command {
-        Command::Save(args) => save_existing_guild(&args.guild, &args.output, args.force),
-        Command::Apply(args) => apply_changes(&args.guild, &args.input, args.dry_run, args.force),
+        Command::Save(args) => save_existing_guild(
+            &args.guild,
+            &args.output,
+            &args.filter,
+            args.presets,
+            args.merge,
+            args.force,
+        ),
+        Command::Apply(args) => apply_changes(
+            args.guild.as_deref(),
+            args.env.as_deref(),
+            args.input.as_deref(),
+            args.template.as_deref(),
+            &args.vars,
+            args.policy.as_deref(),
+            args.dry_run,
+            args.impact,
+            args.force,
+        ),
+        Command::Clone(args) => clone_guild(
+            &args.from,
+            &args.to,
+            &args.filter,
+            &args.rename,
+            args.dry_run,
+            args.force,
+        ),
+        Command::Compare(args) => compare_guilds(&args.source, &args.target, args.json),
+        Command::Backup(args) => backup_guild(&args.guild, &args.directory),
+        Command::Restore(args) => restore_guild(
+            &args.snapshot,
+            args.guild.as_deref(),
+            args.dry_run,
+            args.force,
+        ),
+        Command::Lint(args) => lint_config(&args.input, args.policy.as_deref()),
         Command::ListGuilds => list_guilds(),
         Command::CompileConfig(args) => {
             compile_config(&args.template, &args.vars, &args.output, args.force)
         }
+        Command::GenerateSchema(args) => generate_schema(&args.output, args.force),
+        Command::ShowPermissions(args) => show_permissions(
+            args.guild.as_deref(),
+            args.input.as_deref(),
+            &args.roles,
+            args.channel.as_deref(),
+        ),
     }
 }
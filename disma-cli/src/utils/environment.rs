@@ -0,0 +1,194 @@
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+
+use serde::Deserialize;
+
+use super::{error::ConfigError, io::Deserializer};
+
+const ENVIRONMENTS_FILE_NAME: &str = "environments";
+const OVERLAYS_DIR: &str = "overlays";
+const SUPPORTED_EXTENSIONS: [&str; 4] = ["yaml", "yml", "json", "toml"];
+
+#[derive(Deserialize, Debug)]
+pub struct EnvironmentParams {
+    pub guild: String,
+}
+
+/// Environments of a config, declared in an `environments.yaml` file next to it.
+pub struct Environments {
+    config_dir: PathBuf,
+    items: HashMap<String, EnvironmentParams>,
+}
+
+impl Environments {
+    pub fn load(config_file: &Path, deserializer: &Deserializer) -> Self {
+        let config_dir = config_file
+            .parent()
+            .unwrap_or_else(|| Path::new(""))
+            .to_path_buf();
+
+        let items = match find_file(&config_dir, ENVIRONMENTS_FILE_NAME) {
+            Some(path) => deserializer
+                .deserialize(&path)
+                .unwrap_or_else(|error| error.exit()),
+            None => HashMap::new(),
+        };
+
+        Self { config_dir, items }
+    }
+
+    pub fn guild_id(&self, env: &str) -> Result<String, ConfigError> {
+        match self.items.get(env) {
+            Some(environment) => Ok(environment.guild.clone()),
+            None => Err(ConfigError::new(
+                &self
+                    .config_dir
+                    .join(format!("{ENVIRONMENTS_FILE_NAME}.yaml")),
+                format!("No guild id found for environment '{env}'. Add it, or use --guild."),
+            )),
+        }
+    }
+
+    pub fn overlay_path(&self, env: &str) -> Result<PathBuf, ConfigError> {
+        let overlays_dir = self.config_dir.join(OVERLAYS_DIR);
+        find_file(&overlays_dir, env).ok_or_else(|| {
+            ConfigError::new(
+                &overlays_dir,
+                format!("No overlay found for environment '{env}'."),
+            )
+        })
+    }
+}
+
+fn find_file(dir: &Path, name: &str) -> Option<PathBuf> {
+    SUPPORTED_EXTENSIONS
+        .iter()
+        .map(|extension| dir.join(format!("{name}.{extension}")))
+        .find(|path| path.exists())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::PathBuf};
+
+    use disma::api::params::guild::GuildParams;
+
+    use super::Environments;
+    use crate::utils::{error::Position, io::Deserializer};
+
+    fn config_dir(name: &str, files: &[(&str, &str)]) -> PathBuf {
+        let dir = std::env::temp_dir().join(name);
+        for (path, content) in files {
+            let path = dir.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, content).unwrap();
+        }
+        dir
+    }
+
+    #[test]
+    fn it_selects_guild_id_and_overlay_of_environment() {
+        let dir = config_dir(
+            "disma-environments-selection",
+            &[
+                (
+                    "environments.yaml",
+                    "dev:\n  guild: \"123\"\nprod:\n  guild: \"456\"\n",
+                ),
+                ("overlays/prod.yml", "roles:\n  items: []\n"),
+            ],
+        );
+
+        let environments = Environments::load(&dir.join("guild.yaml"), &Deserializer());
+
+        assert_eq!(environments.guild_id("prod"), Ok("456".to_string()));
+        assert_eq!(
+            environments.overlay_path("prod"),
+            Ok(dir.join("overlays/prod.yml"))
+        );
+    }
+
+    #[test]
+    fn given_unknown_environment_it_fails() {
+        let dir = config_dir(
+            "disma-environments-unknown",
+            &[("environments.yaml", "prod:\n  guild: \"456\"\n")],
+        );
+        let environments = Environments::load(&dir.join("guild.yaml"), &Deserializer());
+
+        let guild_id = environments.guild_id("staging");
+        let overlay_path = environments.overlay_path("staging");
+
+        assert_eq!(
+            guild_id.unwrap_err().message,
+            "No guild id found for environment 'staging'. Add it, or use --guild."
+        );
+        assert_eq!(
+            overlay_path.unwrap_err().message,
+            "No overlay found for environment 'staging'."
+        );
+    }
+
+    #[test]
+    fn given_overlay_removing_unknown_entity_it_fails_in_overlay() {
+        let dir = config_dir(
+            "disma-environments-unknown-removal",
+            &[
+                ("guild.yaml", "roles:\n  items:\n    - name: staff\n"),
+                (
+                    "overlays/prod.yaml",
+                    "roles:\n  items:\n    - name: bots\n      $remove: true\n",
+                ),
+            ],
+        );
+        let config_file = dir.join("guild.yaml");
+        let overlay_path = dir.join("overlays/prod.yaml");
+
+        let error = Deserializer()
+            .deserialize_with_overlay::<GuildParams>(&config_file, None, &overlay_path)
+            .unwrap_err();
+
+        assert_eq!(error.file, overlay_path);
+        assert_eq!(
+            error.position,
+            Some(Position {
+                line: 4,
+                column: 16
+            })
+        );
+        assert_eq!(
+            error.message,
+            "Cannot remove name 'bots': no such entity to patch."
+        );
+    }
+
+    #[test]
+    fn given_overlay_with_explicit_default_type_it_patches_channel_without_type() {
+        let dir = config_dir(
+            "disma-environments-default-type",
+            &[
+                ("guild.yaml", "channels:\n  items:\n    - name: general\n      topic: Hello\n"),
+                ("environments.yaml", "prod:\n  guild: \"456\"\n"),
+                (
+                    "overlays/prod.yaml",
+                    "channels:\n  items:\n    - name: general\n      type: TEXT\n      topic: Welcome\n",
+                ),
+            ],
+        );
+        let config_file = dir.join("guild.yaml");
+        let environments = Environments::load(&config_file, &Deserializer());
+
+        let params: GuildParams = Deserializer()
+            .deserialize_with_overlay(
+                &config_file,
+                None,
+                &environments.overlay_path("prod").unwrap(),
+            )
+            .unwrap();
+
+        assert_eq!(params.channels.items.len(), 1);
+        assert_eq!(params.channels.items[0].topic, Some("Welcome".to_string()));
+    }
+}
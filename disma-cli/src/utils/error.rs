@@ -0,0 +1,141 @@
+use colored::Colorize;
+use std::{
+    fmt::Display,
+    path::{Path, PathBuf},
+    process::exit,
+};
+
+/// Prints `message` as an error, then exits.
+pub fn exit_with_error(message: impl Display) -> ! {
+    println!("{}", format!("➜ ❌ {message}").bold());
+    exit(1);
+}
+
+/// Error of a config file, located at a line and a column when known.
+#[derive(Debug, PartialEq)]
+pub struct ConfigError {
+    pub file: PathBuf,
+    pub position: Option<Position>,
+    pub message: String,
+    snippet: Option<String>,
+}
+
+/// 1-based position in a file.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Position {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl ConfigError {
+    pub fn new(file: &Path, message: impl Display) -> Self {
+        Self {
+            file: file.to_path_buf(),
+            position: None,
+            message: message.to_string(),
+            snippet: None,
+        }
+    }
+
+    /// Error at `position` in `content`, the content of `file`.
+    pub fn at(file: &Path, content: &str, position: Position, message: impl Display) -> Self {
+        Self {
+            file: file.to_path_buf(),
+            position: Some(position),
+            message: message.to_string(),
+            snippet: snippet(content, position),
+        }
+    }
+
+    /// Error at the byte `offset` in `content`, the content of `file`.
+    pub fn at_offset(file: &Path, content: &str, offset: usize, message: impl Display) -> Self {
+        let before = &content[..offset.min(content.len())];
+        let line_start = before.rfind('\n').map(|index| index + 1).unwrap_or(0);
+        let position = Position {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+        };
+
+        Self::at(file, content, position, message)
+    }
+
+    /// Error at the first occurence of `needle` in `content`, the content of
+    /// `file`.
+    pub fn at_first(file: &Path, content: &str, needle: &str, message: impl Display) -> Self {
+        match content.find(needle) {
+            Some(offset) => Self::at_offset(file, content, offset, message),
+            None => Self::new(file, message),
+        }
+    }
+
+    /// Prints the error, then exits.
+    pub fn exit(&self) -> ! {
+        exit_with_error(self)
+    }
+}
+
+impl Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.file.display())?;
+        if let Some(position) = self.position {
+            write!(f, ":{}:{}", position.line, position.column)?;
+        }
+        write!(f, ": {}", self.message)?;
+        if let Some(snippet) = &self.snippet {
+            write!(f, "\n{snippet}")?;
+        }
+        Ok(())
+    }
+}
+
+fn snippet(content: &str, position: Position) -> Option<String> {
+    let line = content.lines().nth(position.line.checked_sub(1)?)?;
+    let number = position.line.to_string();
+    let margin = " ".repeat(number.len());
+    let caret_offset = " ".repeat(position.column.saturating_sub(1));
+
+    Some(format!(
+        "{margin} |\n{number} | {line}\n{margin} | {caret_offset}^"
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::{ConfigError, Position};
+
+    #[test]
+    fn it_displays_file_position_and_snippet() {
+        let content = "channels:\n  items:\n    - name: general\n      type: TXT\n";
+
+        let error = ConfigError::at(
+            Path::new("config.yaml"),
+            content,
+            Position {
+                line: 4,
+                column: 13,
+            },
+            "unknown variant `TXT`, expected `TEXT` or `VOICE`",
+        );
+
+        assert_eq!(
+            error.to_string(),
+            "config.yaml:4:13: unknown variant `TXT`, expected `TEXT` or `VOICE`\n  |\n4 |       type: TXT\n  |             ^"
+        );
+    }
+
+    #[test]
+    fn it_finds_position_of_byte_offset() {
+        let error = ConfigError::at_offset(Path::new("a.toml"), "a = 1\nb = [\n", 10, "error");
+
+        assert_eq!(error.position, Some(Position { line: 2, column: 5 }));
+    }
+
+    #[test]
+    fn given_unknown_position_it_displays_file_only() {
+        let error = ConfigError::new(Path::new("config.yaml"), "error");
+
+        assert_eq!(error.to_string(), "config.yaml: error");
+    }
+}
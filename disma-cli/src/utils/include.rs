@@ -0,0 +1,318 @@
+use std::{
+    io,
+    path::{Component, Path, PathBuf},
+};
+
+use serde_yaml::Value;
+
+use super::{error::ConfigError, locate::Source, merge::merge};
+
+pub const INCLUDE_KEY: &str = "include";
+
+/// Resolved value of a config file, with the files it was resolved from.
+pub struct Resolved {
+    pub value: Value,
+    /// Resolved files, in merge order.
+    pub sources: Vec<Source>,
+}
+
+/// Resolves the `include:` lists of config files into a single value.
+///
+/// Included files are merged in order, then the including file is merged on
+/// top of them:
+/// - mappings are merged key by key;
+/// - lists of entities (items with a `name`) are merged by identity, where an
+///   entity declared later replaces the whole previous one;
+/// - any other value is replaced.
+pub struct IncludeResolver<R>
+where
+    R: Fn(&Path) -> io::Result<String>,
+{
+    read: R,
+}
+
+impl<R> IncludeResolver<R>
+where
+    R: Fn(&Path) -> io::Result<String>,
+{
+    pub fn new(read: R) -> Self {
+        Self { read }
+    }
+
+    pub fn resolve(&self, file_path: &Path) -> Result<Resolved, ConfigError> {
+        let content = (self.read)(file_path)
+            .map_err(|error| ConfigError::new(file_path, format!("Cannot read file: {error}")))?;
+
+        let mut sources = Vec::new();
+        let value = self.resolve_content(
+            &normalize(file_path),
+            &content,
+            &mut Vec::new(),
+            &mut sources,
+        )?;
+
+        Ok(Resolved { value, sources })
+    }
+
+    fn resolve_content(
+        &self,
+        file_path: &Path,
+        content: &str,
+        stack: &mut Vec<PathBuf>,
+        sources: &mut Vec<Source>,
+    ) -> Result<Value, ConfigError> {
+        stack.push(file_path.to_path_buf());
+
+        let mut value = super::io::parse(content, file_path)?;
+        let includes = match &mut value {
+            Value::Mapping(mapping) => mapping.remove(INCLUDE_KEY),
+            _ => None,
+        };
+
+        let mut resolved = Value::Null;
+        for include in include_paths(includes, file_path, content)? {
+            let include_path = normalize(
+                &file_path
+                    .parent()
+                    .unwrap_or_else(|| Path::new(""))
+                    .join(&include),
+            );
+
+            if stack.contains(&include_path) {
+                let cycle = stack
+                    .iter()
+                    .chain([&include_path])
+                    .map(|path| path.display().to_string())
+                    .collect::<Vec<String>>()
+                    .join(" -> ");
+                return Err(ConfigError::at_first(
+                    file_path,
+                    content,
+                    &include,
+                    format!("Include cycle detected: {cycle}"),
+                ));
+            }
+
+            let include_content = (self.read)(&include_path).map_err(|error| {
+                ConfigError::at_first(
+                    file_path,
+                    content,
+                    &include,
+                    format!("Cannot include '{include}': {error}"),
+                )
+            })?;
+
+            let included = self.resolve_content(&include_path, &include_content, stack, sources)?;
+            resolved = merge(resolved, included);
+        }
+
+        stack.pop();
+        sources.push(Source {
+            file_path: file_path.to_path_buf(),
+            content: content.to_string(),
+            value: value.clone(),
+        });
+        Ok(merge(resolved, value))
+    }
+}
+
+fn include_paths(
+    includes: Option<Value>,
+    file_path: &Path,
+    content: &str,
+) -> Result<Vec<String>, ConfigError> {
+    let invalid_include = || {
+        ConfigError::at_first(
+            file_path,
+            content,
+            INCLUDE_KEY,
+            format!("'{INCLUDE_KEY}' must be a list of file paths"),
+        )
+    };
+
+    match includes {
+        None => Ok(Vec::new()),
+        Some(Value::Sequence(paths)) => paths
+            .into_iter()
+            .map(|path| match path {
+                Value::String(path) => Ok(path),
+                _ => Err(invalid_include()),
+            })
+            .collect(),
+        Some(_) => Err(invalid_include()),
+    }
+}
+
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => match normalized.components().next_back() {
+                Some(Component::Normal(_)) => {
+                    normalized.pop();
+                }
+                _ => normalized.push(component),
+            },
+            _ => normalized.push(component),
+        }
+    }
+
+    normalized
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::HashMap,
+        io,
+        path::{Path, PathBuf},
+    };
+
+    use serde_yaml::Value;
+
+    use super::IncludeResolver;
+    use crate::utils::error::{ConfigError, Position};
+
+    fn try_resolve(files: &[(&str, &str)], file: &str) -> Result<Value, ConfigError> {
+        let files: HashMap<PathBuf, String> = files
+            .iter()
+            .map(|(path, content)| (PathBuf::from(path), content.to_string()))
+            .collect();
+
+        let resolver = IncludeResolver::new(|path: &Path| {
+            files
+                .get(path)
+                .cloned()
+                .ok_or_else(|| io::Error::from(io::ErrorKind::NotFound))
+        });
+
+        resolver
+            .resolve(Path::new(file))
+            .map(|resolved| resolved.value)
+    }
+
+    fn resolve(files: &[(&str, &str)], file: &str) -> Value {
+        try_resolve(files, file).unwrap()
+    }
+
+    fn yaml(content: &str) -> Value {
+        serde_yaml::from_str(content).unwrap()
+    }
+
+    #[test]
+    fn given_no_include_it_returns_file_content() {
+        let resolved = resolve(&[("guild.yaml", "roles:\n  items: []\n")], "guild.yaml");
+
+        assert_eq!(resolved, yaml("roles:\n  items: []\n"));
+    }
+
+    #[test]
+    fn it_resolves_includes_relative_to_including_file() {
+        let resolved = resolve(
+            &[
+                ("config/guild.yaml", "include: [parts/roles.yaml]\n"),
+                (
+                    "config/parts/roles.yaml",
+                    "include: [../common.yaml]\nroles:\n  items:\n  - name: staff\n",
+                ),
+                (
+                    "config/common.yaml",
+                    "channels:\n  items:\n  - name: general\n",
+                ),
+            ],
+            "config/guild.yaml",
+        );
+
+        assert_eq!(
+            resolved,
+            yaml("channels:\n  items:\n  - name: general\nroles:\n  items:\n  - name: staff\n")
+        );
+    }
+
+    #[test]
+    fn it_merges_entities_by_name_with_including_file_overriding() {
+        let resolved = resolve(
+            &[
+                (
+                    "guild.yaml",
+                    "include: [roles.yaml]\nroles:\n  items:\n  - name: staff\n    color: ff0000\n  - name: bot\n",
+                ),
+                (
+                    "roles.yaml",
+                    "roles:\n  items:\n  - name: staff\n    is_mentionable: true\n  - name: member\n  extra_items:\n    strategy: REMOVE\n",
+                ),
+            ],
+            "guild.yaml",
+        );
+
+        assert_eq!(
+            resolved,
+            yaml("roles:\n  items:\n  - name: staff\n    color: ff0000\n  - name: member\n  - name: bot\n  extra_items:\n    strategy: REMOVE\n")
+        );
+    }
+
+    #[test]
+    fn it_keeps_channels_with_same_name_in_different_categories() {
+        let resolved = resolve(
+            &[
+                (
+                    "guild.yaml",
+                    "include: [channels.yaml]\nchannels:\n  items:\n  - name: general\n    category: b\n",
+                ),
+                (
+                    "channels.yaml",
+                    "channels:\n  items:\n  - name: general\n    category: a\n",
+                ),
+            ],
+            "guild.yaml",
+        );
+
+        assert_eq!(
+            resolved,
+            yaml("channels:\n  items:\n  - name: general\n    category: a\n  - name: general\n    category: b\n")
+        );
+    }
+
+    #[test]
+    fn given_include_cycle_it_fails() {
+        let error = try_resolve(
+            &[
+                ("a.yaml", "include: [b.yaml]\n"),
+                ("b.yaml", "include: [a.yaml]\n"),
+            ],
+            "a.yaml",
+        )
+        .unwrap_err();
+
+        assert_eq!(error.file, PathBuf::from("b.yaml"));
+        assert_eq!(
+            error.position,
+            Some(Position {
+                line: 1,
+                column: 11
+            })
+        );
+        assert_eq!(
+            error.message,
+            "Include cycle detected: a.yaml -> b.yaml -> a.yaml"
+        );
+    }
+
+    #[test]
+    fn given_missing_include_it_fails_with_file_and_position() {
+        let error = try_resolve(
+            &[
+                ("guild.yaml", "include:\n  - roles.yaml\n  - missing.yaml\n"),
+                ("roles.yaml", ""),
+            ],
+            "guild.yaml",
+        )
+        .unwrap_err();
+
+        assert_eq!(error.file, PathBuf::from("guild.yaml"));
+        assert_eq!(error.position, Some(Position { line: 3, column: 5 }));
+        assert!(error.message.starts_with("Cannot include 'missing.yaml'"));
+    }
+}
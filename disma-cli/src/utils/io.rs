@@ -1,17 +1,35 @@
 use std::{fs, path::Path};
 
+use disma::{api::params::guild::GuildParams, guild::AwaitingGuild};
 use serde::{de::DeserializeOwned, Serialize};
-use yaml_merge_keys::merge_keys;
-use yaml_rust::{yaml::Hash, Yaml, YamlEmitter, YamlLoader};
+use serde_yaml::{Mapping, Value};
+
+use super::{
+    error::{ConfigError, Position},
+    include::{IncludeResolver, Resolved, INCLUDE_KEY},
+    locate::{locate, locate_path, message, Source},
+    merge::merge_overlay,
+    yaml_patch::patch,
+};
+
+const YAML_MERGE_KEY: &str = "<<";
 
 fn serialize_json<T: Serialize>(object: &T, file_path: &Path) {
     let file_content = serde_json::to_string_pretty(object).unwrap();
     fs::write(file_path, format!("{file_content}\n")).unwrap();
 }
 
-fn deserialize_json<T: DeserializeOwned>(file_path: &Path) -> T {
-    let file_content = fs::read_to_string(file_path).unwrap();
-    serde_json::from_str(&file_content).unwrap()
+fn deserialize_json<T: DeserializeOwned>(
+    content: &str,
+    file_path: &Path,
+) -> Result<T, ConfigError> {
+    serde_json::from_str(content).map_err(|error| {
+        let position = Position {
+            line: error.line(),
+            column: error.column(),
+        };
+        ConfigError::at(file_path, content, position, message(&error))
+    })
 }
 
 fn serialize_yaml<T: Serialize + ?Sized>(object: &T, file_path: &Path) {
@@ -19,23 +37,111 @@ fn serialize_yaml<T: Serialize + ?Sized>(object: &T, file_path: &Path) {
     fs::write(file_path, file_content).unwrap();
 }
 
-fn deserialize_yaml<T: DeserializeOwned>(file_path: &Path) -> T {
-    let file_content = fs::read_to_string(file_path).unwrap();
+fn deserialize_yaml<T: DeserializeOwned>(
+    content: &str,
+    file_path: &Path,
+) -> Result<T, ConfigError> {
+    serde_yaml::from_str(content).map_err(|error| match error.location() {
+        Some(location) => {
+            let position = Position {
+                line: location.line(),
+                column: location.column(),
+            };
+            ConfigError::at(file_path, content, position, message(&error))
+        }
+        None => ConfigError::new(file_path, error),
+    })
+}
 
-    let yaml_content = YamlLoader::load_from_str(&file_content)
-        .unwrap()
-        .first()
-        .cloned()
-        .unwrap_or(Yaml::Hash(Hash::new()));
+fn parse_yaml(content: &str, file_path: &Path) -> Result<Value, ConfigError> {
+    let mut value = match deserialize_yaml(content, file_path)? {
+        Value::Null => Value::Mapping(Mapping::new()),
+        value => value,
+    };
 
-    let merged_yaml_content = merge_keys(yaml_content).unwrap();
+    value.apply_merge().map_err(|error| {
+        ConfigError::at_first(file_path, content, YAML_MERGE_KEY, message(&error))
+    })?;
 
-    let mut merge_yaml_str = String::new();
-    YamlEmitter::new(&mut merge_yaml_str)
-        .dump(&merged_yaml_content)
-        .unwrap();
+    Ok(value)
+}
+
+fn format_toml<T: Serialize + ?Sized>(object: &T) -> String {
+    toml::to_string_pretty(object).unwrap_or_else(|error| panic!("Cannot format as TOML: {error}"))
+}
+
+fn serialize_toml<T: Serialize + ?Sized>(object: &T, file_path: &Path) {
+    fs::write(file_path, format_toml(object)).unwrap();
+}
+
+fn deserialize_toml<T: DeserializeOwned>(
+    content: &str,
+    file_path: &Path,
+) -> Result<T, ConfigError> {
+    toml::from_str(content).map_err(|error| match error.span() {
+        Some(span) => ConfigError::at_offset(file_path, content, span.start, error.message()),
+        None => ConfigError::new(file_path, error.message()),
+    })
+}
+
+/// Deserializes a config file content according to its extension.
+fn deserialize_file<T: DeserializeOwned>(
+    content: &str,
+    file_path: &Path,
+) -> Result<T, ConfigError> {
+    match file_path
+        .extension()
+        .and_then(|extension| extension.to_str())
+    {
+        Some("json") => deserialize_json(content, file_path),
+        Some("yml") | Some("yaml") => deserialize_yaml(content, file_path),
+        Some("toml") => deserialize_toml(content, file_path),
+        _ => Err(unsupported_file(file_path)),
+    }
+}
+
+fn unsupported_file(file_path: &Path) -> ConfigError {
+    ConfigError::new(
+        file_path,
+        "Unsupported file type. Supported file types are JSON, YAML and TOML.",
+    )
+}
+
+/// Parses a config file content according to its extension.
+pub fn parse(content: &str, file_path: &Path) -> Result<Value, ConfigError> {
+    match file_path
+        .extension()
+        .and_then(|extension| extension.to_str())
+    {
+        Some("yml") | Some("yaml") => parse_yaml(content, file_path),
+        _ => deserialize_file(content, file_path),
+    }
+}
+
+/// Builds the guild of the config `guild_params`, read from `file_path`. Fails
+/// on invalid references, ex: an ambiguous channel name.
+pub fn awaiting_guild(
+    guild_params: GuildParams,
+    file_path: &Path,
+) -> Result<AwaitingGuild, ConfigError> {
+    guild_params
+        .try_into_awaiting()
+        .map_err(|error| ConfigError::new(file_path, error))
+}
+
+/// Resolves `file_path`, whose content is `content` when given (ex: a compiled
+/// template) instead of being read from disk.
+fn resolve(file_path: &Path, content: Option<&str>) -> Result<Resolved, ConfigError> {
+    IncludeResolver::new(|path: &Path| match content {
+        Some(content) if path == file_path => Ok(content.to_string()),
+        _ => fs::read_to_string(path),
+    })
+    .resolve(file_path)
+}
 
-    serde_yaml::from_str(&merge_yaml_str).unwrap()
+fn read(file_path: &Path) -> Result<String, ConfigError> {
+    fs::read_to_string(file_path)
+        .map_err(|error| ConfigError::new(file_path, format!("Cannot read file: {error}")))
 }
 
 pub struct Serializer();
@@ -46,7 +152,8 @@ impl Serializer {
             Some(extension) => match extension.to_str() {
                 Some("json") => serialize_json(&object, file_path),
                 Some("yml") | Some("yaml") => serialize_yaml(object, file_path),
-                Some(extension) => panic!("No serializer available for extension {extension}. Supported file types are JSON and YAML."),
+                Some("toml") => serialize_toml(object, file_path),
+                Some(extension) => panic!("No serializer available for extension {extension}. Supported file types are JSON, YAML and TOML."),
                 _ => panic!("Invalid characters found in filename."),
             },
             None => panic!("File must have an extension."),
@@ -54,18 +161,389 @@ impl Serializer {
     }
 }
 
+impl Serializer {
+    /// Updates the existing YAML config at `file_path` to describe `object`,
+    /// preserving its comments, anchors and ordering.
+    pub fn serialize_merged<T: Serialize + DeserializeOwned>(
+        &self,
+        object: &T,
+        file_path: &Path,
+    ) -> Result<(), ConfigError> {
+        if !matches!(
+            file_path
+                .extension()
+                .and_then(|extension| extension.to_str()),
+            Some("yml") | Some("yaml")
+        ) {
+            return Err(ConfigError::new(
+                file_path,
+                "Merging is only supported for YAML files.",
+            ));
+        }
+
+        let content = read(file_path)?;
+        let old = parse_yaml(&content, file_path)?;
+        if old.get(INCLUDE_KEY).is_some() {
+            return Err(ConfigError::at_first(
+                file_path,
+                &content,
+                INCLUDE_KEY,
+                "Merging is not supported for files with includes.",
+            ));
+        }
+
+        // Normalizing the existing config, for default values not to be seen as changes
+        let source = Source {
+            file_path: file_path.to_path_buf(),
+            content: content.clone(),
+            value: old.clone(),
+        };
+        let old: T = serde_yaml::from_value(old.clone())
+            .map_err(|error| locate::<T>(&[source], &old, error))?;
+        let old = serde_yaml::to_value(old).unwrap();
+        let new = serde_yaml::to_value(object).unwrap();
+
+        fs::write(file_path, patch(&content, &old, &new)).unwrap();
+        Ok(())
+    }
+}
+
 pub struct Deserializer();
 
 impl Deserializer {
-    pub fn deserialize<T: DeserializeOwned>(&self, file_path: &Path) -> T {
-        match file_path.extension() {
-            Some(extension) => match extension.to_str() {
-                Some("json") => deserialize_json(file_path),
-                Some("yml") | Some("yaml") => deserialize_yaml(file_path),
-                Some(extension) => panic!("No serializer available for extension {extension}. Supported file types are JSON and YAML."),
-                _ => panic!("Invalid characters found in filename."),
-            },
-            None => panic!("File must have an extension."),
-        }
+    pub fn deserialize<T: DeserializeOwned>(&self, file_path: &Path) -> Result<T, ConfigError> {
+        self.deserialize_content(&read(file_path)?, file_path)
+    }
+
+    /// Deserializes `content` as if it was the content of `file_path`.
+    pub fn deserialize_content<T: DeserializeOwned>(
+        &self,
+        content: &str,
+        file_path: &Path,
+    ) -> Result<T, ConfigError> {
+        let Resolved { value, sources } = resolve(file_path, Some(content))?;
+
+        serde_yaml::from_value(value.clone()).map_err(|error| locate::<T>(&sources, &value, error))
+    }
+
+    /// Deserializes `file_path`, patched by the entities of `overlay_path`.
+    /// The content of `file_path` is `content` when given.
+    pub fn deserialize_with_overlay<T: DeserializeOwned>(
+        &self,
+        file_path: &Path,
+        content: Option<&str>,
+        overlay_path: &Path,
+    ) -> Result<T, ConfigError> {
+        let base = resolve(file_path, content)?;
+        let overlay = resolve(overlay_path, None)?;
+        let value = merge_overlay(base.value, overlay.value.clone()).map_err(|error| {
+            locate_path(&overlay.sources, &overlay.value, &error.path, error.message)
+        })?;
+        let sources: Vec<Source> = base.sources.into_iter().chain(overlay.sources).collect();
+
+        serde_yaml::from_value(value.clone()).map_err(|error| locate::<T>(&sources, &value, error))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use disma::api::params::{
+        channel::ChannelParamsPermissionsOverwritesStrategy, guild::GuildParams,
+    };
+
+    use super::{format_toml, parse, Deserializer, Serializer};
+    use crate::utils::error::Position;
+
+    const GUILD_YAML: &str = r"
+roles:
+  items:
+    - name: staff
+      permissions: [ADMINISTRATOR]
+      show_in_sidebar: true
+      is_mentionable: false
+      color: 00ff00
+categories:
+  items:
+    - name: teams
+      permissions_overwrites:
+        - role: staff
+          allow: [VIEW_CHANNEL]
+  extra_items:
+    strategy: REMOVE
+channels:
+  items:
+    - name: general
+      topic: Hello
+      permissions_overwrites:
+        strategy: MANUAL
+        items:
+          - role: staff
+            deny: [SEND_MESSAGES]
+    - name: voice
+      type: VOICE
+      category: teams
+      permissions_overwrites:
+        strategy: FROM_CATEGORY
+automod:
+  items:
+    - name: spam
+      trigger:
+        type: MENTION_SPAM
+        mention_total_limit: 5
+      actions:
+        - type: BLOCK_MESSAGE
+        - type: TIMEOUT
+          duration_seconds: 60
+      enabled: true
+scheduled_events:
+  items:
+    - name: meeting
+      location:
+        type: EXTERNAL
+        location: Online
+      start_time: 2030-01-01T00:00:00Z
+";
+
+    fn guild_params() -> GuildParams {
+        serde_yaml::from_value(parse(GUILD_YAML, Path::new("guild.yaml")).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn given_unchanged_guild_params_when_merging_it_keeps_the_file_as_is() {
+        let file_path = std::env::temp_dir().join("disma-merge-unchanged.yaml");
+        let content = format!("# Guild config\n{}", GUILD_YAML.trim_start());
+        std::fs::write(&file_path, &content).unwrap();
+
+        Serializer()
+            .serialize_merged(&guild_params(), &file_path)
+            .unwrap();
+
+        assert_eq!(std::fs::read_to_string(&file_path).unwrap(), content);
+    }
+
+    #[test]
+    fn given_changed_guild_params_when_merging_it_parses_back_the_same() {
+        let file_path = std::env::temp_dir().join("disma-merge-changed.yaml");
+        std::fs::write(&file_path, GUILD_YAML).unwrap();
+        let mut params = guild_params();
+        params.roles.items[0].color = Some("ff0000".to_string());
+        params.roles.items.push(params.roles.items[0].clone());
+        params.roles.items[1].name = "bot".to_string();
+        params.channels.items[0].topic = None;
+
+        Serializer().serialize_merged(&params, &file_path).unwrap();
+
+        let merged = std::fs::read_to_string(&file_path).unwrap();
+        let parsed: GuildParams =
+            serde_yaml::from_value(parse(&merged, &file_path).unwrap()).unwrap();
+        assert_eq!(parsed, params);
+        assert!(merged.contains("      permissions: [ADMINISTRATOR]\n"));
+    }
+
+    #[test]
+    fn given_guild_params_when_formatting_to_toml_it_parses_back_the_same() {
+        let params = guild_params();
+
+        let toml = format_toml(&params);
+        let parsed: GuildParams =
+            serde_yaml::from_value(parse(&toml, Path::new("guild.toml")).unwrap()).unwrap();
+
+        assert_eq!(parsed, params);
+    }
+
+    #[test]
+    fn it_parses_tagged_enums_from_toml() {
+        let toml = r#"
+[[channels.items]]
+name = "voice"
+category = "teams"
+
+[channels.items.permissions_overwrites]
+strategy = "FROM_CATEGORY"
+"#;
+
+        let params: GuildParams =
+            serde_yaml::from_value(parse(toml, Path::new("guild.toml")).unwrap()).unwrap();
+
+        assert_eq!(
+            params.channels.items[0].permissions_overwrites,
+            ChannelParamsPermissionsOverwritesStrategy::FromCategory
+        );
+    }
+
+    #[test]
+    fn given_invalid_yaml_value_it_returns_error_at_its_position() {
+        let content = "channels:\n  items:\n    - name: general\n      type: TXT\n";
+
+        let error = Deserializer()
+            .deserialize_content::<GuildParams>(content, Path::new("config.yaml"))
+            .unwrap_err();
+
+        assert_eq!(
+            error.position,
+            Some(Position {
+                line: 4,
+                column: 13
+            })
+        );
+        assert!(error
+            .message
+            .contains("unknown variant `TXT`, expected one of `TEXT`, `VOICE`, `STAGE`"));
+    }
+
+    #[test]
+    fn given_invalid_json_syntax_it_returns_error_at_its_position() {
+        let content = "{\n  \"roles\": {\n    \"items\": [,]\n  }\n}\n";
+
+        let error = Deserializer()
+            .deserialize_content::<GuildParams>(content, Path::new("config.json"))
+            .unwrap_err();
+
+        assert_eq!(
+            error.position,
+            Some(Position {
+                line: 3,
+                column: 15
+            })
+        );
+    }
+
+    #[test]
+    fn given_invalid_toml_value_it_returns_error_at_its_position() {
+        let content = "[[channels.items]]\nname = \"general\"\ntype = \"TXT\"\n";
+
+        let error = Deserializer()
+            .deserialize_content::<GuildParams>(content, Path::new("config.toml"))
+            .unwrap_err();
+
+        assert_eq!(error.position, Some(Position { line: 3, column: 8 }));
+    }
+
+    #[test]
+    fn given_yaml_merge_keys_it_applies_them() {
+        let content = "base: &base\n  show_in_sidebar: true\nroles:\n  items:\n    - <<: *base\n      name: staff\n      is_mentionable: false\n";
+
+        let params = Deserializer()
+            .deserialize_content::<GuildParams>(content, Path::new("config.yaml"))
+            .unwrap();
+
+        assert!(params.roles.items[0].show_in_sidebar);
+    }
+
+    #[test]
+    fn given_invalid_value_in_included_file_it_returns_error_at_its_position_in_included_file() {
+        let directory = std::env::temp_dir().join("disma-locate-include");
+        std::fs::create_dir_all(&directory).unwrap();
+        let included_path = directory.join("channels.yaml");
+        std::fs::write(
+            &included_path,
+            "channels:\n  items:\n    - name: general\n      type: TXT\n",
+        )
+        .unwrap();
+        let content = "include: [channels.yaml]\nroles:\n  items:\n    - name: staff\n";
+
+        let error = Deserializer()
+            .deserialize_content::<GuildParams>(content, &directory.join("guild.yaml"))
+            .unwrap_err();
+
+        assert_eq!(error.file, included_path);
+        assert_eq!(
+            error.position,
+            Some(Position {
+                line: 4,
+                column: 13
+            })
+        );
+        assert!(error.message.contains("unknown variant `TXT`"));
+    }
+
+    #[test]
+    fn given_invalid_value_merged_from_yaml_anchor_it_returns_error_at_anchor_position() {
+        let content =
+            "base: &base\n  type: TXT\nchannels:\n  items:\n    - <<: *base\n      name: general\n";
+
+        let error = Deserializer()
+            .deserialize_content::<GuildParams>(content, Path::new("config.yaml"))
+            .unwrap_err();
+
+        assert_eq!(error.position, Some(Position { line: 2, column: 9 }));
+        assert!(error.message.contains("unknown variant `TXT`"));
+    }
+
+    #[test]
+    fn given_invalid_value_overriding_yaml_merge_key_it_returns_error_at_its_position() {
+        let content = "base: &base\n  type: VOICE\nchannels:\n  items:\n    - <<: *base\n      name: general\n      type: TXT\n";
+
+        let error = Deserializer()
+            .deserialize_content::<GuildParams>(content, Path::new("config.yaml"))
+            .unwrap_err();
+
+        assert_eq!(
+            error.position,
+            Some(Position {
+                line: 7,
+                column: 13
+            })
+        );
+    }
+
+    #[test]
+    fn given_invalid_value_in_overlay_it_returns_error_at_its_position_in_overlay() {
+        let directory = std::env::temp_dir().join("disma-locate-overlay");
+        std::fs::create_dir_all(&directory).unwrap();
+        let file_path = directory.join("guild.yaml");
+        let overlay_path = directory.join("prod.yaml");
+        std::fs::write(&file_path, GUILD_YAML).unwrap();
+        std::fs::write(
+            &overlay_path,
+            "roles:\n  items:\n    - name: staff\n      is_mentionable: maybe\n",
+        )
+        .unwrap();
+
+        let error = Deserializer()
+            .deserialize_with_overlay::<GuildParams>(&file_path, None, &overlay_path)
+            .unwrap_err();
+
+        assert_eq!(error.file, overlay_path);
+        assert_eq!(
+            error.position,
+            Some(Position {
+                line: 4,
+                column: 23
+            })
+        );
+    }
+
+    #[test]
+    fn given_invalid_value_in_base_of_overlay_it_returns_error_at_its_position_in_base() {
+        let directory = std::env::temp_dir().join("disma-locate-overlay-base");
+        std::fs::create_dir_all(&directory).unwrap();
+        let file_path = directory.join("guild.yaml");
+        let overlay_path = directory.join("prod.yaml");
+        std::fs::write(
+            &file_path,
+            "roles:\n  items:\n    - name: staff\n      is_mentionable: maybe\n",
+        )
+        .unwrap();
+        std::fs::write(
+            &overlay_path,
+            "roles:\n  items:\n    - name: staff\n      color: ff0000\n",
+        )
+        .unwrap();
+
+        let error = Deserializer()
+            .deserialize_with_overlay::<GuildParams>(&file_path, None, &overlay_path)
+            .unwrap_err();
+
+        assert_eq!(error.file, file_path);
+        assert_eq!(
+            error.position,
+            Some(Position {
+                line: 4,
+                column: 23
+            })
+        );
     }
 }
@@ -0,0 +1,355 @@
+use std::{fmt, path::Path, path::PathBuf};
+
+use serde::de::{DeserializeOwned, DeserializeSeed, IgnoredAny, MapAccess, SeqAccess, Visitor};
+use serde_yaml::Value;
+
+use super::{
+    error::{ConfigError, Position},
+    merge::{identity, is_entity_list},
+};
+
+const YAML_MERGE_KEY: &str = "<<";
+const TARGET: &str = "the located config value";
+
+/// A config file taking part in a resolved config value.
+pub struct Source {
+    pub file_path: PathBuf,
+    pub content: String,
+    /// Parsed content, with YAML merge keys applied and includes removed.
+    pub value: Value,
+}
+
+/// Step of the path of a value in a document.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Segment {
+    Key(Value),
+    Index(usize),
+}
+
+/// Error of the deserialization of `value`, resolved from `sources` (in merge
+/// order), located in the source which declares the invalid value.
+///
+/// Resolved values have no position, so the invalid value path is found by
+/// deserializing `value` again from a text where each value has its own line.
+/// The path is then followed in the last source declaring it.
+pub fn locate<T: DeserializeOwned>(
+    sources: &[Source],
+    value: &Value,
+    error: serde_yaml::Error,
+) -> ConfigError {
+    let located = failing_path::<T>(value)
+        .and_then(|path| locate_declaration(sources, value, &path))
+        .map(|(source, position)| {
+            ConfigError::at(
+                &source.file_path,
+                &source.content,
+                position,
+                message(&error),
+            )
+        });
+
+    match (located, sources.last()) {
+        (Some(located), _) => located,
+        (None, Some(source)) => ConfigError::new(&source.file_path, error),
+        (None, None) => ConfigError::new(Path::new(""), error),
+    }
+}
+
+/// Error of the `path` value of `value`, resolved from `sources` (in merge
+/// order), located in the last source declaring it.
+pub fn locate_path(
+    sources: &[Source],
+    value: &Value,
+    path: &[Segment],
+    message: impl fmt::Display,
+) -> ConfigError {
+    match (locate_declaration(sources, value, path), sources.last()) {
+        (Some((source, position)), _) => {
+            ConfigError::at(&source.file_path, &source.content, position, message)
+        }
+        (None, Some(source)) => ConfigError::new(&source.file_path, message),
+        (None, None) => ConfigError::new(Path::new(""), message),
+    }
+}
+
+fn locate_declaration<'a>(
+    sources: &'a [Source],
+    value: &Value,
+    path: &[Segment],
+) -> Option<(&'a Source, Position)> {
+    sources.iter().rev().find_map(|source| {
+        let source_path = translate(value, &source.value, path)?;
+        Some((source, position(source, &source_path)?))
+    })
+}
+
+/// Message of a parser error, without its position suffix.
+pub fn message(error: &impl ToString) -> String {
+    let message = error.to_string();
+    match message.find(" at line ") {
+        Some(index) => message[..index].to_string(),
+        None => message,
+    }
+}
+
+fn failing_path<T: DeserializeOwned>(value: &Value) -> Option<Vec<Segment>> {
+    let mut trace = Trace {
+        text: String::new(),
+        paths: vec![Vec::new()],
+    };
+    trace.write(value, &mut Vec::new());
+
+    let error = serde_yaml::from_str::<T>(&trace.text).err()?;
+    let line = error.location()?.line();
+    trace.paths.get(line - 1).cloned()
+}
+
+/// YAML flow text of a value, with the path of the value starting each line.
+struct Trace {
+    text: String,
+    paths: Vec<Vec<Segment>>,
+}
+
+impl Trace {
+    fn write(&mut self, value: &Value, path: &mut Vec<Segment>) {
+        match value {
+            Value::Mapping(mapping) if !mapping.is_empty() => {
+                self.text.push('{');
+                for (index, (key, value)) in mapping.iter().enumerate() {
+                    if index > 0 {
+                        self.text.push(',');
+                    }
+                    path.push(Segment::Key(key.clone()));
+                    self.new_line(path);
+                    self.write_scalar(key);
+                    self.text.push_str(": ");
+                    self.write(value, path);
+                    path.pop();
+                }
+                self.new_line(path);
+                self.text.push('}');
+            }
+            Value::Sequence(items) if !items.is_empty() => {
+                self.text.push('[');
+                for (index, item) in items.iter().enumerate() {
+                    if index > 0 {
+                        self.text.push(',');
+                    }
+                    path.push(Segment::Index(index));
+                    self.new_line(path);
+                    self.write(item, path);
+                    path.pop();
+                }
+                self.new_line(path);
+                self.text.push(']');
+            }
+            Value::Mapping(_) => self.text.push_str("{}"),
+            Value::Sequence(_) => self.text.push_str("[]"),
+            Value::Tagged(tagged) => {
+                self.text.push_str(&format!("{} ", tagged.tag));
+                self.write(&tagged.value, path);
+            }
+            scalar => self.write_scalar(scalar),
+        }
+    }
+
+    fn write_scalar(&mut self, scalar: &Value) {
+        match scalar {
+            Value::Null => self.text.push_str("null"),
+            Value::Bool(value) => self.text.push_str(&value.to_string()),
+            Value::Number(value) => self.text.push_str(&value.to_string()),
+            other => self.text.push_str(&serde_json::to_string(other).unwrap()),
+        }
+    }
+
+    fn new_line(&mut self, path: &[Segment]) {
+        self.text.push('\n');
+        self.paths.push(path.to_vec());
+    }
+}
+
+/// Path of the `path` value of `resolved` in `source`, if declared by it.
+fn translate(resolved: &Value, source: &Value, path: &[Segment]) -> Option<Vec<Segment>> {
+    let (mut resolved, mut source) = (resolved, source);
+    let mut source_path = Vec::new();
+
+    for segment in path {
+        match segment {
+            Segment::Key(key) => {
+                resolved = resolved.as_mapping()?.get(key)?;
+                source = source.as_mapping()?.get(key)?;
+                source_path.push(segment.clone());
+            }
+            Segment::Index(index) => {
+                let resolved_items = resolved.as_sequence()?;
+                let source_items = source.as_sequence()?;
+                resolved = resolved_items.get(*index)?;
+
+                let source_index = if is_entity_list(resolved_items) {
+                    source_items
+                        .iter()
+                        .position(|item| identity(item) == identity(resolved))?
+                } else {
+                    *index
+                };
+                source = source_items.get(source_index)?;
+                source_path.push(Segment::Index(source_index));
+            }
+        }
+    }
+
+    Some(source_path)
+}
+
+fn position(source: &Source, path: &[Segment]) -> Option<Position> {
+    let file_path = &source.file_path;
+    let content = &source.content;
+
+    match file_path
+        .extension()
+        .and_then(|extension| extension.to_str())
+    {
+        Some("yml") | Some("yaml") => {
+            let raw: Value = serde_yaml::from_str(content).ok()?;
+            let path = unmerged_path(&raw, path)?;
+            let error = Navigate(&path)
+                .deserialize(serde_yaml::Deserializer::from_str(content))
+                .err()
+                .filter(is_target)?;
+            let location = error.location()?;
+            Some(Position {
+                line: location.line(),
+                column: location.column(),
+            })
+        }
+        Some("json") => {
+            let error = Navigate(path)
+                .deserialize(&mut serde_json::Deserializer::from_str(content))
+                .err()
+                .filter(is_target)?;
+            Some(Position {
+                line: error.line(),
+                column: error.column(),
+            })
+        }
+        Some("toml") => {
+            let error = Navigate(path)
+                .deserialize(toml::Deserializer::new(content))
+                .err()
+                .filter(is_target)?;
+            let offset = error.span()?.start;
+            ConfigError::at_offset(file_path, content, offset, "").position
+        }
+        _ => None,
+    }
+}
+
+fn is_target(error: &impl ToString) -> bool {
+    error.to_string().contains(TARGET)
+}
+
+/// Path of the `path` value of a YAML document `raw` before applying its merge
+/// keys, where keys declared by the mapping override merged ones.
+fn unmerged_path(raw: &Value, path: &[Segment]) -> Option<Vec<Segment>> {
+    let mut node = raw;
+    let mut unmerged = Vec::new();
+
+    for segment in path {
+        match segment {
+            Segment::Key(key) => {
+                let (segments, child) = find_key(node, key)?;
+                unmerged.extend(segments);
+                node = child;
+            }
+            Segment::Index(index) => {
+                node = node.as_sequence()?.get(*index)?;
+                unmerged.push(segment.clone());
+            }
+        }
+    }
+
+    Some(unmerged)
+}
+
+fn find_key<'a>(node: &'a Value, key: &Value) -> Option<(Vec<Segment>, &'a Value)> {
+    let mapping = node.as_mapping()?;
+    if let Some(child) = mapping.get(key) {
+        return Some((vec![Segment::Key(key.clone())], child));
+    }
+
+    let merge_key = Segment::Key(Value::String(YAML_MERGE_KEY.to_string()));
+    match mapping.get(YAML_MERGE_KEY)? {
+        Value::Sequence(merged) => merged.iter().enumerate().find_map(|(index, merged)| {
+            let (segments, child) = find_key(merged, key)?;
+            let prefix = [merge_key.clone(), Segment::Index(index)];
+            Some((prefix.into_iter().chain(segments).collect(), child))
+        }),
+        merged => {
+            let (segments, child) = find_key(merged, key)?;
+            Some(([merge_key].into_iter().chain(segments).collect(), child))
+        }
+    }
+}
+
+/// Follows a path in a document, then fails at its value for the deserializer
+/// to report the position of the value.
+struct Navigate<'a>(&'a [Segment]);
+
+impl<'de> DeserializeSeed<'de> for Navigate<'_> {
+    type Value = ();
+
+    fn deserialize<D: serde::Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        match self.0 {
+            [] => deserializer.deserialize_any(Target),
+            _ => deserializer.deserialize_any(self),
+        }
+    }
+}
+
+impl<'de> Visitor<'de> for Navigate<'_> {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a config value")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<(), A::Error> {
+        let Some((Segment::Key(key), rest)) = self.0.split_first() else {
+            return Ok(());
+        };
+
+        while let Some(map_key) = map.next_key::<Value>()? {
+            if &map_key == key {
+                return map.next_value_seed(Navigate(rest));
+            }
+            map.next_value::<IgnoredAny>()?;
+        }
+
+        Ok(())
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<(), A::Error> {
+        let Some((Segment::Index(index), rest)) = self.0.split_first() else {
+            return Ok(());
+        };
+
+        for _ in 0..*index {
+            if seq.next_element::<IgnoredAny>()?.is_none() {
+                return Ok(());
+            }
+        }
+
+        seq.next_element_seed(Navigate(rest)).map(|_| ())
+    }
+}
+
+/// Fails on any value.
+struct Target;
+
+impl<'de> Visitor<'de> for Target {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str(TARGET)
+    }
+}
@@ -0,0 +1,267 @@
+use disma::api::params::channel::{ChannelParamsChannelType, ThreadParamsThreadType};
+use serde_yaml::{Mapping, Value};
+
+use super::locate::Segment;
+
+const ENTITY_IDENTITY_KEYS: [&str; 3] = ["name", "category", "type"];
+const ENTITY_TYPE_KEY: &str = "type";
+const REMOVE_KEY: &str = "$remove";
+
+/// How an entity (list item with a `name`) is merged with an entity of the
+/// same identity (_name_, _category_ and _type_, where an omitted _type_ is
+/// the default one).
+enum EntityMerge {
+    /// The whole entity is replaced.
+    Replace,
+    /// The entity fields are merged, and entities marked with
+    /// `$remove: true` are removed.
+    Patch,
+}
+
+/// Error of a patch, at the `path` of the faulty value in the overlay.
+#[derive(Debug, PartialEq)]
+pub struct PatchError {
+    pub path: Vec<Segment>,
+    pub message: String,
+}
+
+/// Merges `overlay` on top of `base`. Mappings are merged key by key, lists of
+/// entities are merged by identity, where an entity replaces the whole entity
+/// of the same identity, and any other value is replaced. An empty list, or
+/// `null`, clears a list.
+pub fn merge(base: Value, overlay: Value) -> Value {
+    merge_at(base, overlay, &EntityMerge::Replace, &mut Vec::new())
+        .expect("Entities are only removed when patching")
+}
+
+/// Merges `overlay` on top of `base` like `merge`, except that entities are
+/// patched field by field, and entities marked with `$remove: true` are
+/// removed. Fails on the removal of an entity missing from `base`.
+pub fn merge_overlay(base: Value, overlay: Value) -> Result<Value, PatchError> {
+    merge_at(base, overlay, &EntityMerge::Patch, &mut Vec::new())
+}
+
+fn merge_at(
+    base: Value,
+    overlay: Value,
+    entity_merge: &EntityMerge,
+    path: &mut Vec<Segment>,
+) -> Result<Value, PatchError> {
+    Ok(match (base, overlay) {
+        (Value::Mapping(base), Value::Mapping(overlay)) => {
+            Value::Mapping(merge_mappings(base, overlay, entity_merge, path)?)
+        }
+        (Value::Sequence(base), Value::Sequence(overlay))
+            if !overlay.is_empty() && is_entity_list(&base) && is_entity_list(&overlay) =>
+        {
+            Value::Sequence(merge_entities(base, overlay, entity_merge, path)?)
+        }
+        (Value::Sequence(_), Value::Null) => Value::Sequence(Vec::new()),
+        (base, Value::Null) => base,
+        (_, overlay) => overlay,
+    })
+}
+
+fn merge_mappings(
+    mut base: Mapping,
+    overlay: Mapping,
+    entity_merge: &EntityMerge,
+    path: &mut Vec<Segment>,
+) -> Result<Mapping, PatchError> {
+    for (key, value) in overlay {
+        match base.get_mut(&key) {
+            Some(base_value) => {
+                path.push(Segment::Key(key));
+                *base_value = merge_at(std::mem::take(base_value), value, entity_merge, path)?;
+                path.pop();
+            }
+            None => {
+                base.insert(key, value);
+            }
+        }
+    }
+
+    Ok(base)
+}
+
+fn merge_entities(
+    mut base: Vec<Value>,
+    overlay: Vec<Value>,
+    entity_merge: &EntityMerge,
+    path: &mut Vec<Segment>,
+) -> Result<Vec<Value>, PatchError> {
+    for (overlay_index, mut entity) in overlay.into_iter().enumerate() {
+        let position = base
+            .iter()
+            .position(|base_entity| identity(base_entity) == identity(&entity));
+
+        match entity_merge {
+            EntityMerge::Replace => match position {
+                Some(index) => base[index] = entity,
+                None => base.push(entity),
+            },
+            EntityMerge::Patch => {
+                let remove = entity
+                    .as_mapping_mut()
+                    .and_then(|mapping| mapping.remove(REMOVE_KEY))
+                    .and_then(|remove| remove.as_bool())
+                    .unwrap_or(false);
+
+                path.push(Segment::Index(overlay_index));
+                match (position, remove) {
+                    (Some(index), true) => {
+                        base.remove(index);
+                    }
+                    (None, true) => {
+                        path.push(Segment::Key(Value::String(REMOVE_KEY.to_string())));
+                        return Err(PatchError {
+                            path: path.clone(),
+                            message: format!(
+                                "Cannot remove {}: no such entity to patch.",
+                                describe(&entity)
+                            ),
+                        });
+                    }
+                    (Some(index), false) => {
+                        base[index] =
+                            merge_at(std::mem::take(&mut base[index]), entity, entity_merge, path)?
+                    }
+                    (None, false) => base.push(entity),
+                }
+                path.pop();
+            }
+        }
+    }
+
+    Ok(base)
+}
+
+pub(crate) fn is_entity_list(items: &[Value]) -> bool {
+    items.iter().all(|item| item.get("name").is_some())
+}
+
+pub(crate) fn identity(entity: &Value) -> Vec<Option<&Value>> {
+    let default_types = default_types();
+
+    ENTITY_IDENTITY_KEYS
+        .iter()
+        .map(|key| match entity.get(key) {
+            Some(value) if *key == ENTITY_TYPE_KEY && default_types.contains(value) => None,
+            value => value,
+        })
+        .collect()
+}
+
+/// Default types of the entities having one (channels and threads).
+fn default_types() -> [Value; 2] {
+    [
+        serde_yaml::to_value(ChannelParamsChannelType::default()).unwrap(),
+        serde_yaml::to_value(ThreadParamsThreadType::default()).unwrap(),
+    ]
+}
+
+fn describe(entity: &Value) -> String {
+    ENTITY_IDENTITY_KEYS
+        .iter()
+        .filter_map(|key| {
+            entity
+                .get(key)
+                .and_then(|value| value.as_str())
+                .map(|value| format!("{key} '{value}'"))
+        })
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_yaml::Value;
+
+    use super::{merge, merge_overlay, PatchError};
+    use crate::utils::locate::Segment;
+
+    fn yaml(content: &str) -> Value {
+        serde_yaml::from_str(content).unwrap()
+    }
+
+    #[test]
+    fn when_patching_it_merges_entity_fields() {
+        let base = yaml("items:\n- name: staff\n  color: ff0000\n  is_mentionable: true\n");
+        let overlay = yaml("items:\n- name: staff\n  color: 00ff00\n- name: bot\n");
+
+        let merged = merge_overlay(base, overlay).unwrap();
+
+        assert_eq!(
+            merged,
+            yaml("items:\n- name: staff\n  color: 00ff00\n  is_mentionable: true\n- name: bot\n")
+        );
+    }
+
+    #[test]
+    fn when_patching_it_removes_marked_entities() {
+        let base = yaml("items:\n- name: staff\n- name: legacy\n");
+        let overlay = yaml("items:\n- name: legacy\n  $remove: true\n");
+
+        let merged = merge_overlay(base, overlay).unwrap();
+
+        assert_eq!(merged, yaml("items:\n- name: staff\n"));
+    }
+
+    #[test]
+    fn when_patching_removal_of_unknown_entity_it_fails_at_its_path() {
+        let base = yaml("items:\n- name: staff\n");
+        let overlay = yaml("items:\n- name: staff\n- name: unknown\n  $remove: true\n");
+
+        let error = merge_overlay(base, overlay).unwrap_err();
+
+        assert_eq!(
+            error,
+            PatchError {
+                path: vec![
+                    Segment::Key(Value::from("items")),
+                    Segment::Index(1),
+                    Segment::Key(Value::from("$remove")),
+                ],
+                message: "Cannot remove name 'unknown': no such entity to patch.".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn when_patching_empty_or_null_list_it_clears_list() {
+        let base =
+            yaml("roles:\n  items:\n  - name: staff\ncategories:\n  items:\n  - name: teams\n");
+        let overlay = yaml("roles:\n  items: []\ncategories:\n  items: null\n");
+
+        let merged = merge_overlay(base, overlay).unwrap();
+
+        assert_eq!(
+            merged,
+            yaml("roles:\n  items: []\ncategories:\n  items: []\n")
+        );
+    }
+
+    #[test]
+    fn when_replacing_it_replaces_whole_entity() {
+        let base = yaml("items:\n- name: staff\n  color: ff0000\n  is_mentionable: true\n");
+        let overlay = yaml("items:\n- name: staff\n  color: 00ff00\n");
+
+        let merged = merge(base, overlay);
+
+        assert_eq!(merged, yaml("items:\n- name: staff\n  color: 00ff00\n"));
+    }
+
+    #[test]
+    fn when_merging_it_identifies_omitted_type_as_default_type() {
+        let base =
+            yaml("items:\n- name: general\n  topic: Hello\n- name: general\n  type: VOICE\n");
+        let overlay = yaml("items:\n- name: general\n  type: TEXT\n  topic: Welcome\n");
+
+        let merged = merge_overlay(base, overlay).unwrap();
+
+        assert_eq!(
+            merged,
+            yaml("items:\n- name: general\n  topic: Welcome\n  type: TEXT\n- name: general\n  type: VOICE\n")
+        );
+    }
+}
@@ -1,3 +1,10 @@
 pub mod env;
+pub mod environment;
+pub mod error;
+pub mod include;
 pub mod input;
 pub mod io;
+pub mod locate;
+pub mod merge;
+pub mod template;
+pub mod yaml_patch;
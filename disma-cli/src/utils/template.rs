@@ -0,0 +1,342 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use handlebars::{
+    handlebars_helper, Context, Handlebars, Helper, HelperResult, Output, RenderContext,
+};
+use serde_json::Value as Json;
+use serde_yaml::{Mapping, Value};
+
+use super::{error::ConfigError, io::Deserializer, merge::merge};
+
+const ENV_VAR_PREFIX: &str = "DISMA_VAR_";
+const TEMPLATE_EXTENSION: &str = "hbs";
+
+/// Template variables, layered from the lowest to the highest precedence:
+/// variables files (in order), `DISMA_VAR_*` environment variables, then
+/// `KEY=VALUE` assignments.
+#[derive(Debug, PartialEq)]
+pub struct TemplateVars {
+    value: Value,
+}
+
+impl TemplateVars {
+    pub fn new() -> Self {
+        Self {
+            value: Value::Mapping(Mapping::new()),
+        }
+    }
+
+    pub fn with_value(self, value: Value) -> Self {
+        Self {
+            value: merge(self.value, value),
+        }
+    }
+
+    /// Adds the `DISMA_VAR_<NAME>` environment variables as `<name>` (lowercased).
+    pub fn with_env_vars(self, env_vars: impl IntoIterator<Item = (String, String)>) -> Self {
+        env_vars
+            .into_iter()
+            .filter_map(|(key, value)| {
+                key.strip_prefix(ENV_VAR_PREFIX)
+                    .map(|name| (name.to_lowercase(), value))
+            })
+            .fold(self, |vars, (name, value)| {
+                vars.with_value(nested_value(&name, Value::String(value)))
+            })
+    }
+
+    /// Adds a `KEY=VALUE` assignment, whose value is a string, or a
+    /// `KEY:=VALUE` one, whose value is parsed as YAML (ex: `teams:=[a, b]`).
+    /// Dots in the key create nested variables.
+    pub fn with_assignment(self, assignment: &str) -> Result<Self, String> {
+        let (key, value) = assignment.split_once('=').ok_or_else(|| {
+            format!("Invalid variable assignment '{assignment}'. Expected KEY=VALUE.")
+        })?;
+
+        let (key, value) = match key.strip_suffix(':') {
+            Some(key) => {
+                let value = serde_yaml::from_str::<Value>(value)
+                    .ok()
+                    .filter(|value| !value.is_null())
+                    .ok_or_else(|| {
+                        format!(
+                            "Invalid variable assignment '{assignment}'. Expected a YAML value after ':='."
+                        )
+                    })?;
+                (key, value)
+            }
+            None => (key, Value::String(value.to_string())),
+        };
+
+        Ok(self.with_value(nested_value(key, value)))
+    }
+}
+
+fn nested_value(key: &str, value: Value) -> Value {
+    key.rsplit('.').fold(value, |value, key| {
+        let mut mapping = Mapping::new();
+        mapping.insert(Value::String(key.to_string()), value);
+        Value::Mapping(mapping)
+    })
+}
+
+handlebars_helper!(lowercase: |text: str| text.to_lowercase());
+handlebars_helper!(slugify: |text: str| slug(text));
+handlebars_helper!(join: |items: array, separator: str| items
+    .iter()
+    .map(to_text)
+    .collect::<Vec<String>>()
+    .join(separator));
+
+/// `{{default value fallback}}`, which accepts undefined values even in strict mode.
+fn default(
+    helper: &Helper,
+    _: &Handlebars,
+    _: &Context,
+    _: &mut RenderContext,
+    out: &mut dyn Output,
+) -> HelperResult {
+    let value = helper
+        .param(0)
+        .map(|param| param.value())
+        .filter(|value| !value.is_null());
+    let fallback = helper.param(1).map(|param| param.value());
+
+    if let Some(value) = value.or(fallback) {
+        out.write(&to_text(value))?;
+    }
+
+    Ok(())
+}
+
+fn to_text(value: &Json) -> String {
+    match value {
+        Json::String(text) => text.clone(),
+        value => value.to_string(),
+    }
+}
+
+fn slug(text: &str) -> String {
+    text.to_lowercase()
+        .split(|character: char| !character.is_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<&str>>()
+        .join("-")
+}
+
+/// Renders Handlebars config templates, with the `lowercase`, `slugify`,
+/// `join` and `default` helpers.
+pub struct TemplateRenderer {
+    handlebars: Handlebars<'static>,
+}
+
+impl TemplateRenderer {
+    pub fn new(strict: bool) -> Self {
+        let mut handlebars = Handlebars::new();
+        handlebars.register_escape_fn(|s| s.to_string()); // preventing default HTML escaping
+        handlebars.set_strict_mode(strict);
+        handlebars.register_helper("lowercase", Box::new(lowercase));
+        handlebars.register_helper("slugify", Box::new(slugify));
+        handlebars.register_helper("join", Box::new(join));
+        handlebars.register_helper("default", Box::new(default));
+
+        Self { handlebars }
+    }
+
+    pub fn render(&self, template: &str, vars: &TemplateVars) -> Result<String, String> {
+        self.handlebars
+            .render_template(template, &vars.value)
+            .map_err(|error| error.to_string())
+    }
+}
+
+/// Compiles config templates with variables from files, environment and
+/// assignments.
+pub struct TemplateCompiler {
+    deserializer: Arc<Deserializer>,
+}
+
+impl TemplateCompiler {
+    pub fn new(deserializer: Arc<Deserializer>) -> Self {
+        Self { deserializer }
+    }
+
+    pub fn compile(
+        &self,
+        template_file: &Path,
+        vars_files: &[String],
+        assignments: &[String],
+        strict: bool,
+    ) -> Result<String, ConfigError> {
+        let template = fs::read_to_string(template_file).map_err(|error| {
+            ConfigError::new(template_file, format!("Cannot read file: {error}"))
+        })?;
+
+        let mut vars = TemplateVars::new();
+        for file in vars_files {
+            vars = vars.with_value(self.deserializer.deserialize(Path::new(file))?);
+        }
+        let mut vars = vars.with_env_vars(std::env::vars());
+        for assignment in assignments {
+            vars = vars
+                .with_assignment(assignment)
+                .map_err(|error| ConfigError::new(template_file, error))?;
+        }
+
+        TemplateRenderer::new(strict)
+            .render(&template, &vars)
+            .map_err(|error| ConfigError::new(template_file, error))
+    }
+}
+
+/// Path of the config compiled from `template_file`, used to resolve its
+/// includes and environments (ex: `guild.yaml.hbs` compiles to `guild.yaml`).
+/// Templates without an inner extension are considered as YAML.
+pub fn compiled_path(template_file: &Path) -> PathBuf {
+    let path = match template_file.extension() {
+        Some(extension) if extension == TEMPLATE_EXTENSION => template_file.with_extension(""),
+        _ => template_file.to_path_buf(),
+    };
+
+    match path.extension() {
+        Some(_) => path,
+        None => path.with_extension("yaml"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        path::{Path, PathBuf},
+        sync::Arc,
+    };
+
+    use serde_yaml::Value;
+
+    use super::{compiled_path, TemplateCompiler, TemplateRenderer, TemplateVars};
+    use crate::utils::io::Deserializer;
+
+    fn vars(yaml: &str) -> TemplateVars {
+        TemplateVars::new().with_value(serde_yaml::from_str::<Value>(yaml).unwrap())
+    }
+
+    #[test]
+    fn later_layers_override_earlier_ones() {
+        let vars = vars("{team: a, size: 1, nested: {kept: true}}")
+            .with_env_vars([
+                ("DISMA_VAR_TEAM".to_string(), "b".to_string()),
+                ("OTHER".to_string(), "ignored".to_string()),
+            ])
+            .with_assignment("size:=2")
+            .and_then(|vars| vars.with_assignment("nested.added=yes"))
+            .unwrap();
+
+        assert_eq!(
+            vars,
+            self::vars("{team: b, size: 2, nested: {kept: true, added: 'yes'}}")
+        );
+    }
+
+    #[test]
+    fn assignment_values_are_strings_unless_typed() {
+        let vars = TemplateVars::new()
+            .with_assignment("version=1.10")
+            .and_then(|vars| vars.with_assignment("empty="))
+            .and_then(|vars| vars.with_assignment("teams:=[a, b]"))
+            .unwrap();
+
+        assert_eq!(
+            vars,
+            self::vars("{version: '1.10', empty: '', teams: [a, b]}")
+        );
+    }
+
+    #[test]
+    fn given_typed_assignment_without_value_it_fails() {
+        let error = TemplateVars::new().with_assignment("team:=").unwrap_err();
+
+        assert_eq!(
+            error,
+            "Invalid variable assignment 'team:='. Expected a YAML value after ':='."
+        );
+    }
+
+    #[test]
+    fn given_assignment_without_value_it_fails() {
+        let error = TemplateVars::new().with_assignment("team").unwrap_err();
+
+        assert_eq!(
+            error,
+            "Invalid variable assignment 'team'. Expected KEY=VALUE."
+        );
+    }
+
+    #[test]
+    fn it_renders_helpers() {
+        let vars = vars("{name: Team 01 / Alpha, teams: [a, b], missing: null}");
+
+        let rendered = TemplateRenderer::new(false)
+            .render(
+                "{{lowercase name}}|{{slugify name}}|{{join teams \", \"}}|{{default missing \"none\"}}",
+                &vars,
+            )
+            .unwrap();
+
+        assert_eq!(rendered, "team 01 / alpha|team-01-alpha|a, b|none");
+    }
+
+    #[test]
+    fn given_strict_mode_undefined_variables_are_errors() {
+        let vars = vars("{}");
+
+        let lenient = TemplateRenderer::new(false).render("{{team}}", &vars);
+        let strict = TemplateRenderer::new(true).render("{{team}}", &vars);
+
+        assert_eq!(lenient, Ok("".to_string()));
+        assert_eq!(
+            TemplateRenderer::new(true).render("{{default team \"a\"}}", &vars),
+            Ok("a".to_string())
+        );
+        assert!(strict.is_err());
+    }
+
+    #[test]
+    fn compiled_path_strips_template_extension() {
+        assert_eq!(
+            compiled_path(Path::new("config/guild.json.hbs")),
+            PathBuf::from("config/guild.json")
+        );
+        assert_eq!(compiled_path(Path::new("t.hbs")), PathBuf::from("t.yaml"));
+    }
+
+    #[test]
+    fn given_strict_mode_when_compiling_undefined_variable_it_fails_with_template_file() {
+        let template_file = std::env::temp_dir().join("disma-template-strict.yaml.hbs");
+        std::fs::write(&template_file, "roles:\n  items:\n    - name: {{team}}\n").unwrap();
+        let compiler = TemplateCompiler::new(Arc::new(Deserializer()));
+
+        let error = compiler
+            .compile(&template_file, &[], &[], true)
+            .unwrap_err();
+
+        assert_eq!(error.file, template_file);
+        assert!(error.message.contains("team"));
+    }
+
+    #[test]
+    fn given_missing_template_when_compiling_it_fails_with_template_file() {
+        let template_file = std::env::temp_dir().join("disma-template-missing.yaml.hbs");
+        let compiler = TemplateCompiler::new(Arc::new(Deserializer()));
+
+        let error = compiler
+            .compile(&template_file, &[], &[], false)
+            .unwrap_err();
+
+        assert_eq!(error.file, template_file);
+        assert!(error.message.starts_with("Cannot read file"));
+    }
+}
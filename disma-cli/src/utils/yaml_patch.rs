@@ -0,0 +1,523 @@
+use std::collections::{HashMap, HashSet};
+
+use serde_yaml::{Mapping, Value};
+
+use super::merge::identity;
+
+/// Comment marking the entities of a config that were not found in the guild.
+pub const REMOVED_MARKER: &str = "# disma: not found in guild";
+
+const MERGE_KEY: &str = "<<";
+const PRESETS_KEY: &str = "presets";
+const PRESET_PREFIX: char = '$';
+
+/// Patches a block-style YAML config in place, so that it describes `new`
+/// instead of `old`, its normalized value. Comments, anchors and ordering
+/// are preserved:
+/// - unchanged values are kept as is;
+/// - changed values are replaced (mappings and entity lists are patched
+///   recursively);
+/// - new entities are appended to their list;
+/// - entities not found in `new` are kept and marked with [`REMOVED_MARKER`].
+///
+/// Lists of strings are compared as sets, with the permissions presets of
+/// `old` expanded.
+pub fn patch(content: &str, old: &Value, new: &Value) -> String {
+    let lines: Vec<String> = content.lines().map(str::to_string).collect();
+    let patcher = Patcher {
+        presets: Presets::new(old),
+    };
+
+    let patched = match (old, new) {
+        (Value::Mapping(old), Value::Mapping(new)) => {
+            patcher.patch_mapping(&lines, 0, old, new, false)
+        }
+        _ => lines,
+    };
+
+    let mut patched = patched.join("\n");
+    patched.push('\n');
+    patched
+}
+
+struct Patcher {
+    presets: Presets,
+}
+
+/// Lines of a mapping key or of a list item, with its leading comments.
+struct Segment<'a> {
+    leading: &'a [String],
+    lines: &'a [String],
+}
+
+impl Patcher {
+    fn patch_mapping(
+        &self,
+        lines: &[String],
+        indent: usize,
+        old: &Mapping,
+        new: &Mapping,
+        remove_missing: bool,
+    ) -> Vec<String> {
+        let (head, segments, tail) = split(lines, |line, first| key_at(line, indent, first));
+        let mut patched = head.to_vec();
+        let mut found_keys = HashSet::new();
+
+        for segment in segments {
+            let is_first = std::ptr::eq(segment.lines.as_ptr(), lines.as_ptr());
+            let key = key_at(&segment.lines[0], indent, is_first).unwrap();
+            found_keys.insert(key.clone());
+            let key = Value::String(key);
+
+            match (old.get(&key), new.get(&key)) {
+                (Some(old_value), Some(new_value)) if !self.same(old_value, new_value) => {
+                    patched.extend_from_slice(segment.leading);
+                    patched.extend(self.patch_value(segment.lines, indent, old_value, new_value));
+                }
+                (Some(_), None) if remove_missing && !is_dash_line(&segment.lines[0]) => {}
+                _ => {
+                    patched.extend_from_slice(segment.leading);
+                    patched.extend_from_slice(segment.lines);
+                }
+            }
+        }
+
+        for (key, new_value) in new {
+            let is_found = key.as_str().is_some_and(|key| found_keys.contains(key));
+            let is_same = old
+                .get(key)
+                .is_some_and(|old_value| self.same(old_value, new_value));
+
+            if !is_found && !is_same {
+                patched.extend(serialize_field(key, new_value, indent, None));
+            }
+        }
+
+        patched.extend_from_slice(tail);
+        patched
+    }
+
+    fn patch_value(
+        &self,
+        lines: &[String],
+        indent: usize,
+        old: &Value,
+        new: &Value,
+    ) -> Vec<String> {
+        let key_line = &lines[0];
+        let inline = inline_value(key_line, indent);
+        let anchor = inline
+            .split_whitespace()
+            .next()
+            .filter(|token| token.starts_with('&'));
+        let is_block = inline.is_empty() || anchor.is_some_and(|anchor| anchor == inline);
+        let body = &lines[1..];
+
+        if is_block {
+            if let Some(child_indent) = body_indent(body) {
+                let is_sequence = body
+                    .iter()
+                    .find(|line| !is_blank_or_comment(line))
+                    .is_some_and(|line| line[child_indent..].starts_with("- "));
+
+                let patched_body = match (old, new) {
+                    (Value::Mapping(old), Value::Mapping(new)) if !is_sequence => {
+                        Some(self.patch_mapping(body, child_indent, old, new, true))
+                    }
+                    (Value::Sequence(old), Value::Sequence(new))
+                        if is_sequence && is_entity_list(old) && is_entity_list(new) =>
+                    {
+                        self.patch_entities(body, child_indent, old, new)
+                    }
+                    _ => None,
+                };
+
+                if let Some(patched_body) = patched_body {
+                    return [key_line.clone()].into_iter().chain(patched_body).collect();
+                }
+            }
+        }
+
+        let key = Value::String(key_at(key_line, indent, true).unwrap());
+        let mut replaced = serialize_field(&key, new, indent, anchor);
+        if is_dash_line(key_line) {
+            replaced[0] = format!("{}- {}", " ".repeat(indent - 2), &replaced[0][indent..]);
+        }
+        replaced
+    }
+
+    /// Patches the entities of a block sequence, matched by position with the
+    /// entities of `old`. Returns `None` when they can't be matched.
+    fn patch_entities(
+        &self,
+        lines: &[String],
+        indent: usize,
+        old: &[Value],
+        new: &[Value],
+    ) -> Option<Vec<String>> {
+        let (head, segments, tail) = split(lines, |line, _| {
+            (line.len() > indent
+                && line[..indent].trim().is_empty()
+                && line[indent..].starts_with("- "))
+            .then_some(())
+        });
+
+        if segments.len() != old.len() {
+            return None;
+        }
+
+        let mut patched = head.to_vec();
+        for (segment, old_entity) in segments.iter().zip(old) {
+            let leading = segment
+                .leading
+                .iter()
+                .filter(|line| line.trim() != REMOVED_MARKER);
+
+            match new
+                .iter()
+                .find(|entity| identity(entity) == identity(old_entity))
+            {
+                Some(new_entity) => {
+                    patched.extend(leading.cloned());
+                    match (old_entity, new_entity) {
+                        (Value::Mapping(old_mapping), Value::Mapping(new_mapping))
+                            if !self.same(old_entity, new_entity) =>
+                        {
+                            patched.extend(self.patch_mapping(
+                                segment.lines,
+                                indent + 2,
+                                old_mapping,
+                                new_mapping,
+                                true,
+                            ))
+                        }
+                        _ => patched.extend_from_slice(segment.lines),
+                    }
+                }
+                None => {
+                    patched.extend(leading.cloned());
+                    patched.push(format!("{}{REMOVED_MARKER}", " ".repeat(indent)));
+                    patched.extend_from_slice(segment.lines);
+                }
+            }
+        }
+
+        for new_entity in new {
+            if !old
+                .iter()
+                .any(|entity| identity(entity) == identity(new_entity))
+            {
+                let serialized =
+                    serde_yaml::to_string(&Value::Sequence(vec![new_entity.clone()])).unwrap();
+                patched.extend(indented(&serialized, indent));
+            }
+        }
+
+        patched.extend_from_slice(tail);
+        Some(patched)
+    }
+
+    fn same(&self, old: &Value, new: &Value) -> bool {
+        match (old, new) {
+            (Value::Mapping(old), Value::Mapping(new)) => {
+                old.len() == new.len()
+                    && old.iter().all(|(key, old_value)| {
+                        new.get(key)
+                            .is_some_and(|new_value| self.same(old_value, new_value))
+                    })
+            }
+            (Value::Sequence(old), Value::Sequence(new)) => {
+                match (self.presets.expand(old), strings(new)) {
+                    (Some(old), Some(new)) => old == new,
+                    _ => {
+                        old.len() == new.len()
+                            && old
+                                .iter()
+                                .zip(new)
+                                .all(|(old_value, new_value)| self.same(old_value, new_value))
+                    }
+                }
+            }
+            (old, new) => old == new,
+        }
+    }
+}
+
+/// Permissions presets of a config, with nested presets expanded.
+struct Presets {
+    items: HashMap<String, Vec<String>>,
+}
+
+impl Presets {
+    fn new(config: &Value) -> Self {
+        let items = match config.get(PRESETS_KEY) {
+            Some(Value::Mapping(presets)) => presets
+                .iter()
+                .filter_map(|(name, permissions)| {
+                    Some((
+                        name.as_str()?.to_string(),
+                        strings(permissions.as_sequence()?)?.into_iter().collect(),
+                    ))
+                })
+                .collect(),
+            _ => HashMap::new(),
+        };
+
+        Self { items }
+    }
+
+    fn expand(&self, items: &[Value]) -> Option<HashSet<String>> {
+        let mut expanded = HashSet::new();
+        let mut pending: Vec<String> = items
+            .iter()
+            .map(|item| item.as_str().map(str::to_string))
+            .collect::<Option<Vec<String>>>()?;
+        let mut seen = HashSet::new();
+
+        while let Some(item) = pending.pop() {
+            let preset = item
+                .strip_prefix(PRESET_PREFIX)
+                .and_then(|name| self.items.get(name));
+
+            match preset {
+                Some(permissions) if seen.insert(item.clone()) => {
+                    pending.extend(permissions.iter().cloned())
+                }
+                Some(_) => {}
+                None => {
+                    expanded.insert(item);
+                }
+            }
+        }
+
+        Some(expanded)
+    }
+}
+
+fn strings(items: &[Value]) -> Option<HashSet<String>> {
+    items
+        .iter()
+        .map(|item| item.as_str().map(str::to_string))
+        .collect()
+}
+
+fn is_entity_list(items: &[Value]) -> bool {
+    items.iter().all(|item| item.get("name").is_some())
+}
+
+/// Splits lines into segments starting at the lines matched by `starts`.
+/// Comment and blank lines preceding a segment are its leading lines.
+fn split<T>(
+    lines: &[String],
+    starts: impl Fn(&str, bool) -> Option<T>,
+) -> (&[String], Vec<Segment<'_>>, &[String]) {
+    let starts: Vec<usize> = lines
+        .iter()
+        .enumerate()
+        .filter(|(index, line)| starts(line, *index == 0).is_some())
+        .map(|(index, _)| index)
+        .collect();
+
+    let content_end = |from: usize, to: usize| {
+        (from + 1..to)
+            .rev()
+            .find(|index| !is_blank_or_comment(&lines[*index]))
+            .map_or(from + 1, |index| index + 1)
+    };
+
+    let Some(first) = starts.first() else {
+        return (lines, Vec::new(), &[]);
+    };
+
+    let mut segments = Vec::new();
+    let mut leading_start = *first;
+    for (position, start) in starts.iter().enumerate() {
+        let next = starts.get(position + 1).copied().unwrap_or(lines.len());
+        let end = content_end(*start, next);
+        segments.push(Segment {
+            leading: &lines[leading_start..*start],
+            lines: &lines[*start..end],
+        });
+        leading_start = end;
+    }
+
+    let last_end = leading_start;
+    (&lines[..*first], segments, &lines[last_end..])
+}
+
+/// Key of a mapping entry starting at column `indent`. The first line of a
+/// list item can start with `- `.
+fn key_at(line: &str, indent: usize, first: bool) -> Option<String> {
+    if line.len() <= indent || !line.is_char_boundary(indent) {
+        return None;
+    }
+
+    let (prefix, rest) = line.split_at(indent);
+    let is_prefix_valid = prefix.trim().is_empty()
+        || (first
+            && indent >= 2
+            && prefix.ends_with("- ")
+            && prefix[..indent - 2].trim().is_empty());
+    if !is_prefix_valid || rest.starts_with([' ', '#', '-']) {
+        return None;
+    }
+
+    let end = rest
+        .find(": ")
+        .or_else(|| rest.ends_with(':').then(|| rest.len() - 1))?;
+    let key = rest[..end].trim_matches(['"', '\'']);
+
+    (!key.is_empty() && key != MERGE_KEY).then(|| key.to_string())
+}
+
+fn inline_value(key_line: &str, indent: usize) -> &str {
+    let rest = &key_line[indent..];
+    let value = match rest.find(": ") {
+        Some(index) => &rest[index + 2..],
+        None => "",
+    };
+
+    match value.find(" #") {
+        Some(index) => value[..index].trim(),
+        None => value.trim(),
+    }
+}
+
+fn body_indent(body: &[String]) -> Option<usize> {
+    body.iter()
+        .find(|line| !is_blank_or_comment(line))
+        .map(|line| line.len() - line.trim_start().len())
+}
+
+fn is_blank_or_comment(line: &str) -> bool {
+    let trimmed = line.trim();
+    trimmed.is_empty() || trimmed.starts_with('#')
+}
+
+fn is_dash_line(line: &str) -> bool {
+    line.trim_start().starts_with("- ")
+}
+
+fn serialize_field(key: &Value, value: &Value, indent: usize, anchor: Option<&str>) -> Vec<String> {
+    let mut mapping = Mapping::new();
+    mapping.insert(key.clone(), value.clone());
+    let serialized = serde_yaml::to_string(&mapping).unwrap();
+
+    let mut lines = indented(&serialized, indent);
+    if let Some(anchor) = anchor {
+        let (key_part, value_part) = lines[0].split_once(':').unwrap();
+        lines[0] = format!("{key_part}: {anchor}{value_part}");
+    }
+    lines
+}
+
+fn indented(serialized: &str, indent: usize) -> Vec<String> {
+    serialized
+        .lines()
+        .map(|line| format!("{}{line}", " ".repeat(indent)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_yaml::Value;
+
+    use super::patch;
+
+    fn yaml(content: &str) -> Value {
+        serde_yaml::from_str(content).unwrap()
+    }
+
+    const CONFIG: &str = "\
+# Roles of the guild
+roles:
+  items:
+    # Staff role
+    - name: staff
+      permissions: [ADMINISTRATOR, KICK_MEMBERS] # all of it
+      color: ff0000
+    - name: legacy
+      permissions: []
+  extra_items:
+    strategy: KEEP
+";
+
+    #[test]
+    fn given_unchanged_config_it_keeps_it_as_is() {
+        let old = yaml(CONFIG);
+        let new = yaml("roles: {items: [{name: staff, permissions: [KICK_MEMBERS, ADMINISTRATOR], color: ff0000}, {name: legacy, permissions: []}], extra_items: {strategy: KEEP}}");
+
+        let patched = patch(CONFIG, &old, &new);
+
+        assert_eq!(patched, CONFIG);
+    }
+
+    #[test]
+    fn it_updates_changed_fields_and_appends_new_entities() {
+        let old = yaml(CONFIG);
+        let new = yaml("roles: {items: [{name: staff, permissions: [ADMINISTRATOR], color: 00ff00, is_mentionable: true}, {name: legacy, permissions: []}, {name: bot, permissions: []}], extra_items: {strategy: KEEP}}");
+
+        let patched = patch(CONFIG, &old, &new);
+
+        assert_eq!(
+            patched,
+            "\
+# Roles of the guild
+roles:
+  items:
+    # Staff role
+    - name: staff
+      permissions:
+      - ADMINISTRATOR
+      color: 00ff00
+      is_mentionable: true
+    - name: legacy
+      permissions: []
+    - name: bot
+      permissions: []
+  extra_items:
+    strategy: KEEP
+"
+        );
+    }
+
+    #[test]
+    fn it_marks_removed_entities_once() {
+        let old = yaml(CONFIG);
+        let new = yaml("roles: {items: [{name: staff, permissions: [ADMINISTRATOR, KICK_MEMBERS], color: ff0000}], extra_items: {strategy: KEEP}}");
+
+        let patched = patch(CONFIG, &old, &new);
+        let patched_again = patch(&patched, &old, &new);
+
+        let expected = CONFIG.replace(
+            "    - name: legacy",
+            "    # disma: not found in guild\n    - name: legacy",
+        );
+        assert_eq!(patched, expected);
+        assert_eq!(patched_again, expected);
+    }
+
+    #[test]
+    fn it_expands_presets_and_keeps_anchors() {
+        let content = "\
+presets:
+  moderation: [KICK_MEMBERS, BAN_MEMBERS]
+base: &base
+  show_in_sidebar: true
+roles:
+  items:
+    - <<: *base
+      name: mod
+      permissions: [$moderation]
+";
+        let old = yaml("{presets: {moderation: [KICK_MEMBERS, BAN_MEMBERS]}, roles: {items: [{name: mod, show_in_sidebar: true, permissions: [$moderation]}]}}");
+        let new = yaml("{roles: {items: [{name: mod, show_in_sidebar: false, permissions: [BAN_MEMBERS, KICK_MEMBERS]}]}}");
+
+        let patched = patch(content, &old, &new);
+
+        assert_eq!(
+            patched,
+            content.to_string() + "      show_in_sidebar: false\n"
+        );
+    }
+}
@@ -1,17 +1,27 @@
 use std::sync::Arc;
 
 use crate::{
-    api::params::guild::GuildParams,
     core::{
         changes::{
+            automod::{AutoModRuleChange, AutoModRuleChangesService},
             category::{CategoryChange, CategoryChangesService},
             channel::{ChannelChange, ChannelChangesService},
+            member::{MemberChange, MemberChangesService},
+            onboarding::{OnboardingChange, OnboardingChangesService},
             role::{RoleChange, RoleChangesService},
+            scheduled_event::{ScheduledEventChange, ScheduledEventChangesService},
+            welcome_screen::{WelcomeScreenChange, WelcomeScreenChangesService},
         },
         commands::{
+            automod::{AddAutoModRule, DeleteAutoModRule, UpdateAutoModRule},
             category::{AddCategory, DeleteCategory, UpdateCategory},
             channel::{AddChannel, DeleteChannel, UpdateChannel},
+            member::UpdateMemberRoles,
+            onboarding::UpdateOnboarding,
             role::{AddRole, DeleteRole, UpdateRole},
+            scheduled_event::{AddScheduledEvent, DeleteScheduledEvent, UpdateScheduledEvent},
+            thread::{AddThread, ArchiveThread, UpdateThread},
+            welcome_screen::UpdateWelcomeScreen,
             CommandRef,
         },
         events::ChangeEventListenerRef,
@@ -26,9 +36,15 @@ pub struct ApplyChangesUseCase {
     role_changes_service: Arc<RoleChangesService>,
     category_changes_service: Arc<CategoryChangesService>,
     channel_changes_service: Arc<ChannelChangesService>,
+    automod_rule_changes_service: Arc<AutoModRuleChangesService>,
+    scheduled_event_changes_service: Arc<ScheduledEventChangesService>,
+    member_changes_service: Arc<MemberChangesService>,
+    onboarding_changes_service: Arc<OnboardingChangesService>,
+    welcome_screen_changes_service: Arc<WelcomeScreenChangesService>,
 }
 
 impl ApplyChangesUseCase {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         querier: GuildQuerierRef,
         commander: GuildCommanderRef,
@@ -36,6 +52,11 @@ impl ApplyChangesUseCase {
         role_changes_service: Arc<RoleChangesService>,
         category_changes_service: Arc<CategoryChangesService>,
         channel_changes_service: Arc<ChannelChangesService>,
+        automod_rule_changes_service: Arc<AutoModRuleChangesService>,
+        scheduled_event_changes_service: Arc<ScheduledEventChangesService>,
+        member_changes_service: Arc<MemberChangesService>,
+        onboarding_changes_service: Arc<OnboardingChangesService>,
+        welcome_screen_changes_service: Arc<WelcomeScreenChangesService>,
     ) -> Self {
         Self {
             querier,
@@ -44,23 +65,54 @@ impl ApplyChangesUseCase {
             role_changes_service,
             category_changes_service,
             channel_changes_service,
+            automod_rule_changes_service,
+            scheduled_event_changes_service,
+            member_changes_service,
+            onboarding_changes_service,
+            welcome_screen_changes_service,
         }
     }
 
-    pub fn execute(&self, guild_id: &str, params: GuildParams) {
-        let awaiting_guild: AwaitingGuild = params.into();
-        let mut existing_guild = self.querier.get_guild(guild_id);
+    /// Applies the changes needed for the guild to match `awaiting_guild`,
+    /// usually a `GuildParams` config. Fails without any change when a
+    /// section of the guild declared by `awaiting_guild` cannot be fetched.
+    pub fn execute(
+        &self,
+        guild_id: &str,
+        awaiting_guild: impl Into<AwaitingGuild>,
+    ) -> Result<(), String> {
+        let awaiting_guild: AwaitingGuild = awaiting_guild.into();
+        let mut existing_guild = self
+            .querier
+            .get_guild_with(guild_id, &awaiting_guild.sections())?;
 
         let role_commands = self.list_role_commands(&awaiting_guild, &mut existing_guild);
         let category_commands = self.list_category_commands(&awaiting_guild, &mut existing_guild);
         let channel_commands = self.list_channel_commands(&awaiting_guild, &mut existing_guild);
+        let automod_rule_commands =
+            self.list_automod_rule_commands(&awaiting_guild, &mut existing_guild);
+        let scheduled_event_commands =
+            self.list_scheduled_event_commands(&awaiting_guild, &mut existing_guild);
+        let member_commands = self.list_member_commands(&awaiting_guild, &mut existing_guild);
+        // Onboarding and welcome screen reference roles and channels, so they must come last
+        let onboarding_commands =
+            self.list_onboarding_commands(&awaiting_guild, &mut existing_guild);
+        let welcome_screen_commands =
+            self.list_welcome_screen_commands(&awaiting_guild, &mut existing_guild);
 
         let commands = role_commands
             .chain(category_commands)
             .chain(channel_commands)
+            .chain(automod_rule_commands)
+            .chain(scheduled_event_commands)
+            .chain(member_commands)
+            .chain(onboarding_commands)
+            .chain(welcome_screen_commands)
             .collect();
 
         self.execute_commands(commands, &mut existing_guild);
+
+        Ok(())
     }
 
     fn list_role_commands(
@@ -126,9 +178,124 @@ impl ApplyChangesUseCase {
                     Arc::from(UpdateChannel::new(existing.clone(), awaiting.clone()))
                 }
                 ChannelChange::Delete(existing) => Arc::from(DeleteChannel::new(existing.clone())),
+                ChannelChange::CreateThread(channel, thread) => {
+                    Arc::from(AddThread::new(channel, thread))
+                }
+                ChannelChange::UpdateThread(channel, existing, awaiting, _) => {
+                    Arc::from(UpdateThread::new(channel, existing, awaiting))
+                }
+                ChannelChange::ArchiveThread(channel, thread) => {
+                    Arc::from(ArchiveThread::new(channel, thread))
+                }
+            })
+    }
+
+    fn list_automod_rule_commands(
+        &self,
+        awaiting_guild: &AwaitingGuild,
+        existing_guild: &mut ExistingGuild,
+    ) -> impl Iterator<Item = CommandRef> {
+        let automod_rule_changes = self
+            .automod_rule_changes_service
+            .list_changes(existing_guild, awaiting_guild);
+
+        automod_rule_changes
+            .into_iter()
+            .map(|automod_rule_change| match automod_rule_change {
+                AutoModRuleChange::Create(awaiting) => {
+                    Arc::from(AddAutoModRule::new(awaiting)) as CommandRef
+                }
+                AutoModRuleChange::Update(existing, awaiting, _) => {
+                    Arc::from(UpdateAutoModRule::new(existing, awaiting))
+                }
+                AutoModRuleChange::Delete(existing) => Arc::from(DeleteAutoModRule::new(existing)),
+            })
+    }
+
+    fn list_scheduled_event_commands(
+        &self,
+        awaiting_guild: &AwaitingGuild,
+        existing_guild: &mut ExistingGuild,
+    ) -> impl Iterator<Item = CommandRef> {
+        let scheduled_event_changes = self
+            .scheduled_event_changes_service
+            .list_changes(existing_guild, awaiting_guild);
+
+        scheduled_event_changes
+            .into_iter()
+            .map(|scheduled_event_change| match scheduled_event_change {
+                ScheduledEventChange::Create(awaiting) => {
+                    Arc::from(AddScheduledEvent::new(awaiting)) as CommandRef
+                }
+                ScheduledEventChange::Update(existing, awaiting, _) => {
+                    Arc::from(UpdateScheduledEvent::new(existing, awaiting))
+                }
+                ScheduledEventChange::Delete(existing) => {
+                    Arc::from(DeleteScheduledEvent::new(existing))
+                }
+            })
+    }
+
+    fn list_member_commands(
+        &self,
+        awaiting_guild: &AwaitingGuild,
+        existing_guild: &mut ExistingGuild,
+    ) -> impl Iterator<Item = CommandRef> {
+        let member_changes = self
+            .member_changes_service
+            .list_changes(existing_guild, awaiting_guild);
+
+        member_changes
+            .into_iter()
+            .map(|member_change| match member_change {
+                MemberChange::UpdateRoles {
+                    member,
+                    roles_to_add,
+                    roles_to_remove,
+                } => Arc::from(UpdateMemberRoles::new(
+                    member,
+                    roles_to_add,
+                    roles_to_remove,
+                )) as CommandRef,
+            })
+    }
+
+    fn list_onboarding_commands(
+        &self,
+        awaiting_guild: &AwaitingGuild,
+        existing_guild: &mut ExistingGuild,
+    ) -> impl Iterator<Item = CommandRef> {
+        let onboarding_changes = self
+            .onboarding_changes_service
+            .list_changes(existing_guild, awaiting_guild);
+
+        onboarding_changes
+            .into_iter()
+            .map(|onboarding_change| match onboarding_change {
+                OnboardingChange::Update(_, awaiting, _) => {
+                    Arc::from(UpdateOnboarding::new(awaiting)) as CommandRef
+                }
             })
     }
 
+    fn list_welcome_screen_commands(
+        &self,
+        awaiting_guild: &AwaitingGuild,
+        existing_guild: &mut ExistingGuild,
+    ) -> impl Iterator<Item = CommandRef> {
+        let welcome_screen_changes = self
+            .welcome_screen_changes_service
+            .list_changes(existing_guild, awaiting_guild);
+
+        welcome_screen_changes.into_iter().map(
+            |welcome_screen_change| match welcome_screen_change {
+                WelcomeScreenChange::Update(_, awaiting, _) => {
+                    Arc::from(UpdateWelcomeScreen::new(awaiting)) as CommandRef
+                }
+            },
+        )
+    }
+
     fn execute_commands(&self, commands: Vec<CommandRef>, existing_guild: &mut ExistingGuild) {
         commands.into_iter().for_each(|command| {
             command.execute(
@@ -147,11 +314,14 @@ mod tests {
     use mock_it::{any, eq};
 
     use crate::{
-        api::params::permission::PermissionsOverwriteParams,
+        api::params::permission::{PermissionsOverwriteParams, PermissionsPresets},
         core::{
             changes::{
-                category::CategoryChangesService, channel::ChannelChangesService,
-                role::RoleChangesService,
+                automod::AutoModRuleChangesService, category::CategoryChangesService,
+                channel::ChannelChangesService, member::MemberChangesService,
+                onboarding::OnboardingChangesService, role::RoleChangesService,
+                scheduled_event::ScheduledEventChangesService,
+                welcome_screen::WelcomeScreenChangesService,
             },
             events::ChangeEventListenerMock,
         },
@@ -189,6 +359,11 @@ mod tests {
             Arc::from(RoleChangesService {}),
             Arc::from(CategoryChangesService {}),
             Arc::from(ChannelChangesService {}),
+            Arc::from(AutoModRuleChangesService {}),
+            Arc::from(ScheduledEventChangesService {}),
+            Arc::from(MemberChangesService {}),
+            Arc::from(OnboardingChangesService {}),
+            Arc::from(WelcomeScreenChangesService {}),
         )
     }
 
@@ -250,17 +425,22 @@ mod tests {
 
         let usecase = create_usecase(&querier, &commander);
 
-        usecase.execute(
-            GUILD_ID,
-            GuildParamsFixture::new()
-                .with_role(role_to_add_params.clone())
-                .with_role(role_to_update_params.clone())
-                .with_role(role_not_to_update_params.clone())
-                .build(),
-        );
+        usecase
+            .execute(
+                GUILD_ID,
+                GuildParamsFixture::new()
+                    .with_role(role_to_add_params.clone())
+                    .with_role(role_to_update_params.clone())
+                    .with_role(role_not_to_update_params.clone())
+                    .build(),
+            )
+            .unwrap();
 
-        commander.expect_add_role(eq(&role_to_add_params.into()));
-        commander.expect_update_role(eq(&role_to_update.id), eq(&role_to_update_params.into()));
+        commander.expect_add_role(eq(&role_to_add_params.into(&PermissionsPresets::default())));
+        commander.expect_update_role(
+            eq(&role_to_update.id),
+            eq(&role_to_update_params.into(&PermissionsPresets::default())),
+        );
         commander.expect_delete_role(eq(&role_to_remove.id));
     }
 
@@ -311,19 +491,19 @@ mod tests {
             .with_category(category_to_update_params.clone())
             .with_category(category_not_to_update_params.clone())
             .build();
-        usecase.execute(GUILD_ID, params.clone());
+        usecase.execute(GUILD_ID, params.clone()).unwrap();
 
         // TODO the fact that these need access to the awaiting_guild and existing_guild roles list
         // is a smell : maybe categories should not contain entire roles
         let awaiting_guild: AwaitingGuild = params.into();
         let roles_list = awaiting_guild.roles.items;
         commander.expect_add_category(
-            eq(&category_to_add_params.into(&roles_list)),
+            eq(&category_to_add_params.into(&roles_list, &PermissionsPresets::default())),
             eq(existing_guild.roles()),
         );
         commander.expect_update_category(
             eq(&category_to_update.id),
-            eq(&category_to_update_params.into(&roles_list)),
+            eq(&category_to_update_params.into(&roles_list, &PermissionsPresets::default())),
             eq(existing_guild.roles()),
         );
         commander.expect_delete_category(eq(&category_to_remove.id));
@@ -390,7 +570,7 @@ mod tests {
             .with_channel(channel_not_to_update_params.clone())
             .with_channel(channel_to_change_category_params.clone())
             .build();
-        usecase.execute(GUILD_ID, params.clone());
+        usecase.execute(GUILD_ID, params.clone()).unwrap();
 
         // TODO the fact that these need access to the awaiting_guild and existing_guild roles list
         // is a smell : maybe categories should not contain entire roles
@@ -400,6 +580,7 @@ mod tests {
             eq(&channel_to_add_params.into(
                 &awaiting_guild.roles.items,
                 &awaiting_guild.categories.items,
+                &PermissionsPresets::default(),
             )),
             eq(existing_guild.roles()),
             eq(existing_guild.categories()),
@@ -409,6 +590,7 @@ mod tests {
             eq(&channel_to_update_params.into(
                 &awaiting_guild.roles.items,
                 &awaiting_guild.categories.items,
+                &PermissionsPresets::default(),
             )),
             eq(existing_guild.roles()),
             eq(existing_guild.categories()),
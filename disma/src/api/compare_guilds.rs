@@ -0,0 +1,623 @@
+use std::sync::Arc;
+
+use crate::{
+    api::params::guild::GuildParams,
+    automod::{
+        AutoModAction, AutoModRulesList, AwaitingAutoModRule, ExistingAutoModRule,
+        RemoveExtraAutoModRules,
+    },
+    category::{AwaitingCategory, CategoriesList, ExistingCategory, RemoveExtraCategories},
+    channel::{
+        AwaitingChannel, AwaitingThreadsList, ChannelsList, ExistingChannel, ExistingThread,
+        RemoveExtraChannels, RemoveExtraThreads,
+    },
+    core::changes::{
+        automod::AutoModRuleChangesService, category::CategoryChangesService,
+        channel::ChannelChangesService, onboarding::OnboardingChangesService,
+        role::RoleChangesService, scheduled_event::ScheduledEventChangesService,
+        welcome_screen::WelcomeScreenChangesService, Change,
+    },
+    guild::{AwaitingGuild, ExistingGuild},
+    onboarding::{
+        AwaitingOnboarding, ExistingOnboarding, OnboardingPrompt, OnboardingPromptOption,
+    },
+    permission::{PermissionsOverwrite, PermissionsOverwritesList},
+    role::{AwaitingRole, ExistingRole, RemoveExtraRoles, RolesList},
+    scheduled_event::{
+        AwaitingScheduledEvent, ExistingScheduledEvent, RemoveExtraScheduledEvents,
+        ScheduledEventLocation, ScheduledEventsList,
+    },
+    welcome_screen::{AwaitingWelcomeScreen, ExistingWelcomeScreen, WelcomeScreenChannel},
+};
+
+/// A side of a comparison: an existing guild, or the guild described by a
+/// config.
+pub enum ComparedGuild {
+    Existing(ExistingGuild),
+    Config(AwaitingGuild),
+}
+
+/// Lists the changes needed for a guild to match another one, without any
+/// guild being modified. Items missing from the target are always listed as
+/// removed, and the onboarding and welcome screen are only compared when
+/// described by the target.
+pub struct CompareGuildsUseCase {
+    role_changes_service: Arc<RoleChangesService>,
+    category_changes_service: Arc<CategoryChangesService>,
+    channel_changes_service: Arc<ChannelChangesService>,
+    automod_rule_changes_service: Arc<AutoModRuleChangesService>,
+    scheduled_event_changes_service: Arc<ScheduledEventChangesService>,
+    onboarding_changes_service: Arc<OnboardingChangesService>,
+    welcome_screen_changes_service: Arc<WelcomeScreenChangesService>,
+}
+
+impl CompareGuildsUseCase {
+    pub fn new(
+        role_changes_service: Arc<RoleChangesService>,
+        category_changes_service: Arc<CategoryChangesService>,
+        channel_changes_service: Arc<ChannelChangesService>,
+        automod_rule_changes_service: Arc<AutoModRuleChangesService>,
+        scheduled_event_changes_service: Arc<ScheduledEventChangesService>,
+        onboarding_changes_service: Arc<OnboardingChangesService>,
+        welcome_screen_changes_service: Arc<WelcomeScreenChangesService>,
+    ) -> Self {
+        Self {
+            role_changes_service,
+            category_changes_service,
+            channel_changes_service,
+            automod_rule_changes_service,
+            scheduled_event_changes_service,
+            onboarding_changes_service,
+            welcome_screen_changes_service,
+        }
+    }
+
+    pub fn execute(&self, source: ComparedGuild, target: ComparedGuild) -> Vec<Change> {
+        let existing_guild = match source {
+            ComparedGuild::Existing(guild) => guild,
+            ComparedGuild::Config(guild) => existing_guild_from(&guild),
+        };
+        let mut awaiting_guild = match target {
+            ComparedGuild::Existing(guild) => GuildParams::from(&guild).into(),
+            ComparedGuild::Config(guild) => guild,
+        };
+        remove_extra_items(&mut awaiting_guild);
+
+        self.role_changes_service
+            .list_changes(&existing_guild, &awaiting_guild)
+            .into_iter()
+            .map(Change::from)
+            .chain(
+                self.category_changes_service
+                    .list_changes(&existing_guild, &awaiting_guild)
+                    .into_iter()
+                    .map(Change::from),
+            )
+            .chain(
+                self.channel_changes_service
+                    .list_changes(&existing_guild, &awaiting_guild)
+                    .into_iter()
+                    .map(Change::from),
+            )
+            .chain(
+                self.automod_rule_changes_service
+                    .list_changes(&existing_guild, &awaiting_guild)
+                    .into_iter()
+                    .map(Change::from),
+            )
+            .chain(
+                self.scheduled_event_changes_service
+                    .list_changes(&existing_guild, &awaiting_guild)
+                    .into_iter()
+                    .map(Change::from),
+            )
+            .chain(
+                self.onboarding_changes_service
+                    .list_changes(&existing_guild, &awaiting_guild)
+                    .into_iter()
+                    .map(Change::from),
+            )
+            .chain(
+                self.welcome_screen_changes_service
+                    .list_changes(&existing_guild, &awaiting_guild)
+                    .into_iter()
+                    .map(Change::from),
+            )
+            .collect()
+    }
+}
+
+fn remove_extra_items(guild: &mut AwaitingGuild) {
+    guild.roles.extra_items_strategy = Arc::from(RemoveExtraRoles {});
+    guild.categories.extra_items_strategy = Arc::from(RemoveExtraCategories {});
+    guild.channels.extra_items_strategy = Arc::from(RemoveExtraChannels {});
+    guild.automod.extra_items_strategy = Arc::from(RemoveExtraAutoModRules {});
+    guild.scheduled_events.extra_items_strategy = Arc::from(RemoveExtraScheduledEvents {});
+
+    let channels = guild
+        .channels
+        .items
+        .to_list()
+        .into_iter()
+        .map(|channel| AwaitingChannel {
+            threads: channel.threads.clone().map(|threads| AwaitingThreadsList {
+                extra_items_strategy: Arc::from(RemoveExtraThreads {}),
+                ..threads
+            }),
+            ..channel.clone()
+        })
+        .collect::<Vec<AwaitingChannel>>();
+    guild.channels.items = ChannelsList::from(channels);
+
+    let categories = CategoriesList::from(
+        guild
+            .categories
+            .items
+            .to_list()
+            .into_iter()
+            .map(|category| AwaitingCategory {
+                extra_channels_strategy: Arc::from(RemoveExtraChannels {}),
+                ..category.clone()
+            })
+            .collect::<Vec<AwaitingCategory>>(),
+    );
+    guild.categories.items = categories.clone();
+    guild.channels.categories = categories;
+}
+
+/// Guild described by a config, as if it existed. The ids of its items are
+/// their names.
+fn existing_guild_from(awaiting_guild: &AwaitingGuild) -> ExistingGuild {
+    let roles: Vec<ExistingRole> = awaiting_guild
+        .roles
+        .items
+        .to_list()
+        .into_iter()
+        .map(existing_role_from)
+        .collect();
+    let categories: Vec<ExistingCategory> = awaiting_guild
+        .categories
+        .items
+        .to_list()
+        .into_iter()
+        .map(existing_category_from)
+        .collect();
+    let channels: Vec<ExistingChannel> = awaiting_guild
+        .channels
+        .items
+        .to_list()
+        .into_iter()
+        .map(existing_channel_from)
+        .collect();
+
+    let automod_rules: Vec<ExistingAutoModRule> = awaiting_guild
+        .automod
+        .items
+        .to_list()
+        .into_iter()
+        .map(existing_automod_rule_from)
+        .collect();
+    let scheduled_events: Vec<ExistingScheduledEvent> = awaiting_guild
+        .scheduled_events
+        .items
+        .to_list()
+        .into_iter()
+        .map(existing_scheduled_event_from)
+        .collect();
+
+    ExistingGuild::new(
+        RolesList::from(roles),
+        CategoriesList::from(categories),
+        ChannelsList::from(channels),
+        AutoModRulesList::from(automod_rules),
+        ScheduledEventsList::from(scheduled_events),
+        awaiting_guild
+            .onboarding
+            .as_ref()
+            .map(existing_onboarding_from)
+            .unwrap_or_default(),
+        awaiting_guild
+            .welcome_screen
+            .as_ref()
+            .map(existing_welcome_screen_from)
+            .unwrap_or_default(),
+    )
+}
+
+fn existing_roles_from(roles: &RolesList<AwaitingRole>) -> RolesList<ExistingRole> {
+    RolesList::from(
+        roles
+            .to_list()
+            .into_iter()
+            .map(existing_role_from)
+            .collect::<Vec<ExistingRole>>(),
+    )
+}
+
+fn existing_channels_from(
+    channels: &ChannelsList<AwaitingChannel>,
+) -> ChannelsList<ExistingChannel> {
+    ChannelsList::from(
+        channels
+            .to_list()
+            .into_iter()
+            .map(existing_channel_from)
+            .collect::<Vec<ExistingChannel>>(),
+    )
+}
+
+fn existing_role_from(role: &AwaitingRole) -> ExistingRole {
+    ExistingRole {
+        id: role.name.clone(),
+        name: role.name.clone(),
+        permissions: role.permissions.clone(),
+        color: role.color.clone(),
+        is_mentionable: role.is_mentionable,
+        show_in_sidebar: role.show_in_sidebar,
+        is_managed: false,
+    }
+}
+
+fn existing_overwrites_from(
+    overwrites: &PermissionsOverwritesList<AwaitingRole>,
+) -> PermissionsOverwritesList<ExistingRole> {
+    let overwrites: Vec<PermissionsOverwrite<ExistingRole>> = overwrites
+        .to_list()
+        .iter()
+        .map(|overwrite| PermissionsOverwrite {
+            role: existing_role_from(&overwrite.role),
+            allow: overwrite.allow.clone(),
+            deny: overwrite.deny.clone(),
+        })
+        .collect();
+
+    PermissionsOverwritesList::from(overwrites)
+}
+
+fn existing_category_from(category: &AwaitingCategory) -> ExistingCategory {
+    ExistingCategory {
+        id: category.name.clone(),
+        name: category.name.clone(),
+        overwrites: existing_overwrites_from(&category.overwrites),
+    }
+}
+
+fn existing_channel_from(channel: &AwaitingChannel) -> ExistingChannel {
+    let threads = channel
+        .threads
+        .iter()
+        .flat_map(|threads| &threads.items)
+        .map(|thread| ExistingThread {
+            id: thread.name.clone(),
+            name: thread.name.clone(),
+            thread_type: thread.thread_type.clone(),
+            auto_archive_duration: thread.auto_archive_duration,
+            locked: thread.locked,
+            invitable: thread.invitable,
+            archived: false,
+            is_managed: true,
+        })
+        .collect();
+
+    ExistingChannel {
+        id: channel.name.clone(),
+        name: channel.name.clone(),
+        topic: channel.topic.clone(),
+        channel_type: channel.channel_type.clone(),
+        category: channel.category.as_ref().map(existing_category_from),
+        overwrites: existing_overwrites_from(&channel.overwrites),
+        threads,
+    }
+}
+
+fn existing_automod_rule_from(rule: &AwaitingAutoModRule) -> ExistingAutoModRule {
+    let actions = rule
+        .actions
+        .iter()
+        .map(|action| match action {
+            AutoModAction::BlockMessage { custom_message } => AutoModAction::BlockMessage {
+                custom_message: custom_message.clone(),
+            },
+            AutoModAction::SendAlertMessage { channel } => AutoModAction::SendAlertMessage {
+                channel: existing_channel_from(channel),
+            },
+            AutoModAction::Timeout { duration_seconds } => AutoModAction::Timeout {
+                duration_seconds: *duration_seconds,
+            },
+        })
+        .collect();
+
+    ExistingAutoModRule {
+        id: rule.name.clone(),
+        name: rule.name.clone(),
+        event_type: rule.event_type.clone(),
+        trigger: rule.trigger.clone(),
+        actions,
+        enabled: rule.enabled,
+        exempt_roles: existing_roles_from(&rule.exempt_roles),
+        exempt_channels: existing_channels_from(&rule.exempt_channels),
+    }
+}
+
+fn existing_scheduled_event_from(event: &AwaitingScheduledEvent) -> ExistingScheduledEvent {
+    let location = match &event.location {
+        ScheduledEventLocation::Voice { channel } => ScheduledEventLocation::Voice {
+            channel: existing_channel_from(channel),
+        },
+        ScheduledEventLocation::Stage { channel } => ScheduledEventLocation::Stage {
+            channel: existing_channel_from(channel),
+        },
+        ScheduledEventLocation::External { location } => ScheduledEventLocation::External {
+            location: location.clone(),
+        },
+    };
+
+    ExistingScheduledEvent {
+        id: event.name.clone(),
+        name: event.name.clone(),
+        description: event.description.clone(),
+        location,
+        start_time: event.start_time.clone(),
+        end_time: event.end_time.clone(),
+    }
+}
+
+fn existing_onboarding_from(onboarding: &AwaitingOnboarding) -> ExistingOnboarding {
+    let prompts = onboarding
+        .prompts
+        .iter()
+        .map(|prompt| OnboardingPrompt {
+            title: prompt.title.clone(),
+            prompt_type: prompt.prompt_type.clone(),
+            single_select: prompt.single_select,
+            required: prompt.required,
+            in_onboarding: prompt.in_onboarding,
+            options: prompt
+                .options
+                .iter()
+                .map(|option| OnboardingPromptOption {
+                    title: option.title.clone(),
+                    description: option.description.clone(),
+                    emoji: option.emoji.clone(),
+                    roles: existing_roles_from(&option.roles),
+                    channels: existing_channels_from(&option.channels),
+                })
+                .collect(),
+        })
+        .collect();
+
+    ExistingOnboarding {
+        enabled: onboarding.enabled,
+        mode: onboarding.mode.clone(),
+        default_channels: existing_channels_from(&onboarding.default_channels),
+        prompts,
+    }
+}
+
+fn existing_welcome_screen_from(welcome_screen: &AwaitingWelcomeScreen) -> ExistingWelcomeScreen {
+    ExistingWelcomeScreen {
+        enabled: welcome_screen.enabled,
+        description: welcome_screen.description.clone(),
+        channels: welcome_screen
+            .channels
+            .iter()
+            .map(|channel| WelcomeScreenChannel {
+                channel: existing_channel_from(&channel.channel),
+                description: channel.description.clone(),
+                emoji: channel.emoji.clone(),
+            })
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::{
+        api::params::{
+            guild::GuildParams,
+            onboarding::{OnboardingParams, OnboardingParamsMode},
+            permission::PermissionsOverwriteParams,
+        },
+        core::{
+            changes::{
+                automod::AutoModRuleChangesService, category::CategoryChangesService,
+                channel::ChannelChangesService, onboarding::OnboardingChangesService,
+                role::RoleChangesService, scheduled_event::ScheduledEventChangesService,
+                welcome_screen::WelcomeScreenChangesService, Change, ChangeEntity,
+            },
+            diffs::{Diff, DiffValue},
+        },
+        guild::ExistingGuild,
+        tests::{
+            fixtures::{
+                existing::{
+                    ExistingAutoModRuleFixture, ExistingGuildFixture, ExistingRoleFixture,
+                    ExistingScheduledEventFixture,
+                },
+                params::{
+                    CategoryParamsFixture, ChannelParamsFixture, GuildParamsFixture,
+                    RoleParamsFixture,
+                },
+            },
+            utils::vec::assert_contains_exactly_in_any_order,
+        },
+    };
+
+    use super::{CompareGuildsUseCase, ComparedGuild};
+
+    static A_ROLE_NAME: &str = "a_role";
+    static A_CATEGORY_NAME: &str = "a_category";
+
+    fn create_usecase() -> CompareGuildsUseCase {
+        CompareGuildsUseCase::new(
+            Arc::from(RoleChangesService {}),
+            Arc::from(CategoryChangesService {}),
+            Arc::from(ChannelChangesService {}),
+            Arc::from(AutoModRuleChangesService {}),
+            Arc::from(ScheduledEventChangesService {}),
+            Arc::from(OnboardingChangesService {}),
+            Arc::from(WelcomeScreenChangesService {}),
+        )
+    }
+
+    fn an_existing_guild() -> ExistingGuild {
+        ExistingGuildFixture::new()
+            .with_role(ExistingRoleFixture::new().with_name(A_ROLE_NAME).build())
+            .with_automod_rule(ExistingAutoModRuleFixture::new().with_name("spam").build())
+            .with_scheduled_event(
+                ExistingScheduledEventFixture::new()
+                    .with_name("meeting")
+                    .build(),
+            )
+            .build()
+    }
+
+    fn a_config() -> GuildParamsFixture {
+        GuildParamsFixture::new()
+            .with_role(RoleParamsFixture::new().with_name(A_ROLE_NAME).build())
+            .with_category(
+                CategoryParamsFixture::new()
+                    .with_name(A_CATEGORY_NAME)
+                    .with_permissions_overwrite(PermissionsOverwriteParams {
+                        role: A_ROLE_NAME.to_string(),
+                        allow: Vec::new(),
+                        deny: Vec::new(),
+                    })
+                    .build(),
+            )
+    }
+
+    fn a_channel() -> ChannelParamsFixture {
+        ChannelParamsFixture::new()
+            .with_name("general")
+            .with_category(A_CATEGORY_NAME)
+            .with_permissions_overwrites_from_category()
+    }
+
+    #[test]
+    fn given_same_configs_it_returns_no_change() {
+        let usecase = create_usecase();
+
+        let changes = usecase.execute(
+            ComparedGuild::Config(a_config().with_channel(a_channel().build()).build().into()),
+            ComparedGuild::Config(a_config().with_channel(a_channel().build()).build().into()),
+        );
+
+        assert_eq!(changes, vec![]);
+    }
+
+    #[test]
+    fn given_different_configs_it_returns_changes_including_removals() {
+        let usecase = create_usecase();
+        let source = a_config()
+            .with_channel(a_channel().build())
+            .with_role(RoleParamsFixture::new().with_name("removed").build())
+            .build();
+        let target = a_config()
+            .with_channel(a_channel().with_topic("Welcome").build())
+            .with_category(CategoryParamsFixture::new().with_name("added").build())
+            .build();
+
+        let changes = usecase.execute(
+            ComparedGuild::Config(source.into()),
+            ComparedGuild::Config(target.into()),
+        );
+
+        assert_contains_exactly_in_any_order(
+            &changes,
+            &vec![
+                Change::Delete(ChangeEntity::Role, "removed".to_string()),
+                Change::Create(ChangeEntity::Category, "added".to_string()),
+                Change::Update(
+                    ChangeEntity::Channel,
+                    "a_category:general (TEXT)".to_string(),
+                    vec![Diff::Update(
+                        "topic".to_string(),
+                        vec![Diff::Add(DiffValue::Multiline("Welcome".into()))],
+                    )],
+                ),
+            ],
+        );
+    }
+
+    #[test]
+    fn given_same_existing_guilds_it_returns_no_change() {
+        let usecase = create_usecase();
+        let guild = || {
+            ExistingGuildFixture::new()
+                .with_role(ExistingRoleFixture::new().with_name(A_ROLE_NAME).build())
+                .build()
+        };
+
+        let changes = usecase.execute(
+            ComparedGuild::Existing(guild()),
+            ComparedGuild::Existing(guild()),
+        );
+
+        assert_eq!(changes, vec![]);
+    }
+
+    #[test]
+    fn given_existing_guild_and_its_config_it_returns_no_change() {
+        let usecase = create_usecase();
+        let config = || GuildParams::from(&an_existing_guild());
+
+        let from_guild = usecase.execute(
+            ComparedGuild::Existing(an_existing_guild()),
+            ComparedGuild::Config(config().into()),
+        );
+        let from_config = usecase.execute(
+            ComparedGuild::Config(config().into()),
+            ComparedGuild::Existing(an_existing_guild()),
+        );
+
+        assert_eq!(from_guild, vec![]);
+        assert_eq!(from_config, vec![]);
+    }
+
+    #[test]
+    fn given_existing_guild_and_config_it_compares_automod_rules_and_scheduled_events() {
+        let usecase = create_usecase();
+        let config = GuildParamsFixture::new()
+            .with_role(RoleParamsFixture::new().with_name(A_ROLE_NAME).build())
+            .build();
+
+        let changes = usecase.execute(
+            ComparedGuild::Existing(an_existing_guild()),
+            ComparedGuild::Config(config.into()),
+        );
+
+        assert_contains_exactly_in_any_order(
+            &changes,
+            &vec![
+                Change::Delete(ChangeEntity::AutoModRule, "spam".to_string()),
+                Change::Delete(ChangeEntity::ScheduledEvent, "meeting".to_string()),
+            ],
+        );
+    }
+
+    #[test]
+    fn given_config_with_onboarding_it_compares_it_with_existing_guild() {
+        let usecase = create_usecase();
+        let mut config = GuildParams::from(&an_existing_guild());
+        config.onboarding = Some(OnboardingParams {
+            enabled: true,
+            mode: OnboardingParamsMode::DEFAULT,
+            default_channels: Vec::new(),
+            prompts: Vec::new(),
+        });
+
+        let changes = usecase.execute(
+            ComparedGuild::Existing(an_existing_guild()),
+            ComparedGuild::Config(config.into()),
+        );
+
+        assert_eq!(changes.len(), 1);
+        assert!(matches!(
+            &changes[0],
+            Change::Update(ChangeEntity::Onboarding, name, _) if name == "settings"
+        ));
+    }
+}
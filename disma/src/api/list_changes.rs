@@ -1,13 +1,21 @@
 use std::sync::Arc;
 
 use crate::{
-    api::params::guild::GuildParams,
     channel::Channel,
-    core::changes::{
-        category::{CategoryChange, CategoryChangesService},
-        channel::{ChannelChange, ChannelChangesService},
-        role::{RoleChange, RoleChangesService},
-        Change, ChangeEntity,
+    core::{
+        changes::{
+            automod::AutoModRuleChangesService,
+            category::CategoryChangesService,
+            channel::ChannelChangesService,
+            member::{MemberChange, MemberChangesService},
+            onboarding::OnboardingChangesService,
+            permission::{PermissionImpact, PermissionImpactService},
+            role::RoleChangesService,
+            scheduled_event::ScheduledEventChangesService,
+            welcome_screen::WelcomeScreenChangesService,
+            Change, ChangeEntity,
+        },
+        diffs::Diff,
     },
     guild::{AwaitingGuild, ExistingGuild, GuildQuerier},
 };
@@ -17,30 +25,98 @@ pub struct ListChangesUseCase {
     role_changes_service: Arc<RoleChangesService>,
     category_changes_service: Arc<CategoryChangesService>,
     channel_changes_service: Arc<ChannelChangesService>,
+    automod_rule_changes_service: Arc<AutoModRuleChangesService>,
+    scheduled_event_changes_service: Arc<ScheduledEventChangesService>,
+    member_changes_service: Arc<MemberChangesService>,
+    onboarding_changes_service: Arc<OnboardingChangesService>,
+    welcome_screen_changes_service: Arc<WelcomeScreenChangesService>,
+    permission_impact_service: Arc<PermissionImpactService>,
 }
 
 impl ListChangesUseCase {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         querier: Arc<dyn GuildQuerier>,
         role_changes_service: Arc<RoleChangesService>,
         category_changes_service: Arc<CategoryChangesService>,
         channel_changes_service: Arc<ChannelChangesService>,
+        automod_rule_changes_service: Arc<AutoModRuleChangesService>,
+        scheduled_event_changes_service: Arc<ScheduledEventChangesService>,
+        member_changes_service: Arc<MemberChangesService>,
+        onboarding_changes_service: Arc<OnboardingChangesService>,
+        welcome_screen_changes_service: Arc<WelcomeScreenChangesService>,
+        permission_impact_service: Arc<PermissionImpactService>,
     ) -> Self {
         Self {
             querier,
             role_changes_service,
             category_changes_service,
             channel_changes_service,
+            automod_rule_changes_service,
+            scheduled_event_changes_service,
+            member_changes_service,
+            onboarding_changes_service,
+            welcome_screen_changes_service,
+            permission_impact_service,
         }
     }
 
-    pub fn execute(&self, guild_id: &str, params: GuildParams) -> Vec<Change> {
-        let awaiting_guild: AwaitingGuild = params.into();
-        let existing_guild = self.querier.get_guild(guild_id);
+    /// Lists the changes needed for the guild to match `awaiting_guild`,
+    /// usually a `GuildParams` config. Fails when a section of the guild
+    /// declared by `awaiting_guild` cannot be fetched.
+    pub fn execute(
+        &self,
+        guild_id: &str,
+        awaiting_guild: impl Into<AwaitingGuild>,
+    ) -> Result<Vec<Change>, String> {
+        let (existing_guild, awaiting_guild) = self.load_guilds(guild_id, awaiting_guild)?;
+
+        Ok(self.list_changes(&existing_guild, &awaiting_guild))
+    }
+
+    /// Lists the changes, along with the effective permissions they would
+    /// grant or revoke to each role.
+    pub fn execute_with_impacts(
+        &self,
+        guild_id: &str,
+        awaiting_guild: impl Into<AwaitingGuild>,
+    ) -> Result<(Vec<Change>, Vec<PermissionImpact>), String> {
+        let (existing_guild, awaiting_guild) = self.load_guilds(guild_id, awaiting_guild)?;
+
+        let changes = self.list_changes(&existing_guild, &awaiting_guild);
+        let impacts = self
+            .permission_impact_service
+            .list_impacts(&existing_guild, &awaiting_guild);
+
+        Ok((changes, impacts))
+    }
+
+    fn load_guilds(
+        &self,
+        guild_id: &str,
+        awaiting_guild: impl Into<AwaitingGuild>,
+    ) -> Result<(ExistingGuild, AwaitingGuild), String> {
+        let awaiting_guild: AwaitingGuild = awaiting_guild.into();
+        let existing_guild = self
+            .querier
+            .get_guild_with(guild_id, &awaiting_guild.sections())?;
+
+        Ok((existing_guild, awaiting_guild))
+    }
 
-        self.list_role_changes(&existing_guild, &awaiting_guild)
-            .chain(self.list_category_changes(&existing_guild, &awaiting_guild))
-            .chain(self.list_channel_changes(&existing_guild, &awaiting_guild))
+    fn list_changes(
+        &self,
+        existing_guild: &ExistingGuild,
+        awaiting_guild: &AwaitingGuild,
+    ) -> Vec<Change> {
+        self.list_role_changes(existing_guild, awaiting_guild)
+            .chain(self.list_category_changes(existing_guild, awaiting_guild))
+            .chain(self.list_channel_changes(existing_guild, awaiting_guild))
+            .chain(self.list_automod_rule_changes(existing_guild, awaiting_guild))
+            .chain(self.list_scheduled_event_changes(existing_guild, awaiting_guild))
+            .chain(self.list_member_changes(existing_guild, awaiting_guild))
+            .chain(self.list_onboarding_changes(existing_guild, awaiting_guild))
+            .chain(self.list_welcome_screen_changes(existing_guild, awaiting_guild))
             .collect()
     }
 
@@ -53,13 +129,7 @@ impl ListChangesUseCase {
             .role_changes_service
             .list_changes(existing_guild, awaiting_guild);
 
-        role_changes.into_iter().map(|change| match change {
-            RoleChange::Create(awaiting) => Change::Create(ChangeEntity::Role, awaiting.name),
-            RoleChange::Update(existing, _, diffs) => {
-                Change::Update(ChangeEntity::Role, existing.name.clone(), diffs)
-            }
-            RoleChange::Delete(existing) => Change::Delete(ChangeEntity::Role, existing.name),
-        })
+        role_changes.into_iter().map(Change::from)
     }
 
     fn list_category_changes(
@@ -71,17 +141,7 @@ impl ListChangesUseCase {
             .category_changes_service
             .list_changes(existing_guild, awaiting_guild);
 
-        category_changes.into_iter().map(|change| match change {
-            CategoryChange::Create(awaiting) => {
-                Change::Create(ChangeEntity::Category, awaiting.name)
-            }
-            CategoryChange::Update(existing, _, diffs) => {
-                Change::Update(ChangeEntity::Category, existing.name.clone(), diffs)
-            }
-            CategoryChange::Delete(existing) => {
-                Change::Delete(ChangeEntity::Category, existing.name)
-            }
-        })
+        category_changes.into_iter().map(Change::from)
     }
 
     fn list_channel_changes(
@@ -93,43 +153,177 @@ impl ListChangesUseCase {
             .channel_changes_service
             .list_changes(existing_guild, awaiting_guild);
 
-        channel_changes.into_iter().map(|change| match change {
-            ChannelChange::Create(awaiting) => {
-                Change::Create(ChangeEntity::Channel, awaiting.unique_name().to_string())
+        let mut changes: Vec<Change> = channel_changes.into_iter().map(Change::from).collect();
+
+        let unsynced_channels = self
+            .channel_changes_service
+            .list_unsynced_channels(existing_guild, awaiting_guild);
+
+        for channel in unsynced_channels {
+            let name = channel.unique_name().to_string();
+            let warning = Diff::Warning(format!(
+                "permissions_overwrites: will not be synced with category {}, their overwrites will still differ",
+                channel.category_name().unwrap_or_default()
+            ));
+
+            let update = changes.iter_mut().find_map(|change| match change {
+                Change::Update(ChangeEntity::Channel, update_name, diffs)
+                    if *update_name == name =>
+                {
+                    Some(diffs)
+                }
+                _ => None,
+            });
+            match update {
+                Some(diffs) => diffs.push(warning),
+                None => changes.push(Change::Update(ChangeEntity::Channel, name, vec![warning])),
+            }
+        }
+
+        changes.into_iter()
+    }
+
+    fn list_automod_rule_changes(
+        &self,
+        existing_guild: &ExistingGuild,
+        awaiting_guild: &AwaitingGuild,
+    ) -> impl Iterator<Item = Change> {
+        let automod_rule_changes = self
+            .automod_rule_changes_service
+            .list_changes(existing_guild, awaiting_guild);
+
+        automod_rule_changes.into_iter().map(Change::from)
+    }
+
+    fn list_scheduled_event_changes(
+        &self,
+        existing_guild: &ExistingGuild,
+        awaiting_guild: &AwaitingGuild,
+    ) -> impl Iterator<Item = Change> {
+        let scheduled_event_changes = self
+            .scheduled_event_changes_service
+            .list_changes(existing_guild, awaiting_guild);
+
+        let mut changes: Vec<Change> = scheduled_event_changes
+            .into_iter()
+            .map(Change::from)
+            .collect();
+
+        let ambiguous_events = self
+            .scheduled_event_changes_service
+            .list_ambiguous_events(existing_guild, awaiting_guild);
+
+        for (event, duplicates) in ambiguous_events {
+            let warning = Diff::Warning(format!(
+                "name: {duplicates} other event(s) have this name, only the earliest one is managed"
+            ));
+
+            let update = changes.iter_mut().find_map(|change| match change {
+                Change::Update(ChangeEntity::ScheduledEvent, update_name, diffs)
+                    if *update_name == event.name =>
+                {
+                    Some(diffs)
+                }
+                _ => None,
+            });
+            match update {
+                Some(diffs) => diffs.push(warning),
+                None => changes.push(Change::Update(
+                    ChangeEntity::ScheduledEvent,
+                    event.name,
+                    vec![warning],
+                )),
             }
-            ChannelChange::Update(existing, _, diffs) => Change::Update(
-                ChangeEntity::Channel,
-                existing.unique_name().to_string(),
-                diffs,
-            ),
-            ChannelChange::Delete(existing) => {
-                Change::Delete(ChangeEntity::Channel, existing.unique_name().to_string())
+        }
+
+        changes.into_iter()
+    }
+
+    fn list_member_changes(
+        &self,
+        existing_guild: &ExistingGuild,
+        awaiting_guild: &AwaitingGuild,
+    ) -> impl Iterator<Item = Change> {
+        let member_changes = self
+            .member_changes_service
+            .list_changes(existing_guild, awaiting_guild);
+
+        member_changes.into_iter().map(|change| {
+            let diffs = change.diffs();
+            match change {
+                MemberChange::UpdateRoles { member, .. } => {
+                    Change::Update(ChangeEntity::Member, member.display_name(), diffs)
+                }
             }
         })
     }
+
+    fn list_onboarding_changes(
+        &self,
+        existing_guild: &ExistingGuild,
+        awaiting_guild: &AwaitingGuild,
+    ) -> impl Iterator<Item = Change> {
+        let onboarding_changes = self
+            .onboarding_changes_service
+            .list_changes(existing_guild, awaiting_guild);
+
+        onboarding_changes.into_iter().map(Change::from)
+    }
+
+    fn list_welcome_screen_changes(
+        &self,
+        existing_guild: &ExistingGuild,
+        awaiting_guild: &AwaitingGuild,
+    ) -> impl Iterator<Item = Change> {
+        let welcome_screen_changes = self
+            .welcome_screen_changes_service
+            .list_changes(existing_guild, awaiting_guild);
+
+        welcome_screen_changes.into_iter().map(Change::from)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use std::sync::Arc;
+    use std::{
+        collections::{BTreeMap, HashMap},
+        sync::Arc,
+    };
 
-    use mock_it::eq;
+    use mock_it::{any, eq};
 
     use crate::{
-        api::params::permission::PermissionsOverwriteParams,
+        api::params::{
+            channel::{
+                ThreadParams, ThreadParamsExtraItemsStrategy, ThreadParamsThreadType,
+                ThreadsParamsList,
+            },
+            member::MembersParamsList,
+            permission::PermissionsOverwriteParams,
+        },
         core::{
             changes::{
-                category::CategoryChangesService, channel::ChannelChangesService,
-                role::RoleChangesService, Change, ChangeEntity,
+                automod::AutoModRuleChangesService,
+                category::CategoryChangesService,
+                channel::ChannelChangesService,
+                member::MemberChangesService,
+                onboarding::OnboardingChangesService,
+                permission::{PermissionImpact, PermissionImpactKind, PermissionImpactService},
+                role::RoleChangesService,
+                scheduled_event::ScheduledEventChangesService,
+                welcome_screen::WelcomeScreenChangesService,
+                Change, ChangeEntity,
             },
-            diffs::Diff,
+            diffs::{Diff, DiffValue},
         },
         guild::GuildQuerierMock,
+        member::MembersList,
+        permission::{Permission, PermissionsList, PermissionsOverwrite, EVERYONE_ROLE_NAME},
         tests::{
             fixtures::{
                 existing::{
                     ExistingCategoryFixture, ExistingChannelFixture, ExistingGuildFixture,
-                    ExistingRoleFixture,
+                    ExistingMemberFixture, ExistingRoleFixture, ExistingThreadFixture,
                 },
                 params::{
                     CategoryParamsFixture, ChannelParamsFixture, GuildParamsFixture,
@@ -152,6 +346,12 @@ mod tests {
             Arc::from(RoleChangesService {}),
             Arc::from(CategoryChangesService {}),
             Arc::from(ChannelChangesService {}),
+            Arc::from(AutoModRuleChangesService {}),
+            Arc::from(ScheduledEventChangesService {}),
+            Arc::from(MemberChangesService {}),
+            Arc::from(OnboardingChangesService {}),
+            Arc::from(WelcomeScreenChangesService {}),
+            Arc::from(PermissionImpactService {}),
         )
     }
 
@@ -167,7 +367,7 @@ mod tests {
 
         let usecase = create_usecase(querier);
 
-        let changes = usecase.execute(GUILD_ID, params_with_no_changes);
+        let changes = usecase.execute(GUILD_ID, params_with_no_changes).unwrap();
 
         assert_eq!(changes, Vec::new());
     }
@@ -198,15 +398,17 @@ mod tests {
 
         let usecase = create_usecase(querier);
 
-        let changes = usecase.execute(
-            GUILD_ID,
-            GuildParamsFixture::new()
-                .with_role(role_to_add_params.clone())
-                .with_role(role_to_update_params.clone())
-                .with_role(role_not_to_update_params.clone())
-                .remove_extra_roles()
-                .build(),
-        );
+        let changes = usecase
+            .execute(
+                GUILD_ID,
+                GuildParamsFixture::new()
+                    .with_role(role_to_add_params.clone())
+                    .with_role(role_to_update_params.clone())
+                    .with_role(role_not_to_update_params.clone())
+                    .remove_extra_roles()
+                    .build(),
+            )
+            .unwrap();
 
         assert_eq!(
             changes,
@@ -217,7 +419,7 @@ mod tests {
                     role_to_update.name,
                     vec![Diff::Update(
                         "color".to_string(),
-                        vec![Diff::Add("124f5d".to_string())]
+                        vec![Diff::Add(DiffValue::Color("124f5d".into()))]
                     )]
                 ),
                 Change::Delete(ChangeEntity::Role, role_to_remove.name)
@@ -263,16 +465,18 @@ mod tests {
 
         let usecase = create_usecase(querier);
 
-        let changes = usecase.execute(
-            GUILD_ID,
-            GuildParamsFixture::new()
-                .with_role(RoleParamsFixture::new().with_name(A_ROLE_NAME).build())
-                .with_category(category_to_add_params.clone())
-                .with_category(category_to_update_params.clone())
-                .with_category(category_not_to_update_params.clone())
-                .remove_extra_categories()
-                .build(),
-        );
+        let changes = usecase
+            .execute(
+                GUILD_ID,
+                GuildParamsFixture::new()
+                    .with_role(RoleParamsFixture::new().with_name(A_ROLE_NAME).build())
+                    .with_category(category_to_add_params.clone())
+                    .with_category(category_to_update_params.clone())
+                    .with_category(category_not_to_update_params.clone())
+                    .remove_extra_categories()
+                    .build(),
+            )
+            .unwrap();
 
         assert_eq!(
             changes,
@@ -283,7 +487,7 @@ mod tests {
                     category_to_update.name,
                     vec![Diff::Update(
                         "overwrites".to_string(),
-                        vec![Diff::Add(A_ROLE_NAME.to_string())]
+                        vec![Diff::Add(A_ROLE_NAME.to_string().into())]
                     )]
                 ),
                 Change::Delete(ChangeEntity::Category, category_to_remove.name)
@@ -333,21 +537,23 @@ mod tests {
 
         let usecase = create_usecase(querier);
 
-        let changes = usecase.execute(
-            GUILD_ID,
-            GuildParamsFixture::new()
-                .with_category(
-                    CategoryParamsFixture::new()
-                        .with_name(A_CATEGORY_NAME)
-                        .build(),
-                )
-                .with_channel(channel_to_add_params.clone())
-                .with_channel(channel_to_update_params.clone())
-                .with_channel(channel_not_to_update_params.clone())
-                .with_channel(channel_to_change_category_params.clone())
-                .remove_extra_channels()
-                .build(),
-        );
+        let changes = usecase
+            .execute(
+                GUILD_ID,
+                GuildParamsFixture::new()
+                    .with_category(
+                        CategoryParamsFixture::new()
+                            .with_name(A_CATEGORY_NAME)
+                            .build(),
+                    )
+                    .with_channel(channel_to_add_params.clone())
+                    .with_channel(channel_to_update_params.clone())
+                    .with_channel(channel_not_to_update_params.clone())
+                    .with_channel(channel_to_change_category_params.clone())
+                    .remove_extra_channels()
+                    .build(),
+            )
+            .unwrap();
 
         // TODO should contain the UniqueChannelName and not the pre-computed string
         assert_contains_exactly_in_any_order(
@@ -363,7 +569,7 @@ mod tests {
                     ":to_update (TEXT)".to_string(),
                     vec![Diff::Update(
                         "topic".to_string(),
-                        vec![Diff::Add("new_topic".to_string())],
+                        vec![Diff::Add(DiffValue::Multiline("new_topic".into()))],
                     )],
                 ),
                 Change::Delete(ChangeEntity::Channel, ":category_change (TEXT)".to_string()),
@@ -371,4 +577,295 @@ mod tests {
             ],
         );
     }
+
+    #[test]
+    fn can_list_thread_changes() {
+        let querier = GuildQuerierMock::new();
+
+        let thread_to_update = ExistingThreadFixture::new().with_name("rules").build();
+        let thread_to_archive = ExistingThreadFixture::new().with_name("old").build();
+        let thread_to_keep = ExistingThreadFixture::new().with_name("chat").build();
+        let thread_to_unarchive = ExistingThreadFixture::new()
+            .with_name("faq")
+            .archived()
+            .build();
+        let archived_thread = ExistingThreadFixture::new()
+            .with_name("older")
+            .archived()
+            .build();
+        let existing_channel = ExistingChannelFixture::new().with_name("help").build();
+        let channel_keeping_threads = ExistingChannelFixture::new().with_name("general").build();
+        let thread_params = |name: &str, locked: bool| ThreadParams {
+            name: name.to_string(),
+            _type: ThreadParamsThreadType::PUBLIC,
+            auto_archive_duration: 10080,
+            locked,
+            invitable: true,
+            message: None,
+        };
+        let params = GuildParamsFixture::new()
+            .with_channel(
+                ChannelParamsFixture::new()
+                    .with_name("help")
+                    .with_threads(ThreadsParamsList {
+                        items: vec![thread_params("rules", true), thread_params("faq", false)],
+                        extra_items: ThreadParamsExtraItemsStrategy::Remove,
+                    })
+                    .build(),
+            )
+            .with_channel(
+                ChannelParamsFixture::new()
+                    .with_name("general")
+                    .with_threads(ThreadsParamsList::default())
+                    .build(),
+            )
+            .with_channel(
+                ChannelParamsFixture::new()
+                    .with_name("new")
+                    .with_threads(ThreadsParamsList {
+                        items: vec![thread_params("faq", false)],
+                        ..Default::default()
+                    })
+                    .build(),
+            )
+            .build();
+
+        querier
+            .when_list_threads(eq(GUILD_ID), any())
+            .will_return(Ok(HashMap::from([
+                (
+                    existing_channel.id.clone(),
+                    vec![
+                        thread_to_update,
+                        thread_to_archive,
+                        thread_to_unarchive,
+                        archived_thread,
+                    ],
+                ),
+                (channel_keeping_threads.id.clone(), vec![thread_to_keep]),
+            ])));
+        querier.when_get_guild(eq(GUILD_ID)).will_return(
+            ExistingGuildFixture::new()
+                .with_channel(existing_channel)
+                .with_channel(channel_keeping_threads)
+                .build(),
+        );
+
+        let usecase = create_usecase(querier);
+
+        let changes = usecase.execute(GUILD_ID, params).unwrap();
+
+        assert_contains_exactly_in_any_order(
+            &changes,
+            &vec![
+                Change::Create(ChangeEntity::Channel, ":new (TEXT)".to_string()),
+                Change::Create(ChangeEntity::Thread, ":new (TEXT) > faq".to_string()),
+                Change::Update(
+                    ChangeEntity::Thread,
+                    ":help (TEXT) > faq".to_string(),
+                    vec![Diff::Update(
+                        "archived".to_string(),
+                        vec![Diff::Remove("true".into()), Diff::Add("false".into())],
+                    )],
+                ),
+                Change::Update(
+                    ChangeEntity::Thread,
+                    ":help (TEXT) > rules".to_string(),
+                    vec![Diff::Update(
+                        "locked".to_string(),
+                        vec![Diff::Remove("false".into()), Diff::Add("true".into())],
+                    )],
+                ),
+                Change::Delete(ChangeEntity::Thread, ":help (TEXT) > old".to_string()),
+            ],
+        );
+    }
+
+    #[test]
+    fn when_members_are_configured_it_lists_member_changes() {
+        let querier = GuildQuerierMock::new();
+        let role = ExistingRoleFixture::new().with_name(A_ROLE_NAME).build();
+        let member = ExistingMemberFixture::new().build();
+        let params = GuildParamsFixture::new()
+            .with_role(RoleParamsFixture::new().with_name(A_ROLE_NAME).build())
+            .with_members(MembersParamsList {
+                items: BTreeMap::from([(member.id.clone(), vec![A_ROLE_NAME.to_string()])]),
+                ..Default::default()
+            })
+            .build();
+
+        querier
+            .when_get_guild(eq(GUILD_ID))
+            .will_return(ExistingGuildFixture::new().with_role(role.clone()).build());
+        querier
+            .when_list_members(eq(GUILD_ID), any())
+            .will_return(Ok(MembersList::from(vec![member.clone()])));
+
+        let usecase = create_usecase(querier);
+
+        let changes = usecase.execute(GUILD_ID, params).unwrap();
+
+        assert_eq!(
+            changes,
+            vec![Change::Update(
+                ChangeEntity::Member,
+                member.display_name(),
+                vec![Diff::Update(
+                    "roles".to_string(),
+                    vec![Diff::Add(A_ROLE_NAME.to_string().into())]
+                )]
+            )]
+        );
+    }
+
+    #[test]
+    fn given_configured_section_that_cannot_be_fetched_it_fails() {
+        let querier = GuildQuerierMock::new();
+        let params = GuildParamsFixture::new()
+            .with_members(MembersParamsList::default())
+            .build();
+
+        querier
+            .when_get_guild(eq(GUILD_ID))
+            .will_return(ExistingGuildFixture::new().build());
+        querier
+            .when_list_members(eq(GUILD_ID), any())
+            .will_return(Err("Could not fetch members.".to_string()));
+
+        let usecase = create_usecase(querier);
+
+        let result = usecase.execute(GUILD_ID, params);
+
+        assert_eq!(result, Err("Could not fetch members.".to_string()));
+    }
+
+    #[test]
+    fn can_list_changes_with_permission_impacts() {
+        let querier = GuildQuerierMock::new();
+
+        querier.when_get_guild(eq(GUILD_ID)).will_return(
+            ExistingGuildFixture::new()
+                .with_role(
+                    ExistingRoleFixture::new()
+                        .with_name(EVERYONE_ROLE_NAME)
+                        .with_permissions(PermissionsList::from(vec![
+                            Permission::VIEW_CHANNEL,
+                            Permission::SEND_MESSAGES,
+                        ]))
+                        .build(),
+                )
+                .with_channel(ExistingChannelFixture::new().with_name("general").build())
+                .build(),
+        );
+
+        let usecase = create_usecase(querier);
+
+        let (changes, impacts) = usecase
+            .execute_with_impacts(
+                GUILD_ID,
+                GuildParamsFixture::new()
+                    .with_role(
+                        RoleParamsFixture::new()
+                            .with_name(EVERYONE_ROLE_NAME)
+                            .with_permissions(vec![Permission::VIEW_CHANNEL.into()])
+                            .build(),
+                    )
+                    .with_channel(ChannelParamsFixture::new().with_name("general").build())
+                    .build(),
+            )
+            .unwrap();
+
+        assert_eq!(
+            changes,
+            vec![Change::Update(
+                ChangeEntity::Role,
+                EVERYONE_ROLE_NAME.to_string(),
+                vec![Diff::Update(
+                    "permissions".to_string(),
+                    vec![Diff::Remove("SEND_MESSAGES".into())]
+                )]
+            )]
+        );
+        assert_eq!(
+            impacts,
+            vec![PermissionImpact {
+                role: EVERYONE_ROLE_NAME.to_string(),
+                kind: PermissionImpactKind::Loss,
+                permission: Permission::SEND_MESSAGES,
+                channels: vec![":general (TEXT)".to_string()],
+            }]
+        );
+    }
+
+    #[test]
+    fn given_channel_from_category_that_will_not_be_synced_it_lists_a_warning() {
+        let querier = GuildQuerierMock::new();
+        let role = ExistingRoleFixture::new().with_name(A_ROLE_NAME).build();
+        let category = ExistingCategoryFixture::new()
+            .with_name(A_CATEGORY_NAME)
+            .with_permissions_overwrites(vec![PermissionsOverwrite {
+                role: role.clone(),
+                allow: PermissionsList::from(vec![Permission::VIEW_CHANNEL]).with_bit(60),
+                deny: PermissionsList::new(),
+            }])
+            .build();
+        let channel = ExistingChannelFixture::new()
+            .with_name("synced")
+            .with_category(&category)
+            .with_permissions_overwrites(vec![PermissionsOverwrite {
+                role: role.clone(),
+                allow: PermissionsList::from(vec![Permission::VIEW_CHANNEL]),
+                deny: PermissionsList::new(),
+            }])
+            .build();
+
+        querier.when_get_guild(eq(GUILD_ID)).will_return(
+            ExistingGuildFixture::new()
+                .with_role(role)
+                .with_category(category)
+                .with_channel(channel)
+                .build(),
+        );
+
+        let usecase = create_usecase(querier);
+
+        let changes = usecase
+            .execute(
+                GUILD_ID,
+                GuildParamsFixture::new()
+                    .with_role(RoleParamsFixture::new().with_name(A_ROLE_NAME).build())
+                    .with_category(
+                        CategoryParamsFixture::new()
+                            .with_name(A_CATEGORY_NAME)
+                            .with_permissions_overwrite(PermissionsOverwriteParams {
+                                role: A_ROLE_NAME.to_string(),
+                                allow: vec![Permission::VIEW_CHANNEL.into()],
+                                deny: Vec::new(),
+                            })
+                            .keep_extra_channels()
+                            .build(),
+                    )
+                    .with_channel(
+                        ChannelParamsFixture::new()
+                            .with_name("synced")
+                            .with_category(A_CATEGORY_NAME)
+                            .with_permissions_overwrites_from_category()
+                            .build(),
+                    )
+                    .build(),
+            )
+            .unwrap();
+
+        assert_eq!(
+            changes,
+            vec![Change::Update(
+                ChangeEntity::Channel,
+                "a_category:synced (TEXT)".to_string(),
+                vec![Diff::Warning(
+                    "permissions_overwrites: will not be synced with category a_category, their overwrites will still differ".to_string()
+                )]
+            )]
+        );
+        assert!(!changes[0].is_change());
+    }
 }
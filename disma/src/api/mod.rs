@@ -1,6 +1,9 @@
 mod apply_changes;
 pub use apply_changes::*;
 
+mod compare_guilds;
+pub use compare_guilds::*;
+
 mod list_changes;
 pub use list_changes::*;
 
@@ -0,0 +1,290 @@
+use std::sync::Arc;
+
+use crate::{
+    automod::{
+        AutoModAction, AutoModEventType, AutoModKeywordPreset, AutoModTrigger, AwaitingAutoModRule,
+        AwaitingAutoModRulesList, ExtraAutoModRulesStrategy, KeepExtraAutoModRules,
+        RemoveExtraAutoModRules,
+    },
+    channel::{AwaitingChannel, ChannelsList},
+    role::{AwaitingRole, RolesList},
+};
+
+use super::{
+    AutoModRuleParams, AutoModRuleParamsAction, AutoModRuleParamsEventType,
+    AutoModRuleParamsExtraItemsStrategy, AutoModRuleParamsKeywordPreset, AutoModRuleParamsTrigger,
+    AutoModRulesParamsList,
+};
+
+impl AutoModRulesParamsList {
+    pub fn into(
+        self,
+        roles: &RolesList<AwaitingRole>,
+        channels: &ChannelsList<AwaitingChannel>,
+    ) -> Result<AwaitingAutoModRulesList, String> {
+        let items = self
+            .items
+            .into_iter()
+            .map(|rule| rule.into(roles, channels))
+            .collect::<Result<Vec<AwaitingAutoModRule>, String>>()?
+            .into();
+
+        Ok(AwaitingAutoModRulesList {
+            items,
+            extra_items_strategy: self.extra_items.into(),
+        })
+    }
+}
+
+impl From<AutoModRuleParamsExtraItemsStrategy> for Arc<dyn ExtraAutoModRulesStrategy> {
+    fn from(value: AutoModRuleParamsExtraItemsStrategy) -> Self {
+        match value {
+            AutoModRuleParamsExtraItemsStrategy::Keep => Arc::from(KeepExtraAutoModRules {}),
+            AutoModRuleParamsExtraItemsStrategy::Remove => Arc::from(RemoveExtraAutoModRules {}),
+        }
+    }
+}
+
+impl AutoModRuleParams {
+    pub fn into(
+        self,
+        roles: &RolesList<AwaitingRole>,
+        channels: &ChannelsList<AwaitingChannel>,
+    ) -> Result<AwaitingAutoModRule, String> {
+        let find_channel = |name: &str| {
+            channels
+                .find_by_reference(name)
+                .map_err(|error| format!("Cannot build AutoMod rule '{}': {error}", self.name))?
+                .cloned()
+                .ok_or_else(|| {
+                    format!(
+                        "Cannot build AutoMod rule '{}' from non-existant channel '{name}'",
+                        self.name
+                    )
+                })
+        };
+
+        let actions = self
+            .actions
+            .into_iter()
+            .map(|action| match action {
+                AutoModRuleParamsAction::BlockMessage { custom_message } => {
+                    Ok(AutoModAction::BlockMessage { custom_message })
+                }
+                AutoModRuleParamsAction::SendAlertMessage { channel } => {
+                    Ok(AutoModAction::SendAlertMessage {
+                        channel: find_channel(&channel)?,
+                    })
+                }
+                AutoModRuleParamsAction::Timeout { duration_seconds } => {
+                    Ok(AutoModAction::Timeout { duration_seconds })
+                }
+            })
+            .collect::<Result<_, String>>()?;
+
+        let exempt_roles = self
+            .exempt_roles
+            .iter()
+            .map(|name| {
+                roles.find_by_name(name).cloned().ok_or_else(|| {
+                    format!(
+                        "Cannot build AutoMod rule '{}' from non-existant role '{name}'",
+                        self.name
+                    )
+                })
+            })
+            .collect::<Result<Vec<AwaitingRole>, String>>()?
+            .into();
+
+        let exempt_channels = self
+            .exempt_channels
+            .iter()
+            .map(|name| find_channel(name))
+            .collect::<Result<Vec<AwaitingChannel>, String>>()?
+            .into();
+
+        Ok(AwaitingAutoModRule {
+            name: self.name.clone(),
+            event_type: self.event_type.into(),
+            trigger: self.trigger.into(),
+            actions,
+            enabled: self.enabled,
+            exempt_roles,
+            exempt_channels,
+        })
+    }
+}
+
+impl From<AutoModRuleParamsEventType> for AutoModEventType {
+    fn from(value: AutoModRuleParamsEventType) -> Self {
+        match value {
+            AutoModRuleParamsEventType::MESSAGE_SEND => AutoModEventType::MESSAGE_SEND,
+            AutoModRuleParamsEventType::MEMBER_UPDATE => AutoModEventType::MEMBER_UPDATE,
+        }
+    }
+}
+
+impl From<AutoModRuleParamsKeywordPreset> for AutoModKeywordPreset {
+    fn from(value: AutoModRuleParamsKeywordPreset) -> Self {
+        match value {
+            AutoModRuleParamsKeywordPreset::PROFANITY => AutoModKeywordPreset::PROFANITY,
+            AutoModRuleParamsKeywordPreset::SEXUAL_CONTENT => AutoModKeywordPreset::SEXUAL_CONTENT,
+            AutoModRuleParamsKeywordPreset::SLURS => AutoModKeywordPreset::SLURS,
+        }
+    }
+}
+
+impl From<AutoModRuleParamsTrigger> for AutoModTrigger {
+    fn from(value: AutoModRuleParamsTrigger) -> Self {
+        match value {
+            AutoModRuleParamsTrigger::Keyword {
+                keywords,
+                regex_patterns,
+                allow_list,
+            } => AutoModTrigger::Keyword {
+                keywords,
+                regex_patterns,
+                allow_list,
+            },
+            AutoModRuleParamsTrigger::Spam => AutoModTrigger::Spam,
+            AutoModRuleParamsTrigger::KeywordPreset {
+                presets,
+                allow_list,
+            } => AutoModTrigger::KeywordPreset {
+                presets: presets.into_iter().map(Into::into).collect(),
+                allow_list,
+            },
+            AutoModRuleParamsTrigger::MentionSpam {
+                mention_total_limit,
+                mention_raid_protection_enabled,
+            } => AutoModTrigger::MentionSpam {
+                mention_total_limit,
+                mention_raid_protection_enabled,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        api::params::automod::{
+            AutoModRuleParams, AutoModRuleParamsAction, AutoModRuleParamsEventType,
+            AutoModRuleParamsTrigger,
+        },
+        automod::{AutoModAction, AutoModEventType, AutoModTrigger, AwaitingAutoModRule},
+        channel::{AwaitingChannel, ChannelsList},
+        role::{AwaitingRole, RolesList},
+        tests::fixtures::awaiting::{
+            AwaitingCategoryFixture, AwaitingChannelFixture, AwaitingRoleFixture,
+        },
+    };
+
+    const A_ROLE_NAME: &str = "Moderator";
+    const A_CHANNEL_NAME: &str = "mod-logs";
+
+    fn given_params(role_name: &str, channel_name: &str) -> AutoModRuleParams {
+        AutoModRuleParams {
+            name: "no-spam".to_string(),
+            event_type: AutoModRuleParamsEventType::MESSAGE_SEND,
+            trigger: AutoModRuleParamsTrigger::Spam,
+            actions: vec![AutoModRuleParamsAction::SendAlertMessage {
+                channel: channel_name.to_string(),
+            }],
+            enabled: true,
+            exempt_roles: vec![role_name.to_string()],
+            exempt_channels: vec![channel_name.to_string()],
+        }
+    }
+
+    fn given_roles_and_channels() -> (RolesList<AwaitingRole>, ChannelsList<AwaitingChannel>) {
+        let roles = RolesList::from(vec![AwaitingRoleFixture::new()
+            .with_name(A_ROLE_NAME)
+            .build()]);
+        let channels = ChannelsList::from(vec![AwaitingChannelFixture::new()
+            .with_name(A_CHANNEL_NAME)
+            .build()]);
+
+        (roles, channels)
+    }
+
+    #[test]
+    fn can_convert_params_to_awaiting() {
+        let (roles, channels) = given_roles_and_channels();
+        let params = given_params(A_ROLE_NAME, A_CHANNEL_NAME);
+        let channel = channels
+            .find_by_reference(A_CHANNEL_NAME)
+            .unwrap()
+            .unwrap()
+            .clone();
+        let expected_awaiting = AwaitingAutoModRule {
+            name: "no-spam".to_string(),
+            event_type: AutoModEventType::MESSAGE_SEND,
+            trigger: AutoModTrigger::Spam,
+            actions: vec![AutoModAction::SendAlertMessage {
+                channel: channel.clone(),
+            }],
+            enabled: true,
+            exempt_roles: roles.clone(),
+            exempt_channels: ChannelsList::from(vec![channel]),
+        };
+
+        let awaiting = params.into(&roles, &channels);
+
+        assert_eq!(awaiting, Ok(expected_awaiting));
+    }
+
+    #[test]
+    fn given_non_existant_exempt_role_it_fails() {
+        let (roles, channels) = given_roles_and_channels();
+        let params = given_params("non-existant", A_CHANNEL_NAME);
+
+        let awaiting = params.into(&roles, &channels);
+
+        assert_eq!(
+            awaiting,
+            Err(
+                "Cannot build AutoMod rule 'no-spam' from non-existant role 'non-existant'"
+                    .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn given_non_existant_alert_channel_it_fails() {
+        let (roles, channels) = given_roles_and_channels();
+        let params = given_params(A_ROLE_NAME, "non-existant");
+
+        let awaiting = params.into(&roles, &channels);
+
+        assert_eq!(
+            awaiting,
+            Err(
+                "Cannot build AutoMod rule 'no-spam' from non-existant channel 'non-existant'"
+                    .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn given_channel_name_shared_by_categories_it_needs_category() {
+        let (roles, _) = given_roles_and_channels();
+        let channel = |category: &str| {
+            AwaitingChannelFixture::new()
+                .with_name(A_CHANNEL_NAME)
+                .with_category(&AwaitingCategoryFixture::new().with_name(category).build())
+                .build()
+        };
+        let channels = ChannelsList::from(vec![channel("a"), channel("b")]);
+
+        let ambiguous = given_params(A_ROLE_NAME, A_CHANNEL_NAME).into(&roles, &channels);
+        let qualified =
+            given_params(A_ROLE_NAME, &format!("b:{A_CHANNEL_NAME}")).into(&roles, &channels);
+
+        assert!(ambiguous.is_err());
+        assert_eq!(
+            qualified.unwrap().exempt_channels,
+            ChannelsList::from(vec![channel("b")])
+        );
+    }
+}
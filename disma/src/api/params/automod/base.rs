@@ -0,0 +1,197 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Default, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct AutoModRulesParamsList {
+    #[serde(default)]
+    pub items: Vec<AutoModRuleParams>,
+    #[serde(default = "AutoModRuleParamsExtraItemsStrategy::default")]
+    pub extra_items: AutoModRuleParamsExtraItemsStrategy,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(tag = "strategy", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AutoModRuleParamsExtraItemsStrategy {
+    #[default]
+    Keep,
+    Remove,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct AutoModRuleParams {
+    pub name: String,
+    #[serde(default = "AutoModRuleParamsEventType::default")]
+    pub event_type: AutoModRuleParamsEventType,
+    pub trigger: AutoModRuleParamsTrigger,
+    #[serde(default)]
+    pub actions: Vec<AutoModRuleParamsAction>,
+    pub enabled: bool,
+    #[serde(default)]
+    pub exempt_roles: Vec<String>,
+    #[serde(default)]
+    pub exempt_channels: Vec<String>,
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum AutoModRuleParamsEventType {
+    #[default]
+    MESSAGE_SEND,
+    MEMBER_UPDATE,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(tag = "type", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AutoModRuleParamsTrigger {
+    Keyword {
+        #[serde(default)]
+        keywords: Vec<String>,
+        #[serde(default)]
+        regex_patterns: Vec<String>,
+        #[serde(default)]
+        allow_list: Vec<String>,
+    },
+    Spam,
+    KeywordPreset {
+        presets: Vec<AutoModRuleParamsKeywordPreset>,
+        #[serde(default)]
+        allow_list: Vec<String>,
+    },
+    MentionSpam {
+        mention_total_limit: u8,
+        #[serde(default)]
+        mention_raid_protection_enabled: bool,
+    },
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum AutoModRuleParamsKeywordPreset {
+    PROFANITY,
+    SEXUAL_CONTENT,
+    SLURS,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(tag = "type", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AutoModRuleParamsAction {
+    BlockMessage {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        custom_message: Option<String>,
+    },
+    SendAlertMessage {
+        channel: String,
+    },
+    Timeout {
+        duration_seconds: u64,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::params::automod::{
+        AutoModRuleParams, AutoModRuleParamsAction, AutoModRuleParamsEventType,
+        AutoModRuleParamsExtraItemsStrategy, AutoModRuleParamsKeywordPreset,
+        AutoModRuleParamsTrigger, AutoModRulesParamsList,
+    };
+
+    #[test]
+    fn it_parses_params_list() {
+        let yaml_params_list = r"
+            items:
+            - name: no-slurs
+              event_type: MESSAGE_SEND
+              trigger:
+                type: KEYWORD_PRESET
+                presets:
+                - SLURS
+                allow_list:
+                - grape
+              actions:
+              - type: BLOCK_MESSAGE
+                custom_message: Please be nice
+              - type: SEND_ALERT_MESSAGE
+                channel: mod-logs
+              - type: TIMEOUT
+                duration_seconds: 60
+              enabled: true
+              exempt_roles:
+              - Moderator
+              exempt_channels:
+              - mod-logs
+            extra_items:
+              strategy: REMOVE
+        ";
+        let expected_params_list = AutoModRulesParamsList {
+            items: vec![AutoModRuleParams {
+                name: "no-slurs".to_string(),
+                event_type: AutoModRuleParamsEventType::MESSAGE_SEND,
+                trigger: AutoModRuleParamsTrigger::KeywordPreset {
+                    presets: vec![AutoModRuleParamsKeywordPreset::SLURS],
+                    allow_list: vec!["grape".to_string()],
+                },
+                actions: vec![
+                    AutoModRuleParamsAction::BlockMessage {
+                        custom_message: Some("Please be nice".to_string()),
+                    },
+                    AutoModRuleParamsAction::SendAlertMessage {
+                        channel: "mod-logs".to_string(),
+                    },
+                    AutoModRuleParamsAction::Timeout {
+                        duration_seconds: 60,
+                    },
+                ],
+                enabled: true,
+                exempt_roles: vec!["Moderator".to_string()],
+                exempt_channels: vec!["mod-logs".to_string()],
+            }],
+            extra_items: AutoModRuleParamsExtraItemsStrategy::Remove,
+        };
+
+        let params_list: AutoModRulesParamsList = serde_yaml::from_str(yaml_params_list).unwrap();
+
+        assert_eq!(params_list, expected_params_list);
+    }
+
+    #[test]
+    fn it_parses_empty_params_list_to_defaults() {
+        let yaml_params_list = r"";
+
+        let params_list: AutoModRulesParamsList = serde_yaml::from_str(yaml_params_list).unwrap();
+
+        assert_eq!(params_list, AutoModRulesParamsList::default());
+    }
+
+    #[test]
+    fn it_parses_empty_params_fields_to_defaults() {
+        let yaml_params_list = r"
+            items:
+            - name: spam
+              trigger:
+                type: SPAM
+              enabled: false
+        ";
+        let expected_params_list = AutoModRulesParamsList {
+            items: vec![AutoModRuleParams {
+                name: "spam".to_string(),
+                event_type: AutoModRuleParamsEventType::MESSAGE_SEND,
+                trigger: AutoModRuleParamsTrigger::Spam,
+                actions: vec![],
+                enabled: false,
+                exempt_roles: vec![],
+                exempt_channels: vec![],
+            }],
+            extra_items: AutoModRuleParamsExtraItemsStrategy::Keep,
+        };
+
+        let params_list: AutoModRulesParamsList = serde_yaml::from_str(yaml_params_list).unwrap();
+
+        assert_eq!(params_list, expected_params_list);
+    }
+}
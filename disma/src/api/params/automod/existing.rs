@@ -0,0 +1,187 @@
+use crate::{
+    automod::{
+        AutoModAction, AutoModEventType, AutoModKeywordPreset, AutoModRulesList, AutoModTrigger,
+        ExistingAutoModRule,
+    },
+    channel::Channel,
+    role::Role,
+};
+
+use super::{
+    AutoModRuleParams, AutoModRuleParamsAction, AutoModRuleParamsEventType,
+    AutoModRuleParamsKeywordPreset, AutoModRuleParamsTrigger, AutoModRulesParamsList,
+};
+
+impl From<&AutoModRulesList<ExistingAutoModRule>> for AutoModRulesParamsList {
+    fn from(rules: &AutoModRulesList<ExistingAutoModRule>) -> Self {
+        let mut items: Vec<AutoModRuleParams> =
+            rules.to_list().into_iter().map(Into::into).collect();
+        items.sort_by(|a, b| a.name.cmp(&b.name));
+
+        AutoModRulesParamsList {
+            items,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<&ExistingAutoModRule> for AutoModRuleParams {
+    fn from(rule: &ExistingAutoModRule) -> Self {
+        let actions = rule
+            .actions
+            .iter()
+            .map(|action| match action {
+                AutoModAction::BlockMessage { custom_message } => {
+                    AutoModRuleParamsAction::BlockMessage {
+                        custom_message: custom_message.clone(),
+                    }
+                }
+                AutoModAction::SendAlertMessage { channel } => {
+                    AutoModRuleParamsAction::SendAlertMessage {
+                        channel: channel.unique_name().typed_reference(),
+                    }
+                }
+                AutoModAction::Timeout { duration_seconds } => AutoModRuleParamsAction::Timeout {
+                    duration_seconds: *duration_seconds,
+                },
+            })
+            .collect();
+
+        let mut exempt_roles: Vec<String> = rule
+            .exempt_roles
+            .to_list()
+            .into_iter()
+            .map(|role| role.name().to_string())
+            .collect();
+        exempt_roles.sort();
+
+        let mut exempt_channels: Vec<String> = rule
+            .exempt_channels
+            .to_list()
+            .into_iter()
+            .map(|channel| channel.unique_name().typed_reference())
+            .collect();
+        exempt_channels.sort();
+
+        Self {
+            name: rule.name.clone(),
+            event_type: rule.event_type.clone().into(),
+            trigger: rule.trigger.clone().into(),
+            actions,
+            enabled: rule.enabled,
+            exempt_roles,
+            exempt_channels,
+        }
+    }
+}
+
+impl From<AutoModEventType> for AutoModRuleParamsEventType {
+    fn from(value: AutoModEventType) -> Self {
+        match value {
+            AutoModEventType::MESSAGE_SEND => Self::MESSAGE_SEND,
+            AutoModEventType::MEMBER_UPDATE => Self::MEMBER_UPDATE,
+        }
+    }
+}
+
+impl From<AutoModKeywordPreset> for AutoModRuleParamsKeywordPreset {
+    fn from(value: AutoModKeywordPreset) -> Self {
+        match value {
+            AutoModKeywordPreset::PROFANITY => Self::PROFANITY,
+            AutoModKeywordPreset::SEXUAL_CONTENT => Self::SEXUAL_CONTENT,
+            AutoModKeywordPreset::SLURS => Self::SLURS,
+        }
+    }
+}
+
+impl From<AutoModTrigger> for AutoModRuleParamsTrigger {
+    fn from(value: AutoModTrigger) -> Self {
+        match value {
+            AutoModTrigger::Keyword {
+                keywords,
+                regex_patterns,
+                allow_list,
+            } => Self::Keyword {
+                keywords,
+                regex_patterns,
+                allow_list,
+            },
+            AutoModTrigger::Spam => Self::Spam,
+            AutoModTrigger::KeywordPreset {
+                presets,
+                allow_list,
+            } => Self::KeywordPreset {
+                presets: presets.into_iter().map(Into::into).collect(),
+                allow_list,
+            },
+            AutoModTrigger::MentionSpam {
+                mention_total_limit,
+                mention_raid_protection_enabled,
+            } => Self::MentionSpam {
+                mention_total_limit,
+                mention_raid_protection_enabled,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        api::params::automod::{
+            AutoModRuleParams, AutoModRuleParamsAction, AutoModRuleParamsEventType,
+            AutoModRuleParamsTrigger, AutoModRulesParamsList,
+        },
+        automod::{AutoModAction, AutoModRulesList},
+        role::RolesList,
+        tests::fixtures::existing::{ExistingAutoModRuleFixture, ExistingRoleFixture},
+    };
+
+    const A_RULE_NAME: &str = "no-bad-words";
+
+    #[test]
+    fn can_convert_existing_to_params() {
+        let role = ExistingRoleFixture::new().with_name("Moderator").build();
+        let existing = ExistingAutoModRuleFixture::new()
+            .with_name(A_RULE_NAME)
+            .with_actions(vec![AutoModAction::Timeout {
+                duration_seconds: 30,
+            }])
+            .with_exempt_roles(RolesList::from(vec![role]))
+            .build();
+        let expected_params = AutoModRuleParams {
+            name: A_RULE_NAME.to_string(),
+            event_type: AutoModRuleParamsEventType::MESSAGE_SEND,
+            trigger: AutoModRuleParamsTrigger::Keyword {
+                keywords: vec![],
+                regex_patterns: vec![],
+                allow_list: vec![],
+            },
+            actions: vec![AutoModRuleParamsAction::Timeout {
+                duration_seconds: 30,
+            }],
+            enabled: true,
+            exempt_roles: vec!["Moderator".to_string()],
+            exempt_channels: vec![],
+        };
+
+        let params = AutoModRuleParams::from(&existing);
+
+        assert_eq!(params, expected_params);
+    }
+
+    #[test]
+    fn can_convert_existing_entities_list_to_params_list() {
+        let existing = ExistingAutoModRuleFixture::new()
+            .with_name(A_RULE_NAME)
+            .build();
+        let expected_params_list = AutoModRulesParamsList {
+            items: vec![AutoModRuleParams::from(&existing)],
+            ..Default::default()
+        };
+
+        let params_list = AutoModRulesParamsList::from(&AutoModRulesList::from(vec![existing]));
+
+        assert_eq!(params_list, expected_params_list);
+    }
+}
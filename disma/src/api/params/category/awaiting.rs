@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use crate::{
+    api::params::permission::PermissionsPresets,
     category::{
         AwaitingCategoriesList, AwaitingCategory, ExtraCategoriesStrategy, KeepExtraCategories,
         RemoveExtraCategories,
@@ -18,11 +19,15 @@ use super::{
 };
 
 impl CategoriesParamsList {
-    pub fn into(self, roles: &RolesList<AwaitingRole>) -> AwaitingCategoriesList {
+    pub fn into(
+        self,
+        roles: &RolesList<AwaitingRole>,
+        presets: &PermissionsPresets,
+    ) -> AwaitingCategoriesList {
         let items = self
             .items
             .into_iter()
-            .map(|category| category.into(roles))
+            .map(|category| category.into(roles, presets))
             .collect::<Vec<AwaitingCategory>>()
             .into();
 
@@ -43,11 +48,15 @@ impl Into<Arc<dyn ExtraCategoriesStrategy>> for CategoryParamsExtraItemsStrategy
 }
 
 impl CategoryParams {
-    pub fn into(self, roles: &RolesList<AwaitingRole>) -> AwaitingCategory {
+    pub fn into(
+        self,
+        roles: &RolesList<AwaitingRole>,
+        presets: &PermissionsPresets,
+    ) -> AwaitingCategory {
         let overwrites = self
             .permissions_overwrites
             .into_iter()
-            .map(|permission| permission.into(roles))
+            .map(|permission| permission.into(roles, presets))
             .collect::<Vec<PermissionsOverwrite<AwaitingRole>>>();
 
         AwaitingCategory {
@@ -78,7 +87,7 @@ mod tests {
                 CategoriesParamsList, CategoryParams, CategoryParamsExtraChannelsStrategy,
                 CategoryParamsExtraItemsStrategy,
             },
-            permission::PermissionsOverwriteParams,
+            permission::{PermissionsOverwriteParams, PermissionsPresets},
         },
         category::{AwaitingCategoriesList, AwaitingCategory, CategoriesList, KeepExtraCategories},
         channel::KeepExtraChannels,
@@ -98,8 +107,8 @@ mod tests {
             name: name.to_string(),
             permissions_overwrites: vec![PermissionsOverwriteParams {
                 role: role.name.clone(),
-                allow: vec![Permission::ADMINISTRATOR],
-                deny: vec![Permission::ADMINISTRATOR],
+                allow: vec![Permission::ADMINISTRATOR.into()],
+                deny: vec![Permission::ADMINISTRATOR.into()],
             }],
             extra_channels: CategoryParamsExtraChannelsStrategy::Keep,
         };
@@ -157,7 +166,7 @@ mod tests {
         let roles = given_awaiting_roles(vec!["role_1"]);
         let (params, expected_awaiting) = given_matching_params_and_awaiting(name, &roles);
 
-        let awaiting: AwaitingCategory = params.into(&roles);
+        let awaiting: AwaitingCategory = params.into(&roles, &PermissionsPresets::default());
 
         assert_eq!(awaiting, expected_awaiting);
     }
@@ -169,7 +178,8 @@ mod tests {
         let (params_list, expected_awaiting_list) =
             given_matching_params_list_and_awaiting_list(name, &roles);
 
-        let awaiting_list: AwaitingCategoriesList = params_list.into(&roles);
+        let awaiting_list: AwaitingCategoriesList =
+            params_list.into(&roles, &PermissionsPresets::default());
 
         assert_eq!(awaiting_list, expected_awaiting_list);
     }
@@ -3,14 +3,16 @@ use serde::{Deserialize, Serialize};
 use crate::api::params::permission::PermissionsOverwriteParams;
 
 #[derive(Serialize, Deserialize, Debug, PartialEq, Default, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct CategoriesParamsList {
-    #[serde(default = "Vec::default")]
+    #[serde(default)]
     pub items: Vec<CategoryParams>,
     #[serde(default = "CategoryParamsExtraItemsStrategy::default")]
     pub extra_items: CategoryParamsExtraItemsStrategy,
 }
 
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(tag = "strategy", rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum CategoryParamsExtraItemsStrategy {
     Keep,
@@ -18,15 +20,17 @@ pub enum CategoryParamsExtraItemsStrategy {
 }
 
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct CategoryParams {
     pub name: String,
-    #[serde(default = "Vec::default")]
+    #[serde(default)]
     pub permissions_overwrites: Vec<PermissionsOverwriteParams>,
     #[serde(default = "CategoryParamsExtraChannelsStrategy::default")]
     pub extra_channels: CategoryParamsExtraChannelsStrategy,
 }
 
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(tag = "strategy", rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum CategoryParamsExtraChannelsStrategy {
     Keep,
@@ -78,8 +82,8 @@ mod tests {
                 name: "category_1".to_string(),
                 permissions_overwrites: vec![PermissionsOverwriteParams {
                     role: "role_1".to_string(),
-                    allow: vec![Permission::ADMINISTRATOR],
-                    deny: vec![Permission::SEND_MESSAGES],
+                    allow: vec![Permission::ADMINISTRATOR.into()],
+                    deny: vec![Permission::SEND_MESSAGES.into()],
                 }],
                 extra_channels: CategoryParamsExtraChannelsStrategy::Keep,
             }],
@@ -65,8 +65,8 @@ mod tests {
             name: name.to_string(),
             permissions_overwrites: vec![PermissionsOverwriteParams {
                 role: role.name.clone(),
-                allow: vec![Permission::ADMINISTRATOR],
-                deny: vec![Permission::ADMINISTRATOR],
+                allow: vec![Permission::ADMINISTRATOR.into()],
+                deny: vec![Permission::ADMINISTRATOR.into()],
             }],
             extra_channels: CategoryParamsExtraChannelsStrategy::Keep,
         };
@@ -98,6 +98,7 @@ mod tests {
             color: None,
             is_mentionable: true,
             show_in_sidebar: false,
+            is_managed: false,
         }
     }
 
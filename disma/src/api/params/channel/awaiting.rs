@@ -1,10 +1,12 @@
-use std::sync::Arc;
+use std::{collections::BTreeMap, sync::Arc};
 
 use crate::{
+    api::params::permission::{PermissionsOverwriteParams, PermissionsPresets},
     category::{AwaitingCategory, CategoriesList},
     channel::{
-        AwaitingChannel, AwaitingChannelsList, ChannelType, ExtraChannelsStrategy,
-        KeepExtraChannels, RemoveExtraChannels,
+        AwaitingChannel, AwaitingChannelsList, AwaitingThread, AwaitingThreadsList, ChannelType,
+        ExtraChannelsStrategy, ExtraThreadsStrategy, KeepExtraChannels, KeepExtraThreads,
+        RemoveExtraChannels, RemoveExtraThreads, ThreadType,
     },
     permission::PermissionsOverwrite,
     role::{AwaitingRole, RolesList},
@@ -12,7 +14,8 @@ use crate::{
 
 use super::{
     ChannelParams, ChannelParamsChannelType, ChannelParamsExtraItemsStrategy,
-    ChannelParamsPermissionsOverwritesStrategy, ChannelsParamsList,
+    ChannelParamsPermissionsOverwritesStrategy, ChannelsGenerationParams, ChannelsParamsList,
+    ThreadParams, ThreadParamsExtraItemsStrategy, ThreadParamsThreadType, ThreadsParamsList,
 };
 
 impl ChannelsParamsList {
@@ -20,11 +23,19 @@ impl ChannelsParamsList {
         self,
         roles: &RolesList<AwaitingRole>,
         categories: &CategoriesList<AwaitingCategory>,
+        presets: &PermissionsPresets,
     ) -> AwaitingChannelsList {
+        let generated = self
+            .generate
+            .iter()
+            .flat_map(|generation| generation.generate(&self.templates))
+            .collect::<Vec<ChannelParams>>();
+
         let items = self
             .items
             .into_iter()
-            .map(|channel| channel.into(roles, categories))
+            .chain(generated)
+            .map(|channel| channel.into(roles, categories, presets))
             .collect::<Vec<AwaitingChannel>>()
             .into();
 
@@ -36,6 +47,78 @@ impl ChannelsParamsList {
     }
 }
 
+impl ChannelsGenerationParams {
+    fn generate(&self, templates: &BTreeMap<String, Vec<ChannelParams>>) -> Vec<ChannelParams> {
+        let template = templates
+            .get(&self.template)
+            .unwrap_or_else(|| panic!("No channel template found for name {}.", self.template));
+
+        self._in
+            .iter()
+            .flat_map(|value| {
+                template
+                    .iter()
+                    .map(|channel| channel.interpolate(&self._for, value))
+            })
+            .collect()
+    }
+}
+
+impl ChannelParams {
+    fn interpolate(&self, variable: &str, value: &str) -> ChannelParams {
+        let placeholder = format!("{{{variable}}}");
+        let interpolate = |text: &str| text.replace(&placeholder, value);
+
+        let permissions_overwrites = match &self.permissions_overwrites {
+            ChannelParamsPermissionsOverwritesStrategy::FromCategory => {
+                ChannelParamsPermissionsOverwritesStrategy::FromCategory
+            }
+            ChannelParamsPermissionsOverwritesStrategy::Manual { items } => {
+                ChannelParamsPermissionsOverwritesStrategy::Manual {
+                    items: items
+                        .iter()
+                        .map(|overwrite| PermissionsOverwriteParams {
+                            role: interpolate(&overwrite.role),
+                            ..overwrite.clone()
+                        })
+                        .collect(),
+                }
+            }
+            ChannelParamsPermissionsOverwritesStrategy::ExtendCategory { items, remove } => {
+                ChannelParamsPermissionsOverwritesStrategy::ExtendCategory {
+                    items: items
+                        .iter()
+                        .map(|overwrite| PermissionsOverwriteParams {
+                            role: interpolate(&overwrite.role),
+                            ..overwrite.clone()
+                        })
+                        .collect(),
+                    remove: remove.iter().map(|role| interpolate(role)).collect(),
+                }
+            }
+        };
+
+        ChannelParams {
+            name: interpolate(&self.name),
+            _type: self._type.clone(),
+            topic: self.topic.as_deref().map(interpolate),
+            category: self.category.as_deref().map(interpolate),
+            permissions_overwrites,
+            threads: self.threads.as_ref().map(|threads| ThreadsParamsList {
+                items: threads
+                    .items
+                    .iter()
+                    .map(|thread| ThreadParams {
+                        name: interpolate(&thread.name),
+                        ..thread.clone()
+                    })
+                    .collect(),
+                extra_items: threads.extra_items.clone(),
+            }),
+        }
+    }
+}
+
 impl Into<Arc<dyn ExtraChannelsStrategy>> for ChannelParamsExtraItemsStrategy {
     fn into(self) -> Arc<dyn ExtraChannelsStrategy> {
         match self {
@@ -50,6 +133,7 @@ impl ChannelParams {
         self,
         roles: &RolesList<AwaitingRole>,
         categories: &CategoriesList<AwaitingCategory>,
+        presets: &PermissionsPresets,
     ) -> AwaitingChannel {
         let channel_type = self._type.into();
 
@@ -67,17 +151,102 @@ impl ChannelParams {
             },
             ChannelParamsPermissionsOverwritesStrategy::Manual { items } => items
                 .into_iter()
-                .map(|permission| permission.into(roles))
+                .map(|permission| permission.into(roles, presets))
                 .collect::<Vec<PermissionsOverwrite<AwaitingRole>>>()
                 .into(),
+            ChannelParamsPermissionsOverwritesStrategy::ExtendCategory { items, remove } => {
+                let category = category.as_ref().unwrap_or_else(|| panic!("Cannot use EXTEND_CATEGORY permissions overwrites strategy for channel {} because it has no category.", self.name));
+                let items: Vec<PermissionsOverwrite<AwaitingRole>> = items
+                    .into_iter()
+                    .map(|permission| permission.into(roles, presets))
+                    .collect();
+
+                let mut overwrites: Vec<PermissionsOverwrite<AwaitingRole>> = category
+                    .overwrites
+                    .to_list()
+                    .iter()
+                    .filter(|overwrite| {
+                        !remove.contains(&overwrite.role.name)
+                            && !items.iter().any(|item| item.role.name == overwrite.role.name)
+                    })
+                    .cloned()
+                    .collect();
+                overwrites.extend(items);
+                overwrites.into()
+            }
         };
 
+        let threads = self
+            .threads
+            .map(|threads| threads.into(&self.name, &channel_type));
+
         AwaitingChannel {
             name: self.name,
             topic: self.topic,
             channel_type,
             category,
             overwrites,
+            threads,
+        }
+    }
+}
+
+impl ThreadsParamsList {
+    pub fn into(self, channel_name: &str, channel_type: &ChannelType) -> AwaitingThreadsList {
+        AwaitingThreadsList {
+            items: self
+                .items
+                .into_iter()
+                .map(|thread| thread.into(channel_name, channel_type))
+                .collect(),
+            extra_items_strategy: self.extra_items.into(),
+        }
+    }
+}
+
+impl Into<Arc<dyn ExtraThreadsStrategy>> for ThreadParamsExtraItemsStrategy {
+    fn into(self) -> Arc<dyn ExtraThreadsStrategy> {
+        match self {
+            Self::Keep => Arc::from(KeepExtraThreads {}),
+            Self::Remove => Arc::from(RemoveExtraThreads {}),
+        }
+    }
+}
+
+impl ThreadParams {
+    pub fn into(self, channel_name: &str, channel_type: &ChannelType) -> AwaitingThread {
+        let message = match channel_type {
+            ChannelType::FORUM => {
+                if self._type == ThreadParamsThreadType::PRIVATE {
+                    panic!("Cannot use PRIVATE type for thread {} because channel {channel_name} is a FORUM.", self.name);
+                }
+                Some(self.message.unwrap_or_else(|| self.name.clone()))
+            }
+            ChannelType::TEXT => {
+                if self.message.is_some() {
+                    panic!("Cannot use a message for thread {} because channel {channel_name} is not a FORUM.", self.name);
+                }
+                None
+            }
+            _ => panic!("Cannot use threads for channel {channel_name} because it is not a TEXT or FORUM channel."),
+        };
+
+        AwaitingThread {
+            name: self.name,
+            thread_type: self._type.into(),
+            auto_archive_duration: self.auto_archive_duration,
+            locked: self.locked,
+            invitable: self.invitable,
+            message,
+        }
+    }
+}
+
+impl From<ThreadParamsThreadType> for ThreadType {
+    fn from(value: ThreadParamsThreadType) -> Self {
+        match value {
+            ThreadParamsThreadType::PUBLIC => ThreadType::PUBLIC,
+            ThreadParamsThreadType::PRIVATE => ThreadType::PRIVATE,
         }
     }
 }
@@ -87,21 +256,24 @@ impl Into<ChannelType> for ChannelParamsChannelType {
         match self {
             Self::TEXT => ChannelType::TEXT,
             Self::VOICE => ChannelType::VOICE,
+            Self::STAGE => ChannelType::STAGE,
+            Self::FORUM => ChannelType::FORUM,
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use std::sync::Arc;
+    use std::{collections::BTreeMap, sync::Arc};
 
     use crate::{
         api::params::{
             channel::{
                 ChannelParams, ChannelParamsChannelType, ChannelParamsExtraItemsStrategy,
-                ChannelParamsPermissionsOverwritesStrategy, ChannelsParamsList,
+                ChannelParamsPermissionsOverwritesStrategy, ChannelsGenerationParams,
+                ChannelsParamsList, ThreadParams, ThreadParamsThreadType,
             },
-            permission::PermissionsOverwriteParams,
+            permission::{PermissionsOverwriteParams, PermissionsPresets},
         },
         category::{AwaitingCategory, CategoriesList},
         channel::{
@@ -160,10 +332,11 @@ mod tests {
             permissions_overwrites: ChannelParamsPermissionsOverwritesStrategy::Manual {
                 items: vec![PermissionsOverwriteParams {
                     role: role.name.clone(),
-                    allow: vec![Permission::ADMINISTRATOR],
-                    deny: vec![Permission::SEND_MESSAGES],
+                    allow: vec![Permission::ADMINISTRATOR.into()],
+                    deny: vec![Permission::SEND_MESSAGES.into()],
                 }],
             },
+            threads: None,
         };
 
         let awaiting = AwaitingChannel {
@@ -176,6 +349,7 @@ mod tests {
                 allow: PermissionsList::from(vec![Permission::ADMINISTRATOR]),
                 deny: PermissionsList::from(vec![Permission::SEND_MESSAGES]),
             }]),
+            threads: None,
         };
 
         (params, awaiting)
@@ -193,6 +367,7 @@ mod tests {
             category: Some(category.name.clone()),
             topic: Some("Nice sweater".to_string()),
             permissions_overwrites: ChannelParamsPermissionsOverwritesStrategy::FromCategory,
+            threads: None,
         };
 
         let awaiting = AwaitingChannel {
@@ -201,6 +376,7 @@ mod tests {
             category: Some(category.clone()),
             topic: Some("Nice sweater".to_string()),
             overwrites: category.overwrites.clone(),
+            threads: None,
         };
 
         (params, awaiting)
@@ -215,6 +391,8 @@ mod tests {
 
         let params_list = ChannelsParamsList {
             items: vec![params],
+            templates: BTreeMap::new(),
+            generate: vec![],
             extra_items: ChannelParamsExtraItemsStrategy::Keep,
         };
 
@@ -235,7 +413,7 @@ mod tests {
         let (params, expected_awaiting) =
             given_matching_params_and_awaiting(name, &roles, &categories);
 
-        let awaiting = params.into(&roles, &categories);
+        let awaiting = params.into(&roles, &categories, &PermissionsPresets::default());
 
         assert_eq!(awaiting, expected_awaiting);
     }
@@ -248,7 +426,7 @@ mod tests {
         let (params, expected_awaiting) =
             given_matching_params_and_awaiting_with_permissions_from_categories(name, &categories);
 
-        let awaiting = params.into(&roles, &categories);
+        let awaiting = params.into(&roles, &categories, &PermissionsPresets::default());
 
         assert_eq!(awaiting, expected_awaiting);
     }
@@ -265,9 +443,92 @@ mod tests {
             category: None,
             topic: Some("Nice sweater".to_string()),
             permissions_overwrites: ChannelParamsPermissionsOverwritesStrategy::FromCategory,
+            threads: None,
+        };
+
+        params.into(&roles, &categories, &PermissionsPresets::default());
+    }
+
+    fn given_thread_params(_type: ThreadParamsThreadType, message: Option<&str>) -> ThreadParams {
+        ThreadParams {
+            name: "faq".to_string(),
+            _type,
+            auto_archive_duration: 10080,
+            locked: false,
+            invitable: true,
+            message: message.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn given_forum_thread_without_message_it_uses_name_as_message() {
+        let params = given_thread_params(ThreadParamsThreadType::PUBLIC, None);
+
+        let awaiting = params.into("questions", &ChannelType::FORUM);
+
+        assert_eq!(awaiting.message, Some("faq".to_string()));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Cannot use PRIVATE type for thread faq because channel questions is a FORUM."
+    )]
+    fn given_private_forum_thread_when_converting_params_it_panics() {
+        let params = given_thread_params(ThreadParamsThreadType::PRIVATE, None);
+
+        params.into("questions", &ChannelType::FORUM);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Cannot use a message for thread faq because channel general is not a FORUM."
+    )]
+    fn given_text_thread_with_message_when_converting_params_it_panics() {
+        let params = given_thread_params(ThreadParamsThreadType::PUBLIC, Some("Hello"));
+
+        params.into("general", &ChannelType::TEXT);
+    }
+
+    #[test]
+    fn given_permissions_overwrites_extending_category_channel_items_win_per_role() {
+        let roles = given_awaiting_roles(vec!["kept", "replaced", "removed"]);
+        let overwrite = |name: &str, allow: Vec<Permission>| PermissionsOverwrite {
+            role: roles.find_by_name(name).unwrap().clone(),
+            allow: PermissionsList::from(allow),
+            deny: PermissionsList::new(),
+        };
+        let mut category = given_awaiting_category("category_1");
+        category.overwrites = PermissionsOverwritesList::from(vec![
+            overwrite("kept", vec![Permission::VIEW_CHANNEL]),
+            overwrite("replaced", vec![Permission::VIEW_CHANNEL]),
+            overwrite("removed", vec![Permission::VIEW_CHANNEL]),
+        ]);
+        let categories = CategoriesList::from(vec![category]);
+        let params = ChannelParams {
+            name: "channel_1".to_string(),
+            _type: ChannelParamsChannelType::TEXT,
+            category: Some("category_1".to_string()),
+            topic: None,
+            permissions_overwrites: ChannelParamsPermissionsOverwritesStrategy::ExtendCategory {
+                items: vec![PermissionsOverwriteParams {
+                    role: "replaced".to_string(),
+                    allow: vec![Permission::SEND_MESSAGES.into()],
+                    deny: vec![],
+                }],
+                remove: vec!["removed".to_string()],
+            },
+            threads: None,
         };
 
-        params.into(&roles, &categories);
+        let awaiting = params.into(&roles, &categories, &PermissionsPresets::default());
+
+        assert_eq!(
+            awaiting.overwrites,
+            PermissionsOverwritesList::from(vec![
+                overwrite("kept", vec![Permission::VIEW_CHANNEL]),
+                overwrite("replaced", vec![Permission::SEND_MESSAGES]),
+            ])
+        );
     }
 
     #[test]
@@ -278,8 +539,86 @@ mod tests {
         let (params_list, expected_awaiting_list) =
             given_matching_params_list_and_awaiting_list(name, &roles, &categories);
 
-        let awaiting_list: AwaitingChannelsList = params_list.into(&roles, &categories);
+        let awaiting_list: AwaitingChannelsList =
+            params_list.into(&roles, &categories, &PermissionsPresets::default());
 
         assert_eq!(awaiting_list, expected_awaiting_list);
     }
+
+    fn given_team_template() -> BTreeMap<String, Vec<ChannelParams>> {
+        BTreeMap::from([(
+            "team".to_string(),
+            vec![ChannelParams {
+                name: "{team}-text".to_string(),
+                _type: ChannelParamsChannelType::TEXT,
+                category: Some("{team}".to_string()),
+                topic: Some("Channel of team {team}".to_string()),
+                permissions_overwrites: ChannelParamsPermissionsOverwritesStrategy::Manual {
+                    items: vec![PermissionsOverwriteParams {
+                        role: "{team}".to_string(),
+                        allow: vec![Permission::VIEW_CHANNEL.into()],
+                        deny: vec![],
+                    }],
+                },
+                threads: None,
+            }],
+        )])
+    }
+
+    #[test]
+    fn given_generated_channels_it_interpolates_templates() {
+        let categories = given_awaiting_categories(vec!["a", "b"]);
+        let roles = given_awaiting_roles(vec!["a", "b"]);
+        let params_list = ChannelsParamsList {
+            items: vec![],
+            templates: given_team_template(),
+            generate: vec![ChannelsGenerationParams {
+                template: "team".to_string(),
+                _for: "team".to_string(),
+                _in: vec!["a".to_string(), "b".to_string()],
+            }],
+            extra_items: ChannelParamsExtraItemsStrategy::Keep,
+        };
+
+        let awaiting_list: AwaitingChannelsList =
+            params_list.into(&roles, &categories, &PermissionsPresets::default());
+
+        let expected_channels: Vec<AwaitingChannel> = ["a", "b"]
+            .into_iter()
+            .map(|team| AwaitingChannel {
+                name: format!("{team}-text"),
+                channel_type: ChannelType::TEXT,
+                category: categories.find_by_name(team).cloned(),
+                topic: Some(format!("Channel of team {team}")),
+                overwrites: PermissionsOverwritesList::from(vec![PermissionsOverwrite {
+                    role: roles.find_by_name(team).cloned().unwrap(),
+                    allow: PermissionsList::from(vec![Permission::VIEW_CHANNEL]),
+                    deny: PermissionsList::new(),
+                }]),
+                threads: None,
+            })
+            .collect();
+        assert_eq!(awaiting_list.items, ChannelsList::from(expected_channels));
+    }
+
+    #[test]
+    #[should_panic(expected = "No channel template found for name unknown.")]
+    fn given_unknown_template_when_generating_channels_it_panics() {
+        let params_list = ChannelsParamsList {
+            items: vec![],
+            templates: given_team_template(),
+            generate: vec![ChannelsGenerationParams {
+                template: "unknown".to_string(),
+                _for: "team".to_string(),
+                _in: vec!["a".to_string()],
+            }],
+            extra_items: ChannelParamsExtraItemsStrategy::Keep,
+        };
+
+        params_list.into(
+            &RolesList::new(),
+            &CategoriesList::new(),
+            &PermissionsPresets::default(),
+        );
+    }
 }
@@ -1,16 +1,36 @@
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
 
 use crate::api::params::permission::PermissionsOverwriteParams;
 
 #[derive(Serialize, Deserialize, Debug, PartialEq, Default, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ChannelsParamsList {
-    #[serde(default = "Vec::default")]
+    #[serde(default)]
     pub items: Vec<ChannelParams>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub templates: BTreeMap<String, Vec<ChannelParams>>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub generate: Vec<ChannelsGenerationParams>,
     #[serde(default = "ChannelParamsExtraItemsStrategy::default")]
     pub extra_items: ChannelParamsExtraItemsStrategy,
 }
 
+/// Instantiates a channels template once per value (`for team in [a, b, c]`).
+/// Every `{team}` found in the template is replaced by the value.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ChannelsGenerationParams {
+    pub template: String,
+    #[serde(rename = "for")]
+    pub _for: String,
+    #[serde(rename = "in")]
+    pub _in: Vec<String>,
+}
+
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(tag = "strategy", rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum ChannelParamsExtraItemsStrategy {
     Keep,
@@ -18,6 +38,7 @@ pub enum ChannelParamsExtraItemsStrategy {
 }
 
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ChannelParams {
     pub name: String,
     #[serde(rename = "type", default = "ChannelParamsChannelType::default")]
@@ -28,21 +49,79 @@ pub struct ChannelParams {
     pub category: Option<String>,
     #[serde(default = "ChannelParamsPermissionsOverwritesStrategy::default")]
     pub permissions_overwrites: ChannelParamsPermissionsOverwritesStrategy,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub threads: Option<ThreadsParamsList>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Default, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ThreadsParamsList {
+    #[serde(default)]
+    pub items: Vec<ThreadParams>,
+    #[serde(default = "ThreadParamsExtraItemsStrategy::default")]
+    pub extra_items: ThreadParamsExtraItemsStrategy,
+}
+
+/// Extra threads are the active threads of the channel that are not in
+/// `items`, ex: started by members. Removed ones are archived.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(tag = "strategy", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ThreadParamsExtraItemsStrategy {
+    Keep,
+    Remove,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ThreadParams {
+    pub name: String,
+    #[serde(rename = "type", default = "ThreadParamsThreadType::default")]
+    pub _type: ThreadParamsThreadType,
+    #[serde(default = "default_auto_archive_duration")]
+    pub auto_archive_duration: u32,
+    #[serde(default)]
+    pub locked: bool,
+    #[serde(default = "default_invitable")]
+    pub invitable: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum ThreadParamsThreadType {
+    #[default]
+    PUBLIC,
+    PRIVATE,
 }
 
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(tag = "strategy", rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum ChannelParamsPermissionsOverwritesStrategy {
     FromCategory,
     Manual {
         items: Vec<PermissionsOverwriteParams>,
     },
+    /// Overwrites of the category, where those of `items` replace or are added
+    /// to the ones of the same roles, and the ones of the roles of `remove`
+    /// are dropped.
+    ExtendCategory {
+        #[serde(default)]
+        items: Vec<PermissionsOverwriteParams>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        remove: Vec<String>,
+    },
 }
 
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum ChannelParamsChannelType {
     TEXT,
     VOICE,
+    STAGE,
+    FORUM,
 }
 
 impl Default for ChannelParamsExtraItemsStrategy {
@@ -51,12 +130,26 @@ impl Default for ChannelParamsExtraItemsStrategy {
     }
 }
 
+impl Default for ThreadParamsExtraItemsStrategy {
+    fn default() -> Self {
+        Self::Keep
+    }
+}
+
 impl Default for ChannelParamsChannelType {
     fn default() -> Self {
         Self::TEXT
     }
 }
 
+fn default_auto_archive_duration() -> u32 {
+    10080
+}
+
+fn default_invitable() -> bool {
+    true
+}
+
 impl Default for ChannelParamsPermissionsOverwritesStrategy {
     fn default() -> Self {
         Self::Manual { items: vec![] }
@@ -65,11 +158,15 @@ impl Default for ChannelParamsPermissionsOverwritesStrategy {
 
 #[cfg(test)]
 mod tests {
+    use std::collections::BTreeMap;
+
     use crate::{
         api::params::{
             channel::{
                 ChannelParams, ChannelParamsChannelType, ChannelParamsExtraItemsStrategy,
-                ChannelParamsPermissionsOverwritesStrategy, ChannelsParamsList,
+                ChannelParamsPermissionsOverwritesStrategy, ChannelsGenerationParams,
+                ChannelsParamsList, ThreadParams, ThreadParamsExtraItemsStrategy,
+                ThreadParamsThreadType, ThreadsParamsList,
             },
             permission::PermissionsOverwriteParams,
         },
@@ -102,11 +199,14 @@ mod tests {
                 permissions_overwrites: ChannelParamsPermissionsOverwritesStrategy::Manual {
                     items: vec![PermissionsOverwriteParams {
                         role: "role_1".to_string(),
-                        allow: vec![Permission::ADMINISTRATOR],
-                        deny: vec![Permission::SEND_MESSAGES],
+                        allow: vec![Permission::ADMINISTRATOR.into()],
+                        deny: vec![Permission::SEND_MESSAGES.into()],
                     }],
                 },
+                threads: None,
             }],
+            templates: BTreeMap::new(),
+            generate: vec![],
             extra_items: ChannelParamsExtraItemsStrategy::Keep,
         };
 
@@ -139,6 +239,73 @@ mod tests {
                 permissions_overwrites: ChannelParamsPermissionsOverwritesStrategy::Manual {
                     items: vec![],
                 },
+                threads: None,
+            }],
+            templates: BTreeMap::new(),
+            generate: vec![],
+            extra_items: ChannelParamsExtraItemsStrategy::Keep,
+        };
+
+        let params_list: ChannelsParamsList = serde_yaml::from_str(yaml_params_list).unwrap();
+
+        assert_eq!(params_list, expected_params_list);
+    }
+
+    #[test]
+    fn it_parses_thread_fields_to_defaults() {
+        let yaml_params_list = r"
+            items:
+            - name: channel_1
+              threads:
+                items:
+                - name: faq
+        ";
+        let expected_threads = ThreadsParamsList {
+            items: vec![ThreadParams {
+                name: "faq".to_string(),
+                _type: ThreadParamsThreadType::PUBLIC,
+                auto_archive_duration: 10080,
+                locked: false,
+                invitable: true,
+                message: None,
+            }],
+            extra_items: ThreadParamsExtraItemsStrategy::Keep,
+        };
+
+        let params_list: ChannelsParamsList = serde_yaml::from_str(yaml_params_list).unwrap();
+
+        assert_eq!(params_list.items[0].threads, Some(expected_threads));
+    }
+
+    #[test]
+    fn it_parses_templates_and_generations() {
+        let yaml_params_list = r"
+            templates:
+              team:
+              - name: '{team}-voice'
+                type: VOICE
+            generate:
+            - template: team
+              for: team
+              in: [a, b]
+        ";
+        let expected_params_list = ChannelsParamsList {
+            items: vec![],
+            templates: BTreeMap::from([(
+                "team".to_string(),
+                vec![ChannelParams {
+                    name: "{team}-voice".to_string(),
+                    _type: ChannelParamsChannelType::VOICE,
+                    topic: None,
+                    category: None,
+                    permissions_overwrites: ChannelParamsPermissionsOverwritesStrategy::default(),
+                    threads: None,
+                }],
+            )]),
+            generate: vec![ChannelsGenerationParams {
+                template: "team".to_string(),
+                _for: "team".to_string(),
+                _in: vec!["a".to_string(), "b".to_string()],
             }],
             extra_items: ChannelParamsExtraItemsStrategy::Keep,
         };
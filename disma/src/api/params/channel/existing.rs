@@ -1,9 +1,15 @@
 use crate::{
     api::params::permission::PermissionsOverwriteParams,
-    channel::{ChannelType, ExistingChannel},
+    category::ExistingCategory,
+    channel::{ChannelType, ExistingChannel, ExistingThread, ThreadType},
+    permission::PermissionsOverwrite,
+    role::{ExistingRole, Role},
 };
 
-use super::{ChannelParams, ChannelParamsChannelType, ChannelParamsPermissionsOverwritesStrategy};
+use super::{
+    ChannelParams, ChannelParamsChannelType, ChannelParamsPermissionsOverwritesStrategy,
+    ThreadParams, ThreadParamsExtraItemsStrategy, ThreadParamsThreadType, ThreadsParamsList,
+};
 
 impl From<&ExistingChannel> for ChannelParams {
     fn from(channel: &ExistingChannel) -> Self {
@@ -14,21 +20,121 @@ impl From<&ExistingChannel> for ChannelParams {
             .as_ref()
             .map(|category| category.name.clone());
 
-        let permissions_overwrites = channel
-            .overwrites
-            .to_list()
-            .iter()
-            .map(PermissionsOverwriteParams::from)
-            .collect::<Vec<PermissionsOverwriteParams>>();
+        let permissions_overwrites = match &channel.category {
+            Some(category) => overwrites_from_category(channel, category),
+            None => manual_overwrites(channel),
+        };
 
         Self {
             name: channel.name.clone(),
             topic: channel.topic.clone(),
             _type,
             category,
-            permissions_overwrites: ChannelParamsPermissionsOverwritesStrategy::Manual {
-                items: permissions_overwrites,
-            },
+            permissions_overwrites,
+            threads: managed_threads(channel),
+        }
+    }
+}
+
+/// Threads created by the bot, active ones first and once per name. Threads
+/// started by members are left out, as they are kept by default.
+fn managed_threads(channel: &ExistingChannel) -> Option<ThreadsParamsList> {
+    let mut threads: Vec<&ExistingThread> = channel
+        .threads
+        .iter()
+        .filter(|thread| thread.is_managed)
+        .collect();
+    threads.sort_by_key(|thread| thread.archived);
+
+    let mut items: Vec<ThreadParams> = Vec::new();
+    for thread in threads {
+        if !items.iter().any(|item| item.name == thread.name) {
+            items.push(thread.into());
+        }
+    }
+
+    match items.is_empty() {
+        true => None,
+        false => Some(ThreadsParamsList {
+            items,
+            extra_items: ThreadParamsExtraItemsStrategy::default(),
+        }),
+    }
+}
+
+/// Overwrites of a channel relative to the ones of its `category`: synced
+/// when equal (as shown by Discord), extended when some are kept.
+fn overwrites_from_category(
+    channel: &ExistingChannel,
+    category: &ExistingCategory,
+) -> ChannelParamsPermissionsOverwritesStrategy {
+    if channel.overwrites.matches(&category.overwrites) {
+        return ChannelParamsPermissionsOverwritesStrategy::FromCategory;
+    }
+
+    let is_inherited = |overwrite: &PermissionsOverwrite<ExistingRole>| {
+        category
+            .overwrites
+            .find_by_role_name(overwrite.role.name())
+            .is_some_and(|category_overwrite| overwrite == category_overwrite)
+    };
+
+    if !channel.overwrites.to_list().iter().any(is_inherited) {
+        return manual_overwrites(channel);
+    }
+
+    ChannelParamsPermissionsOverwritesStrategy::ExtendCategory {
+        items: channel
+            .overwrites
+            .to_list()
+            .iter()
+            .filter(|overwrite| !is_inherited(overwrite))
+            .map(PermissionsOverwriteParams::from)
+            .collect(),
+        remove: category
+            .overwrites
+            .to_list()
+            .iter()
+            .filter(|overwrite| {
+                channel
+                    .overwrites
+                    .find_by_role_name(overwrite.role.name())
+                    .is_none()
+            })
+            .map(|overwrite| overwrite.role.name.clone())
+            .collect(),
+    }
+}
+
+fn manual_overwrites(channel: &ExistingChannel) -> ChannelParamsPermissionsOverwritesStrategy {
+    ChannelParamsPermissionsOverwritesStrategy::Manual {
+        items: channel
+            .overwrites
+            .to_list()
+            .iter()
+            .map(PermissionsOverwriteParams::from)
+            .collect(),
+    }
+}
+
+impl From<&ExistingThread> for ThreadParams {
+    fn from(thread: &ExistingThread) -> Self {
+        Self {
+            name: thread.name.clone(),
+            _type: thread.thread_type.clone().into(),
+            auto_archive_duration: thread.auto_archive_duration,
+            locked: thread.locked,
+            invitable: thread.invitable,
+            message: None,
+        }
+    }
+}
+
+impl From<ThreadType> for ThreadParamsThreadType {
+    fn from(value: ThreadType) -> Self {
+        match value {
+            ThreadType::PUBLIC => Self::PUBLIC,
+            ThreadType::PRIVATE => Self::PRIVATE,
         }
     }
 }
@@ -38,6 +144,8 @@ impl From<ChannelType> for ChannelParamsChannelType {
         match value {
             ChannelType::TEXT => Self::TEXT,
             ChannelType::VOICE => Self::VOICE,
+            ChannelType::STAGE => Self::STAGE,
+            ChannelType::FORUM => Self::FORUM,
         }
     }
 }
@@ -57,6 +165,7 @@ mod tests {
             Permission, PermissionsList, PermissionsOverwrite, PermissionsOverwritesList,
         },
         role::ExistingRole,
+        tests::fixtures::existing::{ExistingChannelFixture, ExistingThreadFixture},
     };
 
     fn given_existing_category(name: &str) -> ExistingCategory {
@@ -75,6 +184,7 @@ mod tests {
             color: Some("123456".to_string()),
             is_mentionable: true,
             show_in_sidebar: false,
+            is_managed: false,
         }
     }
 
@@ -94,6 +204,7 @@ mod tests {
                 allow: PermissionsList::from(vec![Permission::ADMINISTRATOR]),
                 deny: PermissionsList::from(vec![Permission::SEND_MESSAGES]),
             }]),
+            threads: Vec::new(),
         };
 
         let params = ChannelParams {
@@ -104,10 +215,11 @@ mod tests {
             permissions_overwrites: ChannelParamsPermissionsOverwritesStrategy::Manual {
                 items: vec![PermissionsOverwriteParams {
                     role: role.name.clone(),
-                    allow: vec![Permission::ADMINISTRATOR],
-                    deny: vec![Permission::SEND_MESSAGES],
+                    allow: vec![Permission::ADMINISTRATOR.into()],
+                    deny: vec![Permission::SEND_MESSAGES.into()],
                 }],
             },
+            threads: None,
         };
 
         (existing, params)
@@ -125,4 +237,101 @@ mod tests {
 
         assert_eq!(params, expected_params);
     }
+
+    #[test]
+    fn given_overwrites_matching_category_it_converts_to_from_category() {
+        let role = given_existing_role("role_1");
+        let (mut existing, mut expected_params) =
+            given_matching_existing_and_params("channel_1", &role, &given_existing_category("a"));
+        let mut category = given_existing_category("category_1");
+        category.overwrites = existing.overwrites.clone();
+        existing.category = Some(category);
+        expected_params.category = Some("category_1".to_string());
+        expected_params.permissions_overwrites =
+            ChannelParamsPermissionsOverwritesStrategy::FromCategory;
+
+        let params = ChannelParams::from(&existing);
+
+        assert_eq!(params, expected_params);
+    }
+
+    #[test]
+    fn given_overwrites_partly_matching_category_it_converts_to_extend_category() {
+        let kept = given_existing_role("kept");
+        let changed = given_existing_role("changed");
+        let removed = given_existing_role("removed");
+        let overwrite = |role: &ExistingRole, allow: Vec<Permission>| PermissionsOverwrite {
+            role: role.clone(),
+            allow: PermissionsList::from(allow),
+            deny: PermissionsList::new(),
+        };
+        let (mut existing, _) =
+            given_matching_existing_and_params("channel_1", &kept, &given_existing_category("a"));
+        let mut category = given_existing_category("category_1");
+        category.overwrites = PermissionsOverwritesList::from(vec![
+            overwrite(&kept, vec![Permission::VIEW_CHANNEL]),
+            overwrite(&changed, vec![Permission::VIEW_CHANNEL]),
+            overwrite(&removed, vec![Permission::VIEW_CHANNEL]),
+        ]);
+        existing.category = Some(category);
+        existing.overwrites = PermissionsOverwritesList::from(vec![
+            overwrite(&kept, vec![Permission::VIEW_CHANNEL]),
+            overwrite(&changed, vec![Permission::SEND_MESSAGES]),
+        ]);
+
+        let params = ChannelParams::from(&existing);
+
+        assert_eq!(
+            params.permissions_overwrites,
+            ChannelParamsPermissionsOverwritesStrategy::ExtendCategory {
+                items: vec![PermissionsOverwriteParams {
+                    role: "changed".to_string(),
+                    allow: vec![Permission::SEND_MESSAGES.into()],
+                    deny: vec![],
+                }],
+                remove: vec!["removed".to_string()],
+            }
+        );
+    }
+
+    #[test]
+    fn given_threads_it_only_saves_managed_ones_once_per_name() {
+        let existing = ExistingChannelFixture::new()
+            .with_threads(vec![
+                ExistingThreadFixture::new()
+                    .with_name("faq")
+                    .archived()
+                    .build(),
+                ExistingThreadFixture::new().with_name("faq").build(),
+                ExistingThreadFixture::new()
+                    .with_name("question")
+                    .started_by_member()
+                    .build(),
+            ])
+            .build();
+
+        let params = ChannelParams::from(&existing);
+
+        let names: Vec<String> = params
+            .threads
+            .unwrap()
+            .items
+            .into_iter()
+            .map(|thread| thread.name)
+            .collect();
+        assert_eq!(names, vec!["faq".to_string()]);
+    }
+
+    #[test]
+    fn given_only_threads_started_by_members_it_saves_no_threads() {
+        let existing = ExistingChannelFixture::new()
+            .with_threads(vec![ExistingThreadFixture::new()
+                .started_by_member()
+                .build()])
+            .build();
+
+        let params = ChannelParams::from(&existing);
+
+        assert_eq!(params.threads, None);
+    }
 }
@@ -1,61 +1,351 @@
+use std::{collections::BTreeMap, sync::Arc};
+
 use serde::{Deserialize, Serialize};
 
-use crate::guild::{AwaitingGuild, ExistingGuild};
+use crate::{
+    channel::{split_channel_type, Channel},
+    guild::{AwaitingGuild, ExistingGuild},
+    onboarding::ExistingOnboarding,
+    welcome_screen::ExistingWelcomeScreen,
+};
 
 use super::{
+    automod::{AutoModRuleParamsAction, AutoModRulesParamsList},
     category::{CategoriesParamsList, CategoryParams, CategoryParamsExtraItemsStrategy},
-    channel::{ChannelParams, ChannelParamsExtraItemsStrategy, ChannelsParamsList},
+    channel::{
+        ChannelParams, ChannelParamsExtraItemsStrategy, ChannelParamsPermissionsOverwritesStrategy,
+        ChannelsParamsList,
+    },
+    member::MembersParamsList,
+    onboarding::OnboardingParams,
+    permission::{PermissionParams, PermissionsPresets},
     role::{RoleParams, RoleParamsExtraItemsStrategy, RolesParamsList},
+    scheduled_event::{ScheduledEventParamsLocation, ScheduledEventsParamsList},
+    welcome_screen::WelcomeScreenParams,
 };
 
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct GuildParams {
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub presets: BTreeMap<String, Vec<PermissionParams>>,
     #[serde(default = "RolesParamsList::default")]
     pub roles: RolesParamsList,
     #[serde(default = "CategoriesParamsList::default")]
     pub categories: CategoriesParamsList,
     #[serde(default = "ChannelsParamsList::default")]
     pub channels: ChannelsParamsList,
+    #[serde(default = "AutoModRulesParamsList::default")]
+    pub automod: AutoModRulesParamsList,
+    #[serde(default = "ScheduledEventsParamsList::default")]
+    pub scheduled_events: ScheduledEventsParamsList,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub members: Option<MembersParamsList>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub onboarding: Option<OnboardingParams>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub welcome_screen: Option<WelcomeScreenParams>,
 }
 
-impl Into<AwaitingGuild> for GuildParams {
-    fn into(self) -> AwaitingGuild {
-        let roles = self.roles.into();
-        let categories = self.categories.into(&roles.items);
-        let channels = self.channels.into(&roles.items, &categories.items);
+/// Minimum size of a permissions list for it to be factored into a preset.
+const MIN_PRESET_SIZE: usize = 2;
+/// Minimum number of occurences of a permissions list for it to be factored into a preset.
+const MIN_PRESET_OCCURENCES: usize = 2;
+
+impl GuildParams {
+    /// Factors the permissions lists repeated across roles and permissions
+    /// overwrites into presets named `preset_<n>`.
+    pub fn factor_presets(mut self) -> Self {
+        let mut occurences: Vec<(Vec<String>, usize)> = Vec::new();
+        for permissions in self.permissions_lists_mut() {
+            if let Some(key) = preset_key(permissions) {
+                match occurences.iter_mut().find(|(other, _)| other == &key) {
+                    Some((_, count)) => *count += 1,
+                    None => occurences.push((key, 1)),
+                }
+            }
+        }
+
+        let mut presets: Vec<(Vec<String>, String)> = Vec::new();
+        let mut index = 0;
+        for (key, count) in occurences {
+            if count < MIN_PRESET_OCCURENCES {
+                continue;
+            }
 
-        AwaitingGuild {
+            let name = loop {
+                index += 1;
+                let name = format!("preset_{index}");
+                if !self.presets.contains_key(&name) {
+                    break name;
+                }
+            };
+
+            let permissions = key
+                .iter()
+                .map(|permission| PermissionParams::try_from(permission.clone()).unwrap())
+                .collect();
+            self.presets.insert(name.clone(), permissions);
+            presets.push((key, name));
+        }
+
+        for permissions in self.permissions_lists_mut() {
+            if let Some(key) = preset_key(permissions) {
+                if let Some((_, name)) = presets.iter().find(|(other, _)| other == &key) {
+                    *permissions = vec![PermissionParams::Preset(name.clone())];
+                }
+            }
+        }
+
+        self
+    }
+
+    /// Renames the roles, categories and channels named as keys of `names`
+    /// to their values, along with all the references to them.
+    pub fn rename(mut self, names: &BTreeMap<String, String>) -> Self {
+        let rename = |name: &mut String| {
+            if let Some(new_name) = names.get(name) {
+                *name = new_name.clone();
+            }
+        };
+
+        for role in self.roles.items.iter_mut() {
+            rename(&mut role.name);
+        }
+
+        for category in self.categories.items.iter_mut() {
+            rename(&mut category.name);
+            for overwrite in category.permissions_overwrites.iter_mut() {
+                rename(&mut overwrite.role);
+            }
+        }
+
+        for channel in self.channels.items.iter_mut() {
+            rename(&mut channel.name);
+            channel.category.iter_mut().for_each(rename);
+            match &mut channel.permissions_overwrites {
+                ChannelParamsPermissionsOverwritesStrategy::FromCategory => {}
+                ChannelParamsPermissionsOverwritesStrategy::Manual { items } => {
+                    items
+                        .iter_mut()
+                        .for_each(|overwrite| rename(&mut overwrite.role));
+                }
+                ChannelParamsPermissionsOverwritesStrategy::ExtendCategory { items, remove } => {
+                    items
+                        .iter_mut()
+                        .for_each(|overwrite| rename(&mut overwrite.role));
+                    remove.iter_mut().for_each(rename);
+                }
+            }
+        }
+
+        for rule in self.automod.items.iter_mut() {
+            rule.exempt_roles.iter_mut().for_each(rename);
+        }
+
+        if let Some(onboarding) = &mut self.onboarding {
+            for option in onboarding
+                .prompts
+                .iter_mut()
+                .flat_map(|prompt| prompt.options.iter_mut())
+            {
+                option.roles.iter_mut().for_each(rename);
+            }
+        }
+
+        for reference in self.channel_references_mut() {
+            let (untyped_reference, channel_type) = split_channel_type(reference);
+            let mut renamed = match untyped_reference.split_once(':') {
+                Some((category, channel)) => {
+                    let (mut category, mut channel) = (category.to_string(), channel.to_string());
+                    rename(&mut category);
+                    rename(&mut channel);
+                    format!("{category}:{channel}")
+                }
+                None => {
+                    let mut channel = untyped_reference.to_string();
+                    rename(&mut channel);
+                    channel
+                }
+            };
+            if let Some(channel_type) = channel_type {
+                renamed = format!("{renamed} ({channel_type})");
+            }
+            *reference = renamed;
+        }
+
+        self
+    }
+
+    fn channel_references_mut(&mut self) -> Vec<&mut String> {
+        let mut references = Vec::new();
+
+        for rule in self.automod.items.iter_mut() {
+            references.extend(rule.exempt_channels.iter_mut());
+            for action in rule.actions.iter_mut() {
+                if let AutoModRuleParamsAction::SendAlertMessage { channel } = action {
+                    references.push(channel);
+                }
+            }
+        }
+
+        for event in self.scheduled_events.items.iter_mut() {
+            match &mut event.location {
+                ScheduledEventParamsLocation::Voice { channel }
+                | ScheduledEventParamsLocation::Stage { channel } => references.push(channel),
+                ScheduledEventParamsLocation::External { .. } => {}
+            }
+        }
+
+        if let Some(onboarding) = &mut self.onboarding {
+            references.extend(onboarding.default_channels.iter_mut());
+            for option in onboarding
+                .prompts
+                .iter_mut()
+                .flat_map(|prompt| prompt.options.iter_mut())
+            {
+                references.extend(option.channels.iter_mut());
+            }
+        }
+
+        if let Some(welcome_screen) = &mut self.welcome_screen {
+            for channel in welcome_screen.channels.iter_mut() {
+                references.push(&mut channel.channel);
+            }
+        }
+
+        references
+    }
+
+    fn permissions_lists_mut(&mut self) -> Vec<&mut Vec<PermissionParams>> {
+        let mut lists = Vec::new();
+
+        for role in self.roles.items.iter_mut() {
+            lists.push(&mut role.permissions);
+        }
+
+        for category in self.categories.items.iter_mut() {
+            for overwrite in category.permissions_overwrites.iter_mut() {
+                lists.push(&mut overwrite.allow);
+                lists.push(&mut overwrite.deny);
+            }
+        }
+
+        for channel in self.channels.items.iter_mut() {
+            if let ChannelParamsPermissionsOverwritesStrategy::Manual { items }
+            | ChannelParamsPermissionsOverwritesStrategy::ExtendCategory { items, .. } =
+                &mut channel.permissions_overwrites
+            {
+                for overwrite in items.iter_mut() {
+                    lists.push(&mut overwrite.allow);
+                    lists.push(&mut overwrite.deny);
+                }
+            }
+        }
+
+        lists
+    }
+}
+
+/// Sorted permissions of a list that can be factored into a preset.
+fn preset_key(permissions: &[PermissionParams]) -> Option<Vec<String>> {
+    let is_factorable = permissions.len() >= MIN_PRESET_SIZE
+        && permissions
+            .iter()
+            .all(|permission| matches!(permission, PermissionParams::Permission(_)));
+
+    is_factorable.then(|| {
+        let mut key: Vec<String> = permissions.iter().map(ToString::to_string).collect();
+        key.sort();
+        key
+    })
+}
+
+#[cfg(feature = "schema")]
+impl GuildParams {
+    pub fn json_schema() -> schemars::schema::RootSchema {
+        schemars::schema_for!(GuildParams)
+    }
+}
+
+impl GuildParams {
+    /// Builds the awaiting guild of the config. Fails on an unknown or cyclic
+    /// permissions preset, or on a channel reference matching no channel, or
+    /// several of them.
+    pub fn try_into_awaiting(mut self) -> Result<AwaitingGuild, String> {
+        let presets = PermissionsPresets::new(&self.presets)?;
+        for permissions in self.permissions_lists_mut() {
+            presets.check(permissions)?;
+        }
+
+        let roles = self.roles.into(&presets);
+        let categories = self.categories.into(&roles.items, &presets);
+        let channels = self
+            .channels
+            .into(&roles.items, &categories.items, &presets);
+        let automod = self.automod.into(&roles.items, &channels.items)?;
+        let scheduled_events = self.scheduled_events.into(&channels.items)?;
+        let members = self.members.map(|members| members.into(&roles.items));
+        let onboarding = self
+            .onboarding
+            .map(|onboarding| onboarding.into(&roles.items, &channels.items))
+            .transpose()?;
+        let welcome_screen = self
+            .welcome_screen
+            .map(|welcome_screen| welcome_screen.into(&channels.items))
+            .transpose()?;
+
+        Ok(AwaitingGuild {
             roles,
             categories,
             channels,
-        }
+            automod,
+            scheduled_events,
+            members,
+            onboarding,
+            welcome_screen,
+            ids: Arc::default(),
+        })
+    }
+}
+
+impl Into<AwaitingGuild> for GuildParams {
+    fn into(self) -> AwaitingGuild {
+        self.try_into_awaiting()
+            .unwrap_or_else(|error| panic!("{error}"))
     }
 }
 
 impl From<&ExistingGuild> for GuildParams {
     fn from(guild: &ExistingGuild) -> Self {
-        let roles: Vec<RoleParams> = guild
+        let mut roles: Vec<RoleParams> = guild
             .roles()
             .to_list()
             .into_iter()
             .map(RoleParams::from)
             .collect();
 
-        let categories: Vec<CategoryParams> = guild
+        let mut categories: Vec<CategoryParams> = guild
             .categories()
             .to_list()
             .into_iter()
             .map(CategoryParams::from)
             .collect();
 
-        let channels: Vec<ChannelParams> = guild
+        let mut channels: Vec<ChannelParams> = guild
             .channels()
             .to_list()
             .into_iter()
             .map(ChannelParams::from)
             .collect();
 
-        Self {
+        // Sorting for the saved config to be stable between saves
+        roles.sort_by(|a, b| a.name.cmp(&b.name));
+        categories.sort_by(|a, b| a.name.cmp(&b.name));
+        channels.sort_by(|a, b| (&a.category, &a.name).cmp(&(&b.category, &b.name)));
+
+        let mut params = Self {
+            presets: BTreeMap::new(),
             roles: RolesParamsList {
                 items: roles,
                 extra_items: RoleParamsExtraItemsStrategy::default(),
@@ -66,36 +356,250 @@ impl From<&ExistingGuild> for GuildParams {
             },
             channels: ChannelsParamsList {
                 items: channels,
+                templates: BTreeMap::new(),
+                generate: vec![],
                 extra_items: ChannelParamsExtraItemsStrategy::default(),
             },
+            automod: AutoModRulesParamsList::from(guild.automod_rules()),
+            scheduled_events: ScheduledEventsParamsList::from(guild.scheduled_events()),
+            members: None,
+            onboarding: (guild.onboarding() != &ExistingOnboarding::default())
+                .then(|| OnboardingParams::from(guild.onboarding())),
+            welcome_screen: (guild.welcome_screen() != &ExistingWelcomeScreen::default())
+                .then(|| WelcomeScreenParams::from(guild.welcome_screen())),
+        };
+
+        // Channels are only qualified by their category or type when their name
+        // is shared with other channels
+        for reference in params.channel_references_mut() {
+            if let Ok(Some(channel)) = guild.channels().find_by_reference(reference) {
+                *reference = guild.channels().reference_to(&channel.unique_name());
+            }
         }
+
+        params
     }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::{
-        category::CategoriesList, channel::ChannelsList, guild::ExistingGuild, role::RolesList,
+        automod::{AutoModAction, AutoModRulesList},
+        category::CategoriesList,
+        channel::{ChannelsList, ExistingChannel},
+        guild::ExistingGuild,
+        onboarding::ExistingOnboarding,
+        role::RolesList,
+        scheduled_event::ScheduledEventsList,
+        welcome_screen::ExistingWelcomeScreen,
     };
 
     use crate::api::params::{
-        category::CategoriesParamsList, channel::ChannelsParamsList, role::RolesParamsList,
+        automod::{AutoModRuleParamsAction, AutoModRulesParamsList},
+        category::CategoriesParamsList,
+        channel::ChannelsParamsList,
+        role::RolesParamsList,
+        scheduled_event::ScheduledEventsParamsList,
+    };
+
+    use std::collections::BTreeMap;
+
+    use crate::{
+        api::params::permission::{PermissionParams, PermissionsOverwriteParams},
+        permission::Permission,
+        tests::fixtures::{
+            existing::{
+                ExistingAutoModRuleFixture, ExistingCategoryFixture, ExistingChannelFixture,
+                ExistingGuildFixture,
+            },
+            params::{
+                CategoryParamsFixture, ChannelParamsFixture, GuildParamsFixture, RoleParamsFixture,
+            },
+        },
     };
 
     use super::GuildParams;
 
     #[test]
     pub fn when_parsing_empty_existing_guild_it_fills_params_with_defaults() {
-        let entity =
-            ExistingGuild::new(RolesList::new(), CategoriesList::new(), ChannelsList::new());
+        let entity = ExistingGuild::new(
+            RolesList::new(),
+            CategoriesList::new(),
+            ChannelsList::new(),
+            AutoModRulesList::new(),
+            ScheduledEventsList::new(),
+            ExistingOnboarding::default(),
+            ExistingWelcomeScreen::default(),
+        );
 
         let params = GuildParams::from(&entity);
 
         let expected_params = GuildParams {
+            presets: BTreeMap::new(),
             roles: RolesParamsList::default(),
             categories: CategoriesParamsList::default(),
             channels: ChannelsParamsList::default(),
+            automod: AutoModRulesParamsList::default(),
+            scheduled_events: ScheduledEventsParamsList::default(),
+            members: None,
+            onboarding: None,
+            welcome_screen: None,
         };
         assert_eq!(params, expected_params);
     }
+
+    #[test]
+    fn when_factoring_presets_it_replaces_repeated_permissions_lists() {
+        let moderation = vec![
+            Permission::KICK_MEMBERS.into(),
+            Permission::MANAGE_MESSAGES.into(),
+        ];
+        let params = GuildParamsFixture::new()
+            .with_role(
+                RoleParamsFixture::new()
+                    .with_name("a")
+                    .with_permissions(moderation.clone())
+                    .build(),
+            )
+            .with_role(
+                RoleParamsFixture::new()
+                    .with_name("b")
+                    .with_permissions(moderation.into_iter().rev().collect())
+                    .build(),
+            )
+            .with_role(
+                RoleParamsFixture::new()
+                    .with_name("c")
+                    .with_permissions(vec![Permission::SEND_MESSAGES.into()])
+                    .build(),
+            )
+            .build();
+
+        let factored = params.factor_presets();
+
+        let preset: Vec<PermissionParams> = vec![PermissionParams::Preset("preset_1".to_string())];
+        assert_eq!(
+            factored.presets,
+            BTreeMap::from([(
+                "preset_1".to_string(),
+                vec![
+                    Permission::KICK_MEMBERS.into(),
+                    Permission::MANAGE_MESSAGES.into()
+                ]
+            )])
+        );
+        assert_eq!(factored.roles.items[0].permissions, preset);
+        assert_eq!(factored.roles.items[1].permissions, preset);
+        assert_eq!(
+            factored.roles.items[2].permissions,
+            vec![Permission::SEND_MESSAGES.into()]
+        );
+    }
+
+    #[test]
+    fn when_renaming_it_renames_entities_and_their_references() {
+        let params = GuildParamsFixture::new()
+            .with_role(RoleParamsFixture::new().with_name("Golden").build())
+            .with_category(
+                CategoryParamsFixture::new()
+                    .with_name("Template")
+                    .with_permissions_overwrite(PermissionsOverwriteParams {
+                        role: "Golden".to_string(),
+                        allow: vec![],
+                        deny: vec![],
+                    })
+                    .build(),
+            )
+            .with_channel(
+                ChannelParamsFixture::new()
+                    .with_name("general")
+                    .with_category("Template")
+                    .build(),
+            )
+            .build();
+
+        let renamed = params.rename(&BTreeMap::from([
+            ("Golden".to_string(), "Member".to_string()),
+            ("Template".to_string(), "Community".to_string()),
+        ]));
+
+        assert_eq!(renamed.roles.items[0].name, "Member");
+        assert_eq!(renamed.categories.items[0].name, "Community");
+        assert_eq!(
+            renamed.categories.items[0].permissions_overwrites[0].role,
+            "Member"
+        );
+        assert_eq!(renamed.channels.items[0].name, "general");
+        assert_eq!(
+            renamed.channels.items[0].category,
+            Some("Community".to_string())
+        );
+    }
+
+    #[test]
+    fn given_unknown_preset_when_building_awaiting_guild_it_fails() {
+        let params = GuildParamsFixture::new()
+            .with_role(
+                RoleParamsFixture::new()
+                    .with_permissions(vec![PermissionParams::Preset("unknown".to_string())])
+                    .build(),
+            )
+            .build();
+
+        let awaiting = params.try_into_awaiting();
+
+        assert_eq!(
+            awaiting.unwrap_err(),
+            "Unknown permissions preset '$unknown'".to_string()
+        );
+    }
+
+    #[test]
+    fn when_parsing_existing_guild_it_qualifies_shared_channel_names_by_category() {
+        let category = ExistingCategoryFixture::new().with_name("a").build();
+        let other_category = ExistingCategoryFixture::new().with_name("b").build();
+        let shared = ExistingChannelFixture::new()
+            .with_name("general")
+            .with_category(&category)
+            .build();
+        let unique = ExistingChannelFixture::new()
+            .with_name("rules")
+            .with_category(&category)
+            .build();
+        let alert = |channel: &ExistingChannel| AutoModAction::SendAlertMessage {
+            channel: channel.clone(),
+        };
+        let guild = ExistingGuildFixture::new()
+            .with_category(category.clone())
+            .with_category(other_category.clone())
+            .with_channel(shared.clone())
+            .with_channel(
+                ExistingChannelFixture::new()
+                    .with_name("general")
+                    .with_category(&other_category)
+                    .build(),
+            )
+            .with_channel(unique.clone())
+            .with_automod_rule(
+                ExistingAutoModRuleFixture::new()
+                    .with_actions(vec![alert(&shared), alert(&unique)])
+                    .build(),
+            )
+            .build();
+
+        let params = GuildParams::from(&guild);
+
+        assert_eq!(
+            params.automod.items[0].actions,
+            vec![
+                AutoModRuleParamsAction::SendAlertMessage {
+                    channel: "a:general".to_string()
+                },
+                AutoModRuleParamsAction::SendAlertMessage {
+                    channel: "rules".to_string()
+                },
+            ]
+        );
+        assert!(params.try_into_awaiting().is_ok());
+    }
 }
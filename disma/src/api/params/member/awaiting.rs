@@ -0,0 +1,90 @@
+use crate::{
+    member::{AwaitingMember, AwaitingMembersList, RosterStrategy},
+    role::{AwaitingRole, RolesList},
+};
+
+use super::{MembersParamsList, MembersParamsRosterStrategy};
+
+impl MembersParamsList {
+    pub fn into(self, roles: &RolesList<AwaitingRole>) -> AwaitingMembersList {
+        let items = self
+            .items
+            .into_iter()
+            .map(|(id, role_names)| {
+                let member_roles = role_names
+                    .iter()
+                    .map(|name| {
+                        roles
+                            .find_by_name(name)
+                            .unwrap_or_else(|| {
+                                panic!("Cannot give non-existant role '{name}' to member '{id}'")
+                            })
+                            .clone()
+                    })
+                    .collect::<Vec<AwaitingRole>>()
+                    .into();
+
+                AwaitingMember {
+                    id,
+                    roles: member_roles,
+                }
+            })
+            .collect::<Vec<AwaitingMember>>()
+            .into();
+
+        AwaitingMembersList {
+            items,
+            roster_strategy: self.roster_strategy.into(),
+        }
+    }
+}
+
+impl From<MembersParamsRosterStrategy> for RosterStrategy {
+    fn from(value: MembersParamsRosterStrategy) -> Self {
+        match value {
+            MembersParamsRosterStrategy::ADDITIVE => RosterStrategy::ADDITIVE,
+            MembersParamsRosterStrategy::EXACT => RosterStrategy::EXACT,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::{
+        api::params::member::{MembersParamsList, MembersParamsRosterStrategy},
+        member::RosterStrategy,
+        role::RolesList,
+        tests::fixtures::awaiting::AwaitingRoleFixture,
+    };
+
+    const A_MEMBER_ID: &str = "123";
+    const A_ROLE_NAME: &str = "Staff";
+
+    fn given_params(role_name: &str) -> MembersParamsList {
+        MembersParamsList {
+            items: BTreeMap::from([(A_MEMBER_ID.to_string(), vec![role_name.to_string()])]),
+            roster_strategy: MembersParamsRosterStrategy::EXACT,
+        }
+    }
+
+    #[test]
+    fn can_convert_params_to_awaiting() {
+        let roles = RolesList::from(vec![AwaitingRoleFixture::new()
+            .with_name(A_ROLE_NAME)
+            .build()]);
+
+        let awaiting = given_params(A_ROLE_NAME).into(&roles);
+
+        let member = awaiting.items.find_by_id(A_MEMBER_ID).unwrap();
+        assert_eq!(member.roles, roles);
+        assert_eq!(awaiting.roster_strategy, RosterStrategy::EXACT);
+    }
+
+    #[test]
+    #[should_panic]
+    fn given_non_existant_role_it_panics() {
+        given_params("non-existant").into(&RolesList::new());
+    }
+}
@@ -0,0 +1,57 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Default, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MembersParamsList {
+    /// Role names by user ID.
+    #[serde(default)]
+    pub items: BTreeMap<String, Vec<String>>,
+    #[serde(default = "MembersParamsRosterStrategy::default")]
+    pub roster_strategy: MembersParamsRosterStrategy,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum MembersParamsRosterStrategy {
+    #[default]
+    ADDITIVE,
+    EXACT,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::api::params::member::{MembersParamsList, MembersParamsRosterStrategy};
+
+    #[test]
+    fn it_parses_params_list() {
+        let yaml_params_list = r#"
+            items:
+              "123456789": [Staff, Moderator]
+            roster_strategy: EXACT
+        "#;
+        let expected_params_list = MembersParamsList {
+            items: BTreeMap::from([(
+                "123456789".to_string(),
+                vec!["Staff".to_string(), "Moderator".to_string()],
+            )]),
+            roster_strategy: MembersParamsRosterStrategy::EXACT,
+        };
+
+        let params_list: MembersParamsList = serde_yaml::from_str(yaml_params_list).unwrap();
+
+        assert_eq!(params_list, expected_params_list);
+    }
+
+    #[test]
+    fn it_parses_empty_params_list_to_defaults() {
+        let yaml_params_list = r"";
+
+        let params_list: MembersParamsList = serde_yaml::from_str(yaml_params_list).unwrap();
+
+        assert_eq!(params_list, MembersParamsList::default());
+    }
+}
@@ -0,0 +1,4 @@
+mod base;
+pub use base::*;
+
+mod awaiting;
@@ -1,5 +1,12 @@
+pub mod automod;
 pub mod category;
 pub mod channel;
 pub mod guild;
+pub mod member;
+pub mod onboarding;
 pub mod permission;
+pub mod policy;
 pub mod role;
+pub mod scheduled_event;
+pub mod snapshot;
+pub mod welcome_screen;
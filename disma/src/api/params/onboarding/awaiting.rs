@@ -0,0 +1,179 @@
+use crate::{
+    channel::{AwaitingChannel, ChannelsList},
+    onboarding::{
+        AwaitingOnboarding, OnboardingMode, OnboardingPrompt, OnboardingPromptOption,
+        OnboardingPromptType,
+    },
+    role::{AwaitingRole, RolesList},
+};
+
+use super::{
+    OnboardingParams, OnboardingParamsMode, OnboardingPromptOptionParams, OnboardingPromptParams,
+    OnboardingPromptParamsType,
+};
+
+impl OnboardingParams {
+    pub fn into(
+        self,
+        roles: &RolesList<AwaitingRole>,
+        channels: &ChannelsList<AwaitingChannel>,
+    ) -> Result<AwaitingOnboarding, String> {
+        Ok(AwaitingOnboarding {
+            enabled: self.enabled,
+            mode: self.mode.into(),
+            default_channels: find_channels(&self.default_channels, channels)?,
+            prompts: self
+                .prompts
+                .into_iter()
+                .map(|prompt| prompt.into(roles, channels))
+                .collect::<Result<_, String>>()?,
+        })
+    }
+}
+
+impl OnboardingPromptParams {
+    fn into(
+        self,
+        roles: &RolesList<AwaitingRole>,
+        channels: &ChannelsList<AwaitingChannel>,
+    ) -> Result<OnboardingPrompt<AwaitingRole, AwaitingChannel>, String> {
+        Ok(OnboardingPrompt {
+            title: self.title,
+            prompt_type: self._type.into(),
+            single_select: self.single_select,
+            required: self.required,
+            in_onboarding: self.in_onboarding,
+            options: self
+                .options
+                .into_iter()
+                .map(|option| option.into(roles, channels))
+                .collect::<Result<_, String>>()?,
+        })
+    }
+}
+
+impl OnboardingPromptOptionParams {
+    fn into(
+        self,
+        roles: &RolesList<AwaitingRole>,
+        channels: &ChannelsList<AwaitingChannel>,
+    ) -> Result<OnboardingPromptOption<AwaitingRole, AwaitingChannel>, String> {
+        let option_roles = self
+            .roles
+            .iter()
+            .map(|name| {
+                roles.find_by_name(name).cloned().ok_or_else(|| {
+                    format!(
+                        "Cannot build onboarding option '{}' from non-existant role '{name}'",
+                        self.title
+                    )
+                })
+            })
+            .collect::<Result<Vec<AwaitingRole>, String>>()?;
+
+        Ok(OnboardingPromptOption {
+            roles: RolesList::from(option_roles),
+            channels: find_channels(&self.channels, channels)?,
+            title: self.title,
+            description: self.description,
+            emoji: self.emoji,
+        })
+    }
+}
+
+fn find_channels(
+    names: &[String],
+    channels: &ChannelsList<AwaitingChannel>,
+) -> Result<ChannelsList<AwaitingChannel>, String> {
+    Ok(names
+        .iter()
+        .map(|name| {
+            channels
+                .find_by_reference(name)
+                .map_err(|error| format!("Cannot build onboarding: {error}"))?
+                .cloned()
+                .ok_or_else(|| {
+                    format!("Cannot build onboarding from non-existant channel '{name}'")
+                })
+        })
+        .collect::<Result<Vec<AwaitingChannel>, String>>()?
+        .into())
+}
+
+impl From<OnboardingParamsMode> for OnboardingMode {
+    fn from(value: OnboardingParamsMode) -> Self {
+        match value {
+            OnboardingParamsMode::DEFAULT => OnboardingMode::DEFAULT,
+            OnboardingParamsMode::ADVANCED => OnboardingMode::ADVANCED,
+        }
+    }
+}
+
+impl From<OnboardingPromptParamsType> for OnboardingPromptType {
+    fn from(value: OnboardingPromptParamsType) -> Self {
+        match value {
+            OnboardingPromptParamsType::MULTIPLE_CHOICE => OnboardingPromptType::MULTIPLE_CHOICE,
+            OnboardingPromptParamsType::DROPDOWN => OnboardingPromptType::DROPDOWN,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        api::params::onboarding::{
+            OnboardingParams, OnboardingParamsMode, OnboardingPromptOptionParams,
+            OnboardingPromptParams, OnboardingPromptParamsType,
+        },
+        channel::ChannelsList,
+        role::RolesList,
+        tests::fixtures::awaiting::{AwaitingChannelFixture, AwaitingRoleFixture},
+    };
+
+    fn given_params(role_name: &str, channel_name: &str) -> OnboardingParams {
+        OnboardingParams {
+            enabled: true,
+            mode: OnboardingParamsMode::DEFAULT,
+            default_channels: vec![channel_name.to_string()],
+            prompts: vec![OnboardingPromptParams {
+                title: "prompt".to_string(),
+                _type: OnboardingPromptParamsType::DROPDOWN,
+                single_select: true,
+                required: false,
+                in_onboarding: true,
+                options: vec![OnboardingPromptOptionParams {
+                    title: "option".to_string(),
+                    description: None,
+                    emoji: None,
+                    roles: vec![role_name.to_string()],
+                    channels: vec![],
+                }],
+            }],
+        }
+    }
+
+    #[test]
+    fn can_convert_params_to_awaiting() {
+        let role = AwaitingRoleFixture::new().with_name("role").build();
+        let channel = AwaitingChannelFixture::new().with_name("channel").build();
+        let roles = RolesList::from(vec![role.clone()]);
+        let channels = ChannelsList::from(vec![channel.clone()]);
+
+        let awaiting = given_params("role", "channel")
+            .into(&roles, &channels)
+            .unwrap();
+
+        assert_eq!(awaiting.default_channels, ChannelsList::from(vec![channel]));
+        assert_eq!(awaiting.prompts[0].options[0].roles, roles);
+    }
+
+    #[test]
+    fn given_non_existant_role_it_fails() {
+        let channel = AwaitingChannelFixture::new().with_name("channel").build();
+
+        let awaiting = given_params("role", "channel")
+            .into(&RolesList::new(), &ChannelsList::from(vec![channel]));
+
+        assert!(awaiting.is_err());
+    }
+}
@@ -0,0 +1,113 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct OnboardingParams {
+    pub enabled: bool,
+    #[serde(default = "OnboardingParamsMode::default")]
+    pub mode: OnboardingParamsMode,
+    #[serde(default)]
+    pub default_channels: Vec<String>,
+    #[serde(default)]
+    pub prompts: Vec<OnboardingPromptParams>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum OnboardingParamsMode {
+    #[default]
+    DEFAULT,
+    ADVANCED,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct OnboardingPromptParams {
+    pub title: String,
+    #[serde(rename = "type", default = "OnboardingPromptParamsType::default")]
+    pub _type: OnboardingPromptParamsType,
+    #[serde(default)]
+    pub single_select: bool,
+    #[serde(default)]
+    pub required: bool,
+    #[serde(default = "default_in_onboarding")]
+    pub in_onboarding: bool,
+    pub options: Vec<OnboardingPromptOptionParams>,
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum OnboardingPromptParamsType {
+    #[default]
+    MULTIPLE_CHOICE,
+    DROPDOWN,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct OnboardingPromptOptionParams {
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emoji: Option<String>,
+    #[serde(default)]
+    pub roles: Vec<String>,
+    #[serde(default)]
+    pub channels: Vec<String>,
+}
+
+fn default_in_onboarding() -> bool {
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::params::onboarding::{
+        OnboardingParams, OnboardingParamsMode, OnboardingPromptOptionParams,
+        OnboardingPromptParams, OnboardingPromptParamsType,
+    };
+
+    #[test]
+    fn it_parses_params() {
+        let yaml_params = r"
+            enabled: true
+            default_channels:
+            - welcome
+            prompts:
+            - title: What are you here for?
+              required: true
+              options:
+              - title: Gaming
+                emoji: 🎮
+                roles:
+                - gamers
+                channels:
+                - gaming
+        ";
+        let expected_params = OnboardingParams {
+            enabled: true,
+            mode: OnboardingParamsMode::DEFAULT,
+            default_channels: vec!["welcome".to_string()],
+            prompts: vec![OnboardingPromptParams {
+                title: "What are you here for?".to_string(),
+                _type: OnboardingPromptParamsType::MULTIPLE_CHOICE,
+                single_select: false,
+                required: true,
+                in_onboarding: true,
+                options: vec![OnboardingPromptOptionParams {
+                    title: "Gaming".to_string(),
+                    description: None,
+                    emoji: Some("🎮".to_string()),
+                    roles: vec!["gamers".to_string()],
+                    channels: vec!["gaming".to_string()],
+                }],
+            }],
+        };
+
+        let params: OnboardingParams = serde_yaml::from_str(yaml_params).unwrap();
+
+        assert_eq!(params, expected_params);
+    }
+}
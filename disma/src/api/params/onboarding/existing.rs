@@ -0,0 +1,116 @@
+use crate::{
+    channel::{Channel, ChannelsList, ExistingChannel},
+    onboarding::{
+        ExistingOnboarding, OnboardingMode, OnboardingPrompt, OnboardingPromptOption,
+        OnboardingPromptType,
+    },
+    role::{ExistingRole, Role},
+};
+
+use super::{
+    OnboardingParams, OnboardingParamsMode, OnboardingPromptOptionParams, OnboardingPromptParams,
+    OnboardingPromptParamsType,
+};
+
+impl From<&ExistingOnboarding> for OnboardingParams {
+    fn from(onboarding: &ExistingOnboarding) -> Self {
+        Self {
+            enabled: onboarding.enabled,
+            mode: onboarding.mode.clone().into(),
+            default_channels: channel_names(&onboarding.default_channels),
+            prompts: onboarding.prompts.iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<&OnboardingPrompt<ExistingRole, ExistingChannel>> for OnboardingPromptParams {
+    fn from(prompt: &OnboardingPrompt<ExistingRole, ExistingChannel>) -> Self {
+        Self {
+            title: prompt.title.clone(),
+            _type: prompt.prompt_type.clone().into(),
+            single_select: prompt.single_select,
+            required: prompt.required,
+            in_onboarding: prompt.in_onboarding,
+            options: prompt.options.iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<&OnboardingPromptOption<ExistingRole, ExistingChannel>> for OnboardingPromptOptionParams {
+    fn from(option: &OnboardingPromptOption<ExistingRole, ExistingChannel>) -> Self {
+        let mut roles: Vec<String> = option
+            .roles
+            .to_list()
+            .into_iter()
+            .map(|role| role.name().to_string())
+            .collect();
+        roles.sort();
+
+        Self {
+            title: option.title.clone(),
+            description: option.description.clone(),
+            emoji: option.emoji.clone(),
+            roles,
+            channels: channel_names(&option.channels),
+        }
+    }
+}
+
+fn channel_names(channels: &ChannelsList<ExistingChannel>) -> Vec<String> {
+    let mut names: Vec<String> = channels
+        .to_list()
+        .into_iter()
+        .map(|channel| channel.unique_name().typed_reference())
+        .collect();
+    names.sort();
+    names
+}
+
+impl From<OnboardingMode> for OnboardingParamsMode {
+    fn from(value: OnboardingMode) -> Self {
+        match value {
+            OnboardingMode::DEFAULT => Self::DEFAULT,
+            OnboardingMode::ADVANCED => Self::ADVANCED,
+        }
+    }
+}
+
+impl From<OnboardingPromptType> for OnboardingPromptParamsType {
+    fn from(value: OnboardingPromptType) -> Self {
+        match value {
+            OnboardingPromptType::MULTIPLE_CHOICE => Self::MULTIPLE_CHOICE,
+            OnboardingPromptType::DROPDOWN => Self::DROPDOWN,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        api::params::onboarding::{OnboardingParams, OnboardingParamsMode},
+        channel::ChannelsList,
+        onboarding::ExistingOnboarding,
+        tests::fixtures::existing::ExistingChannelFixture,
+    };
+
+    #[test]
+    fn can_convert_existing_to_params() {
+        let existing = ExistingOnboarding {
+            enabled: true,
+            default_channels: ChannelsList::from(vec![ExistingChannelFixture::new()
+                .with_name("welcome")
+                .build()]),
+            ..Default::default()
+        };
+        let expected_params = OnboardingParams {
+            enabled: true,
+            mode: OnboardingParamsMode::DEFAULT,
+            default_channels: vec!["welcome (TEXT)".to_string()],
+            prompts: vec![],
+        };
+
+        let params = OnboardingParams::from(&existing);
+
+        assert_eq!(params, expected_params);
+    }
+}
@@ -1,20 +1,236 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::Display,
+    str::FromStr,
+};
+
 use crate::{
-    permission::{Permission, PermissionsList, PermissionsOverwrite},
+    permission::{Permission, PermissionsList, PermissionsOverwrite, PERMISSION_BIT_PREFIX},
     role::{Role, RolesList},
 };
 use serde::{Deserialize, Serialize};
 
+const PRESET_PREFIX: char = '$';
+
+/// A permission, a raw permission bit unknown to disma (`BIT_<n>`), or a
+/// reference to a permissions preset (`$name`).
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(try_from = "String", into = "String")]
+pub enum PermissionParams {
+    Permission(Permission),
+    Bit(u8),
+    Preset(String),
+}
+
+impl PermissionParams {
+    /// Permissions of `permissions`, followed by its unknown bits.
+    pub fn from_list(permissions: &PermissionsList) -> Vec<Self> {
+        permissions
+            .to_list()
+            .into_iter()
+            .map(Self::Permission)
+            .chain(permissions.unknown_bits().into_iter().map(Self::Bit))
+            .collect()
+    }
+}
+
+impl From<Permission> for PermissionParams {
+    fn from(permission: Permission) -> Self {
+        Self::Permission(permission)
+    }
+}
+
+impl TryFrom<String> for PermissionParams {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        if let Some(preset) = value.strip_prefix(PRESET_PREFIX) {
+            return Ok(Self::Preset(preset.to_string()));
+        }
+
+        if let Some(bit) = value.strip_prefix(PERMISSION_BIT_PREFIX) {
+            return match bit.parse::<u8>() {
+                Ok(bit) if bit < 64 => Ok(Permission::from_bit(bit)
+                    .map(Self::Permission)
+                    .unwrap_or(Self::Bit(bit))),
+                _ => Err(format!(
+                    "invalid permission bit '{value}', expected {PERMISSION_BIT_PREFIX}0 to {PERMISSION_BIT_PREFIX}63"
+                )),
+            };
+        }
+
+        Permission::from_str(&value)
+            .map(Self::Permission)
+            .map_err(|_| format!("unknown permission '{value}'"))
+    }
+}
+
+impl From<PermissionParams> for String {
+    fn from(params: PermissionParams) -> Self {
+        params.to_string()
+    }
+}
+
+impl Display for PermissionParams {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Permission(permission) => write!(f, "{permission}"),
+            Self::Bit(bit) => write!(f, "{PERMISSION_BIT_PREFIX}{bit}"),
+            Self::Preset(preset) => write!(f, "{PRESET_PREFIX}{preset}"),
+        }
+    }
+}
+
+#[cfg(feature = "schema")]
+impl schemars::JsonSchema for PermissionParams {
+    fn schema_name() -> String {
+        "PermissionParams".to_string()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        use schemars::schema::{InstanceType, SchemaObject, StringValidation, SubschemaValidation};
+
+        let pattern = |pattern: String| SchemaObject {
+            instance_type: Some(InstanceType::String.into()),
+            string: Some(Box::new(StringValidation {
+                pattern: Some(pattern),
+                ..Default::default()
+            })),
+            ..Default::default()
+        };
+        let bit = pattern(format!("^{PERMISSION_BIT_PREFIX}[0-9]+$"));
+        let preset = pattern(format!("^\\{PRESET_PREFIX}"));
+
+        SchemaObject {
+            subschemas: Some(Box::new(SubschemaValidation {
+                any_of: Some(vec![
+                    gen.subschema_for::<Permission>(),
+                    bit.into(),
+                    preset.into(),
+                ]),
+                ..Default::default()
+            })),
+            ..Default::default()
+        }
+        .into()
+    }
+}
+
+/// Permissions presets, with all nested presets resolved.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct PermissionsPresets {
+    items: HashMap<String, Vec<PermissionParams>>,
+}
+
+impl PermissionsPresets {
+    /// Resolves the nested presets of `params`. Fails on a reference to an
+    /// unknown preset, or on a cycle between presets.
+    pub fn new(params: &BTreeMap<String, Vec<PermissionParams>>) -> Result<Self, String> {
+        let mut items = HashMap::new();
+
+        for name in params.keys() {
+            resolve_preset(name, params, &mut items, &mut Vec::new())?;
+        }
+
+        Ok(Self { items })
+    }
+
+    /// Fails when `permissions` reference an unknown preset.
+    pub fn check(&self, permissions: &[PermissionParams]) -> Result<(), String> {
+        match permissions.iter().find_map(|permission| match permission {
+            PermissionParams::Preset(name) if !self.items.contains_key(name) => Some(name),
+            _ => None,
+        }) {
+            Some(name) => Err(format!(
+                "Unknown permissions preset '{PRESET_PREFIX}{name}'"
+            )),
+            None => Ok(()),
+        }
+    }
+
+    /// Expands the presets of `permissions`, which must have been checked.
+    pub fn expand(&self, permissions: Vec<PermissionParams>) -> PermissionsList {
+        permissions
+            .into_iter()
+            .flat_map(|permission| match permission {
+                PermissionParams::Preset(name) => {
+                    self.items.get(&name).cloned().unwrap_or_else(|| {
+                        panic!("Unknown permissions preset '{PRESET_PREFIX}{name}'")
+                    })
+                }
+                permission => vec![permission],
+            })
+            .fold(
+                PermissionsList::new(),
+                |list, permission| match permission {
+                    PermissionParams::Permission(permission) => list.with_permission(permission),
+                    PermissionParams::Bit(bit) => list.with_bit(bit),
+                    PermissionParams::Preset(_) => list,
+                },
+            )
+    }
+}
+
+fn resolve_preset(
+    name: &str,
+    params: &BTreeMap<String, Vec<PermissionParams>>,
+    resolved: &mut HashMap<String, Vec<PermissionParams>>,
+    stack: &mut Vec<String>,
+) -> Result<Vec<PermissionParams>, String> {
+    if let Some(permissions) = resolved.get(name) {
+        return Ok(permissions.clone());
+    }
+
+    if stack.iter().any(|preset| preset == name) {
+        let cycle = stack
+            .iter()
+            .map(String::as_str)
+            .chain([name])
+            .map(|preset| format!("{PRESET_PREFIX}{preset}"))
+            .collect::<Vec<String>>()
+            .join(" -> ");
+        return Err(format!("Cycle detected in permissions presets: {cycle}"));
+    }
+
+    let preset_params = params.get(name).ok_or_else(|| match stack.last() {
+        Some(parent) => format!(
+            "Unknown permissions preset '{PRESET_PREFIX}{name}' in preset '{PRESET_PREFIX}{parent}'"
+        ),
+        None => format!("Unknown permissions preset '{PRESET_PREFIX}{name}'"),
+    })?;
+
+    stack.push(name.to_string());
+    let mut permissions = Vec::new();
+    for permission in preset_params.iter() {
+        match permission {
+            PermissionParams::Preset(nested) => {
+                permissions.extend(resolve_preset(nested, params, resolved, stack)?)
+            }
+            permission => permissions.push(permission.clone()),
+        }
+    }
+    stack.pop();
+
+    resolved.insert(name.to_string(), permissions.clone());
+    Ok(permissions)
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct PermissionsOverwriteParams {
     pub role: String,
-    #[serde(default = "Vec::default")]
-    pub allow: Vec<Permission>,
-    #[serde(default = "Vec::default")]
-    pub deny: Vec<Permission>,
+    #[serde(default)]
+    pub allow: Vec<PermissionParams>,
+    #[serde(default)]
+    pub deny: Vec<PermissionParams>,
 }
 
 impl PermissionsOverwriteParams {
-    pub fn into<R>(self, roles: &RolesList<R>) -> PermissionsOverwrite<R>
+    pub fn into<R>(
+        self,
+        roles: &RolesList<R>,
+        presets: &PermissionsPresets,
+    ) -> PermissionsOverwrite<R>
     where
         R: Role,
     {
@@ -28,8 +244,8 @@ impl PermissionsOverwriteParams {
                     )
                 })
                 .clone(),
-            allow: PermissionsList::from(self.allow),
-            deny: PermissionsList::from(self.deny),
+            allow: presets.expand(self.allow),
+            deny: presets.expand(self.deny),
         }
     }
 }
@@ -41,21 +257,23 @@ where
     fn from(permissions: &PermissionsOverwrite<R>) -> Self {
         Self {
             role: permissions.role.name().to_string(),
-            allow: permissions.allow.to_list(),
-            deny: permissions.deny.to_list(),
+            allow: PermissionParams::from_list(&permissions.allow),
+            deny: PermissionParams::from_list(&permissions.deny),
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::collections::BTreeMap;
+
     use crate::{
-        permission::{PermissionsList, PermissionsOverwrite},
+        permission::{Permission, PermissionsList, PermissionsOverwrite},
         role::{ExistingRole, RolesList},
         tests::fixtures::{awaiting::AwaitingRoleFixture, existing::ExistingRoleFixture},
     };
 
-    use super::PermissionsOverwriteParams;
+    use super::{PermissionParams, PermissionsOverwriteParams, PermissionsPresets};
 
     const A_ROLE_NAME: &str = "role_a";
 
@@ -69,7 +287,10 @@ mod tests {
             deny: vec![],
         };
 
-        let permissions_overwrite = params.into(&RolesList::from(vec![existing_role]));
+        let permissions_overwrite = params.into(
+            &RolesList::from(vec![existing_role]),
+            &PermissionsPresets::default(),
+        );
 
         assert_eq!(
             permissions_overwrite,
@@ -90,7 +311,10 @@ mod tests {
             deny: vec![],
         };
 
-        params.into(&RolesList::<ExistingRole>::new());
+        params.into(
+            &RolesList::<ExistingRole>::new(),
+            &PermissionsPresets::default(),
+        );
     }
 
     #[test]
@@ -112,4 +336,121 @@ mod tests {
             }
         )
     }
+
+    fn preset(name: &str) -> PermissionParams {
+        PermissionParams::Preset(name.to_string())
+    }
+
+    #[test]
+    fn can_parse_permissions_and_presets() {
+        let permission = PermissionParams::try_from("SEND_MESSAGES".to_string()).unwrap();
+        let preset_reference = PermissionParams::try_from("$moderation".to_string()).unwrap();
+
+        assert_eq!(permission, Permission::SEND_MESSAGES.into());
+        assert_eq!(preset_reference, preset("moderation"));
+        assert_eq!(preset_reference.to_string(), "$moderation");
+    }
+
+    #[test]
+    fn given_unknown_permission_when_parsing_it_fails() {
+        let result = PermissionParams::try_from("NOT_A_PERMISSION".to_string());
+
+        assert_eq!(
+            result,
+            Err("unknown permission 'NOT_A_PERMISSION'".to_string())
+        );
+    }
+
+    #[test]
+    fn can_expand_nested_presets() {
+        let presets = PermissionsPresets::new(&BTreeMap::from([
+            (
+                "moderation".to_string(),
+                vec![Permission::KICK_MEMBERS.into(), preset("messages")],
+            ),
+            (
+                "messages".to_string(),
+                vec![Permission::MANAGE_MESSAGES.into()],
+            ),
+        ]))
+        .unwrap();
+
+        let permissions =
+            presets.expand(vec![preset("moderation"), Permission::SEND_MESSAGES.into()]);
+
+        assert_eq!(
+            permissions,
+            PermissionsList::from(vec![
+                Permission::KICK_MEMBERS,
+                Permission::MANAGE_MESSAGES,
+                Permission::SEND_MESSAGES
+            ])
+        );
+    }
+
+    #[test]
+    fn can_parse_permission_bits() {
+        let unknown_bit = PermissionParams::try_from("BIT_47".to_string()).unwrap();
+        let known_bit = PermissionParams::try_from("BIT_3".to_string()).unwrap();
+        let invalid_bit = PermissionParams::try_from("BIT_64".to_string());
+
+        assert_eq!(unknown_bit, PermissionParams::Bit(47));
+        assert_eq!(unknown_bit.to_string(), "BIT_47");
+        assert_eq!(known_bit, Permission::ADMINISTRATOR.into());
+        assert!(invalid_bit.is_err());
+    }
+
+    #[test]
+    fn it_expands_permission_bits_into_unknown_bits() {
+        let presets = PermissionsPresets::new(&BTreeMap::from([(
+            "future".to_string(),
+            vec![PermissionParams::Bit(47)],
+        )]))
+        .unwrap();
+
+        let permissions = presets.expand(vec![preset("future"), Permission::SEND_MESSAGES.into()]);
+
+        assert_eq!(permissions.to_list(), vec![Permission::SEND_MESSAGES]);
+        assert_eq!(permissions.unknown_bits(), vec![47]);
+        assert_eq!(
+            PermissionParams::from_list(&permissions),
+            vec![Permission::SEND_MESSAGES.into(), PermissionParams::Bit(47)]
+        );
+    }
+
+    #[test]
+    fn given_cyclic_presets_when_resolving_it_fails() {
+        let presets = PermissionsPresets::new(&BTreeMap::from([
+            ("a".to_string(), vec![preset("b")]),
+            ("b".to_string(), vec![preset("a")]),
+        ]));
+
+        assert_eq!(
+            presets,
+            Err("Cycle detected in permissions presets: $a -> $b -> $a".to_string())
+        );
+    }
+
+    #[test]
+    fn given_unknown_nested_preset_when_resolving_it_fails() {
+        let presets = PermissionsPresets::new(&BTreeMap::from([(
+            "a".to_string(),
+            vec![preset("unknown")],
+        )]));
+
+        assert_eq!(
+            presets,
+            Err("Unknown permissions preset '$unknown' in preset '$a'".to_string())
+        );
+    }
+
+    #[test]
+    fn given_unknown_preset_when_checking_it_fails() {
+        let checked = PermissionsPresets::default().check(&[preset("unknown")]);
+
+        assert_eq!(
+            checked,
+            Err("Unknown permissions preset '$unknown'".to_string())
+        );
+    }
 }
@@ -0,0 +1,210 @@
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    permission::{Permission, PermissionsList},
+    policy::{
+        ForbiddenOverwriteAllowsRule, ForbiddenRolePermissionsRule, Policy, PolicyRule,
+        PolicySeverity, RequiredChannelDeniesRule,
+    },
+};
+
+/// Rules that a guild config must follow, usually read from a `policy.yaml`.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct PolicyParams {
+    /// Whether to also check the built-in rules against dangerous permissions.
+    #[serde(default = "default_builtin_rules")]
+    pub builtin_rules: bool,
+    #[serde(default)]
+    pub rules: Vec<PolicyRuleParams>,
+}
+
+fn default_builtin_rules() -> bool {
+    true
+}
+
+impl Default for PolicyParams {
+    fn default() -> Self {
+        Self {
+            builtin_rules: default_builtin_rules(),
+            rules: Vec::new(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct PolicyRuleParams {
+    pub id: String,
+    #[serde(default)]
+    pub severity: PolicySeverityParams,
+    #[serde(flatten)]
+    pub check: PolicyCheckParams,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PolicySeverityParams {
+    #[default]
+    Error,
+    Warning,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum PolicyCheckParams {
+    /// Roles matching `roles` (globs), except `except`, must not have any of
+    /// `permissions`.
+    ForbidRolePermissions {
+        roles: Vec<String>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        except: Vec<String>,
+        permissions: Vec<Permission>,
+    },
+    /// Overwrites of categories and channels must not allow any of
+    /// `permissions` to roles matching `roles` (globs), except `except`.
+    ForbidOverwriteAllows {
+        roles: Vec<String>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        except: Vec<String>,
+        permissions: Vec<Permission>,
+    },
+    /// Every channel of the categories matching `category` (glob) must deny
+    /// all of `permissions` to `role`.
+    RequireChannelDenies {
+        category: String,
+        role: String,
+        permissions: Vec<Permission>,
+    },
+}
+
+impl From<PolicySeverityParams> for PolicySeverity {
+    fn from(severity: PolicySeverityParams) -> Self {
+        match severity {
+            PolicySeverityParams::Error => PolicySeverity::Error,
+            PolicySeverityParams::Warning => PolicySeverity::Warning,
+        }
+    }
+}
+
+impl From<PolicyRuleParams> for Arc<dyn PolicyRule> {
+    fn from(params: PolicyRuleParams) -> Self {
+        let id = params.id;
+        let severity = params.severity.into();
+
+        match params.check {
+            PolicyCheckParams::ForbidRolePermissions {
+                roles,
+                except,
+                permissions,
+            } => Arc::from(ForbiddenRolePermissionsRule {
+                id,
+                severity,
+                roles,
+                except,
+                permissions: PermissionsList::from(permissions),
+            }),
+            PolicyCheckParams::ForbidOverwriteAllows {
+                roles,
+                except,
+                permissions,
+            } => Arc::from(ForbiddenOverwriteAllowsRule {
+                id,
+                severity,
+                roles,
+                except,
+                permissions: PermissionsList::from(permissions),
+            }),
+            PolicyCheckParams::RequireChannelDenies {
+                category,
+                role,
+                permissions,
+            } => Arc::from(RequiredChannelDeniesRule {
+                id,
+                severity,
+                category,
+                role,
+                permissions: PermissionsList::from(permissions),
+            }),
+        }
+    }
+}
+
+impl From<PolicyParams> for Policy {
+    fn from(params: PolicyParams) -> Self {
+        let builtin_rules = match params.builtin_rules {
+            true => Policy::builtin_rules(),
+            false => Vec::new(),
+        };
+
+        Self {
+            rules: builtin_rules
+                .into_iter()
+                .chain(params.rules.into_iter().map(Into::into))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{permission::Permission, policy::Policy};
+
+    use super::{PolicyCheckParams, PolicyParams, PolicyRuleParams, PolicySeverityParams};
+
+    #[test]
+    fn can_deserialize_policy() {
+        let yaml = r#"
+rules:
+  - id: staff-private
+    severity: WARNING
+    require_channel_denies:
+      category: Staff
+      role: "@everyone"
+      permissions: [VIEW_CHANNEL]
+"#;
+
+        let params: PolicyParams = serde_yaml::from_str(yaml).unwrap();
+
+        assert_eq!(
+            params,
+            PolicyParams {
+                builtin_rules: true,
+                rules: vec![PolicyRuleParams {
+                    id: "staff-private".to_string(),
+                    severity: PolicySeverityParams::Warning,
+                    check: PolicyCheckParams::RequireChannelDenies {
+                        category: "Staff".to_string(),
+                        role: "@everyone".to_string(),
+                        permissions: vec![Permission::VIEW_CHANNEL],
+                    },
+                }],
+            }
+        );
+    }
+
+    #[test]
+    fn given_builtin_rules_disabled_only_user_rules_are_kept() {
+        let params = PolicyParams {
+            builtin_rules: false,
+            rules: vec![PolicyRuleParams {
+                id: "admin-only".to_string(),
+                severity: PolicySeverityParams::Error,
+                check: PolicyCheckParams::ForbidRolePermissions {
+                    roles: vec!["*".to_string()],
+                    except: vec!["Admin".to_string()],
+                    permissions: vec![Permission::ADMINISTRATOR],
+                },
+            }],
+        };
+
+        let policy: Policy = params.into();
+
+        assert_eq!(policy.rules.len(), 1);
+        assert_eq!(policy.rules[0].id(), "admin-only");
+    }
+}
@@ -1,18 +1,18 @@
 use std::sync::Arc;
 
 use crate::{
-    permission::PermissionsList,
+    api::params::permission::PermissionsPresets,
     role::{AwaitingRole, AwaitingRolesList, ExtraRolesStrategy, KeepExtraRoles, RemoveExtraRoles},
 };
 
 use super::{RoleParams, RoleParamsExtraItemsStrategy, RolesParamsList};
 
 impl RolesParamsList {
-    pub fn into(self) -> AwaitingRolesList {
+    pub fn into(self, presets: &PermissionsPresets) -> AwaitingRolesList {
         let items = self
             .items
             .into_iter()
-            .map(|role| role.into())
+            .map(|role| role.into(presets))
             .collect::<Vec<AwaitingRole>>()
             .into();
 
@@ -32,11 +32,11 @@ impl Into<Arc<dyn ExtraRolesStrategy>> for RoleParamsExtraItemsStrategy {
     }
 }
 
-impl Into<AwaitingRole> for RoleParams {
-    fn into(self) -> AwaitingRole {
+impl RoleParams {
+    pub fn into(self, presets: &PermissionsPresets) -> AwaitingRole {
         AwaitingRole {
             name: self.name,
-            permissions: PermissionsList::from(self.permissions),
+            permissions: presets.expand(self.permissions),
             color: self.color.map(|color| color.to_lowercase()),
             is_mentionable: self.is_mentionable,
             show_in_sidebar: self.show_in_sidebar,
@@ -49,7 +49,10 @@ mod tests {
     use std::sync::Arc;
 
     use crate::{
-        api::params::role::{RoleParams, RoleParamsExtraItemsStrategy, RolesParamsList},
+        api::params::{
+            permission::PermissionsPresets,
+            role::{RoleParams, RoleParamsExtraItemsStrategy, RolesParamsList},
+        },
         permission::{Permission, PermissionsList},
         role::{AwaitingRole, AwaitingRolesList, KeepExtraRoles, RolesList},
     };
@@ -60,7 +63,7 @@ mod tests {
             color: Some("826d5f".to_string()),
             is_mentionable: true,
             show_in_sidebar: false,
-            permissions: vec![Permission::ADMINISTRATOR],
+            permissions: vec![Permission::ADMINISTRATOR.into()],
         };
 
         let awaiting = AwaitingRole {
@@ -97,7 +100,7 @@ mod tests {
         let name = "Team10";
         let (params, expected_awaiting) = given_matching_params_and_awaiting(name);
 
-        let awaiting: AwaitingRole = params.into();
+        let awaiting: AwaitingRole = params.into(&PermissionsPresets::default());
 
         assert_eq!(awaiting, expected_awaiting);
     }
@@ -108,7 +111,7 @@ mod tests {
         let (params_list, expected_awaiting_list) =
             given_matching_params_list_and_awaiting_list(name);
 
-        let awaiting_list: AwaitingRolesList = params_list.into();
+        let awaiting_list: AwaitingRolesList = params_list.into(&PermissionsPresets::default());
 
         assert_eq!(awaiting_list, expected_awaiting_list);
     }
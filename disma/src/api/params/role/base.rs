@@ -1,16 +1,18 @@
 use serde::{Deserialize, Serialize};
 
-use crate::permission::Permission;
+use crate::api::params::permission::PermissionParams;
 
 #[derive(Serialize, Deserialize, Debug, PartialEq, Default, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct RolesParamsList {
-    #[serde(default = "Vec::default")]
+    #[serde(default)]
     pub items: Vec<RoleParams>,
     #[serde(default = "RoleParamsExtraItemsStrategy::default")]
     pub extra_items: RoleParamsExtraItemsStrategy,
 }
 
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(tag = "strategy", rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum RoleParamsExtraItemsStrategy {
     Keep,
@@ -18,11 +20,13 @@ pub enum RoleParamsExtraItemsStrategy {
 }
 
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct RoleParams {
     pub name: String,
-    #[serde(default = "Vec::default")]
-    pub permissions: Vec<Permission>,
+    #[serde(default)]
+    pub permissions: Vec<PermissionParams>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schema", schemars(regex(pattern = "^[0-9a-fA-F]{6}$")))]
     pub color: Option<String>,
     pub show_in_sidebar: bool,
     pub is_mentionable: bool,
@@ -58,7 +62,10 @@ mod tests {
         let expected_params_list = RolesParamsList {
             items: vec![RoleParams {
                 name: "role_1".to_string(),
-                permissions: vec![Permission::ADMINISTRATOR, Permission::SEND_MESSAGES],
+                permissions: vec![
+                    Permission::ADMINISTRATOR.into(),
+                    Permission::SEND_MESSAGES.into(),
+                ],
                 color: Some("29a1f4".to_string()),
                 show_in_sidebar: true,
                 is_mentionable: false,
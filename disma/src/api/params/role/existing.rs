@@ -1,4 +1,7 @@
-use crate::role::{ExistingRole, RolesList};
+use crate::{
+    api::params::permission::PermissionParams,
+    role::{ExistingRole, RolesList},
+};
 
 use super::{RoleParams, RolesParamsList};
 
@@ -17,7 +20,7 @@ impl From<&ExistingRole> for RoleParams {
     fn from(role: &ExistingRole) -> Self {
         Self {
             name: role.name.clone(),
-            permissions: role.permissions.to_list(),
+            permissions: PermissionParams::from_list(&role.permissions),
             color: role.color.clone(),
             show_in_sidebar: role.show_in_sidebar,
             is_mentionable: role.is_mentionable,
@@ -40,6 +43,7 @@ mod tests {
             color: Some("826d5f".to_string()),
             is_mentionable: true,
             show_in_sidebar: false,
+            is_managed: false,
             permissions: PermissionsList::from(vec![Permission::ADMINISTRATOR]),
         };
 
@@ -48,7 +52,7 @@ mod tests {
             color: Some("826d5f".to_string()),
             is_mentionable: true,
             show_in_sidebar: false,
-            permissions: vec![Permission::ADMINISTRATOR],
+            permissions: vec![Permission::ADMINISTRATOR.into()],
         };
 
         (existing, params)
@@ -0,0 +1,229 @@
+use std::sync::Arc;
+
+use crate::{
+    channel::{AwaitingChannel, ChannelType, ChannelsList},
+    scheduled_event::{
+        AwaitingScheduledEvent, AwaitingScheduledEventsList, ExtraScheduledEventsStrategy,
+        KeepExtraScheduledEvents, RemoveExtraScheduledEvents, ScheduledEventLocation,
+    },
+    utils::time::normalize_timestamp,
+};
+
+use super::{
+    ScheduledEventParams, ScheduledEventParamsExtraItemsStrategy, ScheduledEventParamsLocation,
+    ScheduledEventsParamsList,
+};
+
+impl ScheduledEventsParamsList {
+    pub fn into(
+        self,
+        channels: &ChannelsList<AwaitingChannel>,
+    ) -> Result<AwaitingScheduledEventsList, String> {
+        let items = self
+            .items
+            .into_iter()
+            .map(|event| event.into(channels))
+            .collect::<Result<Vec<AwaitingScheduledEvent>, String>>()?
+            .into();
+
+        Ok(AwaitingScheduledEventsList {
+            items,
+            extra_items_strategy: self.extra_items.into(),
+        })
+    }
+}
+
+impl From<ScheduledEventParamsExtraItemsStrategy> for Arc<dyn ExtraScheduledEventsStrategy> {
+    fn from(value: ScheduledEventParamsExtraItemsStrategy) -> Self {
+        match value {
+            ScheduledEventParamsExtraItemsStrategy::Keep => Arc::from(KeepExtraScheduledEvents {}),
+            ScheduledEventParamsExtraItemsStrategy::Remove => {
+                Arc::from(RemoveExtraScheduledEvents {})
+            }
+        }
+    }
+}
+
+impl ScheduledEventParams {
+    pub fn into(
+        self,
+        channels: &ChannelsList<AwaitingChannel>,
+    ) -> Result<AwaitingScheduledEvent, String> {
+        let invalid =
+            |error: String| format!("Cannot build scheduled event '{}': {error}", self.name);
+        let find_channel = |name: &str, channel_type: ChannelType| {
+            let channel = channels
+                .find_by_reference(name)
+                .map_err(invalid)?
+                .cloned()
+                .ok_or_else(|| {
+                    format!(
+                        "Cannot build scheduled event '{}' from non-existant channel '{name}'",
+                        self.name
+                    )
+                })?;
+            match channel.channel_type == channel_type {
+                true => Ok(channel),
+                false => Err(invalid(format!(
+                    "channel '{name}' is not a {channel_type} channel"
+                ))),
+            }
+        };
+
+        let location = match &self.location {
+            ScheduledEventParamsLocation::Voice { channel } => ScheduledEventLocation::Voice {
+                channel: find_channel(channel, ChannelType::VOICE)?,
+            },
+            ScheduledEventParamsLocation::Stage { channel } => ScheduledEventLocation::Stage {
+                channel: find_channel(channel, ChannelType::STAGE)?,
+            },
+            ScheduledEventParamsLocation::External { location } => {
+                if self.end_time.is_none() {
+                    return Err(invalid("EXTERNAL events require an end_time".to_string()));
+                }
+                ScheduledEventLocation::External {
+                    location: location.clone(),
+                }
+            }
+        };
+
+        Ok(AwaitingScheduledEvent {
+            name: self.name.clone(),
+            description: self.description,
+            location,
+            start_time: normalize_timestamp(&self.start_time).map_err(invalid)?,
+            end_time: self
+                .end_time
+                .as_deref()
+                .map(normalize_timestamp)
+                .transpose()
+                .map_err(invalid)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        api::params::scheduled_event::{ScheduledEventParams, ScheduledEventParamsLocation},
+        channel::{AwaitingChannel, ChannelType, ChannelsList},
+        scheduled_event::{AwaitingScheduledEvent, ScheduledEventLocation},
+        tests::fixtures::awaiting::AwaitingChannelFixture,
+    };
+
+    const A_CHANNEL_NAME: &str = "gaming";
+    const A_TEXT_CHANNEL_NAME: &str = "general";
+
+    fn given_params(channel_name: &str) -> ScheduledEventParams {
+        ScheduledEventParams {
+            name: "Game night".to_string(),
+            description: None,
+            location: ScheduledEventParamsLocation::Voice {
+                channel: channel_name.to_string(),
+            },
+            start_time: "2030-01-01T18:00:00Z".to_string(),
+            end_time: None,
+        }
+    }
+
+    fn given_channels() -> ChannelsList<AwaitingChannel> {
+        ChannelsList::from(vec![
+            AwaitingChannelFixture::new()
+                .with_name(A_CHANNEL_NAME)
+                .with_type(ChannelType::VOICE)
+                .build(),
+            AwaitingChannelFixture::new()
+                .with_name(A_TEXT_CHANNEL_NAME)
+                .build(),
+        ])
+    }
+
+    #[test]
+    fn can_convert_params_to_awaiting() {
+        let channels = given_channels();
+        let params = given_params(A_CHANNEL_NAME);
+        let expected_awaiting = AwaitingScheduledEvent {
+            name: "Game night".to_string(),
+            description: None,
+            location: ScheduledEventLocation::Voice {
+                channel: channels
+                    .find_by_reference(A_CHANNEL_NAME)
+                    .unwrap()
+                    .unwrap()
+                    .clone(),
+            },
+            start_time: "2030-01-01T18:00:00Z".to_string(),
+            end_time: None,
+        };
+
+        let awaiting = params.into(&channels);
+
+        assert_eq!(awaiting, Ok(expected_awaiting));
+    }
+
+    #[test]
+    fn given_non_existant_channel_it_fails() {
+        let channels = given_channels();
+        let params = given_params("non-existant");
+
+        let awaiting = params.into(&channels);
+
+        assert!(awaiting.is_err());
+    }
+
+    #[test]
+    fn given_channel_of_other_type_it_fails() {
+        let channels = given_channels();
+        let params = given_params(A_TEXT_CHANNEL_NAME);
+
+        let awaiting = params.into(&channels);
+
+        assert_eq!(
+            awaiting,
+            Err("Cannot build scheduled event 'Game night': channel 'general' is not a VOICE channel".to_string())
+        );
+    }
+
+    #[test]
+    fn it_normalizes_times_to_utc() {
+        let channels = given_channels();
+        let mut params = given_params(A_CHANNEL_NAME);
+        params.start_time = "2030-01-01T13:00:00-05:00".to_string();
+        params.end_time = Some("2030-01-01T20:00:00.000+00:00".to_string());
+
+        let awaiting = params.into(&channels).unwrap();
+
+        assert_eq!(awaiting.start_time, "2030-01-01T18:00:00Z");
+        assert_eq!(awaiting.end_time.as_deref(), Some("2030-01-01T20:00:00Z"));
+    }
+
+    #[test]
+    fn given_invalid_time_it_fails() {
+        let channels = given_channels();
+        let mut params = given_params(A_CHANNEL_NAME);
+        params.start_time = "tomorrow".to_string();
+
+        let awaiting = params.into(&channels);
+
+        assert!(awaiting.is_err());
+    }
+
+    #[test]
+    fn given_external_event_without_end_time_it_fails() {
+        let channels = given_channels();
+        let mut params = given_params(A_CHANNEL_NAME);
+        params.location = ScheduledEventParamsLocation::External {
+            location: "Park".to_string(),
+        };
+
+        let awaiting = params.into(&channels);
+
+        assert_eq!(
+            awaiting,
+            Err(
+                "Cannot build scheduled event 'Game night': EXTERNAL events require an end_time"
+                    .to_string()
+            )
+        );
+    }
+}
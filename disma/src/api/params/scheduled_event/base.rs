@@ -0,0 +1,107 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Default, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ScheduledEventsParamsList {
+    #[serde(default)]
+    pub items: Vec<ScheduledEventParams>,
+    #[serde(default = "ScheduledEventParamsExtraItemsStrategy::default")]
+    pub extra_items: ScheduledEventParamsExtraItemsStrategy,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(tag = "strategy", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ScheduledEventParamsExtraItemsStrategy {
+    #[default]
+    Keep,
+    Remove,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ScheduledEventParams {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub location: ScheduledEventParamsLocation,
+    pub start_time: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(tag = "type", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ScheduledEventParamsLocation {
+    Voice { channel: String },
+    Stage { channel: String },
+    External { location: String },
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::params::scheduled_event::{
+        ScheduledEventParams, ScheduledEventParamsExtraItemsStrategy, ScheduledEventParamsLocation,
+        ScheduledEventsParamsList,
+    };
+
+    #[test]
+    fn it_parses_params_list() {
+        let yaml_params_list = r"
+            items:
+            - name: Game night
+              description: Weekly game night
+              location:
+                type: VOICE
+                channel: gaming
+              start_time: 2030-01-01T18:00:00Z
+              end_time: 2030-01-01T20:00:00Z
+            - name: Meetup
+              location:
+                type: EXTERNAL
+                location: Montreal
+              start_time: 2030-01-02T18:00:00Z
+            extra_items:
+              strategy: REMOVE
+        ";
+        let expected_params_list = ScheduledEventsParamsList {
+            items: vec![
+                ScheduledEventParams {
+                    name: "Game night".to_string(),
+                    description: Some("Weekly game night".to_string()),
+                    location: ScheduledEventParamsLocation::Voice {
+                        channel: "gaming".to_string(),
+                    },
+                    start_time: "2030-01-01T18:00:00Z".to_string(),
+                    end_time: Some("2030-01-01T20:00:00Z".to_string()),
+                },
+                ScheduledEventParams {
+                    name: "Meetup".to_string(),
+                    description: None,
+                    location: ScheduledEventParamsLocation::External {
+                        location: "Montreal".to_string(),
+                    },
+                    start_time: "2030-01-02T18:00:00Z".to_string(),
+                    end_time: None,
+                },
+            ],
+            extra_items: ScheduledEventParamsExtraItemsStrategy::Remove,
+        };
+
+        let params_list: ScheduledEventsParamsList =
+            serde_yaml::from_str(yaml_params_list).unwrap();
+
+        assert_eq!(params_list, expected_params_list);
+    }
+
+    #[test]
+    fn it_parses_empty_params_list_to_defaults() {
+        let yaml_params_list = r"";
+
+        let params_list: ScheduledEventsParamsList =
+            serde_yaml::from_str(yaml_params_list).unwrap();
+
+        assert_eq!(params_list, ScheduledEventsParamsList::default());
+    }
+}
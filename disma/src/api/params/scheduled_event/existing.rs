@@ -0,0 +1,79 @@
+use crate::{
+    channel::Channel,
+    scheduled_event::{ExistingScheduledEvent, ScheduledEventLocation, ScheduledEventsList},
+};
+
+use super::{ScheduledEventParams, ScheduledEventParamsLocation, ScheduledEventsParamsList};
+
+impl From<&ScheduledEventsList<ExistingScheduledEvent>> for ScheduledEventsParamsList {
+    fn from(events: &ScheduledEventsList<ExistingScheduledEvent>) -> Self {
+        let mut items: Vec<ScheduledEventParams> =
+            events.to_list().into_iter().map(Into::into).collect();
+        items.sort_by(|a, b| a.name.cmp(&b.name));
+
+        ScheduledEventsParamsList {
+            items,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<&ExistingScheduledEvent> for ScheduledEventParams {
+    fn from(event: &ExistingScheduledEvent) -> Self {
+        let location = match &event.location {
+            ScheduledEventLocation::Voice { channel } => ScheduledEventParamsLocation::Voice {
+                channel: channel.unique_name().typed_reference(),
+            },
+            ScheduledEventLocation::Stage { channel } => ScheduledEventParamsLocation::Stage {
+                channel: channel.unique_name().typed_reference(),
+            },
+            ScheduledEventLocation::External { location } => {
+                ScheduledEventParamsLocation::External {
+                    location: location.clone(),
+                }
+            }
+        };
+
+        Self {
+            name: event.name.clone(),
+            description: event.description.clone(),
+            location,
+            start_time: event.start_time.clone(),
+            end_time: event.end_time.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        api::params::scheduled_event::{ScheduledEventParams, ScheduledEventParamsLocation},
+        scheduled_event::ScheduledEventLocation,
+        tests::fixtures::existing::{ExistingChannelFixture, ExistingScheduledEventFixture},
+    };
+
+    const AN_EVENT_NAME: &str = "Game night";
+
+    #[test]
+    fn can_convert_existing_to_params() {
+        let existing = ExistingScheduledEventFixture::new()
+            .with_name(AN_EVENT_NAME)
+            .with_location(ScheduledEventLocation::Voice {
+                channel: ExistingChannelFixture::new().with_name("gaming").build(),
+            })
+            .build();
+        let expected_params = ScheduledEventParams {
+            name: AN_EVENT_NAME.to_string(),
+            description: None,
+            location: ScheduledEventParamsLocation::Voice {
+                channel: "gaming (TEXT)".to_string(),
+            },
+            start_time: "2030-01-01T18:00:00Z".to_string(),
+            end_time: Some("2030-01-01T20:00:00Z".to_string()),
+        };
+
+        let params = ScheduledEventParams::from(&existing);
+
+        assert_eq!(params, expected_params);
+    }
+}
@@ -0,0 +1,6 @@
+mod base;
+pub use base::*;
+
+mod awaiting;
+
+mod existing;
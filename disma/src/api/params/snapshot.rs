@@ -0,0 +1,601 @@
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+    sync::Arc,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    category::{
+        AwaitingCategoriesList, AwaitingCategory, CategoriesList, ExistingCategory,
+        KeepExtraCategories,
+    },
+    channel::{
+        AwaitingChannel, AwaitingChannelsList, Channel, ChannelType, ExistingChannel,
+        KeepExtraChannels,
+    },
+    guild::{AwaitingGuild, AwaitingIds, ExistingGuild},
+    onboarding::ExistingOnboarding,
+    permission::{PermissionsList, PermissionsOverwrite, PermissionsOverwritesList},
+    role::{AwaitingRole, AwaitingRolesList, ExistingRole, KeepExtraRoles, RolesList},
+    welcome_screen::ExistingWelcomeScreen,
+};
+
+use super::{
+    automod::AutoModRulesParamsList, onboarding::OnboardingParams,
+    scheduled_event::ScheduledEventsParamsList, welcome_screen::WelcomeScreenParams,
+};
+
+/// Version of the snapshot format, to be increased on breaking changes.
+pub const GUILD_SNAPSHOT_VERSION: u32 = 1;
+
+/// Full structure of an existing guild at a given time. Unlike `GuildParams`,
+/// it keeps the ids of the entities and their raw permission codes, unknown
+/// bits included.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct GuildSnapshot {
+    pub version: u32,
+    pub guild_id: String,
+    /// Seconds since the Unix epoch.
+    pub created_at: u64,
+    pub roles: Vec<RoleSnapshot>,
+    pub categories: Vec<CategorySnapshot>,
+    pub channels: Vec<ChannelSnapshot>,
+    pub automod: AutoModRulesParamsList,
+    pub scheduled_events: ScheduledEventsParamsList,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub onboarding: Option<OnboardingParams>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub welcome_screen: Option<WelcomeScreenParams>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct RoleSnapshot {
+    pub id: String,
+    pub name: String,
+    pub permissions: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    pub is_mentionable: bool,
+    pub show_in_sidebar: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct PermissionsOverwriteSnapshot {
+    pub role_id: String,
+    pub role: String,
+    pub allow: String,
+    pub deny: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct CategorySnapshot {
+    pub id: String,
+    pub name: String,
+    pub permissions_overwrites: Vec<PermissionsOverwriteSnapshot>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct ChannelSnapshot {
+    pub id: String,
+    pub name: String,
+    #[serde(rename = "type")]
+    pub _type: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub topic: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+    pub permissions_overwrites: Vec<PermissionsOverwriteSnapshot>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub threads: Vec<ThreadSnapshot>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct ThreadSnapshot {
+    pub id: String,
+    pub name: String,
+    #[serde(rename = "type")]
+    pub _type: String,
+    pub auto_archive_duration: u32,
+    pub locked: bool,
+    pub invitable: bool,
+    #[serde(default)]
+    pub archived: bool,
+}
+
+impl GuildSnapshot {
+    pub fn new(guild_id: &str, created_at: u64, guild: &ExistingGuild) -> Self {
+        let mut roles: Vec<RoleSnapshot> = guild
+            .roles()
+            .to_list()
+            .into_iter()
+            .map(RoleSnapshot::from)
+            .collect();
+        let mut categories: Vec<CategorySnapshot> = guild
+            .categories()
+            .to_list()
+            .into_iter()
+            .map(CategorySnapshot::from)
+            .collect();
+        let mut channels: Vec<ChannelSnapshot> = guild
+            .channels()
+            .to_list()
+            .into_iter()
+            .map(ChannelSnapshot::from)
+            .collect();
+
+        // Sorting for successive snapshots to be easily compared
+        roles.sort_by(|a, b| a.name.cmp(&b.name));
+        categories.sort_by(|a, b| a.name.cmp(&b.name));
+        channels.sort_by(|a, b| (&a.category, &a.name).cmp(&(&b.category, &b.name)));
+
+        Self {
+            version: GUILD_SNAPSHOT_VERSION,
+            guild_id: guild_id.to_string(),
+            created_at,
+            roles,
+            categories,
+            channels,
+            automod: AutoModRulesParamsList::from(guild.automod_rules()),
+            scheduled_events: ScheduledEventsParamsList::from(guild.scheduled_events()),
+            onboarding: (guild.onboarding() != &ExistingOnboarding::default())
+                .then(|| OnboardingParams::from(guild.onboarding())),
+            welcome_screen: (guild.welcome_screen() != &ExistingWelcomeScreen::default())
+                .then(|| WelcomeScreenParams::from(guild.welcome_screen())),
+        }
+    }
+}
+
+/// The guild as it was when the snapshot was taken. Roles, categories and
+/// channels are matched to existing ones by id, for renamed ones to be
+/// reverted, and by name when their id no longer exists. Entities created
+/// since are kept, and threads are left untouched, as Discord archives them
+/// over time. Fails on an invalid snapshot, ex: edited by hand.
+impl TryFrom<GuildSnapshot> for AwaitingGuild {
+    type Error = String;
+
+    fn try_from(snapshot: GuildSnapshot) -> Result<Self, Self::Error> {
+        check_unique_names("roles", snapshot.roles.iter().map(|role| role.name.clone()))?;
+        check_unique_names(
+            "categories",
+            snapshot
+                .categories
+                .iter()
+                .map(|category| category.name.clone()),
+        )?;
+
+        let role_names_by_id: HashMap<String, String> = snapshot
+            .roles
+            .iter()
+            .map(|role| (role.id.clone(), role.name.clone()))
+            .collect();
+        let mut ids = AwaitingIds {
+            roles: snapshot
+                .roles
+                .iter()
+                .map(|role| (role.name.clone(), role.id.clone()))
+                .collect(),
+            categories: snapshot
+                .categories
+                .iter()
+                .map(|category| (category.name.clone(), category.id.clone()))
+                .collect(),
+            channels: HashMap::new(),
+        };
+
+        let roles = AwaitingRolesList {
+            items: snapshot
+                .roles
+                .into_iter()
+                .map(AwaitingRole::from)
+                .collect::<Vec<AwaitingRole>>()
+                .into(),
+            extra_items_strategy: Arc::from(KeepExtraRoles {}),
+        };
+        let categories = AwaitingCategoriesList {
+            items: snapshot
+                .categories
+                .into_iter()
+                .map(|category| category.into(&roles.items, &role_names_by_id))
+                .collect::<Result<Vec<AwaitingCategory>, String>>()?
+                .into(),
+            extra_items_strategy: Arc::from(KeepExtraCategories {}),
+        };
+
+        let mut channels_items: Vec<AwaitingChannel> = Vec::new();
+        for channel in snapshot.channels.into_iter() {
+            let id = channel.id.clone();
+            let channel = channel.into(&roles.items, &categories.items, &role_names_by_id)?;
+            let unique_name = channel.unique_name().to_string();
+            if ids.channels.insert(unique_name.clone(), id).is_some() {
+                return Err(format!(
+                    "Invalid snapshot: several channels are named '{unique_name}'."
+                ));
+            }
+            channels_items.push(channel);
+        }
+        let channels = AwaitingChannelsList {
+            items: channels_items.into(),
+            extra_items_strategy: Arc::from(KeepExtraChannels {}),
+            categories: categories.items.clone(),
+        };
+
+        // Channels are referenced by their full `category:name (TYPE)`, which is unique
+        let invalid = |error: String| format!("Invalid snapshot: {error}");
+        let automod = snapshot
+            .automod
+            .into(&roles.items, &channels.items)
+            .map_err(invalid)?;
+        let scheduled_events = snapshot
+            .scheduled_events
+            .into(&channels.items)
+            .map_err(invalid)?;
+        let onboarding = snapshot
+            .onboarding
+            .map(|onboarding| onboarding.into(&roles.items, &channels.items))
+            .transpose()
+            .map_err(invalid)?;
+        let welcome_screen = snapshot
+            .welcome_screen
+            .map(|welcome_screen| welcome_screen.into(&channels.items))
+            .transpose()
+            .map_err(invalid)?;
+
+        Ok(Self {
+            roles,
+            categories,
+            channels,
+            automod,
+            scheduled_events,
+            members: None,
+            onboarding,
+            welcome_screen,
+            ids: Arc::from(ids),
+        })
+    }
+}
+
+fn check_unique_names(entities: &str, names: impl Iterator<Item = String>) -> Result<(), String> {
+    let mut seen: HashSet<String> = HashSet::new();
+    for name in names {
+        if !seen.insert(name.clone()) {
+            return Err(format!(
+                "Invalid snapshot: several {entities} are named '{name}'."
+            ));
+        }
+    }
+    Ok(())
+}
+
+impl From<&ExistingRole> for RoleSnapshot {
+    fn from(role: &ExistingRole) -> Self {
+        Self {
+            id: role.id.clone(),
+            name: role.name.clone(),
+            permissions: role.permissions.code(),
+            color: role.color.clone(),
+            is_mentionable: role.is_mentionable,
+            show_in_sidebar: role.show_in_sidebar,
+        }
+    }
+}
+
+impl From<RoleSnapshot> for AwaitingRole {
+    fn from(role: RoleSnapshot) -> Self {
+        Self {
+            name: role.name,
+            permissions: PermissionsList::from(role.permissions.as_str()),
+            color: role.color,
+            is_mentionable: role.is_mentionable,
+            show_in_sidebar: role.show_in_sidebar,
+        }
+    }
+}
+
+impl From<&PermissionsOverwrite<ExistingRole>> for PermissionsOverwriteSnapshot {
+    fn from(overwrite: &PermissionsOverwrite<ExistingRole>) -> Self {
+        Self {
+            role_id: overwrite.role.id.clone(),
+            role: overwrite.role.name.clone(),
+            allow: overwrite.allow.code(),
+            deny: overwrite.deny.code(),
+        }
+    }
+}
+
+fn overwrites_snapshot(
+    overwrites: &PermissionsOverwritesList<ExistingRole>,
+) -> Vec<PermissionsOverwriteSnapshot> {
+    overwrites
+        .to_list()
+        .iter()
+        .map(PermissionsOverwriteSnapshot::from)
+        .collect()
+}
+
+/// Overwrites of the snapshot roles, found by id, or by name for snapshots
+/// edited by hand.
+fn awaiting_overwrites(
+    overwrites: Vec<PermissionsOverwriteSnapshot>,
+    roles: &RolesList<AwaitingRole>,
+    role_names_by_id: &HashMap<String, String>,
+) -> Result<PermissionsOverwritesList<AwaitingRole>, String> {
+    overwrites
+        .into_iter()
+        .map(|overwrite| {
+            let role_name = role_names_by_id
+                .get(&overwrite.role_id)
+                .unwrap_or(&overwrite.role);
+            let role = roles.find_by_name(role_name).ok_or_else(|| {
+                format!(
+                    "Invalid snapshot: permissions overwrite of non-existant role '{}'.",
+                    overwrite.role
+                )
+            })?;
+
+            Ok(PermissionsOverwrite {
+                role: role.clone(),
+                allow: PermissionsList::from(overwrite.allow.as_str()),
+                deny: PermissionsList::from(overwrite.deny.as_str()),
+            })
+        })
+        .collect::<Result<Vec<PermissionsOverwrite<AwaitingRole>>, String>>()
+        .map(PermissionsOverwritesList::from)
+}
+
+impl From<&ExistingCategory> for CategorySnapshot {
+    fn from(category: &ExistingCategory) -> Self {
+        Self {
+            id: category.id.clone(),
+            name: category.name.clone(),
+            permissions_overwrites: overwrites_snapshot(&category.overwrites),
+        }
+    }
+}
+
+impl CategorySnapshot {
+    pub fn into(
+        self,
+        roles: &RolesList<AwaitingRole>,
+        role_names_by_id: &HashMap<String, String>,
+    ) -> Result<AwaitingCategory, String> {
+        Ok(AwaitingCategory {
+            name: self.name,
+            overwrites: awaiting_overwrites(self.permissions_overwrites, roles, role_names_by_id)?,
+            extra_channels_strategy: Arc::from(KeepExtraChannels {}),
+        })
+    }
+}
+
+impl From<&ExistingChannel> for ChannelSnapshot {
+    fn from(channel: &ExistingChannel) -> Self {
+        Self {
+            id: channel.id.clone(),
+            name: channel.name.clone(),
+            _type: channel.channel_type.to_string(),
+            topic: channel.topic.clone(),
+            category: channel.category_name().map(str::to_string),
+            permissions_overwrites: overwrites_snapshot(&channel.overwrites),
+            threads: channel
+                .threads
+                .iter()
+                .map(|thread| ThreadSnapshot {
+                    id: thread.id.clone(),
+                    name: thread.name.clone(),
+                    _type: thread.thread_type.to_string(),
+                    auto_archive_duration: thread.auto_archive_duration,
+                    locked: thread.locked,
+                    invitable: thread.invitable,
+                    archived: thread.archived,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl ChannelSnapshot {
+    pub fn into(
+        self,
+        roles: &RolesList<AwaitingRole>,
+        categories: &CategoriesList<AwaitingCategory>,
+        role_names_by_id: &HashMap<String, String>,
+    ) -> Result<AwaitingChannel, String> {
+        let category = self
+            .category
+            .map(|name| {
+                categories.find_by_name(&name).cloned().ok_or_else(|| {
+                    format!(
+                        "Invalid snapshot: channel '{}' is in non-existant category '{name}'.",
+                        self.name
+                    )
+                })
+            })
+            .transpose()?;
+        let channel_type = ChannelType::from_str(&self._type).map_err(|_| {
+            format!(
+                "Invalid snapshot: channel '{}' has unknown type '{}'.",
+                self.name, self._type
+            )
+        })?;
+
+        Ok(AwaitingChannel {
+            overwrites: awaiting_overwrites(self.permissions_overwrites, roles, role_names_by_id)?,
+            name: self.name,
+            topic: self.topic,
+            channel_type,
+            category,
+            threads: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        core::{
+            changes::{
+                category::CategoryChangesService,
+                channel::ChannelChangesService,
+                role::{RoleChange, RoleChangesService},
+            },
+            diffs::Diff,
+        },
+        guild::{AwaitingGuild, ExistingGuild},
+        permission::{Permission, PermissionsList, PermissionsOverwrite},
+        tests::fixtures::existing::{
+            ExistingCategoryFixture, ExistingChannelFixture, ExistingGuildFixture,
+            ExistingRoleFixture,
+        },
+    };
+
+    use super::GuildSnapshot;
+
+    fn unknown_bit_code() -> String {
+        PermissionsList::from(vec![Permission::VIEW_CHANNEL])
+            .with_bit(47)
+            .code()
+    }
+
+    fn an_existing_guild() -> ExistingGuild {
+        let role = ExistingRoleFixture::new()
+            .with_name("Member")
+            .with_permissions(PermissionsList::from(unknown_bit_code().as_str()))
+            .build();
+        let overwrite = PermissionsOverwrite {
+            role: role.clone(),
+            allow: PermissionsList::from(unknown_bit_code().as_str()),
+            deny: PermissionsList::new(),
+        };
+        let category = ExistingCategoryFixture::new()
+            .with_name("Staff")
+            .with_permissions_overwrites(vec![overwrite.clone()])
+            .build();
+        let channel = ExistingChannelFixture::new()
+            .with_name("mods")
+            .with_category(&category)
+            .with_permissions_overwrites(vec![overwrite])
+            .build();
+
+        ExistingGuildFixture::new()
+            .with_role(role)
+            .with_category(category)
+            .with_channel(channel)
+            .build()
+    }
+
+    #[test]
+    fn it_keeps_ids_and_raw_permission_codes() {
+        let guild = an_existing_guild();
+
+        let snapshot = GuildSnapshot::new("abc", 1700000000, &guild);
+
+        let role = guild.roles().find_by_name("Member").unwrap();
+        assert_eq!(snapshot.roles[0].id, role.id);
+        assert_eq!(snapshot.roles[0].permissions, unknown_bit_code());
+        assert_eq!(
+            snapshot.channels[0].permissions_overwrites[0].role_id,
+            role.id
+        );
+        assert_eq!(
+            snapshot.channels[0].permissions_overwrites[0].allow,
+            unknown_bit_code()
+        );
+    }
+
+    #[test]
+    fn given_same_guild_it_lists_no_change() {
+        let guild = an_existing_guild();
+        let snapshot = GuildSnapshot::new("abc", 1700000000, &guild);
+
+        let awaiting_guild = AwaitingGuild::try_from(snapshot).unwrap();
+
+        let role = awaiting_guild.roles.items.find_by_name("Member").unwrap();
+        assert_eq!(role.permissions.code(), unknown_bit_code());
+        assert!(RoleChangesService {}
+            .list_changes(&guild, &awaiting_guild)
+            .is_empty());
+        assert!(CategoryChangesService {}
+            .list_changes(&guild, &awaiting_guild)
+            .is_empty());
+        assert!(ChannelChangesService {}
+            .list_changes(&guild, &awaiting_guild)
+            .is_empty());
+    }
+
+    #[test]
+    fn can_be_read_back() {
+        let snapshot = GuildSnapshot::new("abc", 1700000000, &an_existing_guild());
+
+        let json = serde_json::to_string(&snapshot).unwrap();
+        let read_snapshot: GuildSnapshot = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(read_snapshot, snapshot);
+    }
+
+    #[test]
+    fn given_renamed_role_it_reverts_it_instead_of_creating_it() {
+        let guild = an_existing_guild();
+        let snapshot = GuildSnapshot::new("abc", 1700000000, &guild);
+        let mut renamed = guild.roles().find_by_name("Member").unwrap().clone();
+        renamed.name = "Raided".to_string();
+        let imposter = ExistingRoleFixture::new().with_name("Member").build();
+        let raided_guild = ExistingGuildFixture::new()
+            .with_role(renamed.clone())
+            .with_role(imposter)
+            .build();
+
+        let awaiting_guild = AwaitingGuild::try_from(snapshot).unwrap();
+        let changes = RoleChangesService {}.list_changes(&raided_guild, &awaiting_guild);
+
+        assert_eq!(changes.len(), 1);
+        let RoleChange::Update(existing, awaiting, diffs) = &changes[0] else {
+            panic!("Expected an update, got {:?}", changes[0]);
+        };
+        assert_eq!(existing, &renamed);
+        assert_eq!(awaiting.name, "Member");
+        assert!(diffs
+            .iter()
+            .any(|diff| matches!(diff, Diff::Update(field, _) if field == "name")));
+    }
+
+    #[test]
+    fn given_deleted_role_it_matches_the_role_of_same_name() {
+        let guild = an_existing_guild();
+        let snapshot = GuildSnapshot::new("abc", 1700000000, &guild);
+        let mut recreated = guild.roles().find_by_name("Member").unwrap().clone();
+        recreated.id = "recreated".to_string();
+        let raided_guild = ExistingGuildFixture::new().with_role(recreated).build();
+
+        let awaiting_guild = AwaitingGuild::try_from(snapshot).unwrap();
+
+        assert!(RoleChangesService {}
+            .list_changes(&raided_guild, &awaiting_guild)
+            .is_empty());
+    }
+
+    #[test]
+    fn given_duplicate_role_names_it_fails() {
+        let mut snapshot = GuildSnapshot::new("abc", 1700000000, &an_existing_guild());
+        let mut duplicate = snapshot.roles[0].clone();
+        duplicate.id = "duplicate".to_string();
+        snapshot.roles.push(duplicate);
+
+        let result = AwaitingGuild::try_from(snapshot);
+
+        assert_eq!(
+            result.unwrap_err(),
+            "Invalid snapshot: several roles are named 'Member'."
+        );
+    }
+
+    #[test]
+    fn given_overwrite_of_unknown_role_it_fails() {
+        let mut snapshot = GuildSnapshot::new("abc", 1700000000, &an_existing_guild());
+        snapshot.roles.clear();
+
+        let result = AwaitingGuild::try_from(snapshot);
+
+        assert!(result.unwrap_err().contains("non-existant role 'Member'"));
+    }
+}
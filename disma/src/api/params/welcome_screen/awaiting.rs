@@ -0,0 +1,89 @@
+use crate::{
+    channel::{AwaitingChannel, ChannelsList},
+    welcome_screen::{AwaitingWelcomeScreen, WelcomeScreenChannel},
+};
+
+use super::WelcomeScreenParams;
+
+impl WelcomeScreenParams {
+    pub fn into(
+        self,
+        channels: &ChannelsList<AwaitingChannel>,
+    ) -> Result<AwaitingWelcomeScreen, String> {
+        let welcome_channels = self
+            .channels
+            .into_iter()
+            .map(|params| {
+                let channel = channels
+                    .find_by_reference(&params.channel)
+                    .map_err(|error| format!("Cannot build welcome screen: {error}"))?
+                    .cloned()
+                    .ok_or_else(|| {
+                        format!(
+                            "Cannot build welcome screen from non-existant channel '{}'",
+                            params.channel
+                        )
+                    })?;
+
+                Ok(WelcomeScreenChannel {
+                    channel,
+                    description: params.description,
+                    emoji: params.emoji,
+                })
+            })
+            .collect::<Result<_, String>>()?;
+
+        Ok(AwaitingWelcomeScreen {
+            enabled: self.enabled,
+            description: self.description,
+            channels: welcome_channels,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        api::params::welcome_screen::{WelcomeScreenChannelParams, WelcomeScreenParams},
+        channel::ChannelsList,
+        tests::fixtures::awaiting::AwaitingChannelFixture,
+        welcome_screen::{AwaitingWelcomeScreen, WelcomeScreenChannel},
+    };
+
+    fn given_params(channel_name: &str) -> WelcomeScreenParams {
+        WelcomeScreenParams {
+            enabled: true,
+            description: None,
+            channels: vec![WelcomeScreenChannelParams {
+                channel: channel_name.to_string(),
+                description: "Read the rules".to_string(),
+                emoji: None,
+            }],
+        }
+    }
+
+    #[test]
+    fn can_convert_params_to_awaiting() {
+        let channel = AwaitingChannelFixture::new().with_name("rules").build();
+        let expected_awaiting = AwaitingWelcomeScreen {
+            enabled: true,
+            description: None,
+            channels: vec![WelcomeScreenChannel {
+                channel: channel.clone(),
+                description: "Read the rules".to_string(),
+                emoji: None,
+            }],
+        };
+
+        let awaiting = given_params("rules").into(&ChannelsList::from(vec![channel]));
+
+        assert_eq!(awaiting, Ok(expected_awaiting));
+    }
+
+    #[test]
+    fn given_non_existant_channel_it_fails() {
+        let awaiting = given_params("rules").into(&ChannelsList::new());
+
+        assert!(awaiting.is_err());
+    }
+}
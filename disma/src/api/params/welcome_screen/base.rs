@@ -0,0 +1,50 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct WelcomeScreenParams {
+    pub enabled: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub channels: Vec<WelcomeScreenChannelParams>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct WelcomeScreenChannelParams {
+    pub channel: String,
+    pub description: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emoji: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::params::welcome_screen::{WelcomeScreenChannelParams, WelcomeScreenParams};
+
+    #[test]
+    fn it_parses_params() {
+        let yaml_params = r"
+            enabled: true
+            description: Welcome to the server!
+            channels:
+            - channel: rules
+              description: Read the rules
+              emoji: 📜
+        ";
+        let expected_params = WelcomeScreenParams {
+            enabled: true,
+            description: Some("Welcome to the server!".to_string()),
+            channels: vec![WelcomeScreenChannelParams {
+                channel: "rules".to_string(),
+                description: "Read the rules".to_string(),
+                emoji: Some("📜".to_string()),
+            }],
+        };
+
+        let params: WelcomeScreenParams = serde_yaml::from_str(yaml_params).unwrap();
+
+        assert_eq!(params, expected_params);
+    }
+}
@@ -0,0 +1,56 @@
+use crate::{channel::Channel, welcome_screen::ExistingWelcomeScreen};
+
+use super::{WelcomeScreenChannelParams, WelcomeScreenParams};
+
+impl From<&ExistingWelcomeScreen> for WelcomeScreenParams {
+    fn from(welcome_screen: &ExistingWelcomeScreen) -> Self {
+        Self {
+            enabled: welcome_screen.enabled,
+            description: welcome_screen.description.clone(),
+            channels: welcome_screen
+                .channels
+                .iter()
+                .map(|welcome_channel| WelcomeScreenChannelParams {
+                    channel: welcome_channel.channel.unique_name().typed_reference(),
+                    description: welcome_channel.description.clone(),
+                    emoji: welcome_channel.emoji.clone(),
+                })
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        api::params::welcome_screen::{WelcomeScreenChannelParams, WelcomeScreenParams},
+        tests::fixtures::existing::ExistingChannelFixture,
+        welcome_screen::{ExistingWelcomeScreen, WelcomeScreenChannel},
+    };
+
+    #[test]
+    fn can_convert_existing_to_params() {
+        let existing = ExistingWelcomeScreen {
+            enabled: false,
+            description: Some("Hello".to_string()),
+            channels: vec![WelcomeScreenChannel {
+                channel: ExistingChannelFixture::new().with_name("rules").build(),
+                description: "Read the rules".to_string(),
+                emoji: Some("📜".to_string()),
+            }],
+        };
+        let expected_params = WelcomeScreenParams {
+            enabled: false,
+            description: Some("Hello".to_string()),
+            channels: vec![WelcomeScreenChannelParams {
+                channel: "rules (TEXT)".to_string(),
+                description: "Read the rules".to_string(),
+                emoji: Some("📜".to_string()),
+            }],
+        };
+
+        let params = WelcomeScreenParams::from(&existing);
+
+        assert_eq!(params, expected_params);
+    }
+}
@@ -1,5 +1,70 @@
+use std::collections::HashMap;
+
 pub struct ListComparison<T, U> {
     pub extra_self: Vec<T>,
     pub extra_other: Vec<U>,
     pub same: Vec<(T, U)>,
 }
+
+impl<'a, T, U> ListComparison<&'a T, &'a U> {
+    /// Pairs items with the other item of the id known for their key in
+    /// `ids`, ex: entities renamed since a snapshot, instead of the other
+    /// item of the same name. Items without a known id, or whose id no longer
+    /// exists, stay compared by name.
+    pub fn pair_by_id(
+        self,
+        ids: &HashMap<String, String>,
+        key_of: impl Fn(&T) -> String,
+        id_of: impl Fn(&U) -> &str,
+    ) -> Self {
+        if ids.is_empty() {
+            return self;
+        }
+
+        let ListComparison {
+            extra_self,
+            mut extra_other,
+            same,
+        } = self;
+        let take_other = |id: &str, extra_other: &mut Vec<&'a U>| {
+            extra_other
+                .iter()
+                .position(|other| id_of(other) == id)
+                .map(|position| extra_other.remove(position))
+        };
+
+        let mut paired_same: Vec<(&T, &U)> = Vec::new();
+        let mut unpaired: Vec<&U> = Vec::new();
+        for (self_item, other_item) in same.into_iter() {
+            let other_by_id = ids
+                .get(&key_of(self_item))
+                .filter(|&id| id != id_of(other_item))
+                .and_then(|id| take_other(id, &mut extra_other));
+            match other_by_id {
+                Some(other_by_id) => {
+                    paired_same.push((self_item, other_by_id));
+                    unpaired.push(other_item);
+                }
+                None => paired_same.push((self_item, other_item)),
+            }
+        }
+        extra_other.extend(unpaired);
+
+        let mut still_extra_self: Vec<&T> = Vec::new();
+        for self_item in extra_self.into_iter() {
+            match ids
+                .get(&key_of(self_item))
+                .and_then(|id| take_other(id, &mut extra_other))
+            {
+                Some(other_item) => paired_same.push((self_item, other_item)),
+                None => still_extra_self.push(self_item),
+            }
+        }
+
+        ListComparison {
+            extra_self: still_extra_self,
+            extra_other,
+            same: paired_same,
+        }
+    }
+}
@@ -0,0 +1,178 @@
+use crate::{
+    automod::{AutoModRule, AwaitingAutoModRule, ExistingAutoModRule},
+    core::{
+        changes::{Change, ChangeEntity},
+        diffs::{Diff, Differ},
+        ListComparison,
+    },
+    guild::{AwaitingGuild, ExistingGuild},
+};
+
+#[allow(clippy::large_enum_variant)]
+#[derive(PartialEq, Debug)]
+pub enum AutoModRuleChange {
+    Create(AwaitingAutoModRule),
+    Update(ExistingAutoModRule, AwaitingAutoModRule, Vec<Diff>),
+    Delete(ExistingAutoModRule),
+}
+
+impl From<AutoModRuleChange> for Change {
+    fn from(change: AutoModRuleChange) -> Self {
+        match change {
+            AutoModRuleChange::Create(awaiting) => {
+                Change::Create(ChangeEntity::AutoModRule, awaiting.name)
+            }
+            AutoModRuleChange::Update(existing, _, diffs) => {
+                Change::Update(ChangeEntity::AutoModRule, existing.name, diffs)
+            }
+            AutoModRuleChange::Delete(existing) => {
+                Change::Delete(ChangeEntity::AutoModRule, existing.name)
+            }
+        }
+    }
+}
+
+pub struct AutoModRuleChangesService {}
+
+impl AutoModRuleChangesService {
+    pub fn list_changes(
+        &self,
+        existing_guild: &ExistingGuild,
+        awaiting_guild: &AwaitingGuild,
+    ) -> Vec<AutoModRuleChange> {
+        let ListComparison {
+            extra_self: extra_awaiting,
+            extra_other: extra_existing,
+            same,
+        } = awaiting_guild
+            .automod
+            .items
+            .compare_by_name(existing_guild.automod_rules());
+
+        let mut to_create: Vec<AutoModRuleChange> = extra_awaiting
+            .into_iter()
+            .map(|awaiting| AutoModRuleChange::Create(awaiting.clone()))
+            .collect();
+
+        let mut to_update: Vec<AutoModRuleChange> = Vec::new();
+        let mut to_delete: Vec<AutoModRuleChange> = Vec::new();
+
+        for (awaiting, existing) in same.into_iter() {
+            // Discord does not allow changing the trigger type of an existing rule
+            if existing.trigger().trigger_type() != awaiting.trigger().trigger_type() {
+                to_delete.push(AutoModRuleChange::Delete(existing.clone()));
+                to_create.push(AutoModRuleChange::Create(awaiting.clone()));
+                continue;
+            }
+
+            let diffs = existing.diffs_with(awaiting);
+            if !diffs.is_empty() {
+                to_update.push(AutoModRuleChange::Update(
+                    existing.clone(),
+                    awaiting.clone(),
+                    diffs,
+                ));
+            }
+        }
+
+        for existing in extra_existing.into_iter() {
+            awaiting_guild
+                .automod
+                .extra_items_strategy
+                .handle_extra_automod_rule(existing, &mut to_delete);
+        }
+
+        to_delete
+            .into_iter()
+            .chain(to_create)
+            .chain(to_update)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        automod::AutoModTrigger,
+        core::diffs::Diff,
+        tests::fixtures::{
+            awaiting::{AwaitingAutoModRuleFixture, AwaitingGuildFixture},
+            existing::{ExistingAutoModRuleFixture, ExistingGuildFixture},
+        },
+    };
+
+    use super::{AutoModRuleChange, AutoModRuleChangesService};
+
+    const A_RULE_NAME: &str = "rule";
+
+    #[test]
+    fn when_rule_is_missing_it_should_create_it() {
+        let awaiting_rule = AwaitingAutoModRuleFixture::new().build();
+        let awaiting_guild = AwaitingGuildFixture::new()
+            .with_automod_rule(awaiting_rule.clone())
+            .build();
+        let existing_guild = ExistingGuildFixture::new().build();
+
+        let changes = AutoModRuleChangesService {}.list_changes(&existing_guild, &awaiting_guild);
+
+        assert_eq!(changes, vec![AutoModRuleChange::Create(awaiting_rule)]);
+    }
+
+    #[test]
+    fn when_rule_differs_it_should_update_it() {
+        let existing_rule = ExistingAutoModRuleFixture::new()
+            .with_name(A_RULE_NAME)
+            .build();
+        let awaiting_rule = AwaitingAutoModRuleFixture::new()
+            .with_name(A_RULE_NAME)
+            .disabled()
+            .build();
+        let awaiting_guild = AwaitingGuildFixture::new()
+            .with_automod_rule(awaiting_rule.clone())
+            .build();
+        let existing_guild = ExistingGuildFixture::new()
+            .with_automod_rule(existing_rule.clone())
+            .build();
+
+        let changes = AutoModRuleChangesService {}.list_changes(&existing_guild, &awaiting_guild);
+
+        assert_eq!(
+            changes,
+            vec![AutoModRuleChange::Update(
+                existing_rule,
+                awaiting_rule,
+                vec![Diff::Update(
+                    "enabled".to_string(),
+                    vec![Diff::Remove("true".into()), Diff::Add("false".into())]
+                )]
+            )]
+        );
+    }
+
+    #[test]
+    fn when_rule_trigger_type_differs_it_should_recreate_it() {
+        let existing_rule = ExistingAutoModRuleFixture::new()
+            .with_name(A_RULE_NAME)
+            .build();
+        let awaiting_rule = AwaitingAutoModRuleFixture::new()
+            .with_name(A_RULE_NAME)
+            .with_trigger(AutoModTrigger::Spam)
+            .build();
+        let awaiting_guild = AwaitingGuildFixture::new()
+            .with_automod_rule(awaiting_rule.clone())
+            .build();
+        let existing_guild = ExistingGuildFixture::new()
+            .with_automod_rule(existing_rule.clone())
+            .build();
+
+        let changes = AutoModRuleChangesService {}.list_changes(&existing_guild, &awaiting_guild);
+
+        assert_eq!(
+            changes,
+            vec![
+                AutoModRuleChange::Delete(existing_rule),
+                AutoModRuleChange::Create(awaiting_rule)
+            ]
+        );
+    }
+}
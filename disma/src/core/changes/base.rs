@@ -7,6 +7,17 @@ pub enum Change {
     Update(ChangeEntity, ChangeEntityName, Vec<Diff>),
 }
 
+impl Change {
+    /// Whether applying the change modifies the guild, as opposed to an update
+    /// only listing warnings.
+    pub fn is_change(&self) -> bool {
+        match self {
+            Self::Create(_, _) | Self::Delete(_, _) => true,
+            Self::Update(_, _, diffs) => diffs.iter().any(Diff::is_change),
+        }
+    }
+}
+
 type ChangeEntityName = String;
 
 #[derive(Debug, Clone, PartialEq)]
@@ -14,4 +25,37 @@ pub enum ChangeEntity {
     Role,
     Category,
     Channel,
+    Thread,
+    AutoModRule,
+    ScheduledEvent,
+    Member,
+    Onboarding,
+    WelcomeScreen,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::diffs::Diff;
+
+    use super::{Change, ChangeEntity};
+
+    #[test]
+    fn update_with_only_warnings_is_not_a_change() {
+        let warning = Diff::Warning("Something".to_string());
+
+        let warning_only = Change::Update(
+            ChangeEntity::Channel,
+            "a".to_string(),
+            vec![warning.clone()],
+        );
+        let update = Change::Update(
+            ChangeEntity::Channel,
+            "a".to_string(),
+            vec![warning, Diff::Add("b".into())],
+        );
+
+        assert!(!warning_only.is_change());
+        assert!(update.is_change());
+        assert!(Change::Create(ChangeEntity::Channel, "a".to_string()).is_change());
+    }
 }
@@ -1,6 +1,7 @@
 use crate::{
     category::{AwaitingCategory, ExistingCategory},
     core::{
+        changes::{Change, ChangeEntity},
         diffs::{Diff, Differ},
         ListComparison,
     },
@@ -14,6 +15,22 @@ pub enum CategoryChange {
     Delete(ExistingCategory),
 }
 
+impl From<CategoryChange> for Change {
+    fn from(change: CategoryChange) -> Self {
+        match change {
+            CategoryChange::Create(awaiting) => {
+                Change::Create(ChangeEntity::Category, awaiting.name)
+            }
+            CategoryChange::Update(existing, _, diffs) => {
+                Change::Update(ChangeEntity::Category, existing.name, diffs)
+            }
+            CategoryChange::Delete(existing) => {
+                Change::Delete(ChangeEntity::Category, existing.name)
+            }
+        }
+    }
+}
+
 pub struct CategoryChangesService {}
 
 impl CategoryChangesService {
@@ -29,7 +46,12 @@ impl CategoryChangesService {
         } = awaiting_guild
             .categories
             .items
-            .compare_by_name(existing_guild.categories());
+            .compare_by_name(existing_guild.categories())
+            .pair_by_id(
+                &awaiting_guild.ids.categories,
+                |category| category.name.clone(),
+                |category| &category.id,
+            );
 
         let to_create = extra_awaiting
             .into_iter()
@@ -37,9 +59,9 @@ impl CategoryChangesService {
 
         let to_update = same.into_iter().filter_map(|(awaiting, existing)| {
             let diffs = existing.diffs_with(awaiting);
-            match diffs.is_empty() {
-                true => None,
-                false => Some(CategoryChange::Update(
+            match diffs.iter().any(Diff::is_change) {
+                false => None,
+                true => Some(CategoryChange::Update(
                     existing.clone(),
                     awaiting.clone(),
                     diffs,
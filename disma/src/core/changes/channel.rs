@@ -1,10 +1,16 @@
 use crate::{
-    channel::{AwaitingChannel, ExistingChannel},
+    channel::{
+        AwaitingChannel, AwaitingThread, AwaitingThreadsList, Channel, ExistingChannel,
+        ExistingThread,
+    },
     core::{
+        changes::{Change, ChangeEntity},
         diffs::{Diff, Differ},
         ListComparison,
     },
     guild::{AwaitingGuild, ExistingGuild},
+    permission::PermissionsOverwritesList,
+    role::Role,
 };
 
 #[derive(PartialEq, Debug)]
@@ -12,6 +18,40 @@ pub enum ChannelChange {
     Create(AwaitingChannel),
     Update(ExistingChannel, AwaitingChannel, Vec<Diff>),
     Delete(ExistingChannel),
+    CreateThread(AwaitingChannel, AwaitingThread),
+    UpdateThread(ExistingChannel, ExistingThread, AwaitingThread, Vec<Diff>),
+    ArchiveThread(ExistingChannel, ExistingThread),
+}
+
+impl From<ChannelChange> for Change {
+    fn from(change: ChannelChange) -> Self {
+        match change {
+            ChannelChange::Create(awaiting) => {
+                Change::Create(ChangeEntity::Channel, awaiting.unique_name().to_string())
+            }
+            ChannelChange::Update(existing, _, diffs) => Change::Update(
+                ChangeEntity::Channel,
+                existing.unique_name().to_string(),
+                diffs,
+            ),
+            ChannelChange::Delete(existing) => {
+                Change::Delete(ChangeEntity::Channel, existing.unique_name().to_string())
+            }
+            ChannelChange::CreateThread(channel, thread) => Change::Create(
+                ChangeEntity::Thread,
+                format!("{} > {}", channel.unique_name(), thread.name),
+            ),
+            ChannelChange::UpdateThread(channel, existing, _, diffs) => Change::Update(
+                ChangeEntity::Thread,
+                format!("{} > {}", channel.unique_name(), existing.name),
+                diffs,
+            ),
+            ChannelChange::ArchiveThread(channel, thread) => Change::Delete(
+                ChangeEntity::Thread,
+                format!("{} > {}", channel.unique_name(), thread.name),
+            ),
+        }
+    }
 }
 
 pub struct ChannelChangesService {}
@@ -29,7 +69,31 @@ impl ChannelChangesService {
         } = awaiting_guild
             .channels
             .items
-            .compare_by_unique_name(existing_guild.channels());
+            .compare_by_unique_name(existing_guild.channels())
+            .pair_by_id(
+                &awaiting_guild.ids.channels,
+                |channel| channel.unique_name().to_string(),
+                |channel| &channel.id,
+            );
+
+        let mut thread_changes: Vec<ChannelChange> = Vec::new();
+        for &awaiting in extra_awaiting.iter() {
+            for thread in awaiting.threads.iter().flat_map(|threads| &threads.items) {
+                thread_changes.push(ChannelChange::CreateThread(
+                    awaiting.clone(),
+                    thread.clone(),
+                ));
+            }
+        }
+        for &(awaiting, existing) in same.iter() {
+            if let Some(awaiting_threads) = &awaiting.threads {
+                thread_changes.extend(self.list_thread_changes(
+                    existing,
+                    awaiting,
+                    awaiting_threads,
+                ));
+            }
+        }
 
         let to_create = extra_awaiting
             .into_iter()
@@ -37,9 +101,9 @@ impl ChannelChangesService {
 
         let to_update = same.into_iter().filter_map(|(awaiting, existing)| {
             let diffs = existing.diffs_with(awaiting);
-            match diffs.is_empty() {
-                true => None,
-                false => Some(ChannelChange::Update(
+            match diffs.iter().any(Diff::is_change) {
+                false => None,
+                true => Some(ChannelChange::Update(
                     existing.clone(),
                     awaiting.clone(),
                     diffs,
@@ -64,8 +128,113 @@ impl ChannelChangesService {
             )
         }
 
-        to_create.chain(to_update).chain(to_delete).collect()
+        to_create
+            .chain(to_update)
+            .chain(to_delete)
+            .chain(thread_changes)
+            .collect()
+    }
+
+    /// Channels following the permissions overwrites of their category (ex:
+    /// `FROM_CATEGORY`) whose overwrites will still differ from the
+    /// category's once the changes are applied (ex: each keeping its own
+    /// unknown permission bits), so that Discord will not show them as synced.
+    pub fn list_unsynced_channels(
+        &self,
+        existing_guild: &ExistingGuild,
+        awaiting_guild: &AwaitingGuild,
+    ) -> Vec<AwaitingChannel> {
+        let mut unsynced: Vec<AwaitingChannel> = Vec::new();
+
+        for awaiting in awaiting_guild.channels.items.to_list() {
+            let Some(category) = &awaiting.category else {
+                continue;
+            };
+            if !awaiting.overwrites.matches(&category.overwrites) {
+                continue;
+            }
+
+            let channel_overwrites = applied_overwrites(
+                &awaiting.overwrites,
+                existing_guild
+                    .channels()
+                    .find_by_unique_name(&awaiting.unique_name())
+                    .map(|existing| &existing.overwrites),
+            );
+            let category_overwrites = applied_overwrites(
+                &category.overwrites,
+                existing_guild
+                    .categories()
+                    .find_by_name(&category.name)
+                    .map(|existing| &existing.overwrites),
+            );
+
+            if !channel_overwrites.matches(&category_overwrites) {
+                unsynced.push(awaiting.clone());
+            }
+        }
+
+        unsynced.sort_by_key(|channel| channel.unique_name().to_string());
+        unsynced
+    }
+
+    fn list_thread_changes(
+        &self,
+        existing: &ExistingChannel,
+        awaiting: &AwaitingChannel,
+        awaiting_threads: &AwaitingThreadsList,
+    ) -> Vec<ChannelChange> {
+        let mut changes = Vec::new();
+
+        for awaiting_thread in awaiting_threads.items.iter() {
+            match existing.find_thread_by_name(&awaiting_thread.name) {
+                Some(existing_thread) => {
+                    let diffs = existing_thread.diffs_with(awaiting_thread);
+                    if !diffs.is_empty() {
+                        changes.push(ChannelChange::UpdateThread(
+                            existing.clone(),
+                            existing_thread.clone(),
+                            awaiting_thread.clone(),
+                            diffs,
+                        ));
+                    }
+                }
+                None => changes.push(ChannelChange::CreateThread(
+                    awaiting.clone(),
+                    awaiting_thread.clone(),
+                )),
+            }
+        }
+
+        // Archived threads are already removed
+        for existing_thread in existing.threads.iter().filter(|thread| !thread.archived) {
+            let is_awaited = awaiting_threads
+                .items
+                .iter()
+                .any(|awaiting_thread| awaiting_thread.name == existing_thread.name);
+            if !is_awaited {
+                awaiting_threads.extra_items_strategy.handle_extra_thread(
+                    existing,
+                    existing_thread,
+                    &mut changes,
+                );
+            }
+        }
+
+        changes
     }
 }
 
 // TODO add tests
+
+/// Full overwrites of a channel or category once `awaiting` is applied to it,
+/// where the unknown permission bits of its `existing` overwrites are kept.
+fn applied_overwrites<R: Role, R2: Role>(
+    awaiting: &PermissionsOverwritesList<R>,
+    existing: Option<&PermissionsOverwritesList<R2>>,
+) -> PermissionsOverwritesList<R> {
+    match existing {
+        Some(existing) => awaiting.clone().with_unknown_bits_of(existing),
+        None => awaiting.clone(),
+    }
+}
@@ -0,0 +1,310 @@
+use std::collections::HashSet;
+
+use crate::{
+    core::diffs::Diff,
+    guild::{AwaitingGuild, ExistingGuild},
+    member::{ExistingMember, Member, RosterStrategy},
+    role::{AwaitingRole, ExistingRole, Role},
+};
+
+#[derive(PartialEq, Debug)]
+pub enum MemberChange {
+    UpdateRoles {
+        member: ExistingMember,
+        roles_to_add: Vec<AwaitingRole>,
+        roles_to_remove: Vec<ExistingRole>,
+    },
+}
+
+impl MemberChange {
+    pub fn diffs(&self) -> Vec<Diff> {
+        match self {
+            Self::UpdateRoles {
+                roles_to_add,
+                roles_to_remove,
+                ..
+            } => {
+                let role_diffs = roles_to_remove
+                    .iter()
+                    .map(|role| Diff::Remove(role.name().into()))
+                    .chain(
+                        roles_to_add
+                            .iter()
+                            .map(|role| Diff::Add(role.name().into())),
+                    )
+                    .collect();
+
+                vec![Diff::Update("roles".into(), role_diffs)]
+            }
+        }
+    }
+}
+
+pub struct MemberChangesService {}
+
+impl MemberChangesService {
+    pub fn list_changes(
+        &self,
+        existing_guild: &ExistingGuild,
+        awaiting_guild: &AwaitingGuild,
+    ) -> Vec<MemberChange> {
+        let roster = match &awaiting_guild.members {
+            Some(roster) => roster,
+            None => return vec![],
+        };
+
+        // Only roles given to some member are managed, as other roles may be
+        // given by hand or by bots
+        let managed_role_names: HashSet<&str> = roster
+            .items
+            .to_list()
+            .into_iter()
+            .flat_map(|member| member.roles.to_list())
+            .map(|role| role.name())
+            .collect();
+
+        let mut existing_members = existing_guild.members().to_list();
+        existing_members.sort_by_key(|member| member.id().to_string());
+
+        existing_members
+            .into_iter()
+            .filter_map(|existing| {
+                // Members missing from the roster are never touched
+                let listed_roles = roster.items.find_by_id(existing.id())?.roles.to_list();
+                let is_given_by_hand = |role: &ExistingRole| !role.is_managed;
+
+                let mut roles_to_add: Vec<AwaitingRole> = listed_roles
+                    .iter()
+                    .filter(
+                        |role| match existing_guild.roles().find_by_name(role.name()) {
+                            Some(existing_role) => is_given_by_hand(existing_role),
+                            None => true,
+                        },
+                    )
+                    .filter(|role| existing.roles.find_by_name(role.name()).is_none())
+                    .map(|role| (*role).clone())
+                    .collect();
+                roles_to_add.sort_by_key(|role| role.name().to_string());
+
+                let mut roles_to_remove: Vec<ExistingRole> = match roster.roster_strategy {
+                    RosterStrategy::ADDITIVE => vec![],
+                    RosterStrategy::EXACT => existing
+                        .roles
+                        .to_list()
+                        .into_iter()
+                        .filter(|role| {
+                            is_given_by_hand(role)
+                                && managed_role_names.contains(role.name())
+                                && !listed_roles
+                                    .iter()
+                                    .any(|listed| listed.name() == role.name())
+                        })
+                        .cloned()
+                        .collect(),
+                };
+                roles_to_remove.sort_by_key(|role| role.name().to_string());
+
+                match roles_to_add.is_empty() && roles_to_remove.is_empty() {
+                    true => None,
+                    false => Some(MemberChange::UpdateRoles {
+                        member: existing.clone(),
+                        roles_to_add,
+                        roles_to_remove,
+                    }),
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        member::RosterStrategy,
+        role::RolesList,
+        tests::fixtures::{
+            awaiting::{AwaitingGuildFixture, AwaitingMemberFixture, AwaitingRoleFixture},
+            existing::{ExistingGuildFixture, ExistingMemberFixture, ExistingRoleFixture},
+        },
+    };
+
+    use super::{MemberChange, MemberChangesService};
+
+    const A_MEMBER_ID: &str = "123";
+    const ANOTHER_MEMBER_ID: &str = "456";
+    const A_ROLE_NAME: &str = "Staff";
+    const ANOTHER_ROLE_NAME: &str = "Moderator";
+
+    #[test]
+    fn when_member_is_missing_listed_role_it_should_add_it() {
+        let awaiting_role = AwaitingRoleFixture::new().with_name(A_ROLE_NAME).build();
+        let existing_member = ExistingMemberFixture::new().with_id(A_MEMBER_ID).build();
+        let awaiting_guild = AwaitingGuildFixture::new()
+            .with_role(awaiting_role.clone())
+            .with_member(
+                AwaitingMemberFixture::new()
+                    .with_id(A_MEMBER_ID)
+                    .with_roles(RolesList::from(vec![awaiting_role.clone()]))
+                    .build(),
+            )
+            .build();
+        let existing_guild = ExistingGuildFixture::new()
+            .with_member(existing_member.clone())
+            .build();
+
+        let changes = MemberChangesService {}.list_changes(&existing_guild, &awaiting_guild);
+
+        assert_eq!(
+            changes,
+            vec![MemberChange::UpdateRoles {
+                member: existing_member,
+                roles_to_add: vec![awaiting_role],
+                roles_to_remove: vec![],
+            }]
+        );
+    }
+
+    #[test]
+    fn given_additive_strategy_it_should_not_remove_unlisted_roles() {
+        let existing_role = ExistingRoleFixture::new().with_name(A_ROLE_NAME).build();
+        let existing_member = ExistingMemberFixture::new()
+            .with_id(A_MEMBER_ID)
+            .with_roles(RolesList::from(vec![existing_role]))
+            .build();
+        let awaiting_guild = AwaitingGuildFixture::new()
+            .with_role(AwaitingRoleFixture::new().with_name(A_ROLE_NAME).build())
+            .with_roster_strategy(RosterStrategy::ADDITIVE)
+            .build();
+        let existing_guild = ExistingGuildFixture::new()
+            .with_member(existing_member)
+            .build();
+
+        let changes = MemberChangesService {}.list_changes(&existing_guild, &awaiting_guild);
+
+        assert_eq!(changes, vec![]);
+    }
+
+    #[test]
+    fn given_exact_strategy_it_should_remove_unlisted_managed_roles_only() {
+        let managed_role = ExistingRoleFixture::new().with_name(A_ROLE_NAME).build();
+        let unmanaged_role = ExistingRoleFixture::new()
+            .with_name(ANOTHER_ROLE_NAME)
+            .build();
+        let existing_member = ExistingMemberFixture::new()
+            .with_id(A_MEMBER_ID)
+            .with_roles(RolesList::from(vec![managed_role.clone(), unmanaged_role]))
+            .build();
+        let awaiting_role = AwaitingRoleFixture::new().with_name(A_ROLE_NAME).build();
+        let awaiting_guild = AwaitingGuildFixture::new()
+            .with_role(awaiting_role.clone())
+            .with_role(
+                AwaitingRoleFixture::new()
+                    .with_name(ANOTHER_ROLE_NAME)
+                    .build(),
+            )
+            .with_member(AwaitingMemberFixture::new().with_id(A_MEMBER_ID).build())
+            .with_member(
+                AwaitingMemberFixture::new()
+                    .with_id(ANOTHER_MEMBER_ID)
+                    .with_roles(RolesList::from(vec![awaiting_role]))
+                    .build(),
+            )
+            .with_roster_strategy(RosterStrategy::EXACT)
+            .build();
+        let existing_guild = ExistingGuildFixture::new()
+            .with_member(existing_member.clone())
+            .build();
+
+        let changes = MemberChangesService {}.list_changes(&existing_guild, &awaiting_guild);
+
+        assert_eq!(
+            changes,
+            vec![MemberChange::UpdateRoles {
+                member: existing_member,
+                roles_to_add: vec![],
+                roles_to_remove: vec![managed_role],
+            }]
+        );
+    }
+
+    #[test]
+    fn given_exact_strategy_it_should_not_change_members_missing_from_roster() {
+        let existing_role = ExistingRoleFixture::new().with_name(A_ROLE_NAME).build();
+        let awaiting_role = AwaitingRoleFixture::new().with_name(A_ROLE_NAME).build();
+        let awaiting_guild = AwaitingGuildFixture::new()
+            .with_role(awaiting_role.clone())
+            .with_member(
+                AwaitingMemberFixture::new()
+                    .with_id(A_MEMBER_ID)
+                    .with_roles(RolesList::from(vec![awaiting_role]))
+                    .build(),
+            )
+            .with_roster_strategy(RosterStrategy::EXACT)
+            .build();
+        let existing_guild = ExistingGuildFixture::new()
+            .with_member(
+                ExistingMemberFixture::new()
+                    .with_id(ANOTHER_MEMBER_ID)
+                    .with_roles(RolesList::from(vec![existing_role]))
+                    .build(),
+            )
+            .build();
+
+        let changes = MemberChangesService {}.list_changes(&existing_guild, &awaiting_guild);
+
+        assert_eq!(changes, vec![]);
+    }
+
+    #[test]
+    fn given_integration_role_it_should_neither_add_nor_remove_it() {
+        let integration_role = ExistingRoleFixture::new()
+            .with_name(A_ROLE_NAME)
+            .managed()
+            .build();
+        let awaiting_role = AwaitingRoleFixture::new().with_name(A_ROLE_NAME).build();
+        let awaiting_guild = AwaitingGuildFixture::new()
+            .with_role(awaiting_role.clone())
+            .with_member(AwaitingMemberFixture::new().with_id(A_MEMBER_ID).build())
+            .with_member(
+                AwaitingMemberFixture::new()
+                    .with_id(ANOTHER_MEMBER_ID)
+                    .with_roles(RolesList::from(vec![awaiting_role]))
+                    .build(),
+            )
+            .with_roster_strategy(RosterStrategy::EXACT)
+            .build();
+        let existing_guild = ExistingGuildFixture::new()
+            .with_role(integration_role.clone())
+            .with_member(
+                ExistingMemberFixture::new()
+                    .with_id(A_MEMBER_ID)
+                    .with_roles(RolesList::from(vec![integration_role]))
+                    .build(),
+            )
+            .with_member(
+                ExistingMemberFixture::new()
+                    .with_id(ANOTHER_MEMBER_ID)
+                    .build(),
+            )
+            .build();
+
+        let changes = MemberChangesService {}.list_changes(&existing_guild, &awaiting_guild);
+
+        assert_eq!(changes, vec![]);
+    }
+
+    #[test]
+    fn given_no_roster_it_should_not_change_members() {
+        let existing_member = ExistingMemberFixture::new()
+            .with_roles(RolesList::from(vec![ExistingRoleFixture::new().build()]))
+            .build();
+        let awaiting_guild = AwaitingGuildFixture::new().build();
+        let existing_guild = ExistingGuildFixture::new()
+            .with_member(existing_member)
+            .build();
+
+        let changes = MemberChangesService {}.list_changes(&existing_guild, &awaiting_guild);
+
+        assert_eq!(changes, vec![]);
+    }
+}
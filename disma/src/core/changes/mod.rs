@@ -1,6 +1,12 @@
 mod base;
 pub use base::*;
 
+pub mod automod;
 pub mod category;
 pub mod channel;
+pub mod member;
+pub mod onboarding;
+pub mod permission;
 pub mod role;
+pub mod scheduled_event;
+pub mod welcome_screen;
@@ -0,0 +1,102 @@
+use crate::{
+    core::{
+        changes::{Change, ChangeEntity},
+        diffs::{Diff, Differ},
+    },
+    guild::{AwaitingGuild, ExistingGuild},
+    onboarding::{AwaitingOnboarding, ExistingOnboarding},
+};
+
+#[derive(PartialEq, Debug)]
+pub enum OnboardingChange {
+    Update(ExistingOnboarding, AwaitingOnboarding, Vec<Diff>),
+}
+
+impl From<OnboardingChange> for Change {
+    fn from(change: OnboardingChange) -> Self {
+        match change {
+            OnboardingChange::Update(_, _, diffs) => {
+                Change::Update(ChangeEntity::Onboarding, "settings".to_string(), diffs)
+            }
+        }
+    }
+}
+
+pub struct OnboardingChangesService {}
+
+impl OnboardingChangesService {
+    pub fn list_changes(
+        &self,
+        existing_guild: &ExistingGuild,
+        awaiting_guild: &AwaitingGuild,
+    ) -> Vec<OnboardingChange> {
+        let awaiting = match &awaiting_guild.onboarding {
+            Some(awaiting) => awaiting,
+            None => return vec![],
+        };
+
+        let existing = existing_guild.onboarding();
+        let diffs = existing.diffs_with(awaiting);
+
+        if diffs.is_empty() {
+            return vec![];
+        }
+
+        vec![OnboardingChange::Update(
+            existing.clone(),
+            awaiting.clone(),
+            diffs,
+        )]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        onboarding::AwaitingOnboarding,
+        tests::fixtures::{awaiting::AwaitingGuildFixture, existing::ExistingGuildFixture},
+    };
+
+    use super::{OnboardingChange, OnboardingChangesService};
+
+    #[test]
+    fn given_no_awaiting_onboarding_it_returns_no_changes() {
+        let awaiting_guild = AwaitingGuildFixture::new().build();
+        let existing_guild = ExistingGuildFixture::new().build();
+
+        let changes = OnboardingChangesService {}.list_changes(&existing_guild, &awaiting_guild);
+
+        assert_eq!(changes, vec![]);
+    }
+
+    #[test]
+    fn given_same_onboarding_it_returns_no_changes() {
+        let awaiting_guild = AwaitingGuildFixture::new()
+            .with_onboarding(AwaitingOnboarding::default())
+            .build();
+        let existing_guild = ExistingGuildFixture::new().build();
+
+        let changes = OnboardingChangesService {}.list_changes(&existing_guild, &awaiting_guild);
+
+        assert_eq!(changes, vec![]);
+    }
+
+    #[test]
+    fn given_different_onboarding_it_returns_update() {
+        let awaiting_onboarding = AwaitingOnboarding {
+            enabled: true,
+            ..Default::default()
+        };
+        let awaiting_guild = AwaitingGuildFixture::new()
+            .with_onboarding(awaiting_onboarding.clone())
+            .build();
+        let existing_guild = ExistingGuildFixture::new().build();
+
+        let changes = OnboardingChangesService {}.list_changes(&existing_guild, &awaiting_guild);
+
+        assert!(matches!(
+            &changes[..],
+            [OnboardingChange::Update(_, awaiting, _)] if awaiting == &awaiting_onboarding
+        ));
+    }
+}
@@ -0,0 +1,201 @@
+use std::collections::HashMap;
+
+use crate::{
+    guild::{AwaitingGuild, ExistingGuild},
+    permission::{ChannelPermissions, Permission, EVERYONE_ROLE_NAME},
+    role::Role,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum PermissionImpactKind {
+    Gain,
+    Loss,
+}
+
+/// A permission that a role would gain or lose in some channels.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PermissionImpact {
+    pub role: String,
+    pub kind: PermissionImpactKind,
+    pub permission: Permission,
+    pub channels: Vec<String>,
+}
+
+/// Lists the effective permissions gained or lost by each role, in the
+/// channels existing both before and after the changes. Each role is
+/// considered alone, along with `@everyone`.
+pub struct PermissionImpactService {}
+
+impl PermissionImpactService {
+    pub fn list_impacts(
+        &self,
+        existing_guild: &ExistingGuild,
+        awaiting_guild: &AwaitingGuild,
+    ) -> Vec<PermissionImpact> {
+        let mut role_names: Vec<String> = existing_guild
+            .roles()
+            .to_list()
+            .into_iter()
+            .map(|role| role.name().to_string())
+            .filter(|name| awaiting_guild.roles.items.find_by_name(name).is_some())
+            .collect();
+        role_names.sort_by_key(|name| (name != EVERYONE_ROLE_NAME, name.clone()));
+
+        role_names
+            .into_iter()
+            .flat_map(|role_name| {
+                let role_names = [role_name.clone()];
+                // Roles are listed from both guilds
+                let (Ok(existing), Ok(awaiting)) = (
+                    existing_guild.effective_permissions(&role_names),
+                    awaiting_guild.effective_permissions(&role_names),
+                ) else {
+                    return Vec::new();
+                };
+                self.list_role_impacts(&role_name, existing, awaiting)
+            })
+            .collect()
+    }
+
+    fn list_role_impacts(
+        &self,
+        role_name: &str,
+        existing: Vec<ChannelPermissions>,
+        awaiting: Vec<ChannelPermissions>,
+    ) -> Vec<PermissionImpact> {
+        let awaiting: HashMap<String, ChannelPermissions> = awaiting
+            .into_iter()
+            .map(|permissions| (permissions.channel.to_string(), permissions))
+            .collect();
+
+        let mut impacts: Vec<PermissionImpact> = Vec::new();
+        for existing in existing {
+            let channel = existing.channel.to_string();
+            let Some(awaiting) = awaiting.get(&channel) else {
+                continue;
+            };
+
+            let gained = awaiting.permissions.without(&existing.permissions);
+            let lost = existing.permissions.without(&awaiting.permissions);
+            let deltas = gained
+                .to_list()
+                .into_iter()
+                .map(|permission| (PermissionImpactKind::Gain, permission))
+                .chain(
+                    lost.to_list()
+                        .into_iter()
+                        .map(|permission| (PermissionImpactKind::Loss, permission)),
+                );
+
+            for (kind, permission) in deltas {
+                match impacts
+                    .iter_mut()
+                    .find(|impact| impact.kind == kind && impact.permission == permission)
+                {
+                    Some(impact) => impact.channels.push(channel.clone()),
+                    None => impacts.push(PermissionImpact {
+                        role: role_name.to_string(),
+                        kind,
+                        permission,
+                        channels: vec![channel.clone()],
+                    }),
+                }
+            }
+        }
+
+        impacts.sort_by(|a, b| {
+            (&a.kind, a.permission.to_string()).cmp(&(&b.kind, b.permission.to_string()))
+        });
+        impacts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        permission::{Permission, PermissionsList, EVERYONE_ROLE_NAME},
+        tests::fixtures::{
+            awaiting::{AwaitingChannelFixture, AwaitingGuildFixture, AwaitingRoleFixture},
+            existing::{ExistingChannelFixture, ExistingGuildFixture, ExistingRoleFixture},
+        },
+    };
+
+    use super::{PermissionImpact, PermissionImpactKind, PermissionImpactService};
+
+    #[test]
+    fn given_same_permissions_it_returns_no_impact() {
+        let existing_guild = ExistingGuildFixture::new()
+            .with_role(
+                ExistingRoleFixture::new()
+                    .with_name(EVERYONE_ROLE_NAME)
+                    .with_permissions(PermissionsList::from(vec![Permission::VIEW_CHANNEL]))
+                    .build(),
+            )
+            .with_channel(ExistingChannelFixture::new().with_name("general").build())
+            .build();
+        let awaiting_guild = AwaitingGuildFixture::new()
+            .with_role(
+                AwaitingRoleFixture::new()
+                    .with_name(EVERYONE_ROLE_NAME)
+                    .with_permissions(PermissionsList::from(vec![Permission::VIEW_CHANNEL]))
+                    .build(),
+            )
+            .with_channel(AwaitingChannelFixture::new().with_name("general").build())
+            .build();
+
+        let impacts = PermissionImpactService {}.list_impacts(&existing_guild, &awaiting_guild);
+
+        assert_eq!(impacts, vec![]);
+    }
+
+    #[test]
+    fn given_changed_role_permissions_it_returns_gains_and_losses_by_channel() {
+        let existing_guild = ExistingGuildFixture::new()
+            .with_role(
+                ExistingRoleFixture::new()
+                    .with_name(EVERYONE_ROLE_NAME)
+                    .with_permissions(PermissionsList::from(vec![
+                        Permission::VIEW_CHANNEL,
+                        Permission::SEND_MESSAGES,
+                    ]))
+                    .build(),
+            )
+            .with_channel(ExistingChannelFixture::new().with_name("general").build())
+            .with_channel(ExistingChannelFixture::new().with_name("random").build())
+            .with_channel(ExistingChannelFixture::new().with_name("removed").build())
+            .build();
+        let awaiting_guild = AwaitingGuildFixture::new()
+            .with_role(
+                AwaitingRoleFixture::new()
+                    .with_name(EVERYONE_ROLE_NAME)
+                    .with_permissions(PermissionsList::from(vec![
+                        Permission::VIEW_CHANNEL,
+                        Permission::ADD_REACTIONS,
+                    ]))
+                    .build(),
+            )
+            .with_channel(AwaitingChannelFixture::new().with_name("general").build())
+            .with_channel(AwaitingChannelFixture::new().with_name("random").build())
+            .build();
+
+        let impacts = PermissionImpactService {}.list_impacts(&existing_guild, &awaiting_guild);
+
+        assert_eq!(
+            impacts,
+            vec![
+                PermissionImpact {
+                    role: EVERYONE_ROLE_NAME.to_string(),
+                    kind: PermissionImpactKind::Gain,
+                    permission: Permission::ADD_REACTIONS,
+                    channels: vec![":general (TEXT)".to_string(), ":random (TEXT)".to_string()],
+                },
+                PermissionImpact {
+                    role: EVERYONE_ROLE_NAME.to_string(),
+                    kind: PermissionImpactKind::Loss,
+                    permission: Permission::SEND_MESSAGES,
+                    channels: vec![":general (TEXT)".to_string(), ":random (TEXT)".to_string()],
+                },
+            ]
+        );
+    }
+}
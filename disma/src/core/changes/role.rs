@@ -1,5 +1,6 @@
 use crate::{
     core::{
+        changes::{Change, ChangeEntity},
         diffs::{Diff, Differ},
         ListComparison,
     },
@@ -14,6 +15,18 @@ pub enum RoleChange {
     Delete(ExistingRole),
 }
 
+impl From<RoleChange> for Change {
+    fn from(change: RoleChange) -> Self {
+        match change {
+            RoleChange::Create(awaiting) => Change::Create(ChangeEntity::Role, awaiting.name),
+            RoleChange::Update(existing, _, diffs) => {
+                Change::Update(ChangeEntity::Role, existing.name, diffs)
+            }
+            RoleChange::Delete(existing) => Change::Delete(ChangeEntity::Role, existing.name),
+        }
+    }
+}
+
 pub struct RoleChangesService {}
 
 impl RoleChangesService {
@@ -29,7 +42,12 @@ impl RoleChangesService {
         } = awaiting_guild
             .roles
             .items
-            .compare_by_name(existing_guild.roles());
+            .compare_by_name(existing_guild.roles())
+            .pair_by_id(
+                &awaiting_guild.ids.roles,
+                |role| role.name.clone(),
+                |role| &role.id,
+            );
 
         let to_create = extra_awaiting
             .into_iter()
@@ -37,9 +55,9 @@ impl RoleChangesService {
 
         let to_update = same.into_iter().filter_map(|(awaiting, existing)| {
             let diffs = existing.diffs_with(awaiting);
-            match diffs.is_empty() {
-                true => None,
-                false => Some(RoleChange::Update(
+            match diffs.iter().any(Diff::is_change) {
+                false => None,
+                true => Some(RoleChange::Update(
                     existing.clone(),
                     awaiting.clone(),
                     diffs,
@@ -0,0 +1,230 @@
+use crate::{
+    core::{
+        changes::{Change, ChangeEntity},
+        diffs::{Diff, Differ},
+        ListComparison,
+    },
+    guild::{AwaitingGuild, ExistingGuild},
+    scheduled_event::{AwaitingScheduledEvent, ExistingScheduledEvent},
+};
+
+#[allow(clippy::large_enum_variant)]
+#[derive(PartialEq, Debug)]
+pub enum ScheduledEventChange {
+    Create(AwaitingScheduledEvent),
+    Update(ExistingScheduledEvent, AwaitingScheduledEvent, Vec<Diff>),
+    Delete(ExistingScheduledEvent),
+}
+
+impl From<ScheduledEventChange> for Change {
+    fn from(change: ScheduledEventChange) -> Self {
+        match change {
+            ScheduledEventChange::Create(awaiting) => {
+                Change::Create(ChangeEntity::ScheduledEvent, awaiting.name)
+            }
+            ScheduledEventChange::Update(existing, _, diffs) => {
+                Change::Update(ChangeEntity::ScheduledEvent, existing.name, diffs)
+            }
+            ScheduledEventChange::Delete(existing) => {
+                Change::Delete(ChangeEntity::ScheduledEvent, existing.name)
+            }
+        }
+    }
+}
+
+pub struct ScheduledEventChangesService {}
+
+impl ScheduledEventChangesService {
+    pub fn list_changes(
+        &self,
+        existing_guild: &ExistingGuild,
+        awaiting_guild: &AwaitingGuild,
+    ) -> Vec<ScheduledEventChange> {
+        let ListComparison {
+            extra_self: extra_awaiting,
+            extra_other: extra_existing,
+            same,
+        } = awaiting_guild
+            .scheduled_events
+            .items
+            .compare_by_name(existing_guild.scheduled_events());
+
+        let to_create: Vec<ScheduledEventChange> = extra_awaiting
+            .into_iter()
+            .map(|awaiting| ScheduledEventChange::Create(awaiting.clone()))
+            .collect();
+
+        let to_update: Vec<ScheduledEventChange> = same
+            .into_iter()
+            .filter_map(|(awaiting, existing)| {
+                let diffs = existing.diffs_with(awaiting);
+                match diffs.is_empty() {
+                    true => None,
+                    false => Some(ScheduledEventChange::Update(
+                        existing.clone(),
+                        awaiting.clone(),
+                        diffs,
+                    )),
+                }
+            })
+            .collect();
+
+        let mut to_delete: Vec<ScheduledEventChange> = Vec::new();
+        for existing in extra_existing.into_iter() {
+            awaiting_guild
+                .scheduled_events
+                .extra_items_strategy
+                .handle_extra_scheduled_event(existing, &mut to_delete);
+        }
+
+        to_delete
+            .into_iter()
+            .chain(to_create)
+            .chain(to_update)
+            .collect()
+    }
+
+    /// Awaiting events sharing their name with several existing events, ex:
+    /// recurring events, with the number of existing events left untouched.
+    /// Only the earliest existing event is managed.
+    pub fn list_ambiguous_events(
+        &self,
+        existing_guild: &ExistingGuild,
+        awaiting_guild: &AwaitingGuild,
+    ) -> Vec<(AwaitingScheduledEvent, usize)> {
+        awaiting_guild
+            .scheduled_events
+            .items
+            .to_list()
+            .into_iter()
+            .filter_map(|awaiting| {
+                match existing_guild
+                    .scheduled_events()
+                    .count_duplicates(&awaiting.name)
+                {
+                    0 => None,
+                    duplicates => Some((awaiting.clone(), duplicates)),
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        core::diffs::Diff,
+        scheduled_event::ScheduledEventsList,
+        tests::fixtures::{
+            awaiting::{AwaitingGuildFixture, AwaitingScheduledEventFixture},
+            existing::{ExistingGuildFixture, ExistingScheduledEventFixture},
+        },
+    };
+
+    use super::{ScheduledEventChange, ScheduledEventChangesService};
+
+    const AN_EVENT_NAME: &str = "event";
+
+    #[test]
+    fn when_event_is_missing_it_should_create_it() {
+        let awaiting_event = AwaitingScheduledEventFixture::new().build();
+        let awaiting_guild = AwaitingGuildFixture::new()
+            .with_scheduled_event(awaiting_event.clone())
+            .build();
+        let existing_guild = ExistingGuildFixture::new().build();
+
+        let changes =
+            ScheduledEventChangesService {}.list_changes(&existing_guild, &awaiting_guild);
+
+        assert_eq!(changes, vec![ScheduledEventChange::Create(awaiting_event)]);
+    }
+
+    #[test]
+    fn when_event_differs_it_should_update_it() {
+        let existing_event = ExistingScheduledEventFixture::new()
+            .with_name(AN_EVENT_NAME)
+            .build();
+        let awaiting_event = AwaitingScheduledEventFixture::new()
+            .with_name(AN_EVENT_NAME)
+            .with_start_time("2030-02-01T18:00:00Z")
+            .build();
+        let awaiting_guild = AwaitingGuildFixture::new()
+            .with_scheduled_event(awaiting_event.clone())
+            .build();
+        let existing_guild = ExistingGuildFixture::new()
+            .with_scheduled_event(existing_event.clone())
+            .build();
+
+        let changes =
+            ScheduledEventChangesService {}.list_changes(&existing_guild, &awaiting_guild);
+
+        assert_eq!(
+            changes,
+            vec![ScheduledEventChange::Update(
+                existing_event,
+                awaiting_event,
+                vec![Diff::Update(
+                    "start_time".to_string(),
+                    vec![
+                        Diff::Remove("2030-01-01T18:00:00Z".into()),
+                        Diff::Add("2030-02-01T18:00:00Z".into())
+                    ]
+                )]
+            )]
+        );
+    }
+
+    #[test]
+    fn when_event_is_the_same_it_should_not_change_it() {
+        let existing_event = ExistingScheduledEventFixture::new()
+            .with_name(AN_EVENT_NAME)
+            .build();
+        let awaiting_event = AwaitingScheduledEventFixture::new()
+            .with_name(AN_EVENT_NAME)
+            .build();
+        let awaiting_guild = AwaitingGuildFixture::new()
+            .with_scheduled_event(awaiting_event)
+            .build();
+        let existing_guild = ExistingGuildFixture::new()
+            .with_scheduled_event(existing_event)
+            .build();
+
+        let changes =
+            ScheduledEventChangesService {}.list_changes(&existing_guild, &awaiting_guild);
+
+        assert_eq!(changes, vec![]);
+    }
+
+    #[test]
+    fn given_existing_events_with_same_name_it_lists_them_as_ambiguous() {
+        let existing_events = ScheduledEventsList::from_existing(vec![
+            ExistingScheduledEventFixture::new()
+                .with_name(AN_EVENT_NAME)
+                .build(),
+            ExistingScheduledEventFixture::new()
+                .with_name(AN_EVENT_NAME)
+                .with_start_time("2030-01-08T18:00:00Z")
+                .build(),
+        ]);
+        let awaiting_event = AwaitingScheduledEventFixture::new()
+            .with_name(AN_EVENT_NAME)
+            .build();
+        let awaiting_guild = AwaitingGuildFixture::new()
+            .with_scheduled_event(awaiting_event.clone())
+            .build();
+        let existing_guild = ExistingGuildFixture::new()
+            .with_scheduled_events(existing_events)
+            .build();
+
+        let service = ScheduledEventChangesService {};
+
+        assert_eq!(
+            service.list_changes(&existing_guild, &awaiting_guild),
+            vec![]
+        );
+        assert_eq!(
+            service.list_ambiguous_events(&existing_guild, &awaiting_guild),
+            vec![(awaiting_event, 1)]
+        );
+    }
+}
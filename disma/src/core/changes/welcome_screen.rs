@@ -0,0 +1,90 @@
+use crate::{
+    core::{
+        changes::{Change, ChangeEntity},
+        diffs::{Diff, Differ},
+    },
+    guild::{AwaitingGuild, ExistingGuild},
+    welcome_screen::{AwaitingWelcomeScreen, ExistingWelcomeScreen},
+};
+
+#[derive(PartialEq, Debug)]
+pub enum WelcomeScreenChange {
+    Update(ExistingWelcomeScreen, AwaitingWelcomeScreen, Vec<Diff>),
+}
+
+impl From<WelcomeScreenChange> for Change {
+    fn from(change: WelcomeScreenChange) -> Self {
+        match change {
+            WelcomeScreenChange::Update(_, _, diffs) => {
+                Change::Update(ChangeEntity::WelcomeScreen, "settings".to_string(), diffs)
+            }
+        }
+    }
+}
+
+pub struct WelcomeScreenChangesService {}
+
+impl WelcomeScreenChangesService {
+    pub fn list_changes(
+        &self,
+        existing_guild: &ExistingGuild,
+        awaiting_guild: &AwaitingGuild,
+    ) -> Vec<WelcomeScreenChange> {
+        let awaiting = match &awaiting_guild.welcome_screen {
+            Some(awaiting) => awaiting,
+            None => return vec![],
+        };
+
+        let existing = existing_guild.welcome_screen();
+        let diffs = existing.diffs_with(awaiting);
+
+        if diffs.is_empty() {
+            return vec![];
+        }
+
+        vec![WelcomeScreenChange::Update(
+            existing.clone(),
+            awaiting.clone(),
+            diffs,
+        )]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        tests::fixtures::{awaiting::AwaitingGuildFixture, existing::ExistingGuildFixture},
+        welcome_screen::AwaitingWelcomeScreen,
+    };
+
+    use super::{WelcomeScreenChange, WelcomeScreenChangesService};
+
+    #[test]
+    fn given_no_awaiting_welcome_screen_it_returns_no_changes() {
+        let awaiting_guild = AwaitingGuildFixture::new().build();
+        let existing_guild = ExistingGuildFixture::new().build();
+
+        let changes = WelcomeScreenChangesService {}.list_changes(&existing_guild, &awaiting_guild);
+
+        assert_eq!(changes, vec![]);
+    }
+
+    #[test]
+    fn given_different_welcome_screen_it_returns_update() {
+        let awaiting_welcome_screen = AwaitingWelcomeScreen {
+            description: Some("Welcome!".to_string()),
+            ..Default::default()
+        };
+        let awaiting_guild = AwaitingGuildFixture::new()
+            .with_welcome_screen(awaiting_welcome_screen.clone())
+            .build();
+        let existing_guild = ExistingGuildFixture::new().build();
+
+        let changes = WelcomeScreenChangesService {}.list_changes(&existing_guild, &awaiting_guild);
+
+        assert!(matches!(
+            &changes[..],
+            [WelcomeScreenChange::Update(_, awaiting, _)] if awaiting == &awaiting_welcome_screen
+        ));
+    }
+}
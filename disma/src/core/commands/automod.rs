@@ -0,0 +1,236 @@
+use crate::{
+    automod::{AwaitingAutoModRule, ExistingAutoModRule},
+    core::events::{Change, ChangeEntity, ChangeEvent, ChangeEventListener},
+    guild::{ExistingGuild, GuildCommander},
+};
+
+use super::Command;
+
+pub struct AddAutoModRule {
+    rule: AwaitingAutoModRule,
+}
+
+impl AddAutoModRule {
+    pub fn new(rule: AwaitingAutoModRule) -> Self {
+        Self { rule }
+    }
+
+    fn describe(&self) -> Change {
+        Change::Create(ChangeEntity::AutoModRule, self.rule.name.clone())
+    }
+}
+
+impl Command for AddAutoModRule {
+    fn execute(
+        &self,
+        commander: &dyn GuildCommander,
+        event_listener: &dyn ChangeEventListener,
+        existing_guild: &mut ExistingGuild,
+    ) {
+        let result = commander.add_automod_rule(
+            &self.rule,
+            existing_guild.roles(),
+            existing_guild.channels(),
+        );
+
+        let event = match result {
+            Ok(rule) => {
+                existing_guild.add_or_replace_automod_rule(rule);
+                ChangeEvent::Success(self.describe())
+            }
+            Err(message) => ChangeEvent::Error(self.describe(), message),
+        };
+
+        event_listener.handle(event);
+    }
+}
+
+pub struct UpdateAutoModRule {
+    existing_rule: ExistingAutoModRule,
+    awaiting_rule: AwaitingAutoModRule,
+}
+
+impl UpdateAutoModRule {
+    pub fn new(existing_rule: ExistingAutoModRule, awaiting_rule: AwaitingAutoModRule) -> Self {
+        Self {
+            existing_rule,
+            awaiting_rule,
+        }
+    }
+
+    fn describe(&self) -> Change {
+        Change::Update(ChangeEntity::AutoModRule, self.existing_rule.name.clone())
+    }
+}
+
+impl Command for UpdateAutoModRule {
+    fn execute(
+        &self,
+        commander: &dyn GuildCommander,
+        event_listener: &dyn ChangeEventListener,
+        existing_guild: &mut ExistingGuild,
+    ) {
+        let result = commander.update_automod_rule(
+            &self.existing_rule.id,
+            &self.awaiting_rule,
+            existing_guild.roles(),
+            existing_guild.channels(),
+        );
+
+        let event = match result {
+            Ok(rule) => {
+                existing_guild.add_or_replace_automod_rule(rule);
+                ChangeEvent::Success(self.describe())
+            }
+            Err(message) => ChangeEvent::Error(self.describe(), message),
+        };
+
+        event_listener.handle(event);
+    }
+}
+
+pub struct DeleteAutoModRule {
+    rule: ExistingAutoModRule,
+}
+
+impl DeleteAutoModRule {
+    pub fn new(rule: ExistingAutoModRule) -> Self {
+        Self { rule }
+    }
+
+    fn describe(&self) -> Change {
+        Change::Delete(ChangeEntity::AutoModRule, self.rule.name.clone())
+    }
+}
+
+impl Command for DeleteAutoModRule {
+    fn execute(
+        &self,
+        commander: &dyn GuildCommander,
+        event_listener: &dyn ChangeEventListener,
+        existing_guild: &mut ExistingGuild,
+    ) {
+        let result = commander.delete_automod_rule(&self.rule.id);
+
+        let event = match result {
+            Ok(()) => {
+                existing_guild.remove_automod_rule(self.rule.clone());
+                ChangeEvent::Success(self.describe())
+            }
+            Err(message) => ChangeEvent::Error(self.describe(), message),
+        };
+
+        event_listener.handle(event);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mock_it::{any, eq};
+
+    use crate::{
+        core::{
+            commands::Command,
+            events::{Change, ChangeEntity, ChangeEvent, ChangeEventListenerMock},
+        },
+        guild::{ExistingGuild, GuildCommanderMock},
+        tests::fixtures::{
+            awaiting::AwaitingAutoModRuleFixture,
+            existing::{ExistingAutoModRuleFixture, ExistingGuildFixture},
+        },
+    };
+
+    use super::{AddAutoModRule, DeleteAutoModRule, UpdateAutoModRule};
+
+    const AN_ERROR_MESSAGE: &str = "Unexpected error";
+    const A_RULE_NAME: &str = "rule abc";
+
+    fn setup() -> (GuildCommanderMock, ChangeEventListenerMock, ExistingGuild) {
+        let commander = GuildCommanderMock::new();
+        let event_listener = ChangeEventListenerMock::new();
+        let existing_guild = ExistingGuildFixture::new().build();
+
+        event_listener.when_handle(any()).will_return_default();
+
+        (commander, event_listener, existing_guild)
+    }
+
+    #[test]
+    fn when_adding_rule_should_add_rule_with_commander() {
+        let (commander, event_listener, mut existing_guild) = setup();
+        commander
+            .when_add_automod_rule(any(), any(), any())
+            .will_return(Ok(ExistingAutoModRuleFixture::new().build()));
+
+        let add_command = AddAutoModRule::new(AwaitingAutoModRuleFixture::new().build());
+        add_command.execute(&commander, &event_listener, &mut existing_guild);
+
+        commander.expect_add_automod_rule(
+            eq(&add_command.rule),
+            eq(existing_guild.roles()),
+            eq(existing_guild.channels()),
+        );
+    }
+
+    #[test]
+    fn given_succeeding_commander_when_adding_rule_should_notify_of_success_and_add_existing_rule()
+    {
+        let (commander, event_listener, mut existing_guild) = setup();
+        let created_rule = ExistingAutoModRuleFixture::new().build();
+        commander
+            .when_add_automod_rule(any(), any(), any())
+            .will_return(Ok(created_rule.clone()));
+
+        let add_command = AddAutoModRule::new(AwaitingAutoModRuleFixture::new().build());
+        add_command.execute(&commander, &event_listener, &mut existing_guild);
+
+        event_listener.expect_handle(eq(ChangeEvent::Success(Change::Create(
+            ChangeEntity::AutoModRule,
+            add_command.rule.name.to_string(),
+        ))));
+        assert_eq!(
+            existing_guild.automod_rules().to_list(),
+            vec![&created_rule]
+        );
+    }
+
+    #[test]
+    fn given_failing_commander_when_updating_rule_should_notify_of_error() {
+        let (commander, event_listener, mut existing_guild) = setup();
+        commander
+            .when_update_automod_rule(any(), any(), any(), any())
+            .will_return(Err(AN_ERROR_MESSAGE.to_string()));
+
+        let update_command = UpdateAutoModRule::new(
+            ExistingAutoModRuleFixture::new()
+                .with_name(A_RULE_NAME)
+                .build(),
+            AwaitingAutoModRuleFixture::new()
+                .with_name(A_RULE_NAME)
+                .build(),
+        );
+        update_command.execute(&commander, &event_listener, &mut existing_guild);
+
+        event_listener.expect_handle(eq(ChangeEvent::Error(
+            Change::Update(ChangeEntity::AutoModRule, A_RULE_NAME.to_string()),
+            AN_ERROR_MESSAGE.to_string(),
+        )));
+    }
+
+    #[test]
+    fn given_succeeding_commander_when_deleting_rule_should_notify_of_success_and_remove_existing_rule(
+    ) {
+        let (commander, event_listener, mut existing_guild) = setup();
+        commander
+            .when_delete_automod_rule(any())
+            .will_return(Ok(()));
+
+        let delete_command = DeleteAutoModRule::new(ExistingAutoModRuleFixture::new().build());
+        existing_guild.add_or_replace_automod_rule(delete_command.rule.clone());
+
+        delete_command.execute(&commander, &event_listener, &mut existing_guild);
+
+        commander.expect_delete_automod_rule(eq(&delete_command.rule.id));
+        assert!(existing_guild.automod_rules().to_list().is_empty());
+    }
+}
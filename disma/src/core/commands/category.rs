@@ -47,7 +47,14 @@ pub struct UpdateCategory {
 }
 
 impl UpdateCategory {
-    pub fn new(existing_category: ExistingCategory, awaiting_category: AwaitingCategory) -> Self {
+    pub fn new(
+        existing_category: ExistingCategory,
+        mut awaiting_category: AwaitingCategory,
+    ) -> Self {
+        awaiting_category.overwrites = awaiting_category
+            .overwrites
+            .with_unknown_bits_of(&existing_category.overwrites);
+
         Self {
             existing_category,
             awaiting_category,
@@ -54,7 +54,11 @@ pub struct UpdateChannel {
 }
 
 impl UpdateChannel {
-    pub fn new(existing_channel: ExistingChannel, awaiting_channel: AwaitingChannel) -> Self {
+    pub fn new(existing_channel: ExistingChannel, mut awaiting_channel: AwaitingChannel) -> Self {
+        awaiting_channel.overwrites = awaiting_channel
+            .overwrites
+            .with_unknown_bits_of(&existing_channel.overwrites);
+
         Self {
             existing_channel,
             awaiting_channel,
@@ -84,7 +88,8 @@ impl Command for UpdateChannel {
         );
 
         let event = match result {
-            Ok(channel) => {
+            Ok(mut channel) => {
+                channel.threads = self.existing_channel.threads.clone();
                 existing_guild.add_or_replace_channel(channel);
                 ChangeEvent::Success(self.describe())
             }
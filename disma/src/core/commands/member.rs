@@ -0,0 +1,168 @@
+use crate::{
+    core::events::{Change, ChangeEntity, ChangeEvent, ChangeEventListener},
+    guild::{ExistingGuild, GuildCommander},
+    member::ExistingMember,
+    role::{AwaitingRole, ExistingRole, Role},
+};
+
+use super::Command;
+
+pub struct UpdateMemberRoles {
+    member: ExistingMember,
+    roles_to_add: Vec<AwaitingRole>,
+    roles_to_remove: Vec<ExistingRole>,
+}
+
+impl UpdateMemberRoles {
+    pub fn new(
+        member: ExistingMember,
+        roles_to_add: Vec<AwaitingRole>,
+        roles_to_remove: Vec<ExistingRole>,
+    ) -> Self {
+        Self {
+            member,
+            roles_to_add,
+            roles_to_remove,
+        }
+    }
+
+    fn describe(&self) -> Change {
+        Change::Update(ChangeEntity::Member, self.member.display_name())
+    }
+}
+
+impl Command for UpdateMemberRoles {
+    fn execute(
+        &self,
+        commander: &dyn GuildCommander,
+        event_listener: &dyn ChangeEventListener,
+        existing_guild: &mut ExistingGuild,
+    ) {
+        let mut member = self.member.clone();
+        let mut errors: Vec<String> = Vec::new();
+
+        for awaiting_role in self.roles_to_add.iter() {
+            // Roles may have been created earlier in the same run
+            let role = match existing_guild.roles().find_by_name(awaiting_role.name()) {
+                Some(role) => role.clone(),
+                None => {
+                    errors.push(format!("Role '{}' does not exist", awaiting_role.name()));
+                    continue;
+                }
+            };
+
+            match commander.add_member_role(&member.id, &role.id) {
+                Ok(()) => member.roles.add(role),
+                Err(message) => errors.push(message),
+            }
+        }
+
+        for role in self.roles_to_remove.iter() {
+            match commander.remove_member_role(&member.id, &role.id) {
+                Ok(()) => member.roles.remove(role.clone()),
+                Err(message) => errors.push(message),
+            }
+        }
+
+        existing_guild.add_or_replace_member(member);
+
+        let event = match errors.is_empty() {
+            true => ChangeEvent::Success(self.describe()),
+            false => ChangeEvent::Error(self.describe(), errors.join("\n")),
+        };
+
+        event_listener.handle(event);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mock_it::{any, eq};
+
+    use crate::{
+        core::{
+            commands::Command,
+            events::{Change, ChangeEntity, ChangeEvent, ChangeEventListenerMock},
+        },
+        guild::GuildCommanderMock,
+        role::RolesList,
+        tests::fixtures::{
+            awaiting::AwaitingRoleFixture,
+            existing::{ExistingGuildFixture, ExistingMemberFixture, ExistingRoleFixture},
+        },
+    };
+
+    use super::UpdateMemberRoles;
+
+    const A_ROLE_NAME: &str = "Staff";
+    const AN_ERROR_MESSAGE: &str = "Unexpected error";
+
+    #[test]
+    fn when_updating_member_roles_should_add_and_remove_roles_with_commander() {
+        let commander = GuildCommanderMock::new();
+        let event_listener = ChangeEventListenerMock::new();
+        let role_to_add = ExistingRoleFixture::new().with_name(A_ROLE_NAME).build();
+        let role_to_remove = ExistingRoleFixture::new().build();
+        let member = ExistingMemberFixture::new()
+            .with_roles(RolesList::from(vec![role_to_remove.clone()]))
+            .build();
+        let mut existing_guild = ExistingGuildFixture::new()
+            .with_role(role_to_add.clone())
+            .with_member(member.clone())
+            .build();
+        event_listener.when_handle(any()).will_return_default();
+        commander
+            .when_add_member_role(any(), any())
+            .will_return(Ok(()));
+        commander
+            .when_remove_member_role(any(), any())
+            .will_return(Ok(()));
+
+        let command = UpdateMemberRoles::new(
+            member.clone(),
+            vec![AwaitingRoleFixture::new().with_name(A_ROLE_NAME).build()],
+            vec![role_to_remove.clone()],
+        );
+        command.execute(&commander, &event_listener, &mut existing_guild);
+
+        commander.expect_add_member_role(eq(&member.id), eq(&role_to_add.id));
+        commander.expect_remove_member_role(eq(&member.id), eq(&role_to_remove.id));
+        assert_eq!(
+            existing_guild
+                .members()
+                .find_by_id(&member.id)
+                .unwrap()
+                .roles,
+            RolesList::from(vec![role_to_add])
+        );
+        event_listener.expect_handle(eq(ChangeEvent::Success(Change::Update(
+            ChangeEntity::Member,
+            member.display_name(),
+        ))));
+    }
+
+    #[test]
+    fn given_failing_commander_when_updating_member_roles_should_notify_of_error() {
+        let commander = GuildCommanderMock::new();
+        let event_listener = ChangeEventListenerMock::new();
+        let role = ExistingRoleFixture::new().with_name(A_ROLE_NAME).build();
+        let member = ExistingMemberFixture::new().build();
+        let mut existing_guild = ExistingGuildFixture::new().with_role(role).build();
+        event_listener.when_handle(any()).will_return_default();
+        commander
+            .when_add_member_role(any(), any())
+            .will_return(Err(AN_ERROR_MESSAGE.to_string()));
+
+        let command = UpdateMemberRoles::new(
+            member.clone(),
+            vec![AwaitingRoleFixture::new().with_name(A_ROLE_NAME).build()],
+            vec![],
+        );
+        command.execute(&commander, &event_listener, &mut existing_guild);
+
+        event_listener.expect_handle(eq(ChangeEvent::Error(
+            Change::Update(ChangeEntity::Member, member.display_name()),
+            AN_ERROR_MESSAGE.to_string(),
+        )));
+    }
+}
@@ -0,0 +1,93 @@
+use crate::{
+    core::events::{Change, ChangeEntity, ChangeEvent, ChangeEventListener},
+    guild::{ExistingGuild, GuildCommander},
+    onboarding::AwaitingOnboarding,
+};
+
+use super::Command;
+
+pub struct UpdateOnboarding {
+    onboarding: AwaitingOnboarding,
+}
+
+impl UpdateOnboarding {
+    pub fn new(onboarding: AwaitingOnboarding) -> Self {
+        Self { onboarding }
+    }
+
+    fn describe(&self) -> Change {
+        Change::Update(ChangeEntity::Onboarding, "settings".to_string())
+    }
+}
+
+impl Command for UpdateOnboarding {
+    fn execute(
+        &self,
+        commander: &dyn GuildCommander,
+        event_listener: &dyn ChangeEventListener,
+        existing_guild: &mut ExistingGuild,
+    ) {
+        let result = commander.update_onboarding(
+            &self.onboarding,
+            existing_guild.roles(),
+            existing_guild.channels(),
+        );
+
+        let event = match result {
+            Ok(onboarding) => {
+                existing_guild.replace_onboarding(onboarding);
+                ChangeEvent::Success(self.describe())
+            }
+            Err(message) => ChangeEvent::Error(self.describe(), message),
+        };
+
+        event_listener.handle(event);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mock_it::{any, eq};
+
+    use crate::{
+        core::{
+            commands::Command,
+            events::{Change, ChangeEntity, ChangeEvent, ChangeEventListenerMock},
+        },
+        guild::GuildCommanderMock,
+        onboarding::{AwaitingOnboarding, ExistingOnboarding},
+        tests::fixtures::existing::ExistingGuildFixture,
+    };
+
+    use super::UpdateOnboarding;
+
+    #[test]
+    fn given_succeeding_commander_when_updating_onboarding_should_notify_of_success_and_replace_existing_onboarding(
+    ) {
+        let commander = GuildCommanderMock::new();
+        let event_listener = ChangeEventListenerMock::new();
+        let mut existing_guild = ExistingGuildFixture::new().build();
+        let updated_onboarding = ExistingOnboarding {
+            enabled: true,
+            ..Default::default()
+        };
+        event_listener.when_handle(any()).will_return_default();
+        commander
+            .when_update_onboarding(any(), any(), any())
+            .will_return(Ok(updated_onboarding.clone()));
+
+        let command = UpdateOnboarding::new(AwaitingOnboarding::default());
+        command.execute(&commander, &event_listener, &mut existing_guild);
+
+        commander.expect_update_onboarding(
+            eq(&command.onboarding),
+            eq(existing_guild.roles()),
+            eq(existing_guild.channels()),
+        );
+        event_listener.expect_handle(eq(ChangeEvent::Success(Change::Update(
+            ChangeEntity::Onboarding,
+            "settings".to_string(),
+        ))));
+        assert_eq!(existing_guild.onboarding(), &updated_onboarding);
+    }
+}
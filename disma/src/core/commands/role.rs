@@ -47,7 +47,11 @@ pub struct UpdateRole {
 }
 
 impl UpdateRole {
-    pub fn new(existing_role: ExistingRole, awaiting_role: AwaitingRole) -> Self {
+    pub fn new(existing_role: ExistingRole, mut awaiting_role: AwaitingRole) -> Self {
+        awaiting_role.permissions = awaiting_role
+            .permissions
+            .with_unknown_bits_of(&existing_role.permissions);
+
         Self {
             existing_role,
             awaiting_role,
@@ -0,0 +1,234 @@
+use crate::{
+    core::events::{Change, ChangeEntity, ChangeEvent, ChangeEventListener},
+    guild::{ExistingGuild, GuildCommander},
+    scheduled_event::{AwaitingScheduledEvent, ExistingScheduledEvent},
+};
+
+use super::Command;
+
+pub struct AddScheduledEvent {
+    event: AwaitingScheduledEvent,
+}
+
+impl AddScheduledEvent {
+    pub fn new(event: AwaitingScheduledEvent) -> Self {
+        Self { event }
+    }
+
+    fn describe(&self) -> Change {
+        Change::Create(ChangeEntity::ScheduledEvent, self.event.name.clone())
+    }
+}
+
+impl Command for AddScheduledEvent {
+    fn execute(
+        &self,
+        commander: &dyn GuildCommander,
+        event_listener: &dyn ChangeEventListener,
+        existing_guild: &mut ExistingGuild,
+    ) {
+        let result = commander.add_scheduled_event(&self.event, existing_guild.channels());
+
+        let event = match result {
+            Ok(event) => {
+                existing_guild.add_or_replace_scheduled_event(event);
+                ChangeEvent::Success(self.describe())
+            }
+            Err(message) => ChangeEvent::Error(self.describe(), message),
+        };
+
+        event_listener.handle(event);
+    }
+}
+
+pub struct UpdateScheduledEvent {
+    existing_event: ExistingScheduledEvent,
+    awaiting_event: AwaitingScheduledEvent,
+}
+
+impl UpdateScheduledEvent {
+    pub fn new(
+        existing_event: ExistingScheduledEvent,
+        awaiting_event: AwaitingScheduledEvent,
+    ) -> Self {
+        Self {
+            existing_event,
+            awaiting_event,
+        }
+    }
+
+    fn describe(&self) -> Change {
+        Change::Update(
+            ChangeEntity::ScheduledEvent,
+            self.existing_event.name.clone(),
+        )
+    }
+}
+
+impl Command for UpdateScheduledEvent {
+    fn execute(
+        &self,
+        commander: &dyn GuildCommander,
+        event_listener: &dyn ChangeEventListener,
+        existing_guild: &mut ExistingGuild,
+    ) {
+        let result = commander.update_scheduled_event(
+            &self.existing_event.id,
+            &self.awaiting_event,
+            existing_guild.channels(),
+        );
+
+        let event = match result {
+            Ok(event) => {
+                existing_guild.add_or_replace_scheduled_event(event);
+                ChangeEvent::Success(self.describe())
+            }
+            Err(message) => ChangeEvent::Error(self.describe(), message),
+        };
+
+        event_listener.handle(event);
+    }
+}
+
+pub struct DeleteScheduledEvent {
+    event: ExistingScheduledEvent,
+}
+
+impl DeleteScheduledEvent {
+    pub fn new(event: ExistingScheduledEvent) -> Self {
+        Self { event }
+    }
+
+    fn describe(&self) -> Change {
+        Change::Delete(ChangeEntity::ScheduledEvent, self.event.name.clone())
+    }
+}
+
+impl Command for DeleteScheduledEvent {
+    fn execute(
+        &self,
+        commander: &dyn GuildCommander,
+        event_listener: &dyn ChangeEventListener,
+        existing_guild: &mut ExistingGuild,
+    ) {
+        let result = commander.delete_scheduled_event(&self.event.id);
+
+        let event = match result {
+            Ok(()) => {
+                existing_guild.remove_scheduled_event(self.event.clone());
+                ChangeEvent::Success(self.describe())
+            }
+            Err(message) => ChangeEvent::Error(self.describe(), message),
+        };
+
+        event_listener.handle(event);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mock_it::{any, eq};
+
+    use crate::{
+        core::{
+            commands::Command,
+            events::{Change, ChangeEntity, ChangeEvent, ChangeEventListenerMock},
+        },
+        guild::{ExistingGuild, GuildCommanderMock},
+        tests::fixtures::{
+            awaiting::AwaitingScheduledEventFixture,
+            existing::{ExistingGuildFixture, ExistingScheduledEventFixture},
+        },
+    };
+
+    use super::{AddScheduledEvent, DeleteScheduledEvent, UpdateScheduledEvent};
+
+    const AN_ERROR_MESSAGE: &str = "Unexpected error";
+    const AN_EVENT_NAME: &str = "event abc";
+
+    fn setup() -> (GuildCommanderMock, ChangeEventListenerMock, ExistingGuild) {
+        let commander = GuildCommanderMock::new();
+        let event_listener = ChangeEventListenerMock::new();
+        let existing_guild = ExistingGuildFixture::new().build();
+
+        event_listener.when_handle(any()).will_return_default();
+
+        (commander, event_listener, existing_guild)
+    }
+
+    #[test]
+    fn when_adding_event_should_add_event_with_commander() {
+        let (commander, event_listener, mut existing_guild) = setup();
+        commander
+            .when_add_scheduled_event(any(), any())
+            .will_return(Ok(ExistingScheduledEventFixture::new().build()));
+
+        let add_command = AddScheduledEvent::new(AwaitingScheduledEventFixture::new().build());
+        add_command.execute(&commander, &event_listener, &mut existing_guild);
+
+        commander.expect_add_scheduled_event(eq(&add_command.event), eq(existing_guild.channels()));
+    }
+
+    #[test]
+    fn given_succeeding_commander_when_adding_event_should_notify_of_success_and_add_existing_event(
+    ) {
+        let (commander, event_listener, mut existing_guild) = setup();
+        let created_event = ExistingScheduledEventFixture::new().build();
+        commander
+            .when_add_scheduled_event(any(), any())
+            .will_return(Ok(created_event.clone()));
+
+        let add_command = AddScheduledEvent::new(AwaitingScheduledEventFixture::new().build());
+        add_command.execute(&commander, &event_listener, &mut existing_guild);
+
+        event_listener.expect_handle(eq(ChangeEvent::Success(Change::Create(
+            ChangeEntity::ScheduledEvent,
+            add_command.event.name.to_string(),
+        ))));
+        assert_eq!(
+            existing_guild.scheduled_events().to_list(),
+            vec![&created_event]
+        );
+    }
+
+    #[test]
+    fn given_failing_commander_when_updating_event_should_notify_of_error() {
+        let (commander, event_listener, mut existing_guild) = setup();
+        commander
+            .when_update_scheduled_event(any(), any(), any())
+            .will_return(Err(AN_ERROR_MESSAGE.to_string()));
+
+        let update_command = UpdateScheduledEvent::new(
+            ExistingScheduledEventFixture::new()
+                .with_name(AN_EVENT_NAME)
+                .build(),
+            AwaitingScheduledEventFixture::new()
+                .with_name(AN_EVENT_NAME)
+                .build(),
+        );
+        update_command.execute(&commander, &event_listener, &mut existing_guild);
+
+        event_listener.expect_handle(eq(ChangeEvent::Error(
+            Change::Update(ChangeEntity::ScheduledEvent, AN_EVENT_NAME.to_string()),
+            AN_ERROR_MESSAGE.to_string(),
+        )));
+    }
+
+    #[test]
+    fn given_succeeding_commander_when_deleting_event_should_notify_of_success_and_remove_existing_event(
+    ) {
+        let (commander, event_listener, mut existing_guild) = setup();
+        commander
+            .when_delete_scheduled_event(any())
+            .will_return(Ok(()));
+
+        let delete_command =
+            DeleteScheduledEvent::new(ExistingScheduledEventFixture::new().build());
+        existing_guild.add_or_replace_scheduled_event(delete_command.event.clone());
+
+        delete_command.execute(&commander, &event_listener, &mut existing_guild);
+
+        commander.expect_delete_scheduled_event(eq(&delete_command.event.id));
+        assert!(existing_guild.scheduled_events().to_list().is_empty());
+    }
+}
@@ -0,0 +1,267 @@
+use crate::{
+    channel::{AwaitingChannel, AwaitingThread, Channel, ExistingChannel, ExistingThread},
+    core::events::{Change, ChangeEntity, ChangeEvent, ChangeEventListener},
+    guild::{ExistingGuild, GuildCommander},
+};
+
+use super::Command;
+
+pub struct AddThread {
+    channel: AwaitingChannel,
+    thread: AwaitingThread,
+}
+
+impl AddThread {
+    pub fn new(channel: AwaitingChannel, thread: AwaitingThread) -> Self {
+        Self { channel, thread }
+    }
+
+    fn describe(&self) -> Change {
+        Change::Create(
+            ChangeEntity::Thread,
+            format!("{} > {}", self.channel.unique_name(), self.thread.name),
+        )
+    }
+}
+
+impl Command for AddThread {
+    fn execute(
+        &self,
+        commander: &dyn GuildCommander,
+        event_listener: &dyn ChangeEventListener,
+        existing_guild: &mut ExistingGuild,
+    ) {
+        // The channel may have been created earlier in the same run
+        let channel = existing_guild
+            .channels()
+            .find_by_unique_name(&self.channel.unique_name())
+            .cloned();
+
+        let event = match channel {
+            Some(mut channel) => match commander.add_thread(&channel.id, &self.thread) {
+                Ok(thread) => {
+                    channel.add_or_replace_thread(thread);
+                    existing_guild.add_or_replace_channel(channel);
+                    ChangeEvent::Success(self.describe())
+                }
+                Err(message) => ChangeEvent::Error(self.describe(), message),
+            },
+            None => ChangeEvent::Error(
+                self.describe(),
+                format!("Channel '{}' does not exist", self.channel.unique_name()),
+            ),
+        };
+
+        event_listener.handle(event);
+    }
+}
+
+pub struct UpdateThread {
+    channel: ExistingChannel,
+    existing_thread: ExistingThread,
+    awaiting_thread: AwaitingThread,
+}
+
+impl UpdateThread {
+    pub fn new(
+        channel: ExistingChannel,
+        existing_thread: ExistingThread,
+        awaiting_thread: AwaitingThread,
+    ) -> Self {
+        Self {
+            channel,
+            existing_thread,
+            awaiting_thread,
+        }
+    }
+
+    fn describe(&self) -> Change {
+        Change::Update(
+            ChangeEntity::Thread,
+            format!(
+                "{} > {}",
+                self.channel.unique_name(),
+                self.existing_thread.name
+            ),
+        )
+    }
+}
+
+impl Command for UpdateThread {
+    fn execute(
+        &self,
+        commander: &dyn GuildCommander,
+        event_listener: &dyn ChangeEventListener,
+        existing_guild: &mut ExistingGuild,
+    ) {
+        let result = commander.update_thread(&self.existing_thread.id, &self.awaiting_thread);
+
+        let event = match result {
+            Ok(thread) => {
+                if let Some(mut channel) = existing_guild
+                    .channels()
+                    .find_by_id(&self.channel.id)
+                    .cloned()
+                {
+                    channel.add_or_replace_thread(thread);
+                    existing_guild.add_or_replace_channel(channel);
+                }
+                ChangeEvent::Success(self.describe())
+            }
+            Err(message) => ChangeEvent::Error(self.describe(), message),
+        };
+
+        event_listener.handle(event);
+    }
+}
+
+pub struct ArchiveThread {
+    channel: ExistingChannel,
+    thread: ExistingThread,
+}
+
+impl ArchiveThread {
+    pub fn new(channel: ExistingChannel, thread: ExistingThread) -> Self {
+        Self { channel, thread }
+    }
+
+    fn describe(&self) -> Change {
+        Change::Delete(
+            ChangeEntity::Thread,
+            format!("{} > {}", self.channel.unique_name(), self.thread.name),
+        )
+    }
+}
+
+impl Command for ArchiveThread {
+    fn execute(
+        &self,
+        commander: &dyn GuildCommander,
+        event_listener: &dyn ChangeEventListener,
+        existing_guild: &mut ExistingGuild,
+    ) {
+        let result = commander.archive_thread(&self.thread.id);
+
+        let event = match result {
+            Ok(()) => {
+                if let Some(mut channel) = existing_guild
+                    .channels()
+                    .find_by_id(&self.channel.id)
+                    .cloned()
+                {
+                    channel.remove_thread(&self.thread.id);
+                    existing_guild.add_or_replace_channel(channel);
+                }
+                ChangeEvent::Success(self.describe())
+            }
+            Err(message) => ChangeEvent::Error(self.describe(), message),
+        };
+
+        event_listener.handle(event);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mock_it::{any, eq};
+
+    use crate::{
+        core::{
+            commands::Command,
+            events::{Change, ChangeEntity, ChangeEvent, ChangeEventListenerMock},
+        },
+        guild::GuildCommanderMock,
+        tests::fixtures::{
+            awaiting::{AwaitingChannelFixture, AwaitingThreadFixture},
+            existing::{ExistingChannelFixture, ExistingGuildFixture, ExistingThreadFixture},
+        },
+    };
+
+    use super::{AddThread, ArchiveThread};
+
+    const A_CHANNEL_NAME: &str = "faq";
+    const AN_ERROR_MESSAGE: &str = "Unexpected error";
+
+    #[test]
+    fn when_adding_thread_should_add_thread_to_existing_channel() {
+        let commander = GuildCommanderMock::new();
+        let event_listener = ChangeEventListenerMock::new();
+        let channel = ExistingChannelFixture::new()
+            .with_name(A_CHANNEL_NAME)
+            .build();
+        let mut existing_guild = ExistingGuildFixture::new()
+            .with_channel(channel.clone())
+            .build();
+        let thread = AwaitingThreadFixture::new().build();
+        event_listener.when_handle(any()).will_return_default();
+        commander
+            .when_add_thread(any(), any())
+            .will_return(Ok(ExistingThreadFixture::new().build()));
+
+        let command = AddThread::new(
+            AwaitingChannelFixture::new()
+                .with_name(A_CHANNEL_NAME)
+                .build(),
+            thread.clone(),
+        );
+        command.execute(&commander, &event_listener, &mut existing_guild);
+
+        commander.expect_add_thread(eq(&channel.id), eq(&thread));
+        assert_eq!(
+            existing_guild
+                .channels()
+                .find_by_id(&channel.id)
+                .unwrap()
+                .threads
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn given_missing_channel_when_adding_thread_should_notify_of_error() {
+        let commander = GuildCommanderMock::new();
+        let event_listener = ChangeEventListenerMock::new();
+        let mut existing_guild = ExistingGuildFixture::new().build();
+        event_listener.when_handle(any()).will_return_default();
+
+        let command = AddThread::new(
+            AwaitingChannelFixture::new()
+                .with_name(A_CHANNEL_NAME)
+                .build(),
+            AwaitingThreadFixture::new().build(),
+        );
+        command.execute(&commander, &event_listener, &mut existing_guild);
+
+        event_listener.expect_handle(eq(ChangeEvent::Error(
+            Change::Create(ChangeEntity::Thread, ":faq (TEXT) > faq".to_string()),
+            "Channel ':faq (TEXT)' does not exist".to_string(),
+        )));
+    }
+
+    #[test]
+    fn given_failing_commander_when_archiving_thread_should_notify_of_error() {
+        let commander = GuildCommanderMock::new();
+        let event_listener = ChangeEventListenerMock::new();
+        let thread = ExistingThreadFixture::new().with_name("rules").build();
+        let channel = ExistingChannelFixture::new()
+            .with_name(A_CHANNEL_NAME)
+            .with_threads(vec![thread.clone()])
+            .build();
+        let mut existing_guild = ExistingGuildFixture::new()
+            .with_channel(channel.clone())
+            .build();
+        event_listener.when_handle(any()).will_return_default();
+        commander
+            .when_archive_thread(any())
+            .will_return(Err(AN_ERROR_MESSAGE.to_string()));
+
+        let command = ArchiveThread::new(channel, thread);
+        command.execute(&commander, &event_listener, &mut existing_guild);
+
+        event_listener.expect_handle(eq(ChangeEvent::Error(
+            Change::Delete(ChangeEntity::Thread, ":faq (TEXT) > rules".to_string()),
+            AN_ERROR_MESSAGE.to_string(),
+        )));
+    }
+}
@@ -0,0 +1,81 @@
+use crate::{
+    core::events::{Change, ChangeEntity, ChangeEvent, ChangeEventListener},
+    guild::{ExistingGuild, GuildCommander},
+    welcome_screen::AwaitingWelcomeScreen,
+};
+
+use super::Command;
+
+pub struct UpdateWelcomeScreen {
+    welcome_screen: AwaitingWelcomeScreen,
+}
+
+impl UpdateWelcomeScreen {
+    pub fn new(welcome_screen: AwaitingWelcomeScreen) -> Self {
+        Self { welcome_screen }
+    }
+
+    fn describe(&self) -> Change {
+        Change::Update(ChangeEntity::WelcomeScreen, "settings".to_string())
+    }
+}
+
+impl Command for UpdateWelcomeScreen {
+    fn execute(
+        &self,
+        commander: &dyn GuildCommander,
+        event_listener: &dyn ChangeEventListener,
+        existing_guild: &mut ExistingGuild,
+    ) {
+        let result =
+            commander.update_welcome_screen(&self.welcome_screen, existing_guild.channels());
+
+        let event = match result {
+            Ok(welcome_screen) => {
+                existing_guild.replace_welcome_screen(welcome_screen);
+                ChangeEvent::Success(self.describe())
+            }
+            Err(message) => ChangeEvent::Error(self.describe(), message),
+        };
+
+        event_listener.handle(event);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mock_it::{any, eq};
+
+    use crate::{
+        core::{
+            commands::Command,
+            events::{Change, ChangeEntity, ChangeEvent, ChangeEventListenerMock},
+        },
+        guild::GuildCommanderMock,
+        tests::fixtures::existing::ExistingGuildFixture,
+        welcome_screen::AwaitingWelcomeScreen,
+    };
+
+    use super::UpdateWelcomeScreen;
+
+    const AN_ERROR_MESSAGE: &str = "Unexpected error";
+
+    #[test]
+    fn given_failing_commander_when_updating_welcome_screen_should_notify_of_error() {
+        let commander = GuildCommanderMock::new();
+        let event_listener = ChangeEventListenerMock::new();
+        let mut existing_guild = ExistingGuildFixture::new().build();
+        event_listener.when_handle(any()).will_return_default();
+        commander
+            .when_update_welcome_screen(any(), any())
+            .will_return(Err(AN_ERROR_MESSAGE.to_string()));
+
+        let command = UpdateWelcomeScreen::new(AwaitingWelcomeScreen::default());
+        command.execute(&commander, &event_listener, &mut existing_guild);
+
+        event_listener.expect_handle(eq(ChangeEvent::Error(
+            Change::Update(ChangeEntity::WelcomeScreen, "settings".to_string()),
+            AN_ERROR_MESSAGE.to_string(),
+        )));
+    }
+}
@@ -0,0 +1,294 @@
+use crate::{
+    automod::{AutoModTrigger, AwaitingAutoModRule, ExistingAutoModRule},
+    channel::Channel,
+    core::diffs::{Diff, Differ},
+    role::Role,
+    utils::misc::IfThen,
+};
+
+impl Differ<AwaitingAutoModRule> for ExistingAutoModRule {
+    fn diffs_with(&self, awaiting: &AwaitingAutoModRule) -> Vec<Diff> {
+        let mut all_diffs = vec![];
+
+        self.event_type
+            .to_string()
+            .diffs_with(&awaiting.event_type.to_string())
+            .if_then(
+                |diffs| !diffs.is_empty(),
+                |diffs| all_diffs.push(Diff::Update("event_type".into(), diffs)),
+            );
+
+        self.trigger.diffs_with(&awaiting.trigger).if_then(
+            |diffs| !diffs.is_empty(),
+            |diffs| all_diffs.push(Diff::Update("trigger".into(), diffs)),
+        );
+
+        let existing_actions: Vec<String> = self
+            .actions
+            .iter()
+            .map(|action| action.describe())
+            .collect();
+        let awaiting_actions: Vec<String> = awaiting
+            .actions
+            .iter()
+            .map(|action| action.describe())
+            .collect();
+        existing_actions.diffs_with(&awaiting_actions).if_then(
+            |diffs| !diffs.is_empty(),
+            |diffs| all_diffs.push(Diff::Update("actions".into(), diffs)),
+        );
+
+        self.enabled.diffs_with(&awaiting.enabled).if_then(
+            |diffs| !diffs.is_empty(),
+            |diffs| all_diffs.push(Diff::Update("enabled".into(), diffs)),
+        );
+
+        let existing_roles: Vec<&str> = self
+            .exempt_roles
+            .to_list()
+            .into_iter()
+            .map(|role| role.name())
+            .collect();
+        let awaiting_roles: Vec<&str> = awaiting
+            .exempt_roles
+            .to_list()
+            .into_iter()
+            .map(|role| role.name())
+            .collect();
+        existing_roles.diffs_with(&awaiting_roles).if_then(
+            |diffs| !diffs.is_empty(),
+            |diffs| all_diffs.push(Diff::Update("exempt_roles".into(), diffs)),
+        );
+
+        let existing_channels: Vec<String> = self
+            .exempt_channels
+            .to_list()
+            .into_iter()
+            .map(|channel| channel.unique_name().to_string())
+            .collect();
+        let awaiting_channels: Vec<String> = awaiting
+            .exempt_channels
+            .to_list()
+            .into_iter()
+            .map(|channel| channel.unique_name().to_string())
+            .collect();
+        existing_channels.diffs_with(&awaiting_channels).if_then(
+            |diffs| !diffs.is_empty(),
+            |diffs| all_diffs.push(Diff::Update("exempt_channels".into(), diffs)),
+        );
+
+        all_diffs
+    }
+}
+
+impl Differ<AutoModTrigger> for AutoModTrigger {
+    fn diffs_with(&self, target: &AutoModTrigger) -> Vec<Diff> {
+        let mut all_diffs = vec![];
+
+        match (self, target) {
+            (
+                AutoModTrigger::Keyword {
+                    keywords,
+                    regex_patterns,
+                    allow_list,
+                },
+                AutoModTrigger::Keyword {
+                    keywords: target_keywords,
+                    regex_patterns: target_regex_patterns,
+                    allow_list: target_allow_list,
+                },
+            ) => {
+                keywords.diffs_with(target_keywords).if_then(
+                    |diffs| !diffs.is_empty(),
+                    |diffs| all_diffs.push(Diff::Update("keywords".into(), diffs)),
+                );
+                regex_patterns.diffs_with(target_regex_patterns).if_then(
+                    |diffs| !diffs.is_empty(),
+                    |diffs| all_diffs.push(Diff::Update("regex_patterns".into(), diffs)),
+                );
+                allow_list.diffs_with(target_allow_list).if_then(
+                    |diffs| !diffs.is_empty(),
+                    |diffs| all_diffs.push(Diff::Update("allow_list".into(), diffs)),
+                );
+            }
+            (AutoModTrigger::Spam, AutoModTrigger::Spam) => {}
+            (
+                AutoModTrigger::KeywordPreset {
+                    presets,
+                    allow_list,
+                },
+                AutoModTrigger::KeywordPreset {
+                    presets: target_presets,
+                    allow_list: target_allow_list,
+                },
+            ) => {
+                presets.diffs_with(target_presets).if_then(
+                    |diffs| !diffs.is_empty(),
+                    |diffs| all_diffs.push(Diff::Update("presets".into(), diffs)),
+                );
+                allow_list.diffs_with(target_allow_list).if_then(
+                    |diffs| !diffs.is_empty(),
+                    |diffs| all_diffs.push(Diff::Update("allow_list".into(), diffs)),
+                );
+            }
+            (
+                AutoModTrigger::MentionSpam {
+                    mention_total_limit,
+                    mention_raid_protection_enabled,
+                },
+                AutoModTrigger::MentionSpam {
+                    mention_total_limit: target_mention_total_limit,
+                    mention_raid_protection_enabled: target_mention_raid_protection_enabled,
+                },
+            ) => {
+                mention_total_limit
+                    .to_string()
+                    .diffs_with(&target_mention_total_limit.to_string())
+                    .if_then(
+                        |diffs| !diffs.is_empty(),
+                        |diffs| all_diffs.push(Diff::Update("mention_total_limit".into(), diffs)),
+                    );
+                mention_raid_protection_enabled
+                    .diffs_with(target_mention_raid_protection_enabled)
+                    .if_then(
+                        |diffs| !diffs.is_empty(),
+                        |diffs| {
+                            all_diffs.push(Diff::Update(
+                                "mention_raid_protection_enabled".into(),
+                                diffs,
+                            ))
+                        },
+                    );
+            }
+            (origin, target) => {
+                all_diffs.push(Diff::Update(
+                    "type".into(),
+                    origin
+                        .trigger_type()
+                        .to_string()
+                        .diffs_with(&target.trigger_type().to_string()),
+                ));
+            }
+        }
+
+        all_diffs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        automod::{AutoModAction, AutoModKeywordPreset, AutoModTrigger},
+        core::diffs::{Diff, Differ},
+        role::RolesList,
+        tests::fixtures::{
+            awaiting::{AwaitingAutoModRuleFixture, AwaitingRoleFixture},
+            existing::{ExistingAutoModRuleFixture, ExistingRoleFixture},
+        },
+    };
+
+    #[test]
+    fn given_same_rules_it_returns_no_diffs() {
+        let origin = ExistingAutoModRuleFixture::new().build();
+        let target = AwaitingAutoModRuleFixture::new().build();
+
+        let diffs = origin.diffs_with(&target);
+
+        assert_eq!(diffs, vec![]);
+    }
+
+    #[test]
+    fn can_diff_keywords() {
+        let origin = AutoModTrigger::Keyword {
+            keywords: vec!["hello".to_string()],
+            regex_patterns: vec![],
+            allow_list: vec![],
+        };
+        let target = AutoModTrigger::Keyword {
+            keywords: vec!["world".to_string()],
+            regex_patterns: vec![],
+            allow_list: vec![],
+        };
+
+        let diffs = origin.diffs_with(&target);
+
+        assert_eq!(
+            diffs,
+            vec![Diff::Update(
+                "keywords".to_string(),
+                vec![Diff::Remove("hello".into()), Diff::Add("world".into())]
+            )]
+        );
+    }
+
+    #[test]
+    fn can_diff_trigger_types() {
+        let origin = AutoModTrigger::Spam;
+        let target = AutoModTrigger::KeywordPreset {
+            presets: vec![AutoModKeywordPreset::SLURS],
+            allow_list: vec![],
+        };
+
+        let diffs = origin.diffs_with(&target);
+
+        assert_eq!(
+            diffs,
+            vec![Diff::Update(
+                "type".to_string(),
+                vec![
+                    Diff::Remove("SPAM".into()),
+                    Diff::Add("KEYWORD_PRESET".into())
+                ]
+            )]
+        );
+    }
+
+    #[test]
+    fn can_diff_actions() {
+        let origin = ExistingAutoModRuleFixture::new()
+            .with_actions(vec![AutoModAction::BlockMessage {
+                custom_message: None,
+            }])
+            .build();
+        let target = AwaitingAutoModRuleFixture::new()
+            .with_actions(vec![AutoModAction::Timeout {
+                duration_seconds: 60,
+            }])
+            .build();
+
+        let diffs = origin.diffs_with(&target);
+
+        assert_eq!(
+            diffs,
+            vec![Diff::Update(
+                "actions".to_string(),
+                vec![
+                    Diff::Remove("BLOCK_MESSAGE".into()),
+                    Diff::Add("TIMEOUT (60s)".into())
+                ]
+            )]
+        );
+    }
+
+    #[test]
+    fn can_diff_exempt_roles() {
+        let existing_role = ExistingRoleFixture::new().with_name("old").build();
+        let awaiting_role = AwaitingRoleFixture::new().with_name("new").build();
+        let origin = ExistingAutoModRuleFixture::new()
+            .with_exempt_roles(RolesList::from(vec![existing_role]))
+            .build();
+        let target = AwaitingAutoModRuleFixture::new()
+            .with_exempt_roles(RolesList::from(vec![awaiting_role]))
+            .build();
+
+        let diffs = origin.diffs_with(&target);
+
+        assert_eq!(
+            diffs,
+            vec![Diff::Update(
+                "exempt_roles".to_string(),
+                vec![Diff::Remove("old".into()), Diff::Add("new".into())]
+            )]
+        );
+    }
+}
@@ -1,8 +1,60 @@
+use std::fmt::Display;
+
 #[derive(Clone, Debug, PartialEq)]
 pub enum Diff {
-    Add(String),
-    Remove(String),
+    Add(DiffValue),
+    Remove(DiffValue),
+    /// A value replaced by another one, for values better shown side by side.
+    Replace(DiffValue, DiffValue),
     Update(String, Vec<Diff>),
+    /// Something worth reporting, that does not require a change.
+    Warning(String),
+}
+
+impl Diff {
+    pub fn is_change(&self) -> bool {
+        match self {
+            Self::Add(_) | Self::Remove(_) | Self::Replace(_, _) => true,
+            Self::Update(_, diffs) => diffs.iter().any(Diff::is_change),
+            Self::Warning(_) => false,
+        }
+    }
+}
+
+/// A diffed value, typed for it to be rendered meaningfully.
+#[derive(Clone, Debug, PartialEq)]
+pub enum DiffValue {
+    Text(String),
+    /// Hex code of a color, ex: `ff0000`.
+    Color(String),
+    /// Text that may span several lines, ex: a channel topic.
+    Multiline(String),
+}
+
+impl DiffValue {
+    pub fn text(&self) -> &str {
+        match self {
+            Self::Text(text) | Self::Color(text) | Self::Multiline(text) => text,
+        }
+    }
+}
+
+impl Display for DiffValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.text())
+    }
+}
+
+impl From<String> for DiffValue {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}
+
+impl From<&str> for DiffValue {
+    fn from(text: &str) -> Self {
+        Self::Text(text.to_string())
+    }
 }
 
 pub trait Differ<T> {
@@ -35,8 +87,8 @@ where
     fn diffs_with(&self, target: &Option<U>) -> Vec<Diff> {
         match (self, target) {
             (None, None) => vec![],
-            (Some(origin), None) => vec![Diff::Remove(origin.to_string())],
-            (None, Some(target)) => vec![Diff::Add(target.to_string())],
+            (Some(origin), None) => vec![Diff::Remove(origin.to_string().into())],
+            (None, Some(target)) => vec![Diff::Add(target.to_string().into())],
             (Some(origin), Some(target)) => origin.diffs_with(target),
         }
     }
@@ -51,13 +103,13 @@ where
 
         for item in self.iter() {
             if !target.contains(item) {
-                diffs.push(Diff::Remove(item.to_string()))
+                diffs.push(Diff::Remove(item.to_string().into()))
             }
         }
 
         for item in target.iter() {
             if !self.contains(item) {
-                diffs.push(Diff::Add(item.to_string()))
+                diffs.push(Diff::Add(item.to_string().into()))
             }
         }
 
@@ -72,13 +124,32 @@ where
     let mut diffs = vec![];
 
     if origin != target {
-        diffs.push(Diff::Remove(origin.to_string()));
-        diffs.push(Diff::Add(target.to_string()));
+        diffs.push(Diff::Remove(origin.to_string().into()));
+        diffs.push(Diff::Add(target.to_string().into()));
     }
 
     diffs
 }
 
+/// Diffs of optional values of a given type (ex: `DiffValue::Color`), a
+/// changed value being replaced.
+pub fn typed_diffs_between(
+    origin: Option<&str>,
+    target: Option<&str>,
+    value: fn(String) -> DiffValue,
+) -> Vec<Diff> {
+    match (origin, target) {
+        (Some(origin), Some(target)) if origin == target => vec![],
+        (None, None) => vec![],
+        (Some(origin), None) => vec![Diff::Remove(value(origin.to_string()))],
+        (None, Some(target)) => vec![Diff::Add(value(target.to_string()))],
+        (Some(origin), Some(target)) => vec![Diff::Replace(
+            value(origin.to_string()),
+            value(target.to_string()),
+        )],
+    }
+}
+
 #[cfg(test)]
 mod tests {
     mod vec_diffs {
@@ -172,4 +243,38 @@ mod tests {
             assert_eq!(diffs, expected_diffs);
         }
     }
+
+    mod typed_diffs {
+        use crate::core::diffs::{typed_diffs_between, Diff, DiffValue};
+
+        #[test]
+        fn given_changed_value_it_replaces_it() {
+            let diffs = typed_diffs_between(Some("ff0000"), Some("00ff00"), DiffValue::Color);
+
+            let expected_diffs = vec![Diff::Replace(
+                DiffValue::Color("ff0000".into()),
+                DiffValue::Color("00ff00".into()),
+            )];
+            assert_eq!(diffs, expected_diffs);
+        }
+
+        #[test]
+        fn given_missing_value_it_adds_or_removes_it() {
+            assert_eq!(
+                typed_diffs_between(None, Some("ff0000"), DiffValue::Color),
+                vec![Diff::Add(DiffValue::Color("ff0000".into()))]
+            );
+            assert_eq!(
+                typed_diffs_between(Some("ff0000"), None, DiffValue::Color),
+                vec![Diff::Remove(DiffValue::Color("ff0000".into()))]
+            );
+        }
+
+        #[test]
+        fn given_same_values_returns_no_diff() {
+            let diffs = typed_diffs_between(Some("ff0000"), Some("ff0000"), DiffValue::Color);
+
+            assert_eq!(diffs.len(), 0);
+        }
+    }
 }
@@ -8,6 +8,11 @@ impl Differ<AwaitingCategory> for ExistingCategory {
     fn diffs_with(&self, awaiting: &AwaitingCategory) -> Vec<Diff> {
         let mut all_diffs = vec![];
 
+        self.name.diffs_with(&awaiting.name).if_then(
+            |diffs| !diffs.is_empty(),
+            |diffs| all_diffs.push(Diff::Update("name".into(), diffs)),
+        );
+
         self.overwrites.diffs_with(&awaiting.overwrites).if_then(
             |diffs| !diffs.is_empty(),
             |diffs| all_diffs.push(Diff::Update("overwrites".into(), diffs)),
@@ -39,6 +44,7 @@ mod tests {
             color: Some("a3bb30".to_string()),
             is_mentionable: true,
             show_in_sidebar: true,
+            is_managed: false,
         }
     }
 
@@ -88,15 +94,15 @@ mod tests {
                     Diff::Update(
                         "allow".to_string(),
                         vec![
-                            Diff::Remove(Permission::ADD_REACTIONS.to_string()),
-                            Diff::Add(Permission::ADMINISTRATOR.to_string()),
+                            Diff::Remove(Permission::ADD_REACTIONS.to_string().into()),
+                            Diff::Add(Permission::ADMINISTRATOR.to_string().into()),
                         ],
                     ),
                     Diff::Update(
                         "deny".to_string(),
                         vec![
-                            Diff::Remove(Permission::ADMINISTRATOR.to_string()),
-                            Diff::Add(Permission::ADD_REACTIONS.to_string()),
+                            Diff::Remove(Permission::ADMINISTRATOR.to_string().into()),
+                            Diff::Add(Permission::ADD_REACTIONS.to_string().into()),
                         ],
                     ),
                 ],
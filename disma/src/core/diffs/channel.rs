@@ -1,6 +1,6 @@
 use crate::{
-    channel::{AwaitingChannel, ExistingChannel},
-    core::diffs::{Diff, Differ},
+    channel::{AwaitingChannel, AwaitingThread, ExistingChannel, ExistingThread},
+    core::diffs::{typed_diffs_between, Diff, DiffValue, Differ},
     utils::misc::IfThen,
 };
 
@@ -8,7 +8,17 @@ impl Differ<AwaitingChannel> for ExistingChannel {
     fn diffs_with(&self, awaiting: &AwaitingChannel) -> Vec<Diff> {
         let mut all_diffs = vec![];
 
-        self.topic.diffs_with(&awaiting.topic).if_then(
+        self.name.diffs_with(&awaiting.name).if_then(
+            |diffs| !diffs.is_empty(),
+            |diffs| all_diffs.push(Diff::Update("name".into(), diffs)),
+        );
+
+        typed_diffs_between(
+            self.topic.as_deref(),
+            awaiting.topic.as_deref(),
+            DiffValue::Multiline,
+        )
+        .if_then(
             |diffs| !diffs.is_empty(),
             |diffs| all_diffs.push(Diff::Update("topic".into(), diffs)),
         );
@@ -36,14 +46,53 @@ impl Differ<AwaitingChannel> for ExistingChannel {
     }
 }
 
+impl Differ<AwaitingThread> for ExistingThread {
+    fn diffs_with(&self, awaiting: &AwaitingThread) -> Vec<Diff> {
+        let mut all_diffs = vec![];
+
+        self.thread_type.diffs_with(&awaiting.thread_type).if_then(
+            |diffs| !diffs.is_empty(),
+            |diffs| all_diffs.push(Diff::Update("type".into(), diffs)),
+        );
+
+        self.auto_archive_duration
+            .to_string()
+            .diffs_with(&awaiting.auto_archive_duration.to_string())
+            .if_then(
+                |diffs| !diffs.is_empty(),
+                |diffs| all_diffs.push(Diff::Update("auto_archive_duration".into(), diffs)),
+            );
+
+        self.locked.diffs_with(&awaiting.locked).if_then(
+            |diffs| !diffs.is_empty(),
+            |diffs| all_diffs.push(Diff::Update("locked".into(), diffs)),
+        );
+
+        self.invitable.diffs_with(&awaiting.invitable).if_then(
+            |diffs| !diffs.is_empty(),
+            |diffs| all_diffs.push(Diff::Update("invitable".into(), diffs)),
+        );
+
+        self.archived.diffs_with(&false).if_then(
+            |diffs| !diffs.is_empty(),
+            |diffs| all_diffs.push(Diff::Update("archived".into(), diffs)),
+        );
+
+        all_diffs
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::sync::Arc;
 
     use crate::{
         category::{AwaitingCategory, ExistingCategory},
-        channel::{AwaitingChannel, ChannelType, ExistingChannel, KeepExtraChannels},
-        core::diffs::{Diff, Differ},
+        channel::{
+            AwaitingChannel, AwaitingThread, ChannelType, ExistingChannel, ExistingThread,
+            KeepExtraChannels, ThreadType,
+        },
+        core::diffs::{Diff, DiffValue, Differ},
         permission::{
             Permission, PermissionsList, PermissionsOverwrite, PermissionsOverwritesList,
         },
@@ -58,6 +107,7 @@ mod tests {
             color: Some("a3bb30".to_string()),
             is_mentionable: true,
             show_in_sidebar: true,
+            is_managed: false,
         }
     }
 
@@ -99,6 +149,7 @@ mod tests {
             channel_type: channel_type.clone(),
             category: None,
             overwrites: PermissionsOverwritesList::from(vec![]),
+            threads: Vec::new(),
         };
 
         let target = AwaitingChannel {
@@ -107,16 +158,17 @@ mod tests {
             channel_type,
             category: None,
             overwrites: PermissionsOverwritesList::from(vec![]),
+            threads: None,
         };
 
         let diffs = origin.diffs_with(&target);
 
         let expected_diffs = vec![Diff::Update(
             "topic".to_string(),
-            vec![
-                Diff::Remove("bang bang!".to_string()),
-                Diff::Add("Not here".to_string()),
-            ],
+            vec![Diff::Replace(
+                DiffValue::Multiline("bang bang!".into()),
+                DiffValue::Multiline("Not here".into()),
+            )],
         )];
         assert_eq!(diffs, expected_diffs);
     }
@@ -133,6 +185,7 @@ mod tests {
             channel_type: ChannelType::TEXT,
             category: None,
             overwrites: PermissionsOverwritesList::from(vec![]),
+            threads: Vec::new(),
         };
 
         let target = AwaitingChannel {
@@ -141,6 +194,7 @@ mod tests {
             channel_type: ChannelType::VOICE,
             category: None,
             overwrites: PermissionsOverwritesList::from(vec![]),
+            threads: None,
         };
 
         let diffs = origin.diffs_with(&target);
@@ -148,8 +202,8 @@ mod tests {
         let expected_diffs = vec![Diff::Update(
             "channel_type".to_string(),
             vec![
-                Diff::Remove(ChannelType::TEXT.to_string()),
-                Diff::Add(ChannelType::VOICE.to_string()),
+                Diff::Remove(ChannelType::TEXT.to_string().into()),
+                Diff::Add(ChannelType::VOICE.to_string().into()),
             ],
         )];
         assert_eq!(diffs, expected_diffs);
@@ -168,6 +222,7 @@ mod tests {
             channel_type: channel_type.clone(),
             category: Some(given_existing_category_with_name("category_a".to_string())),
             overwrites: PermissionsOverwritesList::from(vec![]),
+            threads: Vec::new(),
         };
 
         let target = AwaitingChannel {
@@ -176,6 +231,7 @@ mod tests {
             channel_type,
             category: Some(given_awaiting_category_with_name("category_b".to_string())),
             overwrites: PermissionsOverwritesList::from(vec![]),
+            threads: None,
         };
 
         let diffs = origin.diffs_with(&target);
@@ -183,8 +239,8 @@ mod tests {
         let expected_diffs = vec![Diff::Update(
             "category".to_string(),
             vec![
-                Diff::Remove("category_a".to_string()),
-                Diff::Add("category_b".to_string()),
+                Diff::Remove("category_a".into()),
+                Diff::Add("category_b".into()),
             ],
         )];
         assert_eq!(diffs, expected_diffs);
@@ -208,6 +264,7 @@ mod tests {
                 allow: PermissionsList::from(vec![Permission::READ_MESSAGE_HISTORY]),
                 deny: PermissionsList::from(vec![Permission::SEND_MESSAGES]),
             }]),
+            threads: Vec::new(),
         };
 
         let target = AwaitingChannel {
@@ -220,6 +277,7 @@ mod tests {
                 allow: PermissionsList::from(vec![Permission::SEND_MESSAGES]),
                 deny: PermissionsList::from(vec![Permission::READ_MESSAGE_HISTORY]),
             }]),
+            threads: None,
         };
 
         let diffs = origin.diffs_with(&target);
@@ -232,15 +290,15 @@ mod tests {
                     Diff::Update(
                         "allow".to_string(),
                         vec![
-                            Diff::Remove(Permission::READ_MESSAGE_HISTORY.to_string()),
-                            Diff::Add(Permission::SEND_MESSAGES.to_string()),
+                            Diff::Remove(Permission::READ_MESSAGE_HISTORY.to_string().into()),
+                            Diff::Add(Permission::SEND_MESSAGES.to_string().into()),
                         ],
                     ),
                     Diff::Update(
                         "deny".to_string(),
                         vec![
-                            Diff::Remove(Permission::SEND_MESSAGES.to_string()),
-                            Diff::Add(Permission::READ_MESSAGE_HISTORY.to_string()),
+                            Diff::Remove(Permission::SEND_MESSAGES.to_string().into()),
+                            Diff::Add(Permission::READ_MESSAGE_HISTORY.to_string().into()),
                         ],
                     ),
                 ],
@@ -248,4 +306,72 @@ mod tests {
         )];
         assert_eq!(diffs, expected_diffs);
     }
+
+    #[test]
+    fn can_diff_thread_update() {
+        let origin = ExistingThread {
+            id: "something".to_string(),
+            name: "faq".to_string(),
+            thread_type: ThreadType::PUBLIC,
+            auto_archive_duration: 10080,
+            locked: false,
+            invitable: true,
+            archived: false,
+            is_managed: true,
+        };
+
+        let target = AwaitingThread {
+            name: "faq".to_string(),
+            thread_type: ThreadType::PUBLIC,
+            auto_archive_duration: 1440,
+            locked: true,
+            invitable: true,
+            message: None,
+        };
+
+        let diffs = origin.diffs_with(&target);
+
+        let expected_diffs = vec![
+            Diff::Update(
+                "auto_archive_duration".to_string(),
+                vec![Diff::Remove("10080".into()), Diff::Add("1440".into())],
+            ),
+            Diff::Update(
+                "locked".to_string(),
+                vec![Diff::Remove("false".into()), Diff::Add("true".into())],
+            ),
+        ];
+        assert_eq!(diffs, expected_diffs);
+    }
+
+    #[test]
+    fn given_archived_thread_it_diffs_unarchiving() {
+        let origin = ExistingThread {
+            id: "something".to_string(),
+            name: "faq".to_string(),
+            thread_type: ThreadType::PUBLIC,
+            auto_archive_duration: 10080,
+            locked: false,
+            invitable: true,
+            archived: true,
+            is_managed: true,
+        };
+
+        let target = AwaitingThread {
+            name: "faq".to_string(),
+            thread_type: ThreadType::PUBLIC,
+            auto_archive_duration: 10080,
+            locked: false,
+            invitable: true,
+            message: None,
+        };
+
+        let diffs = origin.diffs_with(&target);
+
+        let expected_diffs = vec![Diff::Update(
+            "archived".to_string(),
+            vec![Diff::Remove("true".into()), Diff::Add("false".into())],
+        )];
+        assert_eq!(diffs, expected_diffs);
+    }
 }
@@ -0,0 +1,319 @@
+use crate::{
+    channel::{Channel, ChannelsList},
+    core::diffs::{Diff, Differ},
+    onboarding::{
+        AwaitingOnboarding, ExistingOnboarding, OnboardingPrompt, OnboardingPromptOption,
+    },
+    role::{Role, RolesList},
+    utils::misc::IfThen,
+};
+
+impl Differ<AwaitingOnboarding> for ExistingOnboarding {
+    fn diffs_with(&self, awaiting: &AwaitingOnboarding) -> Vec<Diff> {
+        let mut all_diffs = vec![];
+
+        self.enabled.diffs_with(&awaiting.enabled).if_then(
+            |diffs| !diffs.is_empty(),
+            |diffs| all_diffs.push(Diff::Update("enabled".into(), diffs)),
+        );
+
+        self.mode
+            .to_string()
+            .diffs_with(&awaiting.mode.to_string())
+            .if_then(
+                |diffs| !diffs.is_empty(),
+                |diffs| all_diffs.push(Diff::Update("mode".into(), diffs)),
+            );
+
+        channel_names(&self.default_channels)
+            .diffs_with(&channel_names(&awaiting.default_channels))
+            .if_then(
+                |diffs| !diffs.is_empty(),
+                |diffs| all_diffs.push(Diff::Update("default_channels".into(), diffs)),
+            );
+
+        diffs_by_title(
+            &self.prompts,
+            &awaiting.prompts,
+            |prompt| &prompt.title,
+            |prompt| &prompt.title,
+        )
+        .if_then(
+            |diffs| !diffs.is_empty(),
+            |diffs| all_diffs.push(Diff::Update("prompts".into(), diffs)),
+        );
+
+        all_diffs
+    }
+}
+
+impl<R1, C1, R2, C2> Differ<OnboardingPrompt<R2, C2>> for OnboardingPrompt<R1, C1>
+where
+    R1: Role,
+    C1: Channel + Clone,
+    R2: Role,
+    C2: Channel + Clone,
+{
+    fn diffs_with(&self, target: &OnboardingPrompt<R2, C2>) -> Vec<Diff> {
+        let mut all_diffs = vec![];
+
+        self.prompt_type
+            .to_string()
+            .diffs_with(&target.prompt_type.to_string())
+            .if_then(
+                |diffs| !diffs.is_empty(),
+                |diffs| all_diffs.push(Diff::Update("type".into(), diffs)),
+            );
+
+        self.single_select
+            .diffs_with(&target.single_select)
+            .if_then(
+                |diffs| !diffs.is_empty(),
+                |diffs| all_diffs.push(Diff::Update("single_select".into(), diffs)),
+            );
+
+        self.required.diffs_with(&target.required).if_then(
+            |diffs| !diffs.is_empty(),
+            |diffs| all_diffs.push(Diff::Update("required".into(), diffs)),
+        );
+
+        self.in_onboarding
+            .diffs_with(&target.in_onboarding)
+            .if_then(
+                |diffs| !diffs.is_empty(),
+                |diffs| all_diffs.push(Diff::Update("in_onboarding".into(), diffs)),
+            );
+
+        diffs_by_title(
+            &self.options,
+            &target.options,
+            |option| &option.title,
+            |option| &option.title,
+        )
+        .if_then(
+            |diffs| !diffs.is_empty(),
+            |diffs| all_diffs.push(Diff::Update("options".into(), diffs)),
+        );
+
+        all_diffs
+    }
+}
+
+impl<R1, C1, R2, C2> Differ<OnboardingPromptOption<R2, C2>> for OnboardingPromptOption<R1, C1>
+where
+    R1: Role,
+    C1: Channel + Clone,
+    R2: Role,
+    C2: Channel + Clone,
+{
+    fn diffs_with(&self, target: &OnboardingPromptOption<R2, C2>) -> Vec<Diff> {
+        let mut all_diffs = vec![];
+
+        self.description.diffs_with(&target.description).if_then(
+            |diffs| !diffs.is_empty(),
+            |diffs| all_diffs.push(Diff::Update("description".into(), diffs)),
+        );
+
+        self.emoji.diffs_with(&target.emoji).if_then(
+            |diffs| !diffs.is_empty(),
+            |diffs| all_diffs.push(Diff::Update("emoji".into(), diffs)),
+        );
+
+        role_names(&self.roles)
+            .diffs_with(&role_names(&target.roles))
+            .if_then(
+                |diffs| !diffs.is_empty(),
+                |diffs| all_diffs.push(Diff::Update("roles".into(), diffs)),
+            );
+
+        channel_names(&self.channels)
+            .diffs_with(&channel_names(&target.channels))
+            .if_then(
+                |diffs| !diffs.is_empty(),
+                |diffs| all_diffs.push(Diff::Update("channels".into(), diffs)),
+            );
+
+        all_diffs
+    }
+}
+
+fn diffs_by_title<T, U>(
+    origin: &[T],
+    target: &[U],
+    origin_title: fn(&T) -> &str,
+    target_title: fn(&U) -> &str,
+) -> Vec<Diff>
+where
+    T: Differ<U>,
+{
+    let mut diffs = vec![];
+
+    for origin_item in origin.iter() {
+        let title = origin_title(origin_item);
+
+        match target.iter().find(|item| target_title(item) == title) {
+            Some(target_item) => origin_item.diffs_with(target_item).if_then(
+                |item_diffs| !item_diffs.is_empty(),
+                |item_diffs| diffs.push(Diff::Update(title.to_string(), item_diffs)),
+            ),
+            None => diffs.push(Diff::Remove(title.into())),
+        }
+    }
+
+    for target_item in target.iter() {
+        let title = target_title(target_item);
+
+        if !origin.iter().any(|item| origin_title(item) == title) {
+            diffs.push(Diff::Add(title.into()));
+        }
+    }
+
+    diffs
+}
+
+fn role_names<R: Role>(roles: &RolesList<R>) -> Vec<String> {
+    roles
+        .to_list()
+        .into_iter()
+        .map(|role| role.name().to_string())
+        .collect()
+}
+
+fn channel_names<C: Channel + Clone>(channels: &ChannelsList<C>) -> Vec<String> {
+    channels
+        .to_list()
+        .into_iter()
+        .map(|channel| channel.unique_name().to_string())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        channel::ChannelsList,
+        core::diffs::{Diff, Differ},
+        onboarding::{
+            AwaitingOnboarding, ExistingOnboarding, OnboardingPrompt, OnboardingPromptOption,
+            OnboardingPromptType,
+        },
+        role::RolesList,
+        tests::fixtures::{awaiting::AwaitingRoleFixture, existing::ExistingRoleFixture},
+    };
+
+    fn given_prompt<R: crate::role::Role, C: crate::channel::Channel + Clone>(
+        title: &str,
+        options: Vec<OnboardingPromptOption<R, C>>,
+    ) -> OnboardingPrompt<R, C> {
+        OnboardingPrompt {
+            title: title.to_string(),
+            prompt_type: OnboardingPromptType::MULTIPLE_CHOICE,
+            single_select: false,
+            required: false,
+            in_onboarding: true,
+            options,
+        }
+    }
+
+    #[test]
+    fn given_default_onboardings_it_returns_no_diffs() {
+        let origin = ExistingOnboarding::default();
+        let target = AwaitingOnboarding::default();
+
+        let diffs = origin.diffs_with(&target);
+
+        assert_eq!(diffs, vec![]);
+    }
+
+    #[test]
+    fn it_diffs_prompts_by_title() {
+        let origin = ExistingOnboarding {
+            prompts: vec![
+                given_prompt("removed", vec![]),
+                given_prompt("kept", vec![]),
+            ],
+            ..Default::default()
+        };
+        let mut kept_prompt = given_prompt("kept", vec![]);
+        kept_prompt.required = true;
+        let target = AwaitingOnboarding {
+            prompts: vec![kept_prompt, given_prompt("added", vec![])],
+            ..Default::default()
+        };
+
+        let diffs = origin.diffs_with(&target);
+
+        assert_eq!(
+            diffs,
+            vec![Diff::Update(
+                "prompts".into(),
+                vec![
+                    Diff::Remove("removed".into()),
+                    Diff::Update(
+                        "kept".into(),
+                        vec![Diff::Update(
+                            "required".into(),
+                            vec![Diff::Remove("false".into()), Diff::Add("true".into())]
+                        )]
+                    ),
+                    Diff::Add("added".into()),
+                ]
+            )]
+        );
+    }
+
+    #[test]
+    fn it_diffs_option_roles_by_name() {
+        let origin = ExistingOnboarding {
+            prompts: vec![given_prompt(
+                "prompt",
+                vec![OnboardingPromptOption {
+                    title: "option".into(),
+                    description: None,
+                    emoji: None,
+                    roles: RolesList::from(vec![ExistingRoleFixture::new()
+                        .with_name("old")
+                        .build()]),
+                    channels: ChannelsList::new(),
+                }],
+            )],
+            ..Default::default()
+        };
+        let target = AwaitingOnboarding {
+            prompts: vec![given_prompt(
+                "prompt",
+                vec![OnboardingPromptOption {
+                    title: "option".into(),
+                    description: None,
+                    emoji: None,
+                    roles: RolesList::from(vec![AwaitingRoleFixture::new()
+                        .with_name("new")
+                        .build()]),
+                    channels: ChannelsList::new(),
+                }],
+            )],
+            ..Default::default()
+        };
+
+        let diffs = origin.diffs_with(&target);
+
+        assert_eq!(
+            diffs,
+            vec![Diff::Update(
+                "prompts".into(),
+                vec![Diff::Update(
+                    "prompt".into(),
+                    vec![Diff::Update(
+                        "options".into(),
+                        vec![Diff::Update(
+                            "option".into(),
+                            vec![Diff::Update(
+                                "roles".into(),
+                                vec![Diff::Remove("old".into()), Diff::Add("new".into())]
+                            )]
+                        )]
+                    )]
+                )]
+            )]
+        );
+    }
+}
@@ -1,13 +1,34 @@
 use crate::{
     core::diffs::{Diff, Differ},
-    permission::{PermissionsList, PermissionsOverwrite, PermissionsOverwritesList},
+    permission::{
+        PermissionsList, PermissionsOverwrite, PermissionsOverwritesList, PERMISSION_BIT_PREFIX,
+    },
     role::{AwaitingRole, ExistingRole},
     utils::misc::IfThen,
 };
 
+/// Unknown bits are never removed, so their removal is only a warning.
 impl Differ<PermissionsList> for PermissionsList {
     fn diffs_with(&self, target: &Self) -> Vec<Diff> {
-        self.to_list().diffs_with(&target.to_list())
+        let mut diffs = self.to_list().diffs_with(&target.to_list());
+
+        let target_bits = target.unknown_bits();
+        for bit in self.unknown_bits() {
+            if !target_bits.contains(&bit) {
+                diffs.push(Diff::Warning(format!(
+                    "{PERMISSION_BIT_PREFIX}{bit} (unknown permission, kept)"
+                )));
+            }
+        }
+
+        let origin_bits = self.unknown_bits();
+        for bit in target_bits {
+            if !origin_bits.contains(&bit) {
+                diffs.push(Diff::Add(format!("{PERMISSION_BIT_PREFIX}{bit}").into()));
+            }
+        }
+
+        diffs
     }
 }
 
@@ -44,13 +65,13 @@ impl Differ<PermissionsOverwritesList<AwaitingRole>> for PermissionsOverwritesLi
                         },
                     );
                 }
-                None => all_diffs.push(Diff::Remove(existing_overwrite.role.name.clone())),
+                None => all_diffs.push(Diff::Remove(existing_overwrite.role.name.clone().into())),
             }
         }
 
         for awaiting_role in target.to_list().iter() {
             if self.find_by_role_name(&awaiting_role.role.name).is_none() {
-                all_diffs.push(Diff::Add(awaiting_role.role.name.clone()))
+                all_diffs.push(Diff::Add(awaiting_role.role.name.clone().into()))
             }
         }
 
@@ -68,6 +89,34 @@ mod tests {
         role::{AwaitingRole, ExistingRole},
     };
 
+    #[test]
+    fn it_sorts_permissions_diffs() {
+        let origin = PermissionsList::from(vec![
+            Permission::SEND_MESSAGES,
+            Permission::ADMINISTRATOR,
+            Permission::VIEW_CHANNEL,
+        ]);
+        let target = PermissionsList::from(vec![
+            Permission::MANAGE_ROLES,
+            Permission::ADD_REACTIONS,
+            Permission::KICK_MEMBERS,
+        ]);
+
+        let diffs = origin.diffs_with(&target);
+
+        assert_eq!(
+            diffs,
+            vec![
+                Diff::Remove("ADMINISTRATOR".into()),
+                Diff::Remove("VIEW_CHANNEL".into()),
+                Diff::Remove("SEND_MESSAGES".into()),
+                Diff::Add("KICK_MEMBERS".into()),
+                Diff::Add("ADD_REACTIONS".into()),
+                Diff::Add("MANAGE_ROLES".into()),
+            ]
+        );
+    }
+
     fn given_awaiting_role_with_name(name: String) -> AwaitingRole {
         AwaitingRole {
             name,
@@ -86,6 +135,7 @@ mod tests {
             color: None,
             is_mentionable: true,
             show_in_sidebar: false,
+            is_managed: false,
         }
     }
 
@@ -97,10 +147,25 @@ mod tests {
         let diffs = origin.diffs_with(&target);
 
         let expected_diffs = vec![
-            Diff::Remove(Permission::USE_VAD.to_string()),
-            Diff::Add(Permission::CREATE_PUBLIC_THREADS.to_string()),
+            Diff::Remove(Permission::USE_VAD.to_string().into()),
+            Diff::Add(Permission::CREATE_PUBLIC_THREADS.to_string().into()),
+        ];
+        assert_eq!(diffs, expected_diffs);
+    }
+
+    #[test]
+    fn given_unknown_bits_it_warns_about_kept_ones_and_adds_new_ones() {
+        let origin = PermissionsList::from(1u64 << 47);
+        let target = PermissionsList::new().with_bit(62);
+
+        let diffs = origin.diffs_with(&target);
+
+        let expected_diffs = vec![
+            Diff::Warning("BIT_47 (unknown permission, kept)".to_string()),
+            Diff::Add("BIT_62".into()),
         ];
         assert_eq!(diffs, expected_diffs);
+        assert!(!Diff::Update("role".to_string(), diffs[..1].to_vec()).is_change());
     }
 
     #[test]
@@ -124,15 +189,15 @@ mod tests {
             Diff::Update(
                 "allow".to_string(),
                 vec![
-                    Diff::Remove(Permission::USE_VAD.to_string()),
-                    Diff::Add(Permission::CREATE_PUBLIC_THREADS.to_string()),
+                    Diff::Remove(Permission::USE_VAD.to_string().into()),
+                    Diff::Add(Permission::CREATE_PUBLIC_THREADS.to_string().into()),
                 ],
             ),
             Diff::Update(
                 "deny".to_string(),
                 vec![
-                    Diff::Remove(Permission::CREATE_PUBLIC_THREADS.to_string()),
-                    Diff::Add(Permission::USE_VAD.to_string()),
+                    Diff::Remove(Permission::CREATE_PUBLIC_THREADS.to_string().into()),
+                    Diff::Add(Permission::USE_VAD.to_string().into()),
                 ],
             ),
         ];
@@ -163,15 +228,15 @@ mod tests {
                 Diff::Update(
                     "allow".to_string(),
                     vec![
-                        Diff::Remove(Permission::USE_VAD.to_string()),
-                        Diff::Add(Permission::CREATE_PUBLIC_THREADS.to_string()),
+                        Diff::Remove(Permission::USE_VAD.to_string().into()),
+                        Diff::Add(Permission::CREATE_PUBLIC_THREADS.to_string().into()),
                     ],
                 ),
                 Diff::Update(
                     "deny".to_string(),
                     vec![
-                        Diff::Remove(Permission::CREATE_PUBLIC_THREADS.to_string()),
-                        Diff::Add(Permission::USE_VAD.to_string()),
+                        Diff::Remove(Permission::CREATE_PUBLIC_THREADS.to_string().into()),
+                        Diff::Add(Permission::USE_VAD.to_string().into()),
                     ],
                 ),
             ],
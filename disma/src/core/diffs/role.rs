@@ -1,5 +1,5 @@
 use crate::{
-    core::diffs::{Diff, Differ},
+    core::diffs::{typed_diffs_between, Diff, DiffValue, Differ},
     role::{AwaitingRole, ExistingRole},
     utils::misc::IfThen,
 };
@@ -8,6 +8,11 @@ impl Differ<AwaitingRole> for ExistingRole {
     fn diffs_with(&self, awaiting: &AwaitingRole) -> Vec<Diff> {
         let mut all_diffs = vec![];
 
+        self.name.diffs_with(&awaiting.name).if_then(
+            |diffs| !diffs.is_empty(),
+            |diffs| all_diffs.push(Diff::Update("name".into(), diffs)),
+        );
+
         self.permissions.diffs_with(&awaiting.permissions).if_then(
             |diffs| !diffs.is_empty(),
             |diffs| all_diffs.push(Diff::Update("permissions".into(), diffs)),
@@ -27,7 +32,12 @@ impl Differ<AwaitingRole> for ExistingRole {
                 |diffs| all_diffs.push(Diff::Update("show_in_sidebar".into(), diffs)),
             );
 
-        self.color.diffs_with(&awaiting.color).if_then(
+        typed_diffs_between(
+            self.color.as_deref(),
+            awaiting.color.as_deref(),
+            DiffValue::Color,
+        )
+        .if_then(
             |diffs| !diffs.is_empty(),
             |diffs| all_diffs.push(Diff::Update("color".into(), diffs)),
         );
@@ -39,7 +49,7 @@ impl Differ<AwaitingRole> for ExistingRole {
 #[cfg(test)]
 mod tests {
     use crate::{
-        core::diffs::{Diff, Differ},
+        core::diffs::{Diff, DiffValue, Differ},
         permission::{Permission, PermissionsList},
         role::{AwaitingRole, ExistingRole},
     };
@@ -58,6 +68,7 @@ mod tests {
             color: color.clone(),
             is_mentionable,
             show_in_sidebar,
+            is_managed: false,
         };
 
         let target = AwaitingRole {
@@ -73,8 +84,8 @@ mod tests {
         let expected_diffs = vec![Diff::Update(
             "permissions".to_string(),
             vec![
-                Diff::Remove(Permission::SEND_MESSAGES.to_string()),
-                Diff::Add(Permission::ADMINISTRATOR.to_string()),
+                Diff::Remove(Permission::SEND_MESSAGES.to_string().into()),
+                Diff::Add(Permission::ADMINISTRATOR.to_string().into()),
             ],
         )];
         assert_eq!(diffs, expected_diffs);
@@ -94,6 +105,7 @@ mod tests {
             color: color.clone(),
             is_mentionable: false,
             show_in_sidebar,
+            is_managed: false,
         };
 
         let target = AwaitingRole {
@@ -108,10 +120,7 @@ mod tests {
 
         let expected_diffs = vec![Diff::Update(
             "is_mentionable".to_string(),
-            vec![
-                Diff::Remove("false".to_string()),
-                Diff::Add("true".to_string()),
-            ],
+            vec![Diff::Remove("false".into()), Diff::Add("true".into())],
         )];
         assert_eq!(diffs, expected_diffs);
     }
@@ -130,6 +139,7 @@ mod tests {
             color: color.clone(),
             is_mentionable,
             show_in_sidebar: true,
+            is_managed: false,
         };
 
         let target = AwaitingRole {
@@ -144,10 +154,7 @@ mod tests {
 
         let expected_diffs = vec![Diff::Update(
             "show_in_sidebar".to_string(),
-            vec![
-                Diff::Remove("true".to_string()),
-                Diff::Add("false".to_string()),
-            ],
+            vec![Diff::Remove("true".into()), Diff::Add("false".into())],
         )];
         assert_eq!(diffs, expected_diffs);
     }
@@ -166,6 +173,7 @@ mod tests {
             color: Some("237683".to_string()),
             is_mentionable,
             show_in_sidebar,
+            is_managed: false,
         };
 
         let target = AwaitingRole {
@@ -180,10 +188,10 @@ mod tests {
 
         let expected_diffs = vec![Diff::Update(
             "color".to_string(),
-            vec![
-                Diff::Remove("237683".to_string()),
-                Diff::Add("ab83ba".to_string()),
-            ],
+            vec![Diff::Replace(
+                DiffValue::Color("237683".into()),
+                DiffValue::Color("ab83ba".into()),
+            )],
         )];
         assert_eq!(diffs, expected_diffs);
     }
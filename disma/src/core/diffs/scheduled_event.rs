@@ -0,0 +1,85 @@
+use crate::{
+    core::diffs::{Diff, Differ},
+    scheduled_event::{AwaitingScheduledEvent, ExistingScheduledEvent},
+    utils::misc::IfThen,
+};
+
+impl Differ<AwaitingScheduledEvent> for ExistingScheduledEvent {
+    fn diffs_with(&self, awaiting: &AwaitingScheduledEvent) -> Vec<Diff> {
+        let mut all_diffs = vec![];
+
+        self.description.diffs_with(&awaiting.description).if_then(
+            |diffs| !diffs.is_empty(),
+            |diffs| all_diffs.push(Diff::Update("description".into(), diffs)),
+        );
+
+        self.location
+            .describe()
+            .diffs_with(&awaiting.location.describe())
+            .if_then(
+                |diffs| !diffs.is_empty(),
+                |diffs| all_diffs.push(Diff::Update("location".into(), diffs)),
+            );
+
+        self.start_time.diffs_with(&awaiting.start_time).if_then(
+            |diffs| !diffs.is_empty(),
+            |diffs| all_diffs.push(Diff::Update("start_time".into(), diffs)),
+        );
+
+        self.end_time.diffs_with(&awaiting.end_time).if_then(
+            |diffs| !diffs.is_empty(),
+            |diffs| all_diffs.push(Diff::Update("end_time".into(), diffs)),
+        );
+
+        all_diffs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        core::diffs::{Diff, Differ},
+        scheduled_event::ScheduledEventLocation,
+        tests::fixtures::{
+            awaiting::{AwaitingChannelFixture, AwaitingScheduledEventFixture},
+            existing::{ExistingChannelFixture, ExistingScheduledEventFixture},
+        },
+    };
+
+    #[test]
+    fn given_same_events_it_returns_no_diffs() {
+        let origin = ExistingScheduledEventFixture::new().build();
+        let target = AwaitingScheduledEventFixture::new().build();
+
+        let diffs = origin.diffs_with(&target);
+
+        assert_eq!(diffs, vec![]);
+    }
+
+    #[test]
+    fn given_different_locations_it_returns_location_diff() {
+        let origin = ExistingScheduledEventFixture::new()
+            .with_location(ScheduledEventLocation::Voice {
+                channel: ExistingChannelFixture::new().with_name("hangout").build(),
+            })
+            .build();
+        let target = AwaitingScheduledEventFixture::new()
+            .with_location(ScheduledEventLocation::Stage {
+                channel: AwaitingChannelFixture::new().with_name("hangout").build(),
+            })
+            .build();
+
+        let diffs = origin.diffs_with(&target);
+
+        assert_eq!(
+            diffs,
+            vec![Diff::Update(
+                "location".into(),
+                vec![
+                    Diff::Remove("VOICE (:hangout (TEXT))".into()),
+                    Diff::Add("STAGE (:hangout (TEXT))".into())
+                ]
+            )]
+        );
+    }
+}
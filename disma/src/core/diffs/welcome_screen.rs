@@ -0,0 +1,140 @@
+use crate::{
+    channel::Channel,
+    core::diffs::{Diff, Differ},
+    utils::misc::IfThen,
+    welcome_screen::{AwaitingWelcomeScreen, ExistingWelcomeScreen},
+};
+
+impl Differ<AwaitingWelcomeScreen> for ExistingWelcomeScreen {
+    fn diffs_with(&self, awaiting: &AwaitingWelcomeScreen) -> Vec<Diff> {
+        let mut all_diffs = vec![];
+
+        self.enabled.diffs_with(&awaiting.enabled).if_then(
+            |diffs| !diffs.is_empty(),
+            |diffs| all_diffs.push(Diff::Update("enabled".into(), diffs)),
+        );
+
+        self.description.diffs_with(&awaiting.description).if_then(
+            |diffs| !diffs.is_empty(),
+            |diffs| all_diffs.push(Diff::Update("description".into(), diffs)),
+        );
+
+        let mut channel_diffs = vec![];
+
+        for existing in self.channels.iter() {
+            let name = existing.channel.unique_name().to_string();
+
+            match awaiting
+                .channels
+                .iter()
+                .find(|awaiting| awaiting.channel.unique_name().to_string() == name)
+            {
+                Some(awaiting) => {
+                    let mut diffs = vec![];
+
+                    existing
+                        .description
+                        .diffs_with(&awaiting.description)
+                        .if_then(
+                            |description_diffs| !description_diffs.is_empty(),
+                            |description_diffs| {
+                                diffs.push(Diff::Update("description".into(), description_diffs))
+                            },
+                        );
+
+                    existing.emoji.diffs_with(&awaiting.emoji).if_then(
+                        |emoji_diffs| !emoji_diffs.is_empty(),
+                        |emoji_diffs| diffs.push(Diff::Update("emoji".into(), emoji_diffs)),
+                    );
+
+                    if !diffs.is_empty() {
+                        channel_diffs.push(Diff::Update(name, diffs));
+                    }
+                }
+                None => channel_diffs.push(Diff::Remove(name.into())),
+            }
+        }
+
+        for awaiting in awaiting.channels.iter() {
+            let name = awaiting.channel.unique_name().to_string();
+
+            if !self
+                .channels
+                .iter()
+                .any(|existing| existing.channel.unique_name().to_string() == name)
+            {
+                channel_diffs.push(Diff::Add(name.into()));
+            }
+        }
+
+        if !channel_diffs.is_empty() {
+            all_diffs.push(Diff::Update("channels".into(), channel_diffs));
+        }
+
+        all_diffs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        core::diffs::{Diff, Differ},
+        tests::fixtures::{awaiting::AwaitingChannelFixture, existing::ExistingChannelFixture},
+        welcome_screen::{AwaitingWelcomeScreen, ExistingWelcomeScreen, WelcomeScreenChannel},
+    };
+
+    #[test]
+    fn given_default_welcome_screens_it_returns_no_diffs() {
+        let origin = ExistingWelcomeScreen::default();
+        let target = AwaitingWelcomeScreen::default();
+
+        let diffs = origin.diffs_with(&target);
+
+        assert_eq!(diffs, vec![]);
+    }
+
+    #[test]
+    fn it_diffs_channels_by_name() {
+        let origin = ExistingWelcomeScreen {
+            enabled: true,
+            description: None,
+            channels: vec![
+                WelcomeScreenChannel {
+                    channel: ExistingChannelFixture::new().with_name("rules").build(),
+                    description: "Read the rules".into(),
+                    emoji: None,
+                },
+                WelcomeScreenChannel {
+                    channel: ExistingChannelFixture::new().with_name("old").build(),
+                    description: "Old".into(),
+                    emoji: None,
+                },
+            ],
+        };
+        let target = AwaitingWelcomeScreen {
+            enabled: true,
+            description: None,
+            channels: vec![WelcomeScreenChannel {
+                channel: AwaitingChannelFixture::new().with_name("rules").build(),
+                description: "Read the rules".into(),
+                emoji: Some("📜".into()),
+            }],
+        };
+
+        let diffs = origin.diffs_with(&target);
+
+        assert_eq!(
+            diffs,
+            vec![Diff::Update(
+                "channels".into(),
+                vec![
+                    Diff::Update(
+                        ":rules (TEXT)".into(),
+                        vec![Diff::Update("emoji".into(), vec![Diff::Add("📜".into())])]
+                    ),
+                    Diff::Remove(":old (TEXT)".into()),
+                ]
+            )]
+        );
+    }
+}
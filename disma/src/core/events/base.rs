@@ -14,6 +14,12 @@ pub enum ChangeEntity {
     Role,
     Category,
     Channel,
+    Thread,
+    AutoModRule,
+    ScheduledEvent,
+    Member,
+    Onboarding,
+    WelcomeScreen,
 }
 
 #[derive(Debug, PartialEq)]
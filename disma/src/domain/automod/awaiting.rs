@@ -0,0 +1,45 @@
+use std::sync::Arc;
+
+use crate::{
+    channel::{AwaitingChannel, ChannelsList},
+    role::{AwaitingRole, RolesList},
+};
+
+use super::{
+    AutoModAction, AutoModEventType, AutoModRule, AutoModRulesList, AutoModTrigger,
+    ExtraAutoModRulesStrategy,
+};
+
+#[derive(Debug, Clone)]
+pub struct AwaitingAutoModRulesList {
+    pub items: AutoModRulesList<AwaitingAutoModRule>,
+    pub extra_items_strategy: Arc<dyn ExtraAutoModRulesStrategy>,
+}
+
+impl PartialEq for AwaitingAutoModRulesList {
+    fn eq(&self, other: &Self) -> bool {
+        self.items == other.items
+            && self.extra_items_strategy._type() == other.extra_items_strategy._type()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AwaitingAutoModRule {
+    pub name: String,
+    pub event_type: AutoModEventType,
+    pub trigger: AutoModTrigger,
+    pub actions: Vec<AutoModAction<AwaitingChannel>>,
+    pub enabled: bool,
+    pub exempt_roles: RolesList<AwaitingRole>,
+    pub exempt_channels: ChannelsList<AwaitingChannel>,
+}
+
+impl AutoModRule for AwaitingAutoModRule {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn trigger(&self) -> &AutoModTrigger {
+        &self.trigger
+    }
+}
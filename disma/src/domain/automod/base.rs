@@ -0,0 +1,268 @@
+use std::collections::HashMap;
+
+use strum::{Display, EnumString};
+
+use crate::{channel::Channel, core::ListComparison};
+
+use super::ExistingAutoModRule;
+
+pub trait AutoModRule: Clone {
+    fn name(&self) -> &str;
+    fn trigger(&self) -> &AutoModTrigger;
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AutoModRulesList<R>
+where
+    R: AutoModRule,
+{
+    rules_by_name: HashMap<String, R>,
+}
+
+impl<R: AutoModRule> AutoModRulesList<R> {
+    pub fn new() -> Self {
+        Self {
+            rules_by_name: HashMap::new(),
+        }
+    }
+
+    pub fn find_by_name(&self, name: &str) -> Option<&R> {
+        self.rules_by_name.get(name)
+    }
+
+    pub fn add(&mut self, rule: R) {
+        if self.rules_by_name.contains_key(rule.name()) {
+            // TODO replace with Result
+            panic!("All AutoMod rules must have unique names.");
+        }
+
+        self.rules_by_name.insert(rule.name().to_string(), rule);
+    }
+
+    pub fn to_list(&self) -> Vec<&R> {
+        self.rules_by_name.values().collect()
+    }
+
+    pub fn compare_by_name<'a, R2: AutoModRule>(
+        &'a self,
+        other: &'a AutoModRulesList<R2>,
+    ) -> ListComparison<&'a R, &'a R2> {
+        let mut extra_self: Vec<&R> = Vec::new();
+        let mut extra_other: Vec<&R2> = Vec::new();
+        let mut same: Vec<(&R, &R2)> = Vec::new();
+
+        for self_item in self.to_list() {
+            match other.find_by_name(self_item.name()) {
+                Some(other_item) => same.push((self_item, other_item)),
+                None => extra_self.push(self_item),
+            }
+        }
+
+        for other_item in other.to_list() {
+            if self.find_by_name(other_item.name()).is_none() {
+                extra_other.push(other_item)
+            }
+        }
+
+        ListComparison {
+            extra_self,
+            extra_other,
+            same,
+        }
+    }
+}
+
+impl<R: AutoModRule> Default for AutoModRulesList<R> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<R: AutoModRule> From<Vec<R>> for AutoModRulesList<R> {
+    fn from(rules: Vec<R>) -> Self {
+        let mut rules_list = AutoModRulesList::new();
+
+        for rule in rules.into_iter() {
+            rules_list.add(rule);
+        }
+
+        rules_list
+    }
+}
+
+impl AutoModRulesList<ExistingAutoModRule> {
+    pub fn add_or_replace(&mut self, rule: ExistingAutoModRule) {
+        self.rules_by_name.insert(rule.name().to_string(), rule);
+    }
+
+    pub fn remove(&mut self, rule: ExistingAutoModRule) {
+        self.rules_by_name.remove(rule.name());
+    }
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, Display, EnumString, PartialEq, Clone)]
+pub enum AutoModEventType {
+    MESSAGE_SEND,
+    MEMBER_UPDATE,
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, Display, EnumString, PartialEq, Clone)]
+pub enum AutoModTriggerType {
+    KEYWORD,
+    SPAM,
+    KEYWORD_PRESET,
+    MENTION_SPAM,
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, Display, EnumString, PartialEq, Clone)]
+pub enum AutoModKeywordPreset {
+    PROFANITY,
+    SEXUAL_CONTENT,
+    SLURS,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum AutoModTrigger {
+    Keyword {
+        keywords: Vec<String>,
+        regex_patterns: Vec<String>,
+        allow_list: Vec<String>,
+    },
+    Spam,
+    KeywordPreset {
+        presets: Vec<AutoModKeywordPreset>,
+        allow_list: Vec<String>,
+    },
+    MentionSpam {
+        mention_total_limit: u8,
+        mention_raid_protection_enabled: bool,
+    },
+}
+
+impl AutoModTrigger {
+    pub fn trigger_type(&self) -> AutoModTriggerType {
+        match self {
+            Self::Keyword { .. } => AutoModTriggerType::KEYWORD,
+            Self::Spam => AutoModTriggerType::SPAM,
+            Self::KeywordPreset { .. } => AutoModTriggerType::KEYWORD_PRESET,
+            Self::MentionSpam { .. } => AutoModTriggerType::MENTION_SPAM,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum AutoModAction<C>
+where
+    C: Channel,
+{
+    BlockMessage { custom_message: Option<String> },
+    SendAlertMessage { channel: C },
+    Timeout { duration_seconds: u64 },
+}
+
+impl<C: Channel> AutoModAction<C> {
+    pub fn describe(&self) -> String {
+        match self {
+            Self::BlockMessage {
+                custom_message: None,
+            } => "BLOCK_MESSAGE".to_string(),
+            Self::BlockMessage {
+                custom_message: Some(message),
+            } => format!("BLOCK_MESSAGE ({message})"),
+            Self::SendAlertMessage { channel } => {
+                format!("SEND_ALERT_MESSAGE ({})", channel.unique_name())
+            }
+            Self::Timeout { duration_seconds } => format!("TIMEOUT ({duration_seconds}s)"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        automod::{AutoModRulesList, ExistingAutoModRule},
+        core::ListComparison,
+        tests::fixtures::existing::ExistingAutoModRuleFixture,
+    };
+
+    const SOME_NAME: &str = "non-existant";
+
+    #[test]
+    fn can_find_by_name() {
+        let rule = ExistingAutoModRuleFixture::new().build();
+        let list = AutoModRulesList::from(vec![rule.clone()]);
+
+        let found = list.find_by_name(&rule.name);
+
+        assert_eq!(found, Some(&rule));
+    }
+
+    #[test]
+    #[should_panic]
+    fn given_rule_with_same_name_already_in_list_when_adding_rule_should_panic() {
+        let rule = ExistingAutoModRuleFixture::new()
+            .with_name(SOME_NAME)
+            .build();
+        let rule_copy = ExistingAutoModRuleFixture::new()
+            .with_name(SOME_NAME)
+            .build();
+        let mut list = AutoModRulesList::from(vec![rule]);
+
+        list.add(rule_copy);
+    }
+
+    #[test]
+    fn given_rule_already_in_list_when_adding_or_replacing_should_replace_according_to_name() {
+        let rule = ExistingAutoModRuleFixture::new()
+            .with_name(SOME_NAME)
+            .build();
+        let rule_clone = ExistingAutoModRuleFixture::new()
+            .with_name(SOME_NAME)
+            .build();
+        let mut list = AutoModRulesList::from(vec![rule]);
+
+        list.add_or_replace(rule_clone.clone());
+
+        assert_eq!(list.to_list(), vec![&rule_clone]);
+    }
+
+    #[test]
+    fn can_remove_rule() {
+        let rule = ExistingAutoModRuleFixture::new().build();
+        let mut list = AutoModRulesList::from(vec![rule.clone()]);
+
+        list.remove(rule);
+
+        assert_eq!(list.to_list(), Vec::<&ExistingAutoModRule>::new());
+    }
+
+    #[test]
+    fn can_compare_lists_by_rule_names() {
+        let extra_self_rule = ExistingAutoModRuleFixture::new().build();
+        let extra_other_rule = ExistingAutoModRuleFixture::new().build();
+        let same_self_rule = ExistingAutoModRuleFixture::new()
+            .with_name(SOME_NAME)
+            .build();
+        let same_other_rule = ExistingAutoModRuleFixture::new()
+            .with_name(SOME_NAME)
+            .build();
+
+        let self_list =
+            AutoModRulesList::from(vec![same_self_rule.clone(), extra_self_rule.clone()]);
+        let other_list =
+            AutoModRulesList::from(vec![same_other_rule.clone(), extra_other_rule.clone()]);
+
+        let ListComparison {
+            extra_self,
+            extra_other,
+            same,
+        } = self_list.compare_by_name(&other_list);
+
+        assert_eq!(extra_self, vec![&extra_self_rule]);
+        assert_eq!(extra_other, vec![&extra_other_rule]);
+        assert_eq!(same, vec![(&same_self_rule, &same_other_rule)]);
+    }
+}
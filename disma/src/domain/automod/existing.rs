@@ -0,0 +1,28 @@
+use crate::{
+    channel::{ChannelsList, ExistingChannel},
+    role::{ExistingRole, RolesList},
+};
+
+use super::{AutoModAction, AutoModEventType, AutoModRule, AutoModTrigger};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExistingAutoModRule {
+    pub id: String,
+    pub name: String,
+    pub event_type: AutoModEventType,
+    pub trigger: AutoModTrigger,
+    pub actions: Vec<AutoModAction<ExistingChannel>>,
+    pub enabled: bool,
+    pub exempt_roles: RolesList<ExistingRole>,
+    pub exempt_channels: ChannelsList<ExistingChannel>,
+}
+
+impl AutoModRule for ExistingAutoModRule {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn trigger(&self) -> &AutoModTrigger {
+        &self.trigger
+    }
+}
@@ -0,0 +1,86 @@
+use core::fmt::Debug;
+
+use crate::core::changes::automod::AutoModRuleChange;
+
+use super::ExistingAutoModRule;
+
+pub trait ExtraAutoModRulesStrategy {
+    fn _type(&self) -> ExtraAutoModRulesStrategyType;
+    fn handle_extra_automod_rule(
+        &self,
+        extra_existing: &ExistingAutoModRule,
+        changes: &mut Vec<AutoModRuleChange>,
+    );
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ExtraAutoModRulesStrategyType {
+    Keep,
+    Remove,
+}
+
+impl Debug for dyn ExtraAutoModRulesStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self._type())
+    }
+}
+
+pub struct RemoveExtraAutoModRules {}
+
+impl ExtraAutoModRulesStrategy for RemoveExtraAutoModRules {
+    fn _type(&self) -> ExtraAutoModRulesStrategyType {
+        ExtraAutoModRulesStrategyType::Remove
+    }
+
+    fn handle_extra_automod_rule(
+        &self,
+        extra_existing: &ExistingAutoModRule,
+        changes: &mut Vec<AutoModRuleChange>,
+    ) {
+        changes.push(AutoModRuleChange::Delete(extra_existing.clone()));
+    }
+}
+
+pub struct KeepExtraAutoModRules {}
+
+impl ExtraAutoModRulesStrategy for KeepExtraAutoModRules {
+    fn _type(&self) -> ExtraAutoModRulesStrategyType {
+        ExtraAutoModRulesStrategyType::Keep
+    }
+
+    fn handle_extra_automod_rule(
+        &self,
+        _extra_existing: &ExistingAutoModRule,
+        _changes: &mut Vec<AutoModRuleChange>,
+    ) {
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::fixtures::existing::ExistingAutoModRuleFixture;
+
+    use super::*;
+
+    #[test]
+    fn when_keeping_extra_rules_should_not_add_changes() {
+        let mut changes: Vec<AutoModRuleChange> = Vec::new();
+        let extra_rule = ExistingAutoModRuleFixture::new().build();
+        let strategy = KeepExtraAutoModRules {};
+
+        strategy.handle_extra_automod_rule(&extra_rule, &mut changes);
+
+        assert!(changes.is_empty());
+    }
+
+    #[test]
+    fn when_removing_extra_rules_should_add_delete_change() {
+        let mut changes: Vec<AutoModRuleChange> = Vec::new();
+        let extra_rule = ExistingAutoModRuleFixture::new().build();
+        let strategy = RemoveExtraAutoModRules {};
+
+        strategy.handle_extra_automod_rule(&extra_rule, &mut changes);
+
+        assert_eq!(changes, vec![AutoModRuleChange::Delete(extra_rule)]);
+    }
+}
@@ -0,0 +1,11 @@
+mod awaiting;
+pub use awaiting::*;
+
+mod base;
+pub use base::*;
+
+mod existing;
+pub use existing::*;
+
+mod extra;
+pub use extra::*;
@@ -96,6 +96,9 @@ impl CategoriesList<ExistingCategory> {
     }
 
     pub fn add_or_replace(&mut self, category: ExistingCategory) {
+        // The category may have been renamed
+        self.categories_by_name
+            .retain(|_, existing| existing.id != category.id);
         self.categories_by_name
             .insert(category.name().to_string(), category);
     }
@@ -6,7 +6,10 @@ use crate::{
     role::AwaitingRole,
 };
 
-use super::{Channel, ChannelType, ChannelsList, ExtraChannelsStrategy, UniqueChannelName};
+use super::{
+    AwaitingThreadsList, Channel, ChannelType, ChannelsList, ExtraChannelsStrategy,
+    UniqueChannelName,
+};
 
 #[derive(Debug, Clone)]
 pub struct AwaitingChannelsList {
@@ -29,6 +32,8 @@ pub struct AwaitingChannel {
     pub channel_type: ChannelType,
     pub category: Option<AwaitingCategory>,
     pub overwrites: PermissionsOverwritesList<AwaitingRole>,
+    /// Permanent threads of the channel. `None` leaves threads untouched.
+    pub threads: Option<AwaitingThreadsList>,
 }
 
 impl AwaitingChannel {
@@ -1,4 +1,4 @@
-use std::fmt::Display;
+use std::{fmt::Display, str::FromStr};
 
 use crate::core::diffs::{Diff, Differ};
 
@@ -8,6 +8,8 @@ use strum::{Display, EnumString};
 pub enum ChannelType {
     TEXT,
     VOICE,
+    STAGE,
+    FORUM,
 }
 
 impl Differ<ChannelType> for ChannelType {
@@ -16,7 +18,7 @@ impl Differ<ChannelType> for ChannelType {
     }
 }
 
-#[derive(PartialEq, Debug)]
+#[derive(PartialEq, Debug, Clone)]
 pub struct UniqueChannelName {
     channel_name: String,
     channel_type: ChannelType,
@@ -35,6 +37,40 @@ impl UniqueChannelName {
             category_name: category_name.unwrap_or_default().to_string(),
         }
     }
+
+    pub fn channel_name(&self) -> &str {
+        &self.channel_name
+    }
+
+    pub fn channel_type(&self) -> &ChannelType {
+        &self.channel_type
+    }
+
+    /// Reference to the channel in a config, as `category:name`, or only
+    /// `name` for a channel without category.
+    pub fn reference(&self) -> String {
+        match self.category_name.is_empty() {
+            true => self.channel_name.clone(),
+            false => format!("{}:{}", self.category_name, self.channel_name),
+        }
+    }
+
+    /// Reference to the channel in a config, suffixed by its type, as
+    /// `category:name (TYPE)`. Unlike `reference`, it is always unique.
+    pub fn typed_reference(&self) -> String {
+        format!("{} ({})", self.reference(), self.channel_type)
+    }
+}
+
+/// Splits the optional ` (TYPE)` suffix of a channel reference.
+pub fn split_channel_type(reference: &str) -> (&str, Option<ChannelType>) {
+    reference
+        .strip_suffix(')')
+        .and_then(|rest| rest.rsplit_once(" ("))
+        .and_then(|(reference, channel_type)| {
+            Some((reference, Some(ChannelType::from_str(channel_type).ok()?)))
+        })
+        .unwrap_or((reference, None))
 }
 
 impl Display for UniqueChannelName {
@@ -77,5 +113,21 @@ mod tests {
         }
     }
 
+    mod split_channel_type {
+        use crate::channel::{split_channel_type, ChannelType};
+
+        #[test]
+        fn given_type_suffix_it_splits_it() {
+            let split = split_channel_type("category:name (VOICE)");
+            assert_eq!(split, ("category:name", Some(ChannelType::VOICE)));
+        }
+
+        #[test]
+        fn given_no_type_suffix_it_keeps_reference() {
+            assert_eq!(split_channel_type("name"), ("name", None));
+            assert_eq!(split_channel_type("name (other)"), ("name (other)", None));
+        }
+    }
+
     // TODO more tests
 }
@@ -4,7 +4,7 @@ use crate::{
     role::ExistingRole,
 };
 
-use super::{Channel, ChannelType, UniqueChannelName};
+use super::{Channel, ChannelType, ExistingThread, UniqueChannelName};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct ExistingChannel {
@@ -14,12 +14,32 @@ pub struct ExistingChannel {
     pub channel_type: ChannelType,
     pub category: Option<ExistingCategory>,
     pub overwrites: PermissionsOverwritesList<ExistingRole>,
+    /// Active and archived threads of the channel.
+    pub threads: Vec<ExistingThread>,
 }
 
 impl ExistingChannel {
     pub fn category_name(&self) -> Option<&str> {
         self.category.as_ref().map(|category| category.name())
     }
+
+    /// Thread named `name`, an active one before any archived one, as
+    /// archived threads may share its name.
+    pub fn find_thread_by_name(&self, name: &str) -> Option<&ExistingThread> {
+        self.threads
+            .iter()
+            .filter(|thread| thread.name == name)
+            .min_by_key(|thread| thread.archived)
+    }
+
+    pub fn add_or_replace_thread(&mut self, thread: ExistingThread) {
+        self.remove_thread(&thread.id);
+        self.threads.push(thread);
+    }
+
+    pub fn remove_thread(&mut self, id: &str) {
+        self.threads.retain(|thread| thread.id != id);
+    }
 }
 
 impl Channel for ExistingChannel {
@@ -2,8 +2,11 @@ use core::fmt::Debug;
 
 use crate::{
     category::AwaitingCategory,
-    channel::{AwaitingChannel, Channel, ExistingChannel},
-    core::{changes::channel::ChannelChange, diffs::Differ},
+    channel::{AwaitingChannel, Channel, ExistingChannel, ExistingThread},
+    core::{
+        changes::channel::ChannelChange,
+        diffs::{Diff, Differ},
+    },
 };
 
 pub trait ExtraChannelsStrategy {
@@ -82,10 +85,11 @@ impl ExtraChannelsStrategy for SyncExtraChannelsPermissions {
                 channel_type: extra_existing.channel_type.clone(),
                 category: Some(category.clone()),
                 overwrites: category.overwrites.clone(),
+                threads: None,
             };
 
             let diffs = extra_existing.diffs_with(&awaiting_channel);
-            if diffs.is_empty() {
+            if !diffs.iter().any(Diff::is_change) {
                 return;
             };
 
@@ -100,6 +104,65 @@ impl ExtraChannelsStrategy for SyncExtraChannelsPermissions {
     }
 }
 
+pub trait ExtraThreadsStrategy {
+    fn _type(&self) -> ExtraThreadsStrategyType;
+    fn handle_extra_thread(
+        &self,
+        channel: &ExistingChannel,
+        extra_existing: &ExistingThread,
+        changes: &mut Vec<ChannelChange>,
+    );
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ExtraThreadsStrategyType {
+    Keep,
+    Remove,
+}
+
+impl Debug for dyn ExtraThreadsStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self._type())
+    }
+}
+
+/// Archives the extra threads, as Discord keeps the archived ones.
+pub struct RemoveExtraThreads {}
+
+impl ExtraThreadsStrategy for RemoveExtraThreads {
+    fn _type(&self) -> ExtraThreadsStrategyType {
+        ExtraThreadsStrategyType::Remove
+    }
+
+    fn handle_extra_thread(
+        &self,
+        channel: &ExistingChannel,
+        extra_existing: &ExistingThread,
+        changes: &mut Vec<ChannelChange>,
+    ) {
+        changes.push(ChannelChange::ArchiveThread(
+            channel.clone(),
+            extra_existing.clone(),
+        ));
+    }
+}
+
+pub struct KeepExtraThreads {}
+
+impl ExtraThreadsStrategy for KeepExtraThreads {
+    fn _type(&self) -> ExtraThreadsStrategyType {
+        ExtraThreadsStrategyType::Keep
+    }
+
+    fn handle_extra_thread(
+        &self,
+        _channel: &ExistingChannel,
+        _extra_existing: &ExistingThread,
+        _changes: &mut Vec<ChannelChange>,
+    ) {
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
@@ -107,7 +170,10 @@ mod tests {
         permission::{PermissionsList, PermissionsOverwrite},
         tests::fixtures::{
             awaiting::{AwaitingCategoryFixture, AwaitingChannelFixture, AwaitingRoleFixture},
-            existing::{ExistingCategoryFixture, ExistingChannelFixture, ExistingRoleFixture},
+            existing::{
+                ExistingCategoryFixture, ExistingChannelFixture, ExistingRoleFixture,
+                ExistingThreadFixture,
+            },
         },
     };
 
@@ -214,9 +280,36 @@ mod tests {
                 expected_awaiting_channel,
                 vec![Diff::Update(
                     "overwrites".to_string(),
-                    vec![Diff::Add(A_ROLE_NAME.to_string())]
+                    vec![Diff::Add(A_ROLE_NAME.to_string().into())]
                 )]
             )]
         );
     }
+
+    #[test]
+    fn when_keeping_extra_threads_should_not_add_changes() {
+        let mut changes: Vec<ChannelChange> = Vec::new();
+        let channel = ExistingChannelFixture::new().build();
+        let extra_thread = ExistingThreadFixture::new().build();
+
+        let strategy = KeepExtraThreads {};
+        strategy.handle_extra_thread(&channel, &extra_thread, &mut changes);
+
+        assert!(changes.is_empty());
+    }
+
+    #[test]
+    fn when_removing_extra_threads_should_add_archive_change() {
+        let mut changes: Vec<ChannelChange> = Vec::new();
+        let channel = ExistingChannelFixture::new().build();
+        let extra_thread = ExistingThreadFixture::new().build();
+
+        let strategy = RemoveExtraThreads {};
+        strategy.handle_extra_thread(&channel, &extra_thread, &mut changes);
+
+        assert_eq!(
+            changes,
+            vec![ChannelChange::ArchiveThread(channel, extra_thread)]
+        );
+    }
 }
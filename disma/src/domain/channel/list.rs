@@ -2,7 +2,7 @@ use std::collections::HashMap;
 
 use crate::core::ListComparison;
 
-use super::{Channel, ExistingChannel, UniqueChannelName};
+use super::{split_channel_type, Channel, ExistingChannel, UniqueChannelName};
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct ChannelsList<C>
@@ -26,6 +26,66 @@ where
         self.channels_by_name.get(&unique_name.to_string())
     }
 
+    /// Finds a channel from a config reference, either `category:name` or
+    /// only `name`, optionally suffixed by the channel type as ` (TYPE)`. A
+    /// name alone refers to the channel of that name without category, else to
+    /// the only channel of that name.
+    pub fn find_by_reference(&self, reference: &str) -> Result<Option<&C>, String> {
+        let (untyped_reference, channel_type) = split_channel_type(reference);
+        let candidates: Vec<&C> = self
+            .channels_by_name
+            .values()
+            .filter(|channel| {
+                channel_type
+                    .as_ref()
+                    .is_none_or(|channel_type| channel.unique_name().channel_type() == channel_type)
+            })
+            .collect();
+
+        let exact: Vec<&C> = candidates
+            .iter()
+            .copied()
+            .filter(|channel| channel.unique_name().reference() == untyped_reference)
+            .collect();
+
+        let matching = match exact.is_empty() {
+            true => candidates
+                .into_iter()
+                .filter(|channel| channel.name() == untyped_reference)
+                .collect(),
+            false => exact,
+        };
+
+        match matching.as_slice() {
+            [] => Ok(None),
+            [channel] => Ok(Some(channel)),
+            _ => Err(format!(
+                "Channel '{reference}' is ambiguous, {} channels match it. Use the 'category:name' form instead, suffixed by ' (TYPE)' if needed.",
+                matching.len()
+            )),
+        }
+    }
+
+    /// Shortest config reference finding back the channel of `unique_name`.
+    pub fn reference_to(&self, unique_name: &UniqueChannelName) -> String {
+        let name = unique_name.channel_name();
+        let channel_type = unique_name.channel_type();
+
+        [
+            name.to_string(),
+            unique_name.reference(),
+            format!("{name} ({channel_type})"),
+        ]
+        .into_iter()
+        .find(|reference| {
+            matches!(
+                self.find_by_reference(reference),
+                Ok(Some(channel)) if &channel.unique_name() == unique_name
+            )
+        })
+        .unwrap_or_else(|| unique_name.typed_reference())
+    }
+
     pub fn add(&mut self, channel: C) {
         if self
             .channels_by_name
@@ -97,7 +157,14 @@ where
 }
 
 impl ChannelsList<ExistingChannel> {
+    pub fn find_by_id(&self, id: &str) -> Option<&ExistingChannel> {
+        self.to_list().into_iter().find(|channel| channel.id == id)
+    }
+
     pub fn add_or_replace(&mut self, channel: ExistingChannel) {
+        // The channel may have been renamed or moved to another category
+        self.channels_by_name
+            .retain(|_, existing| existing.id != channel.id);
         self.channels_by_name
             .insert(channel.unique_name().to_string(), channel);
     }
@@ -113,7 +180,7 @@ mod tests {
     use crate::{
         channel::{Channel, ChannelType, ExistingChannel, UniqueChannelName},
         core::ListComparison,
-        tests::fixtures::existing::ExistingChannelFixture,
+        tests::fixtures::existing::{ExistingCategoryFixture, ExistingChannelFixture},
     };
 
     use super::ChannelsList;
@@ -142,6 +209,128 @@ mod tests {
         assert!(found.is_none());
     }
 
+    #[test]
+    fn can_find_by_reference() {
+        let category = ExistingCategoryFixture::new().build();
+        let channel = ExistingChannelFixture::new()
+            .with_name(SOME_NAME)
+            .with_category(&category)
+            .build();
+        let list = ChannelsList::from(vec![channel.clone()]);
+
+        assert_eq!(list.find_by_reference(SOME_NAME), Ok(Some(&channel)));
+        assert_eq!(
+            list.find_by_reference(&format!("{}:{SOME_NAME}", category.name)),
+            Ok(Some(&channel))
+        );
+        assert_eq!(list.find_by_reference("other:name"), Ok(None));
+    }
+
+    #[test]
+    fn given_multiple_channels_with_same_name_when_finding_by_name_should_prefer_channel_without_category(
+    ) {
+        let category = ExistingCategoryFixture::new().build();
+        let channel = ExistingChannelFixture::new().with_name(SOME_NAME).build();
+        let channel_in_category = ExistingChannelFixture::new()
+            .with_name(SOME_NAME)
+            .with_category(&category)
+            .build();
+        let list = ChannelsList::from(vec![channel.clone(), channel_in_category.clone()]);
+
+        assert_eq!(list.find_by_reference(SOME_NAME), Ok(Some(&channel)));
+        assert_eq!(
+            list.find_by_reference(&format!("{}:{SOME_NAME}", category.name)),
+            Ok(Some(&channel_in_category))
+        );
+    }
+
+    #[test]
+    fn given_multiple_channels_with_same_name_in_categories_when_finding_by_name_should_be_an_error(
+    ) {
+        let category = ExistingCategoryFixture::new().with_name("a").build();
+        let other_category = ExistingCategoryFixture::new().with_name("b").build();
+        let list = ChannelsList::from(vec![
+            ExistingChannelFixture::new()
+                .with_name(SOME_NAME)
+                .with_category(&category)
+                .build(),
+            ExistingChannelFixture::new()
+                .with_name(SOME_NAME)
+                .with_category(&other_category)
+                .build(),
+        ]);
+
+        assert!(list.find_by_reference(SOME_NAME).is_err());
+        assert!(list
+            .find_by_reference(&format!("a:{SOME_NAME}"))
+            .unwrap()
+            .is_some());
+    }
+
+    #[test]
+    fn references_are_qualified_by_category_only_when_needed() {
+        let category = ExistingCategoryFixture::new().with_name("a").build();
+        let other_category = ExistingCategoryFixture::new().with_name("b").build();
+        let unique = ExistingChannelFixture::new()
+            .with_name("unique")
+            .with_category(&category)
+            .build();
+        let shared = ExistingChannelFixture::new()
+            .with_name(SOME_NAME)
+            .with_category(&category)
+            .build();
+        let other_shared = ExistingChannelFixture::new()
+            .with_name(SOME_NAME)
+            .with_category(&other_category)
+            .build();
+        let list = ChannelsList::from(vec![unique.clone(), shared.clone(), other_shared]);
+
+        assert_eq!(list.reference_to(&unique.unique_name()), "unique");
+        assert_eq!(
+            list.reference_to(&shared.unique_name()),
+            format!("a:{SOME_NAME}")
+        );
+    }
+
+    #[test]
+    fn given_channels_of_different_types_with_same_name_in_category_they_are_referenced_by_type() {
+        let category = ExistingCategoryFixture::new().with_name("a").build();
+        let text = ExistingChannelFixture::new()
+            .with_name(SOME_NAME)
+            .with_category(&category)
+            .build();
+        let voice = ExistingChannelFixture::new()
+            .with_name(SOME_NAME)
+            .with_type(ChannelType::VOICE)
+            .with_category(&category)
+            .build();
+        let list = ChannelsList::from(vec![text.clone(), voice.clone()]);
+
+        assert!(list.find_by_reference(&format!("a:{SOME_NAME}")).is_err());
+        assert_eq!(
+            list.reference_to(&voice.unique_name()),
+            format!("{SOME_NAME} (VOICE)")
+        );
+        assert_eq!(
+            list.find_by_reference(&list.reference_to(&voice.unique_name())),
+            Ok(Some(&voice))
+        );
+        assert_eq!(
+            list.find_by_reference(&format!("a:{SOME_NAME} (TEXT)")),
+            Ok(Some(&text))
+        );
+    }
+
+    #[test]
+    fn can_find_by_id() {
+        let channel = ExistingChannelFixture::new().build();
+        let list = ChannelsList::from(vec![channel.clone()]);
+
+        let found = list.find_by_id(&channel.id);
+
+        assert_eq!(found, Some(&channel));
+    }
+
     #[test]
     fn can_add_channel() {
         let channel = ExistingChannelFixture::new().build();
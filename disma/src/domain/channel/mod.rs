@@ -12,3 +12,6 @@ pub use extra::*;
 
 mod list;
 pub use list::*;
+
+mod thread;
+pub use thread::*;
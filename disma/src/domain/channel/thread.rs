@@ -0,0 +1,60 @@
+use std::sync::Arc;
+
+use crate::core::diffs::{Diff, Differ};
+
+use strum::{Display, EnumString};
+
+use super::ExtraThreadsStrategy;
+
+#[derive(Debug, Display, EnumString, PartialEq, Clone)]
+pub enum ThreadType {
+    PUBLIC,
+    PRIVATE,
+}
+
+impl Differ<ThreadType> for ThreadType {
+    fn diffs_with(&self, target: &ThreadType) -> Vec<Diff> {
+        self.to_string().diffs_with(&target.to_string())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct AwaitingThreadsList {
+    pub items: Vec<AwaitingThread>,
+    pub extra_items_strategy: Arc<dyn ExtraThreadsStrategy>,
+}
+
+impl PartialEq for AwaitingThreadsList {
+    fn eq(&self, other: &Self) -> bool {
+        self.items == other.items
+            && self.extra_items_strategy._type() == other.extra_items_strategy._type()
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct AwaitingThread {
+    pub name: String,
+    pub thread_type: ThreadType,
+    pub auto_archive_duration: u32,
+    pub locked: bool,
+    pub invitable: bool,
+    /// First message of the thread, which threads of `FORUM` channels (posts)
+    /// are created with. `None` for other channels.
+    pub message: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct ExistingThread {
+    pub id: String,
+    pub name: String,
+    pub thread_type: ThreadType,
+    pub auto_archive_duration: u32,
+    pub locked: bool,
+    pub invitable: bool,
+    /// Discord archives threads after `auto_archive_duration` minutes of
+    /// inactivity. Awaiting threads are never archived.
+    pub archived: bool,
+    /// Created by the bot, as the threads of the config are. Others are
+    /// started by members.
+    pub is_managed: bool,
+}
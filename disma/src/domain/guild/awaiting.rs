@@ -1,10 +1,88 @@
+use std::{collections::HashMap, sync::Arc};
+
 use crate::{
-    category::AwaitingCategoriesList, channel::AwaitingChannelsList, role::AwaitingRolesList,
+    automod::{AwaitingAutoModRulesList, ExtraAutoModRulesStrategyType},
+    category::AwaitingCategoriesList,
+    channel::{AwaitingChannelsList, Channel},
+    member::AwaitingMembersList,
+    onboarding::AwaitingOnboarding,
+    permission::{ChannelPermissions, EffectivePermissions},
+    role::AwaitingRolesList,
+    scheduled_event::{AwaitingScheduledEventsList, ExtraScheduledEventsStrategyType},
+    welcome_screen::AwaitingWelcomeScreen,
 };
 
-#[derive(Debug)]
+use super::query::{sorted_by_channel, GuildSections, ThreadChannels};
+
+#[derive(Debug, Clone)]
 pub struct AwaitingGuild {
     pub roles: AwaitingRolesList,
     pub categories: AwaitingCategoriesList,
     pub channels: AwaitingChannelsList,
+    pub automod: AwaitingAutoModRulesList,
+    pub scheduled_events: AwaitingScheduledEventsList,
+    pub members: Option<AwaitingMembersList>,
+    pub onboarding: Option<AwaitingOnboarding>,
+    pub welcome_screen: Option<AwaitingWelcomeScreen>,
+    pub ids: Arc<AwaitingIds>,
+}
+
+/// Ids the awaiting entities had when they were saved, ex: in a snapshot.
+/// Entities are matched to existing ones by id when it still exists, and by
+/// name otherwise.
+#[derive(Debug, Clone, Default)]
+pub struct AwaitingIds {
+    /// Ids by role name.
+    pub roles: HashMap<String, String>,
+    /// Ids by category name.
+    pub categories: HashMap<String, String>,
+    /// Ids by channel unique name.
+    pub channels: HashMap<String, String>,
+}
+
+impl AwaitingGuild {
+    /// Sections of the existing guild this guild can change, the only ones to
+    /// be fetched.
+    pub fn sections(&self) -> GuildSections {
+        GuildSections {
+            threads: ThreadChannels::Named(
+                self.channels
+                    .items
+                    .to_list()
+                    .into_iter()
+                    .filter(|channel| channel.threads.is_some())
+                    .map(|channel| channel.unique_name().to_string())
+                    .collect(),
+            ),
+            automod: !self.automod.items.to_list().is_empty()
+                || self.automod.extra_items_strategy._type() != ExtraAutoModRulesStrategyType::Keep,
+            scheduled_events: !self.scheduled_events.items.to_list().is_empty()
+                || self.scheduled_events.extra_items_strategy._type()
+                    != ExtraScheduledEventsStrategyType::Keep,
+            members: self.members.is_some(),
+            onboarding: self.onboarding.is_some(),
+            welcome_screen: self.welcome_screen.is_some(),
+        }
+    }
+
+    /// Effective permissions in every channel of a member having the roles
+    /// named `role_names`. Fails when a role is not in the guild.
+    pub fn effective_permissions(
+        &self,
+        role_names: &[String],
+    ) -> Result<Vec<ChannelPermissions>, String> {
+        let effective_permissions = EffectivePermissions::new(&self.roles.items, role_names)?;
+
+        Ok(sorted_by_channel(
+            self.channels
+                .items
+                .to_list()
+                .into_iter()
+                .map(|channel| ChannelPermissions {
+                    channel: channel.unique_name(),
+                    permissions: effective_permissions.in_channel(&channel.overwrites),
+                })
+                .collect(),
+        ))
+    }
 }
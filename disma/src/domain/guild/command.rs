@@ -1,9 +1,13 @@
 use std::sync::Arc;
 
 use crate::{
+    automod::{AwaitingAutoModRule, ExistingAutoModRule},
     category::{AwaitingCategory, CategoriesList, ExistingCategory},
-    channel::{AwaitingChannel, ExistingChannel},
+    channel::{AwaitingChannel, AwaitingThread, ChannelsList, ExistingChannel, ExistingThread},
+    onboarding::{AwaitingOnboarding, ExistingOnboarding},
     role::{AwaitingRole, ExistingRole, RolesList},
+    scheduled_event::{AwaitingScheduledEvent, ExistingScheduledEvent},
+    welcome_screen::{AwaitingWelcomeScreen, ExistingWelcomeScreen},
 };
 
 #[cfg_attr(test, mock_it::mock_it)]
@@ -37,5 +41,51 @@ pub trait GuildCommander {
         categories: &CategoriesList<ExistingCategory>,
     ) -> Result<ExistingChannel, String>;
     fn delete_channel(&self, id: &str) -> Result<(), String>;
+    fn add_thread(
+        &self,
+        channel_id: &str,
+        thread: &AwaitingThread,
+    ) -> Result<ExistingThread, String>;
+    fn update_thread(&self, id: &str, thread: &AwaitingThread) -> Result<ExistingThread, String>;
+    fn archive_thread(&self, id: &str) -> Result<(), String>;
+    fn add_automod_rule(
+        &self,
+        rule: &AwaitingAutoModRule,
+        roles: &RolesList<ExistingRole>,
+        channels: &ChannelsList<ExistingChannel>,
+    ) -> Result<ExistingAutoModRule, String>;
+    fn update_automod_rule(
+        &self,
+        id: &str,
+        rule: &AwaitingAutoModRule,
+        roles: &RolesList<ExistingRole>,
+        channels: &ChannelsList<ExistingChannel>,
+    ) -> Result<ExistingAutoModRule, String>;
+    fn delete_automod_rule(&self, id: &str) -> Result<(), String>;
+    fn add_scheduled_event(
+        &self,
+        event: &AwaitingScheduledEvent,
+        channels: &ChannelsList<ExistingChannel>,
+    ) -> Result<ExistingScheduledEvent, String>;
+    fn update_scheduled_event(
+        &self,
+        id: &str,
+        event: &AwaitingScheduledEvent,
+        channels: &ChannelsList<ExistingChannel>,
+    ) -> Result<ExistingScheduledEvent, String>;
+    fn delete_scheduled_event(&self, id: &str) -> Result<(), String>;
+    fn add_member_role(&self, member_id: &str, role_id: &str) -> Result<(), String>;
+    fn remove_member_role(&self, member_id: &str, role_id: &str) -> Result<(), String>;
+    fn update_onboarding(
+        &self,
+        onboarding: &AwaitingOnboarding,
+        roles: &RolesList<ExistingRole>,
+        channels: &ChannelsList<ExistingChannel>,
+    ) -> Result<ExistingOnboarding, String>;
+    fn update_welcome_screen(
+        &self,
+        welcome_screen: &AwaitingWelcomeScreen,
+        channels: &ChannelsList<ExistingChannel>,
+    ) -> Result<ExistingWelcomeScreen, String>;
 }
 pub type GuildCommanderRef = Arc<dyn GuildCommander>;
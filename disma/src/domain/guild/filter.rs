@@ -0,0 +1,245 @@
+use crate::{
+    automod::AutoModRulesList,
+    category::{CategoriesList, Category, ExistingCategory},
+    channel::{Channel, ChannelsList, ExistingChannel},
+    onboarding::ExistingOnboarding,
+    permission::PermissionsOverwritesList,
+    role::{ExistingRole, Role, RolesList},
+    scheduled_event::ScheduledEventsList,
+    utils::glob::glob_matches,
+    welcome_screen::ExistingWelcomeScreen,
+};
+
+use super::ExistingGuild;
+
+/// Selection of a part of a guild. Roles referenced by the permissions
+/// overwrites of the selected categories and channels are always kept.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GuildFilter {
+    /// Keeps all roles.
+    pub roles: bool,
+    /// Names of the categories to keep.
+    pub categories: Vec<String>,
+    /// Names of the categories whose channels (and themselves) are kept.
+    pub channels_in: Vec<String>,
+    /// Glob patterns (`*` and `?`) of the names of the roles, categories and
+    /// channels to leave out.
+    pub exclude: Vec<String>,
+}
+
+impl GuildFilter {
+    fn selects_all(&self) -> bool {
+        !self.roles && self.categories.is_empty() && self.channels_in.is_empty()
+    }
+
+    fn is_excluded(&self, name: &str) -> bool {
+        self.exclude
+            .iter()
+            .any(|pattern| glob_matches(pattern, name))
+    }
+
+    /// Filtered copy of `guild`. Without selection, all entities are kept
+    /// (except excluded ones). Otherwise, entities other than roles,
+    /// categories and channels are left out.
+    pub fn apply(&self, guild: &ExistingGuild) -> ExistingGuild {
+        if self.selects_all() && self.exclude.is_empty() {
+            return guild.clone();
+        }
+
+        let categories: Vec<ExistingCategory> = guild
+            .categories()
+            .to_list()
+            .into_iter()
+            .filter(|category| {
+                self.selects_all()
+                    || self.categories.iter().any(|name| name == category.name())
+                    || self.channels_in.iter().any(|name| name == category.name())
+            })
+            .filter(|category| !self.is_excluded(category.name()))
+            .cloned()
+            .collect();
+
+        let channels: Vec<ExistingChannel> = guild
+            .channels()
+            .to_list()
+            .into_iter()
+            .filter(|channel| {
+                self.selects_all()
+                    || channel.category_name().is_some_and(|category| {
+                        self.channels_in.iter().any(|name| name == category)
+                    })
+            })
+            .filter(|channel| !self.is_excluded(channel.name()))
+            .filter(|channel| {
+                channel
+                    .category_name()
+                    .is_none_or(|category| categories.iter().any(|kept| kept.name() == category))
+            })
+            .cloned()
+            .collect();
+
+        let referenced_roles: Vec<&str> = categories
+            .iter()
+            .map(|category| &category.overwrites)
+            .chain(channels.iter().map(|channel| &channel.overwrites))
+            .flat_map(overwritten_roles)
+            .collect();
+
+        let roles: Vec<ExistingRole> = guild
+            .roles()
+            .to_list()
+            .into_iter()
+            .filter(|role| {
+                ((self.roles || self.selects_all()) && !self.is_excluded(role.name()))
+                    || referenced_roles.contains(&role.name())
+            })
+            .cloned()
+            .collect();
+
+        let (automod_rules, scheduled_events, onboarding, welcome_screen) = if self.selects_all() {
+            (
+                guild.automod_rules().clone(),
+                guild.scheduled_events().clone(),
+                guild.onboarding().clone(),
+                guild.welcome_screen().clone(),
+            )
+        } else {
+            (
+                AutoModRulesList::new(),
+                ScheduledEventsList::new(),
+                ExistingOnboarding::default(),
+                ExistingWelcomeScreen::default(),
+            )
+        };
+
+        ExistingGuild::new(
+            RolesList::from(roles),
+            CategoriesList::from(categories),
+            ChannelsList::from(channels),
+            automod_rules,
+            scheduled_events,
+            onboarding,
+            welcome_screen,
+        )
+    }
+}
+
+fn overwritten_roles(overwrites: &PermissionsOverwritesList<ExistingRole>) -> Vec<&str> {
+    overwrites
+        .to_list()
+        .iter()
+        .map(|overwrite| overwrite.role.name())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        category::Category,
+        channel::Channel,
+        permission::{PermissionsList, PermissionsOverwrite},
+        role::Role,
+        tests::fixtures::existing::{
+            ExistingCategoryFixture, ExistingChannelFixture, ExistingGuildFixture,
+            ExistingRoleFixture,
+        },
+    };
+
+    use super::GuildFilter;
+
+    #[test]
+    fn given_empty_filter_it_keeps_whole_guild() {
+        let guild = ExistingGuildFixture::new()
+            .with_role(ExistingRoleFixture::new().build())
+            .build();
+
+        let filtered = GuildFilter::default().apply(&guild);
+
+        assert_eq!(filtered.roles(), guild.roles());
+    }
+
+    #[test]
+    fn it_keeps_channels_of_category_and_their_referenced_roles() {
+        let staff = ExistingRoleFixture::new().with_name("staff").build();
+        let other_role = ExistingRoleFixture::new().with_name("other").build();
+        let teams = ExistingCategoryFixture::new()
+            .with_name("teams")
+            .with_permissions_overwrites(vec![PermissionsOverwrite {
+                role: staff.clone(),
+                allow: PermissionsList::new(),
+                deny: PermissionsList::new(),
+            }])
+            .build();
+        let other_category = ExistingCategoryFixture::new().with_name("other").build();
+        let guild = ExistingGuildFixture::new()
+            .with_role(staff)
+            .with_role(other_role)
+            .with_category(teams.clone())
+            .with_category(other_category.clone())
+            .with_channel(
+                ExistingChannelFixture::new()
+                    .with_name("alpha")
+                    .with_category(&teams)
+                    .build(),
+            )
+            .with_channel(
+                ExistingChannelFixture::new()
+                    .with_name("alpha-old")
+                    .with_category(&teams)
+                    .build(),
+            )
+            .with_channel(
+                ExistingChannelFixture::new()
+                    .with_name("beta")
+                    .with_category(&other_category)
+                    .build(),
+            )
+            .build();
+        let filter = GuildFilter {
+            channels_in: vec!["teams".to_string()],
+            exclude: vec!["*-old".to_string()],
+            ..Default::default()
+        };
+
+        let filtered = filter.apply(&guild);
+
+        let names = |names: Vec<&str>| {
+            let mut names: Vec<String> = names.into_iter().map(str::to_string).collect();
+            names.sort();
+            names
+        };
+        assert_eq!(
+            names(
+                filtered
+                    .roles()
+                    .to_list()
+                    .iter()
+                    .map(|r| r.name())
+                    .collect()
+            ),
+            vec!["staff"]
+        );
+        assert_eq!(
+            names(
+                filtered
+                    .categories()
+                    .to_list()
+                    .iter()
+                    .map(|c| c.name())
+                    .collect()
+            ),
+            vec!["teams"]
+        );
+        assert_eq!(
+            names(
+                filtered
+                    .channels()
+                    .to_list()
+                    .iter()
+                    .map(|c| c.name())
+                    .collect()
+            ),
+            vec!["alpha"]
+        );
+    }
+}
@@ -6,3 +6,6 @@ pub use query::*;
 
 mod command;
 pub use command::*;
+
+mod filter;
+pub use filter::*;
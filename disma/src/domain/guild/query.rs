@@ -1,26 +1,170 @@
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 
 use crate::{
+    automod::{AutoModRulesList, ExistingAutoModRule},
     category::{CategoriesList, ExistingCategory},
-    channel::{ChannelsList, ExistingChannel},
-    permission::PermissionsList,
+    channel::{Channel, ChannelsList, ExistingChannel, ExistingThread},
+    member::{ExistingMember, MembersList},
+    onboarding::ExistingOnboarding,
+    permission::{ChannelPermissions, EffectivePermissions, PermissionsList},
     role::{ExistingRole, RolesList},
+    scheduled_event::{ExistingScheduledEvent, ScheduledEventsList},
+    welcome_screen::ExistingWelcomeScreen,
 };
 
 #[cfg_attr(test, mock_it::mock_it)]
 pub trait GuildQuerier {
     // TODO probably add find_<entity>_by_name() -> Result
     // to be used by commands instead of using a whole existing tree
+    /// Roles, categories and channels of the guild, its other sections being
+    /// left empty. See `get_guild_with()` to fetch them as well.
     fn get_guild(&self, guild_id: &str) -> ExistingGuild; // Still needed for computing diffs
     fn list_guilds(&self) -> Vec<GuildSummary>;
+    /// Active and archived threads of `channels`, by channel id.
+    fn list_threads(
+        &self,
+        guild_id: &str,
+        channels: &ChannelsList<ExistingChannel>,
+    ) -> Result<HashMap<String, Vec<ExistingThread>>, String>;
+    /// Requires the _Manage Server_ permission.
+    fn list_automod_rules(
+        &self,
+        guild_id: &str,
+        roles: &RolesList<ExistingRole>,
+        channels: &ChannelsList<ExistingChannel>,
+    ) -> Result<AutoModRulesList<ExistingAutoModRule>, String>;
+    fn list_scheduled_events(
+        &self,
+        guild_id: &str,
+        channels: &ChannelsList<ExistingChannel>,
+    ) -> Result<ScheduledEventsList<ExistingScheduledEvent>, String>;
+    /// Requires the privileged _Server Members_ intent, so it is only used
+    /// when members are part of the config.
+    fn list_members(
+        &self,
+        guild_id: &str,
+        roles: &RolesList<ExistingRole>,
+    ) -> Result<MembersList<ExistingMember>, String>;
+    /// Requires the _Manage Server_ permission.
+    fn get_onboarding(
+        &self,
+        guild_id: &str,
+        roles: &RolesList<ExistingRole>,
+        channels: &ChannelsList<ExistingChannel>,
+    ) -> Result<ExistingOnboarding, String>;
+    /// Requires the _Manage Server_ permission.
+    fn get_welcome_screen(
+        &self,
+        guild_id: &str,
+        channels: &ChannelsList<ExistingChannel>,
+    ) -> Result<ExistingWelcomeScreen, String>;
 }
 pub type GuildQuerierRef = Arc<dyn GuildQuerier>;
 
+impl dyn GuildQuerier {
+    /// Guild with its roles, categories and channels, along with the given
+    /// `sections`. Fails when a section cannot be fetched, ex: for missing
+    /// permissions.
+    pub fn get_guild_with(
+        &self,
+        guild_id: &str,
+        sections: &GuildSections,
+    ) -> Result<ExistingGuild, String> {
+        let mut guild = self.get_guild(guild_id);
+
+        let thread_channels: Vec<ExistingChannel> = guild
+            .channels
+            .to_list()
+            .into_iter()
+            .filter(|channel| {
+                sections
+                    .threads
+                    .includes(&channel.unique_name().to_string())
+            })
+            .cloned()
+            .collect();
+        if !thread_channels.is_empty() {
+            let threads = self.list_threads(guild_id, &ChannelsList::from(thread_channels))?;
+            guild.replace_threads(threads);
+        }
+        if sections.automod {
+            guild.automod_rules =
+                self.list_automod_rules(guild_id, &guild.roles, &guild.channels)?;
+        }
+        if sections.scheduled_events {
+            guild.scheduled_events = self.list_scheduled_events(guild_id, &guild.channels)?;
+        }
+        if sections.members {
+            guild.members = self.list_members(guild_id, &guild.roles)?;
+        }
+        if sections.onboarding {
+            guild.onboarding = self.get_onboarding(guild_id, &guild.roles, &guild.channels)?;
+        }
+        if sections.welcome_screen {
+            guild.welcome_screen = self.get_welcome_screen(guild_id, &guild.channels)?;
+        }
+
+        Ok(guild)
+    }
+}
+
+/// Sections of a guild other than its roles, categories and channels. They
+/// need more requests or permissions, so they are only fetched when used.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct GuildSections {
+    pub threads: ThreadChannels,
+    pub automod: bool,
+    pub scheduled_events: bool,
+    pub members: bool,
+    pub onboarding: bool,
+    pub welcome_screen: bool,
+}
+
+/// Channels whose threads are fetched. Archived threads take a few requests
+/// per channel.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum ThreadChannels {
+    #[default]
+    None,
+    All,
+    /// Channels of these unique names.
+    Named(Vec<String>),
+}
+
+impl ThreadChannels {
+    pub fn includes(&self, unique_name: &str) -> bool {
+        match self {
+            Self::None => false,
+            Self::All => true,
+            Self::Named(names) => names.iter().any(|name| name == unique_name),
+        }
+    }
+}
+
+impl GuildSections {
+    /// Sections of a saved config or snapshot, which are all but members.
+    pub fn saved() -> Self {
+        Self {
+            threads: ThreadChannels::All,
+            automod: true,
+            scheduled_events: true,
+            members: false,
+            onboarding: true,
+            welcome_screen: true,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct ExistingGuild {
     roles: RolesList<ExistingRole>,
     categories: CategoriesList<ExistingCategory>,
     channels: ChannelsList<ExistingChannel>,
+    automod_rules: AutoModRulesList<ExistingAutoModRule>,
+    scheduled_events: ScheduledEventsList<ExistingScheduledEvent>,
+    onboarding: ExistingOnboarding,
+    welcome_screen: ExistingWelcomeScreen,
+    members: MembersList<ExistingMember>,
 }
 
 impl ExistingGuild {
@@ -28,11 +172,20 @@ impl ExistingGuild {
         roles: RolesList<ExistingRole>,
         categories: CategoriesList<ExistingCategory>,
         channels: ChannelsList<ExistingChannel>,
+        automod_rules: AutoModRulesList<ExistingAutoModRule>,
+        scheduled_events: ScheduledEventsList<ExistingScheduledEvent>,
+        onboarding: ExistingOnboarding,
+        welcome_screen: ExistingWelcomeScreen,
     ) -> Self {
         Self {
             roles,
             categories,
             channels,
+            automod_rules,
+            scheduled_events,
+            onboarding,
+            welcome_screen,
+            members: MembersList::new(),
         }
     }
 
@@ -71,6 +224,98 @@ impl ExistingGuild {
     pub fn remove_channel(&mut self, channel: ExistingChannel) {
         self.channels.remove(channel);
     }
+
+    /// Replaces the threads of every channel by the ones of its id in
+    /// `threads_by_channel`.
+    pub fn replace_threads(
+        &mut self,
+        mut threads_by_channel: HashMap<String, Vec<ExistingThread>>,
+    ) {
+        let channels: Vec<ExistingChannel> = self.channels.to_list().into_iter().cloned().collect();
+        for mut channel in channels {
+            channel.threads = threads_by_channel.remove(&channel.id).unwrap_or_default();
+            self.channels.add_or_replace(channel);
+        }
+    }
+
+    pub fn automod_rules(&self) -> &AutoModRulesList<ExistingAutoModRule> {
+        &self.automod_rules
+    }
+
+    pub fn add_or_replace_automod_rule(&mut self, rule: ExistingAutoModRule) {
+        self.automod_rules.add_or_replace(rule);
+    }
+
+    pub fn remove_automod_rule(&mut self, rule: ExistingAutoModRule) {
+        self.automod_rules.remove(rule);
+    }
+
+    pub fn scheduled_events(&self) -> &ScheduledEventsList<ExistingScheduledEvent> {
+        &self.scheduled_events
+    }
+
+    pub fn add_or_replace_scheduled_event(&mut self, event: ExistingScheduledEvent) {
+        self.scheduled_events.add_or_replace(event);
+    }
+
+    pub fn remove_scheduled_event(&mut self, event: ExistingScheduledEvent) {
+        self.scheduled_events.remove(event);
+    }
+
+    pub fn members(&self) -> &MembersList<ExistingMember> {
+        &self.members
+    }
+
+    pub fn replace_members(&mut self, members: MembersList<ExistingMember>) {
+        self.members = members;
+    }
+
+    pub fn add_or_replace_member(&mut self, member: ExistingMember) {
+        self.members.add_or_replace(member);
+    }
+
+    pub fn onboarding(&self) -> &ExistingOnboarding {
+        &self.onboarding
+    }
+
+    pub fn replace_onboarding(&mut self, onboarding: ExistingOnboarding) {
+        self.onboarding = onboarding;
+    }
+
+    pub fn welcome_screen(&self) -> &ExistingWelcomeScreen {
+        &self.welcome_screen
+    }
+
+    pub fn replace_welcome_screen(&mut self, welcome_screen: ExistingWelcomeScreen) {
+        self.welcome_screen = welcome_screen;
+    }
+
+    /// Effective permissions in every channel of a member having the roles
+    /// named `role_names`. Fails when a role is not in the guild.
+    pub fn effective_permissions(
+        &self,
+        role_names: &[String],
+    ) -> Result<Vec<ChannelPermissions>, String> {
+        let effective_permissions = EffectivePermissions::new(&self.roles, role_names)?;
+
+        Ok(sorted_by_channel(
+            self.channels
+                .to_list()
+                .into_iter()
+                .map(|channel| ChannelPermissions {
+                    channel: channel.unique_name(),
+                    permissions: effective_permissions.in_channel(&channel.overwrites),
+                })
+                .collect(),
+        ))
+    }
+}
+
+pub(super) fn sorted_by_channel(
+    mut permissions: Vec<ChannelPermissions>,
+) -> Vec<ChannelPermissions> {
+    permissions.sort_by_key(|permissions| permissions.channel.to_string());
+    permissions
 }
 
 #[derive(Debug, Clone)]
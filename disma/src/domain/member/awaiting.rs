@@ -0,0 +1,21 @@
+use crate::role::{AwaitingRole, RolesList};
+
+use super::{Member, MembersList, RosterStrategy};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AwaitingMembersList {
+    pub items: MembersList<AwaitingMember>,
+    pub roster_strategy: RosterStrategy,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AwaitingMember {
+    pub id: String,
+    pub roles: RolesList<AwaitingRole>,
+}
+
+impl Member for AwaitingMember {
+    fn id(&self) -> &str {
+        &self.id
+    }
+}
@@ -0,0 +1,102 @@
+use std::collections::HashMap;
+
+use strum::{Display, EnumString};
+
+use super::ExistingMember;
+
+pub trait Member: Clone {
+    fn id(&self) -> &str;
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MembersList<M>
+where
+    M: Member,
+{
+    members_by_id: HashMap<String, M>,
+}
+
+impl<M: Member> MembersList<M> {
+    pub fn new() -> Self {
+        Self {
+            members_by_id: HashMap::new(),
+        }
+    }
+
+    pub fn find_by_id(&self, id: &str) -> Option<&M> {
+        self.members_by_id.get(id)
+    }
+
+    pub fn add(&mut self, member: M) {
+        if self.members_by_id.contains_key(member.id()) {
+            // TODO replace with Result
+            panic!("All members must have unique ids.");
+        }
+
+        self.members_by_id.insert(member.id().to_string(), member);
+    }
+
+    pub fn to_list(&self) -> Vec<&M> {
+        self.members_by_id.values().collect()
+    }
+}
+
+impl<M: Member> Default for MembersList<M> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<M: Member> From<Vec<M>> for MembersList<M> {
+    fn from(members: Vec<M>) -> Self {
+        let mut members_list = MembersList::new();
+
+        for member in members.into_iter() {
+            members_list.add(member);
+        }
+
+        members_list
+    }
+}
+
+impl MembersList<ExistingMember> {
+    pub fn add_or_replace(&mut self, member: ExistingMember) {
+        self.members_by_id.insert(member.id().to_string(), member);
+    }
+}
+
+/// How roles of the roster are reconciled with the roles members already have.
+#[derive(Debug, Display, EnumString, PartialEq, Clone)]
+pub enum RosterStrategy {
+    /// Only adds missing roles.
+    ADDITIVE,
+    /// Also removes managed roles that are not listed for the member.
+    EXACT,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{member::MembersList, tests::fixtures::existing::ExistingMemberFixture};
+
+    const SOME_ID: &str = "123";
+
+    #[test]
+    fn can_find_by_id() {
+        let member = ExistingMemberFixture::new().with_id(SOME_ID).build();
+        let list = MembersList::from(vec![member.clone()]);
+
+        let found = list.find_by_id(SOME_ID);
+
+        assert_eq!(found, Some(&member));
+    }
+
+    #[test]
+    #[should_panic]
+    fn given_member_with_same_id_already_in_list_when_adding_member_should_panic() {
+        let member = ExistingMemberFixture::new().with_id(SOME_ID).build();
+        let member_copy = ExistingMemberFixture::new().with_id(SOME_ID).build();
+        let mut list = MembersList::from(vec![member]);
+
+        list.add(member_copy);
+    }
+}
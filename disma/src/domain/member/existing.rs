@@ -0,0 +1,22 @@
+use crate::role::{ExistingRole, RolesList};
+
+use super::Member;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExistingMember {
+    pub id: String,
+    pub username: String,
+    pub roles: RolesList<ExistingRole>,
+}
+
+impl Member for ExistingMember {
+    fn id(&self) -> &str {
+        &self.id
+    }
+}
+
+impl ExistingMember {
+    pub fn display_name(&self) -> String {
+        format!("{} ({})", self.username, self.id)
+    }
+}
@@ -0,0 +1,8 @@
+mod awaiting;
+pub use awaiting::*;
+
+mod base;
+pub use base::*;
+
+mod existing;
+pub use existing::*;
@@ -0,0 +1,72 @@
+use strum::{Display, EnumString};
+
+use crate::{
+    channel::{AwaitingChannel, Channel, ChannelsList, ExistingChannel},
+    role::{AwaitingRole, ExistingRole, Role, RolesList},
+};
+
+pub type AwaitingOnboarding = Onboarding<AwaitingRole, AwaitingChannel>;
+pub type ExistingOnboarding = Onboarding<ExistingRole, ExistingChannel>;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Onboarding<R, C>
+where
+    R: Role,
+    C: Channel + Clone,
+{
+    pub enabled: bool,
+    pub mode: OnboardingMode,
+    pub default_channels: ChannelsList<C>,
+    pub prompts: Vec<OnboardingPrompt<R, C>>,
+}
+
+impl<R: Role, C: Channel + Clone> Default for Onboarding<R, C> {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            mode: OnboardingMode::DEFAULT,
+            default_channels: ChannelsList::new(),
+            prompts: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct OnboardingPrompt<R, C>
+where
+    R: Role,
+    C: Channel + Clone,
+{
+    pub title: String,
+    pub prompt_type: OnboardingPromptType,
+    pub single_select: bool,
+    pub required: bool,
+    pub in_onboarding: bool,
+    pub options: Vec<OnboardingPromptOption<R, C>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct OnboardingPromptOption<R, C>
+where
+    R: Role,
+    C: Channel + Clone,
+{
+    pub title: String,
+    pub description: Option<String>,
+    pub emoji: Option<String>,
+    pub roles: RolesList<R>,
+    pub channels: ChannelsList<C>,
+}
+
+#[derive(Debug, Display, EnumString, PartialEq, Clone)]
+pub enum OnboardingMode {
+    DEFAULT,
+    ADVANCED,
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, Display, EnumString, PartialEq, Clone)]
+pub enum OnboardingPromptType {
+    MULTIPLE_CHOICE,
+    DROPDOWN,
+}
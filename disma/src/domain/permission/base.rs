@@ -3,9 +3,12 @@
 use std::collections::HashSet;
 
 use serde::{Deserialize, Serialize};
-use strum::{Display, EnumIter, IntoEnumIterator};
+use strum::{Display, EnumIter, EnumString, IntoEnumIterator};
 
-#[derive(Serialize, Deserialize, Clone, Debug, Display, Eq, PartialEq, Hash, EnumIter)]
+#[derive(
+    Serialize, Deserialize, Clone, Debug, Display, EnumString, Eq, PartialEq, Hash, EnumIter,
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum Permission {
     CREATE_INSTANT_INVITE,
     KICK_MEMBERS,
@@ -50,10 +53,20 @@ pub enum Permission {
     MODERATE_MEMBERS,
     VIEW_CREATOR_MONETIZATION_ANALYTICS,
     USE_SOUNDBOARD,
+    CREATE_GUILD_EXPRESSIONS,
+    CREATE_EVENTS,
     USE_EXTERNAL_SOUNDS,
     SEND_VOICE_MESSAGES,
+    SET_VOICE_CHANNEL_STATUS,
+    SEND_POLLS,
+    USE_EXTERNAL_APPS,
+    PIN_MESSAGES,
+    BYPASS_SLOWMODE,
 }
 
+/// Prefix of the raw names of permission bits (ex: `BIT_47`).
+pub const PERMISSION_BIT_PREFIX: &str = "BIT_";
+
 impl Permission {
     fn code(&self) -> u64 {
         match self {
@@ -100,41 +113,112 @@ impl Permission {
             Self::MODERATE_MEMBERS => 1 << 40,
             Self::VIEW_CREATOR_MONETIZATION_ANALYTICS => 1 << 41,
             Self::USE_SOUNDBOARD => 1 << 42,
+            Self::CREATE_GUILD_EXPRESSIONS => 1 << 43,
+            Self::CREATE_EVENTS => 1 << 44,
             Self::USE_EXTERNAL_SOUNDS => 1 << 45,
             Self::SEND_VOICE_MESSAGES => 1 << 46,
+            Self::SET_VOICE_CHANNEL_STATUS => 1 << 48,
+            Self::SEND_POLLS => 1 << 49,
+            Self::USE_EXTERNAL_APPS => 1 << 50,
+            Self::PIN_MESSAGES => 1 << 51,
+            Self::BYPASS_SLOWMODE => 1 << 52,
         }
     }
+
+    /// Known permission of the `bit`-th bit, if any.
+    pub fn from_bit(bit: u8) -> Option<Self> {
+        let code = 1u64.checked_shl(bit.into())?;
+        Self::iter().find(|permission| permission.code() == code)
+    }
+}
+
+fn known_bits() -> u64 {
+    Permission::iter().fold(0, |code, permission| code | permission.code())
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct PermissionsList {
     permissions: HashSet<Permission>,
+    /// Bits matching no known permission, carried through untouched.
+    unknown_bits: u64,
 }
 
 impl PermissionsList {
     pub fn new() -> Self {
         Self {
             permissions: HashSet::new(),
+            unknown_bits: 0,
         }
     }
 
     pub fn code(&self) -> String {
-        let mut code: u64 = 0;
-
-        for permission in self.permissions.iter() {
-            code |= permission.code()
-        }
-
-        code.to_string()
+        self.bits().to_string()
     }
 
+    /// Permissions, ordered by their bit.
     pub fn to_list(&self) -> Vec<Permission> {
-        self.permissions.iter().cloned().collect()
+        let mut permissions: Vec<Permission> = self.permissions.iter().cloned().collect();
+        permissions.sort_by_key(Permission::code);
+        permissions
     }
 
     pub fn contains(&self, permission: Permission) -> bool {
         self.permissions.contains(&permission)
     }
+
+    /// Indexes of the bits matching no known permission, in order.
+    pub fn unknown_bits(&self) -> Vec<u8> {
+        (0..u64::BITS as u8)
+            .filter(|bit| self.unknown_bits & (1 << bit) != 0)
+            .collect()
+    }
+
+    pub fn with_permission(mut self, permission: Permission) -> Self {
+        self.permissions.insert(permission);
+        self
+    }
+
+    /// Adds the `bit`-th bit (lower than 64), as a known permission if there
+    /// is one.
+    pub fn with_bit(mut self, bit: u8) -> Self {
+        match Permission::from_bit(bit) {
+            Some(permission) => {
+                self.permissions.insert(permission);
+            }
+            None => self.unknown_bits |= 1 << bit,
+        }
+        self
+    }
+
+    /// Permissions of either `self` or `other`.
+    pub fn union(&self, other: &PermissionsList) -> Self {
+        Self::from(self.bits() | other.bits())
+    }
+
+    /// Permissions of `self` that are not in `other`.
+    pub fn without(&self, other: &PermissionsList) -> Self {
+        Self::from(self.bits() & !other.bits())
+    }
+
+    /// All the known permissions.
+    pub fn all() -> Self {
+        Self::from(known_bits())
+    }
+
+    fn bits(&self) -> u64 {
+        self.permissions
+            .iter()
+            .fold(self.unknown_bits, |code, permission| {
+                code | permission.code()
+            })
+    }
+
+    /// Adds the unknown bits of `other`, for them not to be erased when
+    /// replacing `other`.
+    pub fn with_unknown_bits_of(mut self, other: &PermissionsList) -> Self {
+        self.unknown_bits |= other.unknown_bits;
+        self
+    }
 }
 
 impl Default for PermissionsList {
@@ -161,7 +245,10 @@ impl From<u64> for PermissionsList {
             }
         }
 
-        Self { permissions }
+        Self {
+            permissions,
+            unknown_bits: code & !known_bits(),
+        }
     }
 }
 
@@ -169,13 +256,17 @@ impl From<Vec<Permission>> for PermissionsList {
     fn from(permissions: Vec<Permission>) -> Self {
         Self {
             permissions: HashSet::from_iter(permissions),
+            unknown_bits: 0,
         }
     }
 }
 
 impl From<HashSet<Permission>> for PermissionsList {
     fn from(permissions: HashSet<Permission>) -> Self {
-        Self { permissions }
+        Self {
+            permissions,
+            unknown_bits: 0,
+        }
     }
 }
 
@@ -257,6 +348,25 @@ mod tests {
             assert_eq!(code, "278592");
         }
 
+        #[test]
+        fn it_keeps_unknown_bits_of_code() {
+            let code = (1u64 << 47 | 1 << 63 | Permission::ADMINISTRATOR.code()).to_string();
+
+            let permission_list = PermissionsList::from(code.as_str());
+
+            assert_eq!(permission_list.to_list(), vec![Permission::ADMINISTRATOR]);
+            assert_eq!(permission_list.unknown_bits(), vec![47, 63]);
+            assert_eq!(permission_list.code(), code);
+        }
+
+        #[test]
+        fn given_known_bit_it_adds_its_permission() {
+            let permission_list = PermissionsList::new().with_bit(3).with_bit(47);
+
+            assert_eq!(permission_list.to_list(), vec![Permission::ADMINISTRATOR]);
+            assert_eq!(permission_list.unknown_bits(), vec![47]);
+        }
+
         #[test]
         fn lists_are_equal_if_same_permissions() {
             let list1 =
@@ -0,0 +1,239 @@
+use crate::{
+    channel::UniqueChannelName,
+    role::{Role, RolesList},
+};
+
+use super::{Permission, PermissionsList, PermissionsOverwritesList};
+
+/// Name of the role that all members have.
+pub const EVERYONE_ROLE_NAME: &str = "@everyone";
+
+/// Permissions that are lost along with `SEND_MESSAGES`.
+const SEND_MESSAGES_DEPENDENTS: [Permission; 4] = [
+    Permission::MENTION_EVERYONE,
+    Permission::SEND_TTS_MESSAGES,
+    Permission::ATTACH_FILES,
+    Permission::EMBED_LINKS,
+];
+
+/// Effective permissions in a channel.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChannelPermissions {
+    pub channel: UniqueChannelName,
+    pub permissions: PermissionsList,
+}
+
+/// Computes the effective permissions of a member having a set of roles
+/// (and `@everyone`), following Discord's precedence:
+/// 1. the permissions of all the roles are combined, and `ADMINISTRATOR`
+///    grants every permission;
+/// 2. in a channel, the `@everyone` overwrite is applied, then the denies and
+///    the allows of the overwrites of all the roles;
+/// 3. without `VIEW_CHANNEL`, no permission applies in the channel, and
+///    without `SEND_MESSAGES`, the permissions depending on it don't either.
+pub struct EffectivePermissions<'a, R: Role> {
+    everyone: Option<&'a R>,
+    roles: Vec<&'a R>,
+}
+
+impl<'a, R: Role> EffectivePermissions<'a, R> {
+    /// Fails when a role of `role_names` is not in `roles`.
+    pub fn new(roles: &'a RolesList<R>, role_names: &[String]) -> Result<Self, String> {
+        let everyone = roles.find_by_name(EVERYONE_ROLE_NAME);
+        let roles = role_names
+            .iter()
+            .filter(|name| name.as_str() != EVERYONE_ROLE_NAME)
+            .map(|name| {
+                roles
+                    .find_by_name(name)
+                    .ok_or_else(|| format!("No role found for name {name}."))
+            })
+            .collect::<Result<Vec<&R>, String>>()?;
+
+        Ok(Self { everyone, roles })
+    }
+
+    fn all_roles(&self) -> impl Iterator<Item = &&'a R> {
+        self.everyone.iter().chain(self.roles.iter())
+    }
+
+    /// Permissions outside of any channel.
+    pub fn base(&self) -> PermissionsList {
+        let permissions = self
+            .all_roles()
+            .fold(PermissionsList::new(), |permissions, role| {
+                permissions.union(role.permissions())
+            });
+
+        match permissions.contains(Permission::ADMINISTRATOR) {
+            true => PermissionsList::all(),
+            false => permissions,
+        }
+    }
+
+    /// Permissions in a channel having `overwrites`.
+    pub fn in_channel<R2: Role>(
+        &self,
+        overwrites: &PermissionsOverwritesList<R2>,
+    ) -> PermissionsList {
+        let mut permissions = self.base();
+        if permissions.contains(Permission::ADMINISTRATOR) {
+            return permissions;
+        }
+
+        if let Some(overwrite) = overwrites.find_by_role_name(EVERYONE_ROLE_NAME) {
+            permissions = permissions.without(&overwrite.deny).union(&overwrite.allow);
+        }
+
+        let (allow, deny) = self
+            .roles
+            .iter()
+            .filter_map(|role| overwrites.find_by_role_name(role.name()))
+            .fold(
+                (PermissionsList::new(), PermissionsList::new()),
+                |(allow, deny), overwrite| {
+                    (allow.union(&overwrite.allow), deny.union(&overwrite.deny))
+                },
+            );
+        permissions = permissions.without(&deny).union(&allow);
+
+        if !permissions.contains(Permission::VIEW_CHANNEL) {
+            return PermissionsList::new();
+        }
+
+        if !permissions.contains(Permission::SEND_MESSAGES) {
+            permissions =
+                permissions.without(&PermissionsList::from(SEND_MESSAGES_DEPENDENTS.to_vec()));
+        }
+
+        permissions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        permission::{
+            Permission, PermissionsList, PermissionsOverwrite, PermissionsOverwritesList,
+        },
+        role::{ExistingRole, RolesList},
+        tests::fixtures::existing::ExistingRoleFixture,
+    };
+
+    use super::{EffectivePermissions, EVERYONE_ROLE_NAME};
+
+    fn role(name: &str, permissions: Vec<Permission>) -> ExistingRole {
+        ExistingRoleFixture::new()
+            .with_name(name)
+            .with_permissions(PermissionsList::from(permissions))
+            .build()
+    }
+
+    fn overwrite(
+        role: &ExistingRole,
+        allow: Vec<Permission>,
+        deny: Vec<Permission>,
+    ) -> PermissionsOverwrite<ExistingRole> {
+        PermissionsOverwrite {
+            role: role.clone(),
+            allow: PermissionsList::from(allow),
+            deny: PermissionsList::from(deny),
+        }
+    }
+
+    fn given_roles() -> RolesList<ExistingRole> {
+        RolesList::from(vec![
+            role(
+                EVERYONE_ROLE_NAME,
+                vec![Permission::VIEW_CHANNEL, Permission::SEND_MESSAGES],
+            ),
+            role("moderator", vec![Permission::MANAGE_MESSAGES]),
+            role("admin", vec![Permission::ADMINISTRATOR]),
+        ])
+    }
+
+    #[test]
+    fn base_permissions_combine_everyone_and_roles() {
+        let roles = given_roles();
+
+        let permissions = EffectivePermissions::new(&roles, &["moderator".to_string()])
+            .unwrap()
+            .base();
+
+        assert_eq!(
+            permissions,
+            PermissionsList::from(vec![
+                Permission::VIEW_CHANNEL,
+                Permission::SEND_MESSAGES,
+                Permission::MANAGE_MESSAGES
+            ])
+        );
+    }
+
+    #[test]
+    fn role_overwrites_take_precedence_over_everyone_overwrite() {
+        let roles = given_roles();
+        let everyone = roles.find_by_name(EVERYONE_ROLE_NAME).unwrap();
+        let moderator = roles.find_by_name("moderator").unwrap();
+        let overwrites = PermissionsOverwritesList::from(vec![
+            overwrite(everyone, vec![], vec![Permission::SEND_MESSAGES]),
+            overwrite(moderator, vec![Permission::SEND_MESSAGES], vec![]),
+        ]);
+
+        let everyone_permissions = EffectivePermissions::new(&roles, &[])
+            .unwrap()
+            .in_channel(&overwrites);
+        let moderator_permissions = EffectivePermissions::new(&roles, &["moderator".to_string()])
+            .unwrap()
+            .in_channel(&overwrites);
+
+        assert!(!everyone_permissions.contains(Permission::SEND_MESSAGES));
+        assert!(moderator_permissions.contains(Permission::SEND_MESSAGES));
+    }
+
+    #[test]
+    fn without_view_channel_no_permission_applies() {
+        let roles = given_roles();
+        let everyone = roles.find_by_name(EVERYONE_ROLE_NAME).unwrap();
+        let overwrites = PermissionsOverwritesList::from(vec![overwrite(
+            everyone,
+            vec![],
+            vec![Permission::VIEW_CHANNEL],
+        )]);
+
+        let permissions = EffectivePermissions::new(&roles, &["moderator".to_string()])
+            .unwrap()
+            .in_channel(&overwrites);
+
+        assert_eq!(permissions, PermissionsList::new());
+    }
+
+    #[test]
+    fn administrator_bypasses_overwrites() {
+        let roles = given_roles();
+        let admin = roles.find_by_name("admin").unwrap();
+        let overwrites = PermissionsOverwritesList::from(vec![overwrite(
+            admin,
+            vec![],
+            vec![Permission::VIEW_CHANNEL],
+        )]);
+
+        let permissions = EffectivePermissions::new(&roles, &["admin".to_string()])
+            .unwrap()
+            .in_channel(&overwrites);
+
+        assert_eq!(permissions, PermissionsList::all());
+    }
+
+    #[test]
+    fn given_unknown_role_it_fails() {
+        let roles = given_roles();
+
+        let result = EffectivePermissions::new(&roles, &["unknown".to_string()]);
+
+        assert_eq!(
+            result.err(),
+            Some("No role found for name unknown.".to_string())
+        );
+    }
+}
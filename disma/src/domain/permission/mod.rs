@@ -3,3 +3,6 @@ pub use base::*;
 
 mod overwrites;
 pub use overwrites::*;
+
+mod effective;
+pub use effective::*;
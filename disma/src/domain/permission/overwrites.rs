@@ -52,6 +52,34 @@ impl<R: Role> PermissionsOverwritesList<R> {
     pub fn to_list(&self) -> &Vec<PermissionsOverwrite<R>> {
         &self.items
     }
+
+    /// Whether both lists have the same overwrites, in any order.
+    pub fn matches<R2: Role>(&self, other: &PermissionsOverwritesList<R2>) -> bool {
+        self.items.len() == other.items.len()
+            && self.items.iter().all(|overwrite| {
+                other
+                    .find_by_role_name(overwrite.role.name())
+                    .is_some_and(|other_overwrite| overwrite == other_overwrite)
+            })
+    }
+
+    /// Adds the unknown bits of the matching overwrites of `other`, for them
+    /// not to be erased when replacing `other`.
+    pub fn with_unknown_bits_of<R2: Role>(mut self, other: &PermissionsOverwritesList<R2>) -> Self {
+        for overwrite in self.items.iter_mut() {
+            if let Some(other_overwrite) = other.find_by_role_name(overwrite.role.name()) {
+                overwrite.allow = overwrite
+                    .allow
+                    .clone()
+                    .with_unknown_bits_of(&other_overwrite.allow);
+                overwrite.deny = overwrite
+                    .deny
+                    .clone()
+                    .with_unknown_bits_of(&other_overwrite.deny);
+            }
+        }
+        self
+    }
 }
 
 impl<R: Role> From<Vec<PermissionsOverwrite<R>>> for PermissionsOverwritesList<R> {
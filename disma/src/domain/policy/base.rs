@@ -0,0 +1,119 @@
+use std::sync::Arc;
+
+use crate::{
+    guild::AwaitingGuild,
+    permission::{Permission, PermissionsList, EVERYONE_ROLE_NAME},
+};
+
+use super::{ForbiddenOverwriteAllowsRule, ForbiddenRolePermissionsRule};
+
+/// Permissions that should never be granted to everyone.
+const DANGEROUS_PERMISSIONS: [Permission; 9] = [
+    Permission::ADMINISTRATOR,
+    Permission::MANAGE_GUILD,
+    Permission::MANAGE_ROLES,
+    Permission::MANAGE_CHANNELS,
+    Permission::MANAGE_WEBHOOKS,
+    Permission::BAN_MEMBERS,
+    Permission::KICK_MEMBERS,
+    Permission::MODERATE_MEMBERS,
+    Permission::MENTION_EVERYONE,
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PolicySeverity {
+    Error,
+    Warning,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PolicyViolation {
+    pub rule: String,
+    pub severity: PolicySeverity,
+    pub message: String,
+}
+
+pub trait PolicyRule {
+    fn id(&self) -> &str;
+    fn check(&self, guild: &AwaitingGuild) -> Vec<PolicyViolation>;
+}
+
+/// Rules that a guild config must follow.
+pub struct Policy {
+    pub rules: Vec<Arc<dyn PolicyRule>>,
+}
+
+impl Policy {
+    /// Rules against granting dangerous permissions to `@everyone`.
+    pub fn builtin_rules() -> Vec<Arc<dyn PolicyRule>> {
+        let permissions = PermissionsList::from(DANGEROUS_PERMISSIONS.to_vec());
+
+        vec![
+            Arc::from(ForbiddenRolePermissionsRule {
+                id: "everyone-dangerous-permissions".to_string(),
+                severity: PolicySeverity::Error,
+                roles: vec![EVERYONE_ROLE_NAME.to_string()],
+                except: Vec::new(),
+                permissions: permissions.clone(),
+            }),
+            Arc::from(ForbiddenOverwriteAllowsRule {
+                id: "everyone-dangerous-overwrites".to_string(),
+                severity: PolicySeverity::Error,
+                roles: vec![EVERYONE_ROLE_NAME.to_string()],
+                except: Vec::new(),
+                permissions,
+            }),
+        ]
+    }
+
+    pub fn evaluate(&self, guild: &AwaitingGuild) -> Vec<PolicyViolation> {
+        self.rules
+            .iter()
+            .flat_map(|rule| rule.check(guild))
+            .collect()
+    }
+}
+
+impl Default for Policy {
+    fn default() -> Self {
+        Self {
+            rules: Self::builtin_rules(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        permission::{Permission, PermissionsList, EVERYONE_ROLE_NAME},
+        tests::fixtures::awaiting::{AwaitingGuildFixture, AwaitingRoleFixture},
+    };
+
+    use super::{Policy, PolicySeverity, PolicyViolation};
+
+    #[test]
+    fn builtin_rules_forbid_dangerous_permissions_for_everyone() {
+        let guild = AwaitingGuildFixture::new()
+            .with_role(
+                AwaitingRoleFixture::new()
+                    .with_name(EVERYONE_ROLE_NAME)
+                    .with_permissions(PermissionsList::from(vec![
+                        Permission::VIEW_CHANNEL,
+                        Permission::MENTION_EVERYONE,
+                    ]))
+                    .build(),
+            )
+            .build();
+
+        let violations = Policy::default().evaluate(&guild);
+
+        assert_eq!(
+            violations,
+            vec![PolicyViolation {
+                rule: "everyone-dangerous-permissions".to_string(),
+                severity: PolicySeverity::Error,
+                message: "Role '@everyone' has MENTION_EVERYONE.".to_string(),
+            }]
+        );
+    }
+}
@@ -0,0 +1,5 @@
+mod base;
+pub use base::*;
+
+mod rules;
+pub use rules::*;
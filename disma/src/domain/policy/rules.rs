@@ -0,0 +1,332 @@
+use crate::{
+    category::Category,
+    channel::Channel,
+    guild::AwaitingGuild,
+    permission::{Permission, PermissionsList, PermissionsOverwritesList},
+    role::{AwaitingRole, Role},
+    utils::glob::glob_matches,
+};
+
+use super::{PolicyRule, PolicySeverity, PolicyViolation};
+
+/// Forbids roles whose name matches one of `roles` (and none of `except`)
+/// from having any of `permissions`.
+pub struct ForbiddenRolePermissionsRule {
+    pub id: String,
+    pub severity: PolicySeverity,
+    pub roles: Vec<String>,
+    pub except: Vec<String>,
+    pub permissions: PermissionsList,
+}
+
+impl PolicyRule for ForbiddenRolePermissionsRule {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn check(&self, guild: &AwaitingGuild) -> Vec<PolicyViolation> {
+        let mut roles = guild.roles.items.to_list();
+        roles.sort_by_key(|role| role.name());
+
+        roles
+            .into_iter()
+            .filter(|role| matches_any(&self.roles, role.name(), &self.except))
+            .filter_map(|role| {
+                let forbidden = intersection(&self.permissions, &role.permissions)?;
+                Some(violation(
+                    self,
+                    self.severity,
+                    format!("Role '{}' has {forbidden}.", role.name()),
+                ))
+            })
+            .collect()
+    }
+}
+
+/// Forbids the permissions overwrites of categories and channels from
+/// allowing any of `permissions` to roles whose name matches one of `roles`
+/// (and none of `except`).
+pub struct ForbiddenOverwriteAllowsRule {
+    pub id: String,
+    pub severity: PolicySeverity,
+    pub roles: Vec<String>,
+    pub except: Vec<String>,
+    pub permissions: PermissionsList,
+}
+
+impl ForbiddenOverwriteAllowsRule {
+    fn check_overwrites(
+        &self,
+        entity: &str,
+        overwrites: &PermissionsOverwritesList<AwaitingRole>,
+    ) -> Vec<PolicyViolation> {
+        overwrites
+            .to_list()
+            .iter()
+            .filter(|overwrite| matches_any(&self.roles, overwrite.role.name(), &self.except))
+            .filter_map(|overwrite| {
+                let forbidden = intersection(&self.permissions, &overwrite.allow)?;
+                Some(violation(
+                    self,
+                    self.severity,
+                    format!(
+                        "{entity} allows {forbidden} to role '{}'.",
+                        overwrite.role.name()
+                    ),
+                ))
+            })
+            .collect()
+    }
+}
+
+impl PolicyRule for ForbiddenOverwriteAllowsRule {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn check(&self, guild: &AwaitingGuild) -> Vec<PolicyViolation> {
+        let mut categories = guild.categories.items.to_list();
+        categories.sort_by_key(|category| category.name());
+        let mut channels = guild.channels.items.to_list();
+        channels.sort_by_key(|channel| channel.unique_name().to_string());
+
+        let category_violations = categories.into_iter().flat_map(|category| {
+            self.check_overwrites(
+                &format!("Category '{}'", category.name()),
+                &category.overwrites,
+            )
+        });
+        let channel_violations = channels.into_iter().flat_map(|channel| {
+            self.check_overwrites(
+                &format!("Channel '{}'", channel.unique_name()),
+                &channel.overwrites,
+            )
+        });
+
+        category_violations.chain(channel_violations).collect()
+    }
+}
+
+/// Requires every channel of the categories matching `category` to deny all
+/// of `permissions` to `role`.
+pub struct RequiredChannelDeniesRule {
+    pub id: String,
+    pub severity: PolicySeverity,
+    pub category: String,
+    pub role: String,
+    pub permissions: PermissionsList,
+}
+
+impl PolicyRule for RequiredChannelDeniesRule {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn check(&self, guild: &AwaitingGuild) -> Vec<PolicyViolation> {
+        let mut channels = guild.channels.items.to_list();
+        channels.sort_by_key(|channel| channel.unique_name().to_string());
+
+        channels
+            .into_iter()
+            .filter(|channel| {
+                channel
+                    .category_name()
+                    .is_some_and(|category| glob_matches(&self.category, category))
+            })
+            .filter_map(|channel| {
+                let denied = channel
+                    .overwrites
+                    .find_by_role_name(&self.role)
+                    .map(|overwrite| overwrite.deny.clone())
+                    .unwrap_or_default();
+                let missing = self.permissions.without(&denied);
+                let missing = join_permissions(&missing.to_list())?;
+
+                Some(violation(
+                    self,
+                    self.severity,
+                    format!(
+                        "Channel '{}' does not deny {missing} to role '{}'.",
+                        channel.unique_name(),
+                        self.role
+                    ),
+                ))
+            })
+            .collect()
+    }
+}
+
+fn matches_any(patterns: &[String], name: &str, except: &[String]) -> bool {
+    patterns.iter().any(|pattern| glob_matches(pattern, name))
+        && !except.iter().any(|pattern| glob_matches(pattern, name))
+}
+
+/// Permissions of `permissions` that are in `other`, joined by commas.
+fn intersection(permissions: &PermissionsList, other: &PermissionsList) -> Option<String> {
+    let common: Vec<Permission> = permissions
+        .to_list()
+        .into_iter()
+        .filter(|permission| other.contains(permission.clone()))
+        .collect();
+
+    join_permissions(&common)
+}
+
+fn join_permissions(permissions: &[Permission]) -> Option<String> {
+    match permissions.is_empty() {
+        true => None,
+        false => Some(
+            permissions
+                .iter()
+                .map(|permission| permission.to_string())
+                .collect::<Vec<String>>()
+                .join(", "),
+        ),
+    }
+}
+
+fn violation(rule: &dyn PolicyRule, severity: PolicySeverity, message: String) -> PolicyViolation {
+    PolicyViolation {
+        rule: rule.id().to_string(),
+        severity,
+        message,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        guild::AwaitingGuild,
+        permission::{Permission, PermissionsList, PermissionsOverwrite, EVERYONE_ROLE_NAME},
+        policy::{PolicyRule, PolicySeverity, PolicyViolation},
+        role::AwaitingRole,
+        tests::fixtures::awaiting::{
+            AwaitingCategoryFixture, AwaitingChannelFixture, AwaitingGuildFixture,
+            AwaitingRoleFixture,
+        },
+    };
+
+    use super::{
+        ForbiddenOverwriteAllowsRule, ForbiddenRolePermissionsRule, RequiredChannelDeniesRule,
+    };
+
+    fn role(name: &str, permissions: Vec<Permission>) -> AwaitingRole {
+        AwaitingRoleFixture::new()
+            .with_name(name)
+            .with_permissions(PermissionsList::from(permissions))
+            .build()
+    }
+
+    fn overwrite(
+        role: &AwaitingRole,
+        allow: Vec<Permission>,
+        deny: Vec<Permission>,
+    ) -> PermissionsOverwrite<AwaitingRole> {
+        PermissionsOverwrite {
+            role: role.clone(),
+            allow: PermissionsList::from(allow),
+            deny: PermissionsList::from(deny),
+        }
+    }
+
+    fn error(rule: &str, message: &str) -> PolicyViolation {
+        PolicyViolation {
+            rule: rule.to_string(),
+            severity: PolicySeverity::Error,
+            message: message.to_string(),
+        }
+    }
+
+    fn given_guild() -> AwaitingGuild {
+        let everyone = role(EVERYONE_ROLE_NAME, vec![Permission::VIEW_CHANNEL]);
+        let staff = AwaitingCategoryFixture::new().with_name("Staff").build();
+
+        AwaitingGuildFixture::new()
+            .with_role(everyone.clone())
+            .with_role(role("Admin", vec![Permission::ADMINISTRATOR]))
+            .with_role(role("Moderator", vec![Permission::ADMINISTRATOR]))
+            .with_channel(
+                AwaitingChannelFixture::new()
+                    .with_name("private")
+                    .with_category(&staff)
+                    .with_permissions_overwrites(vec![overwrite(
+                        &everyone,
+                        vec![],
+                        vec![Permission::VIEW_CHANNEL],
+                    )])
+                    .build(),
+            )
+            .with_channel(
+                AwaitingChannelFixture::new()
+                    .with_name("open")
+                    .with_category(&staff)
+                    .with_permissions_overwrites(vec![overwrite(
+                        &everyone,
+                        vec![Permission::MENTION_EVERYONE],
+                        vec![],
+                    )])
+                    .build(),
+            )
+            .build()
+    }
+
+    #[test]
+    fn forbidden_role_permissions_skip_excepted_roles() {
+        let rule = ForbiddenRolePermissionsRule {
+            id: "admin-only".to_string(),
+            severity: PolicySeverity::Error,
+            roles: vec!["*".to_string()],
+            except: vec!["Admin".to_string()],
+            permissions: PermissionsList::from(vec![Permission::ADMINISTRATOR]),
+        };
+
+        let violations = rule.check(&given_guild());
+
+        assert_eq!(
+            violations,
+            vec![error("admin-only", "Role 'Moderator' has ADMINISTRATOR.")]
+        );
+    }
+
+    #[test]
+    fn forbidden_overwrite_allows_are_reported_by_channel() {
+        let rule = ForbiddenOverwriteAllowsRule {
+            id: "no-mention".to_string(),
+            severity: PolicySeverity::Error,
+            roles: vec![EVERYONE_ROLE_NAME.to_string()],
+            except: vec![],
+            permissions: PermissionsList::from(vec![Permission::MENTION_EVERYONE]),
+        };
+
+        let violations = rule.check(&given_guild());
+
+        assert_eq!(
+            violations,
+            vec![error(
+                "no-mention",
+                "Channel 'Staff:open (TEXT)' allows MENTION_EVERYONE to role '@everyone'."
+            )]
+        );
+    }
+
+    #[test]
+    fn required_channel_denies_are_checked_in_matching_categories() {
+        let rule = RequiredChannelDeniesRule {
+            id: "staff-private".to_string(),
+            severity: PolicySeverity::Error,
+            category: "Sta*".to_string(),
+            role: EVERYONE_ROLE_NAME.to_string(),
+            permissions: PermissionsList::from(vec![Permission::VIEW_CHANNEL]),
+        };
+
+        let violations = rule.check(&given_guild());
+
+        assert_eq!(
+            violations,
+            vec![error(
+                "staff-private",
+                "Channel 'Staff:open (TEXT)' does not deny VIEW_CHANNEL to role '@everyone'."
+            )]
+        );
+    }
+}
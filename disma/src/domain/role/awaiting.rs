@@ -4,7 +4,7 @@ use crate::permission::PermissionsList;
 
 use super::{ExtraRolesStrategy, Role, RolesList};
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct AwaitingRolesList {
     pub items: RolesList<AwaitingRole>,
     pub extra_items_strategy: Arc<dyn ExtraRolesStrategy>,
@@ -30,4 +30,8 @@ impl Role for AwaitingRole {
     fn name(&self) -> &str {
         &self.name
     }
+
+    fn permissions(&self) -> &PermissionsList {
+        &self.permissions
+    }
 }
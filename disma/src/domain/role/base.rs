@@ -2,10 +2,13 @@ use std::collections::HashMap;
 
 use crate::core::ListComparison;
 
+use crate::permission::PermissionsList;
+
 use super::ExistingRole;
 
 pub trait Role: Clone {
     fn name(&self) -> &str;
+    fn permissions(&self) -> &PermissionsList;
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -93,6 +96,9 @@ impl RolesList<ExistingRole> {
     }
 
     pub fn add_or_replace(&mut self, role: ExistingRole) {
+        // The role may have been renamed
+        self.roles_by_name
+            .retain(|_, existing| existing.id != role.id);
         self.roles_by_name.insert(role.name().to_string(), role);
     }
 
@@ -10,10 +10,17 @@ pub struct ExistingRole {
     pub color: Option<String>,
     pub is_mentionable: bool,
     pub show_in_sidebar: bool,
+    /// Managed by an integration, ex: a bot's role, which cannot be given
+    /// or removed.
+    pub is_managed: bool,
 }
 
 impl Role for ExistingRole {
     fn name(&self) -> &str {
         &self.name
     }
+
+    fn permissions(&self) -> &PermissionsList {
+        &self.permissions
+    }
 }
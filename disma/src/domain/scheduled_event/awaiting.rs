@@ -0,0 +1,35 @@
+use std::sync::Arc;
+
+use crate::channel::AwaitingChannel;
+
+use super::{
+    ExtraScheduledEventsStrategy, ScheduledEvent, ScheduledEventLocation, ScheduledEventsList,
+};
+
+#[derive(Debug, Clone)]
+pub struct AwaitingScheduledEventsList {
+    pub items: ScheduledEventsList<AwaitingScheduledEvent>,
+    pub extra_items_strategy: Arc<dyn ExtraScheduledEventsStrategy>,
+}
+
+impl PartialEq for AwaitingScheduledEventsList {
+    fn eq(&self, other: &Self) -> bool {
+        self.items == other.items
+            && self.extra_items_strategy._type() == other.extra_items_strategy._type()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AwaitingScheduledEvent {
+    pub name: String,
+    pub description: Option<String>,
+    pub location: ScheduledEventLocation<AwaitingChannel>,
+    pub start_time: String,
+    pub end_time: Option<String>,
+}
+
+impl ScheduledEvent for AwaitingScheduledEvent {
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
@@ -0,0 +1,262 @@
+use std::collections::HashMap;
+
+use strum::{Display, EnumString};
+
+use crate::{channel::Channel, core::ListComparison};
+
+use super::ExistingScheduledEvent;
+
+pub trait ScheduledEvent: Clone {
+    fn name(&self) -> &str;
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScheduledEventsList<E>
+where
+    E: ScheduledEvent,
+{
+    events_by_name: HashMap<String, E>,
+    duplicates_by_name: HashMap<String, usize>,
+}
+
+impl<E: ScheduledEvent> ScheduledEventsList<E> {
+    pub fn new() -> Self {
+        Self {
+            events_by_name: HashMap::new(),
+            duplicates_by_name: HashMap::new(),
+        }
+    }
+
+    pub fn find_by_name(&self, name: &str) -> Option<&E> {
+        self.events_by_name.get(name)
+    }
+
+    pub fn add(&mut self, event: E) {
+        if self.events_by_name.contains_key(event.name()) {
+            // TODO replace with Result
+            panic!("All scheduled events must have unique names.");
+        }
+
+        self.events_by_name.insert(event.name().to_string(), event);
+    }
+
+    pub fn to_list(&self) -> Vec<&E> {
+        self.events_by_name.values().collect()
+    }
+
+    pub fn compare_by_name<'a, E2: ScheduledEvent>(
+        &'a self,
+        other: &'a ScheduledEventsList<E2>,
+    ) -> ListComparison<&'a E, &'a E2> {
+        let mut extra_self: Vec<&E> = Vec::new();
+        let mut extra_other: Vec<&E2> = Vec::new();
+        let mut same: Vec<(&E, &E2)> = Vec::new();
+
+        for self_item in self.to_list() {
+            match other.find_by_name(self_item.name()) {
+                Some(other_item) => same.push((self_item, other_item)),
+                None => extra_self.push(self_item),
+            }
+        }
+
+        for other_item in other.to_list() {
+            if self.find_by_name(other_item.name()).is_none() {
+                extra_other.push(other_item)
+            }
+        }
+
+        ListComparison {
+            extra_self,
+            extra_other,
+            same,
+        }
+    }
+}
+
+impl<E: ScheduledEvent> Default for ScheduledEventsList<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E: ScheduledEvent> From<Vec<E>> for ScheduledEventsList<E> {
+    fn from(events: Vec<E>) -> Self {
+        let mut events_list = ScheduledEventsList::new();
+
+        for event in events.into_iter() {
+            events_list.add(event);
+        }
+
+        events_list
+    }
+}
+
+impl ScheduledEventsList<ExistingScheduledEvent> {
+    /// Events as listed by Discord, which may share their name, ex: recurring
+    /// events. Only the earliest event of each name is kept, the others being
+    /// counted as duplicates.
+    pub fn from_existing(mut events: Vec<ExistingScheduledEvent>) -> Self {
+        events.sort_by(|a, b| a.start_time.cmp(&b.start_time));
+
+        let mut events_list = ScheduledEventsList::new();
+        for event in events.into_iter() {
+            match events_list.events_by_name.contains_key(&event.name) {
+                true => {
+                    *events_list
+                        .duplicates_by_name
+                        .entry(event.name)
+                        .or_default() += 1
+                }
+                false => events_list.add(event),
+            }
+        }
+
+        events_list
+    }
+
+    /// Number of other events named `name`, left out of the list.
+    pub fn count_duplicates(&self, name: &str) -> usize {
+        self.duplicates_by_name
+            .get(name)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    pub fn add_or_replace(&mut self, event: ExistingScheduledEvent) {
+        self.events_by_name.insert(event.name().to_string(), event);
+    }
+
+    pub fn remove(&mut self, event: ExistingScheduledEvent) {
+        self.events_by_name.remove(event.name());
+    }
+}
+
+#[derive(Debug, Display, EnumString, PartialEq, Clone)]
+pub enum ScheduledEventLocationType {
+    VOICE,
+    STAGE,
+    EXTERNAL,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum ScheduledEventLocation<C>
+where
+    C: Channel,
+{
+    Voice { channel: C },
+    Stage { channel: C },
+    External { location: String },
+}
+
+impl<C: Channel> ScheduledEventLocation<C> {
+    pub fn location_type(&self) -> ScheduledEventLocationType {
+        match self {
+            Self::Voice { .. } => ScheduledEventLocationType::VOICE,
+            Self::Stage { .. } => ScheduledEventLocationType::STAGE,
+            Self::External { .. } => ScheduledEventLocationType::EXTERNAL,
+        }
+    }
+
+    pub fn describe(&self) -> String {
+        match self {
+            Self::Voice { channel } | Self::Stage { channel } => {
+                format!("{} ({})", self.location_type(), channel.unique_name())
+            }
+            Self::External { location } => format!("{} ({location})", self.location_type()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        core::ListComparison,
+        scheduled_event::{ExistingScheduledEvent, ScheduledEventsList},
+        tests::fixtures::existing::ExistingScheduledEventFixture,
+    };
+
+    const SOME_NAME: &str = "non-existant";
+
+    #[test]
+    fn can_find_by_name() {
+        let event = ExistingScheduledEventFixture::new()
+            .with_name(SOME_NAME)
+            .build();
+        let list = ScheduledEventsList::from(vec![event.clone()]);
+
+        let found = list.find_by_name(SOME_NAME);
+
+        assert_eq!(found, Some(&event));
+    }
+
+    #[test]
+    #[should_panic]
+    fn given_event_with_same_name_already_in_list_when_adding_event_should_panic() {
+        let event = ExistingScheduledEventFixture::new()
+            .with_name(SOME_NAME)
+            .build();
+        let event_copy = ExistingScheduledEventFixture::new()
+            .with_name(SOME_NAME)
+            .build();
+        let mut list = ScheduledEventsList::from(vec![event]);
+
+        list.add(event_copy);
+    }
+
+    #[test]
+    fn can_add_or_replace_event() {
+        let event = ExistingScheduledEventFixture::new()
+            .with_name(SOME_NAME)
+            .build();
+        let mut list: ScheduledEventsList<ExistingScheduledEvent> = ScheduledEventsList::new();
+
+        list.add_or_replace(event.clone());
+        list.add_or_replace(event.clone());
+
+        assert_eq!(list.to_list(), vec![&event]);
+    }
+
+    #[test]
+    fn given_existing_events_with_same_name_it_keeps_the_earliest() {
+        let later_event = ExistingScheduledEventFixture::new()
+            .with_name(SOME_NAME)
+            .with_start_time("2030-01-08T18:00:00Z")
+            .build();
+        let earliest_event = ExistingScheduledEventFixture::new()
+            .with_name(SOME_NAME)
+            .with_start_time("2030-01-01T18:00:00Z")
+            .build();
+
+        let list = ScheduledEventsList::from_existing(vec![later_event, earliest_event.clone()]);
+
+        assert_eq!(list.to_list(), vec![&earliest_event]);
+        assert_eq!(list.count_duplicates(SOME_NAME), 1);
+    }
+
+    #[test]
+    fn can_compare_by_name() {
+        let same_self_event = ExistingScheduledEventFixture::new()
+            .with_name(SOME_NAME)
+            .build();
+        let same_other_event = ExistingScheduledEventFixture::new()
+            .with_name(SOME_NAME)
+            .build();
+        let extra_self_event = ExistingScheduledEventFixture::new().build();
+        let extra_other_event = ExistingScheduledEventFixture::new().build();
+
+        let self_list =
+            ScheduledEventsList::from(vec![same_self_event.clone(), extra_self_event.clone()]);
+        let other_list =
+            ScheduledEventsList::from(vec![same_other_event.clone(), extra_other_event.clone()]);
+
+        let ListComparison {
+            extra_self,
+            extra_other,
+            same,
+        } = self_list.compare_by_name(&other_list);
+
+        assert_eq!(extra_self, vec![&extra_self_event]);
+        assert_eq!(extra_other, vec![&extra_other_event]);
+        assert_eq!(same, vec![(&same_self_event, &same_other_event)]);
+    }
+}
@@ -0,0 +1,19 @@
+use crate::channel::ExistingChannel;
+
+use super::{ScheduledEvent, ScheduledEventLocation};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExistingScheduledEvent {
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub location: ScheduledEventLocation<ExistingChannel>,
+    pub start_time: String,
+    pub end_time: Option<String>,
+}
+
+impl ScheduledEvent for ExistingScheduledEvent {
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
@@ -0,0 +1,86 @@
+use core::fmt::Debug;
+
+use crate::core::changes::scheduled_event::ScheduledEventChange;
+
+use super::ExistingScheduledEvent;
+
+pub trait ExtraScheduledEventsStrategy {
+    fn _type(&self) -> ExtraScheduledEventsStrategyType;
+    fn handle_extra_scheduled_event(
+        &self,
+        extra_existing: &ExistingScheduledEvent,
+        changes: &mut Vec<ScheduledEventChange>,
+    );
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ExtraScheduledEventsStrategyType {
+    Keep,
+    Remove,
+}
+
+impl Debug for dyn ExtraScheduledEventsStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self._type())
+    }
+}
+
+pub struct RemoveExtraScheduledEvents {}
+
+impl ExtraScheduledEventsStrategy for RemoveExtraScheduledEvents {
+    fn _type(&self) -> ExtraScheduledEventsStrategyType {
+        ExtraScheduledEventsStrategyType::Remove
+    }
+
+    fn handle_extra_scheduled_event(
+        &self,
+        extra_existing: &ExistingScheduledEvent,
+        changes: &mut Vec<ScheduledEventChange>,
+    ) {
+        changes.push(ScheduledEventChange::Delete(extra_existing.clone()));
+    }
+}
+
+pub struct KeepExtraScheduledEvents {}
+
+impl ExtraScheduledEventsStrategy for KeepExtraScheduledEvents {
+    fn _type(&self) -> ExtraScheduledEventsStrategyType {
+        ExtraScheduledEventsStrategyType::Keep
+    }
+
+    fn handle_extra_scheduled_event(
+        &self,
+        _extra_existing: &ExistingScheduledEvent,
+        _changes: &mut Vec<ScheduledEventChange>,
+    ) {
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::fixtures::existing::ExistingScheduledEventFixture;
+
+    use super::*;
+
+    #[test]
+    fn when_keeping_extra_events_should_not_add_changes() {
+        let mut changes: Vec<ScheduledEventChange> = Vec::new();
+        let extra_event = ExistingScheduledEventFixture::new().build();
+        let strategy = KeepExtraScheduledEvents {};
+
+        strategy.handle_extra_scheduled_event(&extra_event, &mut changes);
+
+        assert!(changes.is_empty());
+    }
+
+    #[test]
+    fn when_removing_extra_events_should_add_delete_change() {
+        let mut changes: Vec<ScheduledEventChange> = Vec::new();
+        let extra_event = ExistingScheduledEventFixture::new().build();
+        let strategy = RemoveExtraScheduledEvents {};
+
+        strategy.handle_extra_scheduled_event(&extra_event, &mut changes);
+
+        assert_eq!(changes, vec![ScheduledEventChange::Delete(extra_event)]);
+    }
+}
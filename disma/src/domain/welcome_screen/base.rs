@@ -0,0 +1,34 @@
+use crate::channel::{AwaitingChannel, Channel, ExistingChannel};
+
+pub type AwaitingWelcomeScreen = WelcomeScreen<AwaitingChannel>;
+pub type ExistingWelcomeScreen = WelcomeScreen<ExistingChannel>;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct WelcomeScreen<C>
+where
+    C: Channel,
+{
+    pub enabled: bool,
+    pub description: Option<String>,
+    pub channels: Vec<WelcomeScreenChannel<C>>,
+}
+
+impl<C: Channel> Default for WelcomeScreen<C> {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            description: None,
+            channels: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct WelcomeScreenChannel<C>
+where
+    C: Channel,
+{
+    pub channel: C,
+    pub description: String,
+    pub emoji: Option<String>,
+}
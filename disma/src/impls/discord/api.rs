@@ -8,11 +8,25 @@ use reqwest::{
 };
 
 use super::dtos::{
+    automod::{AutoModRuleRequest, AutoModRuleResponse},
     channel::{ChannelRequest, ChannelResponse},
-    guild::GuildResponse,
+    guild::{GuildDetailsResponse, GuildResponse},
+    member::{MemberResponse, UserResponse},
+    onboarding::{OnboardingRequest, OnboardingResponse},
     role::{RoleRequest, RoleResponse},
+    scheduled_event::{ScheduledEventRequest, ScheduledEventResponse},
+    thread::{
+        ActiveThreadsResponse, ArchivedThreadsResponse, ThreadRequest, ThreadResponse,
+        ThreadUpdateRequest,
+    },
+    welcome_screen::{WelcomeScreenRequest, WelcomeScreenResponse},
 };
 
+/// Maximum number of members Discord returns per page.
+const MEMBERS_PAGE_SIZE: usize = 1000;
+/// Maximum number of archived threads Discord returns per page.
+const ARCHIVED_THREADS_PAGE_SIZE: usize = 100;
+
 pub struct DiscordApi {
     client: Client,
 }
@@ -143,6 +157,285 @@ impl DiscordApi {
         self.handle_response(response).map(|_| ())
     }
 
+    pub fn list_active_threads(
+        &self,
+        guild_id: &str,
+    ) -> Result<ActiveThreadsResponse, DiscordError> {
+        let url = format!("/guilds/{guild_id}/threads/active");
+        let response = self.handle_http_error(self.client.clone().get(&url).send())?;
+
+        self.handle_response(response)
+            .map(|response| response.parsed_body().unwrap())
+    }
+
+    pub fn list_public_archived_threads(
+        &self,
+        channel_id: &str,
+    ) -> Result<Vec<ThreadResponse>, DiscordError> {
+        self.list_archived_threads(&format!("/channels/{channel_id}/threads/archived/public"))
+    }
+
+    pub fn list_private_archived_threads(
+        &self,
+        channel_id: &str,
+    ) -> Result<Vec<ThreadResponse>, DiscordError> {
+        self.list_archived_threads(&format!("/channels/{channel_id}/threads/archived/private"))
+    }
+
+    /// Every page of archived threads of `url`, the most recently archived
+    /// first.
+    fn list_archived_threads(&self, url: &str) -> Result<Vec<ThreadResponse>, DiscordError> {
+        let mut threads: Vec<ThreadResponse> = Vec::new();
+        let mut before: Option<String> = None;
+
+        loop {
+            let mut page_url = format!("{url}?limit={ARCHIVED_THREADS_PAGE_SIZE}");
+            if let Some(before) = &before {
+                page_url.push_str(&format!("&before={}", before.replace('+', "%2B")));
+            }
+            let response = self.handle_http_error(self.client.clone().get(&page_url).send())?;
+            let page: ArchivedThreadsResponse = self
+                .handle_response(response)
+                .map(|response| response.parsed_body().unwrap())?;
+
+            if let Some(last) = page.threads.last() {
+                before = Some(last.thread_metadata.archive_timestamp.clone());
+            }
+            threads.extend(page.threads);
+
+            if !page.has_more {
+                return Ok(threads);
+            }
+        }
+    }
+
+    pub fn add_thread(
+        &self,
+        channel_id: &str,
+        body: ThreadRequest,
+    ) -> Result<ThreadResponse, DiscordError> {
+        let url = format!("/channels/{channel_id}/threads");
+        let request = self.handle_request(self.client.clone().post(&url).json_body(body))?;
+        let response = self.handle_http_error(request.send())?;
+
+        self.handle_response(response)
+            .map(|response| response.parsed_body().unwrap())
+    }
+
+    pub fn update_thread(
+        &self,
+        id: &str,
+        body: ThreadUpdateRequest,
+    ) -> Result<ThreadResponse, DiscordError> {
+        let url = format!("/channels/{id}");
+        let request = self.handle_request(self.client.clone().patch(&url).json_body(body))?;
+        let response = self.handle_http_error(request.send())?;
+
+        self.handle_response(response)
+            .map(|response| response.parsed_body().unwrap())
+    }
+
+    pub fn list_automod_rules(
+        &self,
+        guild_id: &str,
+    ) -> Result<Vec<AutoModRuleResponse>, DiscordError> {
+        let url = format!("/guilds/{guild_id}/auto-moderation/rules");
+        let response = self.handle_http_error(self.client.clone().get(&url).send())?;
+
+        self.handle_response(response)
+            .map(|response| response.parsed_body().unwrap())
+    }
+
+    pub fn add_automod_rule(
+        &self,
+        guild_id: &str,
+        body: AutoModRuleRequest,
+    ) -> Result<AutoModRuleResponse, DiscordError> {
+        let url = format!("/guilds/{guild_id}/auto-moderation/rules");
+        let request = self.handle_request(self.client.clone().post(&url).json_body(body))?;
+        let response = self.handle_http_error(request.send())?;
+
+        self.handle_response(response)
+            .map(|response| response.parsed_body().unwrap())
+    }
+
+    pub fn update_automod_rule(
+        &self,
+        guild_id: &str,
+        rule_id: &str,
+        body: AutoModRuleRequest,
+    ) -> Result<AutoModRuleResponse, DiscordError> {
+        let url = format!("/guilds/{guild_id}/auto-moderation/rules/{rule_id}");
+        let request = self.handle_request(self.client.clone().patch(&url).json_body(body))?;
+        let response = self.handle_http_error(request.send())?;
+
+        self.handle_response(response)
+            .map(|response| response.parsed_body().unwrap())
+    }
+
+    pub fn delete_automod_rule(&self, guild_id: &str, rule_id: &str) -> Result<(), DiscordError> {
+        let url = format!("/guilds/{guild_id}/auto-moderation/rules/{rule_id}");
+        let response = self.handle_http_error(self.client.clone().delete(&url).send())?;
+
+        self.handle_response(response).map(|_| ())
+    }
+
+    pub fn list_scheduled_events(
+        &self,
+        guild_id: &str,
+    ) -> Result<Vec<ScheduledEventResponse>, DiscordError> {
+        let url = format!("/guilds/{guild_id}/scheduled-events");
+        let response = self.handle_http_error(self.client.clone().get(&url).send())?;
+
+        self.handle_response(response)
+            .map(|response| response.parsed_body().unwrap())
+    }
+
+    pub fn add_scheduled_event(
+        &self,
+        guild_id: &str,
+        body: ScheduledEventRequest,
+    ) -> Result<ScheduledEventResponse, DiscordError> {
+        let url = format!("/guilds/{guild_id}/scheduled-events");
+        let request = self.handle_request(self.client.clone().post(&url).json_body(body))?;
+        let response = self.handle_http_error(request.send())?;
+
+        self.handle_response(response)
+            .map(|response| response.parsed_body().unwrap())
+    }
+
+    pub fn update_scheduled_event(
+        &self,
+        guild_id: &str,
+        event_id: &str,
+        body: ScheduledEventRequest,
+    ) -> Result<ScheduledEventResponse, DiscordError> {
+        let url = format!("/guilds/{guild_id}/scheduled-events/{event_id}");
+        let request = self.handle_request(self.client.clone().patch(&url).json_body(body))?;
+        let response = self.handle_http_error(request.send())?;
+
+        self.handle_response(response)
+            .map(|response| response.parsed_body().unwrap())
+    }
+
+    pub fn delete_scheduled_event(
+        &self,
+        guild_id: &str,
+        event_id: &str,
+    ) -> Result<(), DiscordError> {
+        let url = format!("/guilds/{guild_id}/scheduled-events/{event_id}");
+        let response = self.handle_http_error(self.client.clone().delete(&url).send())?;
+
+        self.handle_response(response).map(|_| ())
+    }
+
+    pub fn get_current_user(&self) -> Result<UserResponse, DiscordError> {
+        let response = self.handle_http_error(self.client.clone().get("/users/@me").send())?;
+
+        self.handle_response(response)
+            .map(|response| response.parsed_body().unwrap())
+    }
+
+    pub fn list_members(&self, guild_id: &str) -> Result<Vec<MemberResponse>, DiscordError> {
+        let mut members: Vec<MemberResponse> = Vec::new();
+        let mut after = "0".to_string();
+
+        loop {
+            let url = format!("/guilds/{guild_id}/members?limit={MEMBERS_PAGE_SIZE}&after={after}");
+            let response = self.handle_http_error(self.client.clone().get(&url).send())?;
+            let page: Vec<MemberResponse> = self
+                .handle_response(response)
+                .map(|response| response.parsed_body().unwrap())?;
+
+            let is_last_page = page.len() < MEMBERS_PAGE_SIZE;
+            if let Some(last) = page.last() {
+                after = last.user.id.clone();
+            }
+            members.extend(page);
+
+            if is_last_page {
+                return Ok(members);
+            }
+        }
+    }
+
+    pub fn add_member_role(
+        &self,
+        guild_id: &str,
+        member_id: &str,
+        role_id: &str,
+    ) -> Result<(), DiscordError> {
+        let url = format!("/guilds/{guild_id}/members/{member_id}/roles/{role_id}");
+        let response = self.handle_http_error(self.client.clone().put(&url).send())?;
+
+        self.handle_response(response).map(|_| ())
+    }
+
+    pub fn remove_member_role(
+        &self,
+        guild_id: &str,
+        member_id: &str,
+        role_id: &str,
+    ) -> Result<(), DiscordError> {
+        let url = format!("/guilds/{guild_id}/members/{member_id}/roles/{role_id}");
+        let response = self.handle_http_error(self.client.clone().delete(&url).send())?;
+
+        self.handle_response(response).map(|_| ())
+    }
+
+    pub fn get_guild(&self, guild_id: &str) -> Result<GuildDetailsResponse, DiscordError> {
+        let url = format!("/guilds/{guild_id}");
+        let response = self.handle_http_error(self.client.clone().get(&url).send())?;
+
+        self.handle_response(response)
+            .map(|response| response.parsed_body().unwrap())
+    }
+
+    pub fn get_onboarding(&self, guild_id: &str) -> Result<OnboardingResponse, DiscordError> {
+        let url = format!("/guilds/{guild_id}/onboarding");
+        let response = self.handle_http_error(self.client.clone().get(&url).send())?;
+
+        self.handle_response(response)
+            .map(|response| response.parsed_body().unwrap())
+    }
+
+    pub fn update_onboarding(
+        &self,
+        guild_id: &str,
+        body: OnboardingRequest,
+    ) -> Result<OnboardingResponse, DiscordError> {
+        let url = format!("/guilds/{guild_id}/onboarding");
+        let request = self.handle_request(self.client.clone().put(&url).json_body(body))?;
+        let response = self.handle_http_error(request.send())?;
+
+        self.handle_response(response)
+            .map(|response| response.parsed_body().unwrap())
+    }
+
+    pub fn get_welcome_screen(
+        &self,
+        guild_id: &str,
+    ) -> Result<WelcomeScreenResponse, DiscordError> {
+        let url = format!("/guilds/{guild_id}/welcome-screen");
+        let response = self.handle_http_error(self.client.clone().get(&url).send())?;
+
+        self.handle_response(response)
+            .map(|response| response.parsed_body().unwrap())
+    }
+
+    pub fn update_welcome_screen(
+        &self,
+        guild_id: &str,
+        body: WelcomeScreenRequest,
+    ) -> Result<WelcomeScreenResponse, DiscordError> {
+        let url = format!("/guilds/{guild_id}/welcome-screen");
+        let request = self.handle_request(self.client.clone().patch(&url).json_body(body))?;
+        let response = self.handle_http_error(request.send())?;
+
+        self.handle_response(response)
+            .map(|response| response.parsed_body().unwrap())
+    }
+
     fn handle_request(&self, result: Result<Request, HttpError>) -> Result<Request, DiscordError> {
         result.map_err(|error| DiscordError::InvalidRequest(error.to_string()))
     }
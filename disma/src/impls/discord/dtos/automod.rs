@@ -0,0 +1,473 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    automod::{
+        AutoModAction, AutoModEventType, AutoModKeywordPreset, AutoModTrigger, AwaitingAutoModRule,
+        ExistingAutoModRule,
+    },
+    channel::{Channel, ChannelsList, ExistingChannel},
+    role::{ExistingRole, RolesList},
+};
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Clone)]
+pub struct AutoModTriggerMetadataDto {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keyword_filter: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub regex_patterns: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub presets: Option<Vec<u8>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_list: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mention_total_limit: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mention_raid_protection_enabled: Option<bool>,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Clone)]
+pub struct AutoModActionMetadataDto {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration_seconds: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_message: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct AutoModActionDto {
+    #[serde(rename = "type")]
+    pub _type: u8,
+    #[serde(default = "AutoModActionMetadataDto::default")]
+    pub metadata: AutoModActionMetadataDto,
+}
+
+#[derive(Debug, Serialize, PartialEq)]
+pub struct AutoModRuleRequest {
+    pub name: String,
+    pub event_type: u8,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trigger_type: Option<u8>,
+    pub trigger_metadata: AutoModTriggerMetadataDto,
+    pub actions: Vec<AutoModActionDto>,
+    pub enabled: bool,
+    pub exempt_roles: Vec<String>,
+    pub exempt_channels: Vec<String>,
+}
+
+impl AutoModRuleRequest {
+    pub fn from_rule(
+        rule: &AwaitingAutoModRule,
+        roles: &RolesList<ExistingRole>,
+        channels: &ChannelsList<ExistingChannel>,
+    ) -> Self {
+        let find_channel_id = |channel: &dyn Channel| {
+            channels
+                .find_by_unique_name(&channel.unique_name())
+                .unwrap_or_else(|| {
+                    panic!(
+                        "Cannot create AutoMod rule request with non-existant channel {}.",
+                        channel.unique_name()
+                    )
+                })
+                .id
+                .clone()
+        };
+
+        let actions = rule
+            .actions
+            .iter()
+            .map(|action| match action {
+                AutoModAction::BlockMessage { custom_message } => AutoModActionDto {
+                    _type: 1,
+                    metadata: AutoModActionMetadataDto {
+                        custom_message: custom_message.clone(),
+                        ..Default::default()
+                    },
+                },
+                AutoModAction::SendAlertMessage { channel } => AutoModActionDto {
+                    _type: 2,
+                    metadata: AutoModActionMetadataDto {
+                        channel_id: Some(find_channel_id(channel)),
+                        ..Default::default()
+                    },
+                },
+                AutoModAction::Timeout { duration_seconds } => AutoModActionDto {
+                    _type: 3,
+                    metadata: AutoModActionMetadataDto {
+                        duration_seconds: Some(*duration_seconds),
+                        ..Default::default()
+                    },
+                },
+            })
+            .collect();
+
+        let exempt_roles = rule
+            .exempt_roles
+            .to_list()
+            .iter()
+            .map(|role| {
+                roles
+                    .find_by_name(&role.name)
+                    .unwrap_or_else(|| {
+                        panic!(
+                            "Cannot create AutoMod rule request with non-existant role {}.",
+                            &role.name
+                        )
+                    })
+                    .id
+                    .clone()
+            })
+            .collect();
+
+        let exempt_channels = rule
+            .exempt_channels
+            .to_list()
+            .into_iter()
+            .map(|channel| find_channel_id(channel))
+            .collect();
+
+        let (trigger_type, trigger_metadata) = trigger_to_dto(&rule.trigger);
+
+        Self {
+            name: rule.name.clone(),
+            event_type: match rule.event_type {
+                AutoModEventType::MESSAGE_SEND => 1,
+                AutoModEventType::MEMBER_UPDATE => 2,
+            },
+            trigger_type: Some(trigger_type),
+            trigger_metadata,
+            actions,
+            enabled: rule.enabled,
+            exempt_roles,
+            exempt_channels,
+        }
+    }
+
+    /// Discord does not allow the trigger type to be sent when updating a rule.
+    pub fn for_update(self) -> Self {
+        Self {
+            trigger_type: None,
+            ..self
+        }
+    }
+}
+
+fn trigger_to_dto(trigger: &AutoModTrigger) -> (u8, AutoModTriggerMetadataDto) {
+    match trigger {
+        AutoModTrigger::Keyword {
+            keywords,
+            regex_patterns,
+            allow_list,
+        } => (
+            1,
+            AutoModTriggerMetadataDto {
+                keyword_filter: Some(keywords.clone()),
+                regex_patterns: Some(regex_patterns.clone()),
+                allow_list: Some(allow_list.clone()),
+                ..Default::default()
+            },
+        ),
+        AutoModTrigger::Spam => (3, AutoModTriggerMetadataDto::default()),
+        AutoModTrigger::KeywordPreset {
+            presets,
+            allow_list,
+        } => (
+            4,
+            AutoModTriggerMetadataDto {
+                presets: Some(
+                    presets
+                        .iter()
+                        .map(|preset| match preset {
+                            AutoModKeywordPreset::PROFANITY => 1,
+                            AutoModKeywordPreset::SEXUAL_CONTENT => 2,
+                            AutoModKeywordPreset::SLURS => 3,
+                        })
+                        .collect(),
+                ),
+                allow_list: Some(allow_list.clone()),
+                ..Default::default()
+            },
+        ),
+        AutoModTrigger::MentionSpam {
+            mention_total_limit,
+            mention_raid_protection_enabled,
+        } => (
+            5,
+            AutoModTriggerMetadataDto {
+                mention_total_limit: Some(*mention_total_limit),
+                mention_raid_protection_enabled: Some(*mention_raid_protection_enabled),
+                ..Default::default()
+            },
+        ),
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct AutoModRuleResponse {
+    pub id: String,
+    pub name: String,
+    pub event_type: u8,
+    pub trigger_type: u8,
+    #[serde(default = "AutoModTriggerMetadataDto::default")]
+    pub trigger_metadata: AutoModTriggerMetadataDto,
+    pub actions: Vec<AutoModActionDto>,
+    pub enabled: bool,
+    pub exempt_roles: Vec<String>,
+    pub exempt_channels: Vec<String>,
+}
+
+impl AutoModRuleResponse {
+    pub fn _try_into(
+        self,
+        roles: &RolesList<ExistingRole>,
+        channels: &ChannelsList<ExistingChannel>,
+    ) -> Result<ExistingAutoModRule, String> {
+        let find_channel = |id: &str| {
+            channels
+                .find_by_id(id)
+                .cloned()
+                .ok_or_else(|| format!("Unsupported or non-existant channel with id '{id}'"))
+        };
+
+        let event_type = match self.event_type {
+            1 => AutoModEventType::MESSAGE_SEND,
+            2 => AutoModEventType::MEMBER_UPDATE,
+            other => return Err(format!("Unsupported event type {other}")),
+        };
+
+        let metadata = self.trigger_metadata;
+        let trigger = match self.trigger_type {
+            1 => AutoModTrigger::Keyword {
+                keywords: metadata.keyword_filter.unwrap_or_default(),
+                regex_patterns: metadata.regex_patterns.unwrap_or_default(),
+                allow_list: metadata.allow_list.unwrap_or_default(),
+            },
+            3 => AutoModTrigger::Spam,
+            4 => AutoModTrigger::KeywordPreset {
+                presets: metadata
+                    .presets
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|preset| match preset {
+                        1 => Ok(AutoModKeywordPreset::PROFANITY),
+                        2 => Ok(AutoModKeywordPreset::SEXUAL_CONTENT),
+                        3 => Ok(AutoModKeywordPreset::SLURS),
+                        other => Err(format!("Unsupported keyword preset {other}")),
+                    })
+                    .collect::<Result<Vec<AutoModKeywordPreset>, String>>()?,
+                allow_list: metadata.allow_list.unwrap_or_default(),
+            },
+            5 => AutoModTrigger::MentionSpam {
+                mention_total_limit: metadata.mention_total_limit.unwrap_or_default(),
+                mention_raid_protection_enabled: metadata
+                    .mention_raid_protection_enabled
+                    .unwrap_or_default(),
+            },
+            other => return Err(format!("Unsupported trigger type {other}")),
+        };
+
+        let actions = self
+            .actions
+            .into_iter()
+            .map(|action| match action._type {
+                1 => Ok(AutoModAction::BlockMessage {
+                    custom_message: action.metadata.custom_message,
+                }),
+                2 => Ok(AutoModAction::SendAlertMessage {
+                    channel: find_channel(&action.metadata.channel_id.unwrap_or_default())?,
+                }),
+                3 => Ok(AutoModAction::Timeout {
+                    duration_seconds: action.metadata.duration_seconds.unwrap_or_default(),
+                }),
+                other => Err(format!("Unsupported action type {other}")),
+            })
+            .collect::<Result<Vec<AutoModAction<ExistingChannel>>, String>>()?;
+
+        let exempt_roles = self
+            .exempt_roles
+            .iter()
+            .map(|id| {
+                roles
+                    .find_by_id(id)
+                    .cloned()
+                    .ok_or_else(|| format!("Non-existant exempt role with id '{id}'"))
+            })
+            .collect::<Result<Vec<ExistingRole>, String>>()?;
+
+        let exempt_channels = self
+            .exempt_channels
+            .iter()
+            .map(|id| find_channel(id))
+            .collect::<Result<Vec<ExistingChannel>, String>>()?;
+
+        Ok(ExistingAutoModRule {
+            id: self.id,
+            name: self.name,
+            event_type,
+            trigger,
+            actions,
+            enabled: self.enabled,
+            exempt_roles: RolesList::from(exempt_roles),
+            exempt_channels: ChannelsList::from(exempt_channels),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod request {
+        use crate::{
+            automod::{AutoModAction, AutoModTrigger},
+            channel::ChannelsList,
+            impls::discord::dtos::automod::{
+                AutoModActionDto, AutoModActionMetadataDto, AutoModRuleRequest,
+                AutoModTriggerMetadataDto,
+            },
+            role::RolesList,
+            tests::fixtures::{
+                awaiting::{AwaitingAutoModRuleFixture, AwaitingChannelFixture},
+                existing::ExistingChannelFixture,
+            },
+        };
+
+        #[test]
+        fn can_be_created_from_awaiting_rule() {
+            let existing_channel = ExistingChannelFixture::new().with_name("logs").build();
+            let rule = AwaitingAutoModRuleFixture::new()
+                .with_name("mentions")
+                .with_trigger(AutoModTrigger::MentionSpam {
+                    mention_total_limit: 5,
+                    mention_raid_protection_enabled: true,
+                })
+                .with_actions(vec![AutoModAction::SendAlertMessage {
+                    channel: AwaitingChannelFixture::new().with_name("logs").build(),
+                }])
+                .build();
+
+            let expected_request = AutoModRuleRequest {
+                name: "mentions".to_string(),
+                event_type: 1,
+                trigger_type: Some(5),
+                trigger_metadata: AutoModTriggerMetadataDto {
+                    mention_total_limit: Some(5),
+                    mention_raid_protection_enabled: Some(true),
+                    ..Default::default()
+                },
+                actions: vec![AutoModActionDto {
+                    _type: 2,
+                    metadata: AutoModActionMetadataDto {
+                        channel_id: Some(existing_channel.id.clone()),
+                        ..Default::default()
+                    },
+                }],
+                enabled: true,
+                exempt_roles: vec![],
+                exempt_channels: vec![],
+            };
+
+            let request = AutoModRuleRequest::from_rule(
+                &rule,
+                &RolesList::new(),
+                &ChannelsList::from(vec![existing_channel]),
+            );
+
+            assert_eq!(request, expected_request);
+        }
+
+        #[test]
+        fn given_update_it_omits_trigger_type() {
+            let rule = AwaitingAutoModRuleFixture::new().build();
+
+            let request =
+                AutoModRuleRequest::from_rule(&rule, &RolesList::new(), &ChannelsList::new())
+                    .for_update();
+
+            assert_eq!(request.trigger_type, None);
+        }
+    }
+
+    mod response {
+        use crate::{
+            automod::{
+                AutoModAction, AutoModEventType, AutoModKeywordPreset, AutoModTrigger,
+                ExistingAutoModRule,
+            },
+            channel::ChannelsList,
+            impls::discord::dtos::automod::{
+                AutoModActionDto, AutoModActionMetadataDto, AutoModRuleResponse,
+                AutoModTriggerMetadataDto,
+            },
+            role::RolesList,
+            tests::fixtures::existing::ExistingRoleFixture,
+        };
+
+        fn given_response(trigger_type: u8) -> AutoModRuleResponse {
+            AutoModRuleResponse {
+                id: "abc-123".to_string(),
+                name: "presets".to_string(),
+                event_type: 1,
+                trigger_type,
+                trigger_metadata: AutoModTriggerMetadataDto {
+                    presets: Some(vec![1, 3]),
+                    allow_list: Some(vec![]),
+                    ..Default::default()
+                },
+                actions: vec![AutoModActionDto {
+                    _type: 1,
+                    metadata: AutoModActionMetadataDto::default(),
+                }],
+                enabled: false,
+                exempt_roles: vec![],
+                exempt_channels: vec![],
+            }
+        }
+
+        #[test]
+        fn can_convert_into_existing_rule() {
+            let response = given_response(4);
+            let expected_rule = ExistingAutoModRule {
+                id: "abc-123".to_string(),
+                name: "presets".to_string(),
+                event_type: AutoModEventType::MESSAGE_SEND,
+                trigger: AutoModTrigger::KeywordPreset {
+                    presets: vec![AutoModKeywordPreset::PROFANITY, AutoModKeywordPreset::SLURS],
+                    allow_list: vec![],
+                },
+                actions: vec![AutoModAction::BlockMessage {
+                    custom_message: None,
+                }],
+                enabled: false,
+                exempt_roles: RolesList::new(),
+                exempt_channels: ChannelsList::new(),
+            };
+
+            let rule = response._try_into(&RolesList::new(), &ChannelsList::new());
+
+            assert_eq!(rule, Ok(expected_rule));
+        }
+
+        #[test]
+        fn given_unsupported_trigger_type_it_returns_error() {
+            let response = given_response(42);
+
+            let rule = response._try_into(&RolesList::new(), &ChannelsList::new());
+
+            assert!(rule.is_err());
+        }
+
+        #[test]
+        fn can_resolve_exempt_roles() {
+            let role = ExistingRoleFixture::new().build();
+            let mut response = given_response(4);
+            response.exempt_roles = vec![role.id.clone()];
+
+            let rule = response
+                ._try_into(&RolesList::from(vec![role.clone()]), &ChannelsList::new())
+                .unwrap();
+
+            assert_eq!(rule.exempt_roles, RolesList::from(vec![role]));
+        }
+    }
+}
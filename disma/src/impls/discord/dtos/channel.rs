@@ -16,6 +16,8 @@ pub enum ChannelDtoType {
     Text = 0,
     Voice = 2,
     Category = 4,
+    Stage = 13,
+    Forum = 15,
 }
 
 impl From<&ChannelType> for ChannelDtoType {
@@ -23,6 +25,8 @@ impl From<&ChannelType> for ChannelDtoType {
         match _type {
             ChannelType::TEXT => ChannelDtoType::Text,
             ChannelType::VOICE => ChannelDtoType::Voice,
+            ChannelType::STAGE => ChannelDtoType::Stage,
+            ChannelType::FORUM => ChannelDtoType::Forum,
         }
     }
 }
@@ -139,6 +143,8 @@ impl ChannelResponse {
         let channel_type = match self._type {
             0 => ChannelType::TEXT,
             2 => ChannelType::VOICE,
+            13 => ChannelType::STAGE,
+            15 => ChannelType::FORUM,
             _ => panic!(
                 "Cannot convert a Discord type {} channel into a Disma channel",
                 &self._type
@@ -182,6 +188,7 @@ impl ChannelResponse {
             topic: self.topic.clone(),
             category: category.cloned(),
             overwrites: PermissionsOverwritesList::from(permission_overwrites),
+            threads: Vec::new(),
         }
     }
 }
@@ -278,6 +285,7 @@ mod tests {
                     allow: PermissionsList::from("2113536"),
                     deny: PermissionsList::from("2113536"),
                 }]),
+                threads: None,
             };
 
             let expected_request = ChannelRequest {
@@ -315,6 +323,7 @@ mod tests {
                     allow: PermissionsList::from("2113536"),
                     deny: PermissionsList::from("2113536"),
                 }]),
+                threads: None,
             };
 
             ChannelRequest::from_channel(&channel, &RolesList::new(), &CategoriesList::new());
@@ -333,6 +342,7 @@ mod tests {
                 channel_type: ChannelType::TEXT,
                 category: Some(awaiting_category),
                 overwrites: PermissionsOverwritesList::new(),
+                threads: None,
             };
 
             ChannelRequest::from_channel(&channel, &RolesList::new(), &CategoriesList::new());
@@ -449,6 +459,7 @@ mod tests {
                     deny: PermissionsList::from("2113536"),
                 }]),
                 category: Some(existing_category.clone()),
+                threads: Vec::new(),
             };
 
             let channel = response.into_channel(
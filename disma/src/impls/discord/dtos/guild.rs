@@ -10,6 +10,11 @@ pub struct GuildResponse {
     pub permissions: String,
 }
 
+#[derive(Deserialize)]
+pub struct GuildDetailsResponse {
+    pub features: Vec<String>,
+}
+
 impl Into<GuildSummary> for GuildResponse {
     fn into(self) -> GuildSummary {
         GuildSummary {
@@ -0,0 +1,65 @@
+use serde::Deserialize;
+
+use crate::{
+    member::ExistingMember,
+    role::{ExistingRole, RolesList},
+};
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct UserResponse {
+    pub id: String,
+    pub username: String,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct MemberResponse {
+    pub user: UserResponse,
+    pub roles: Vec<String>,
+}
+
+impl MemberResponse {
+    pub fn into(self, roles: &RolesList<ExistingRole>) -> ExistingMember {
+        let member_roles: Vec<ExistingRole> = self
+            .roles
+            .iter()
+            .filter_map(|id| roles.find_by_id(id).cloned())
+            .collect();
+
+        ExistingMember {
+            id: self.user.id,
+            username: self.user.username,
+            roles: RolesList::from(member_roles),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        impls::discord::dtos::member::{MemberResponse, UserResponse},
+        member::ExistingMember,
+        role::RolesList,
+        tests::fixtures::existing::ExistingRoleFixture,
+    };
+
+    #[test]
+    fn can_convert_into_existing_member_ignoring_unknown_roles() {
+        let role = ExistingRoleFixture::new().build();
+        let response = MemberResponse {
+            user: UserResponse {
+                id: "123".to_string(),
+                username: "john".to_string(),
+            },
+            roles: vec![role.id.clone(), "unknown".to_string()],
+        };
+        let expected_member = ExistingMember {
+            id: "123".to_string(),
+            username: "john".to_string(),
+            roles: RolesList::from(vec![role.clone()]),
+        };
+
+        let member = response.into(&RolesList::from(vec![role]));
+
+        assert_eq!(member, expected_member);
+    }
+}
@@ -1,4 +1,10 @@
+pub mod automod;
 pub mod channel;
 pub mod guild;
+pub mod member;
+pub mod onboarding;
 pub mod permissions;
 pub mod role;
+pub mod scheduled_event;
+pub mod thread;
+pub mod welcome_screen;
@@ -0,0 +1,358 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    channel::{AwaitingChannel, Channel, ChannelsList, ExistingChannel},
+    onboarding::{
+        AwaitingOnboarding, ExistingOnboarding, OnboardingMode, OnboardingPrompt,
+        OnboardingPromptOption, OnboardingPromptType,
+    },
+    role::{AwaitingRole, ExistingRole, RolesList},
+};
+
+const DISCORD_EPOCH_MILLIS: u128 = 1420070400000;
+
+#[derive(Debug, Serialize, PartialEq)]
+pub struct OnboardingRequest {
+    pub prompts: Vec<OnboardingPromptRequest>,
+    pub default_channel_ids: Vec<String>,
+    pub enabled: bool,
+    pub mode: u8,
+}
+
+#[derive(Debug, Serialize, PartialEq)]
+pub struct OnboardingPromptRequest {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub _type: u8,
+    pub title: String,
+    pub single_select: bool,
+    pub required: bool,
+    pub in_onboarding: bool,
+    pub options: Vec<OnboardingPromptOptionRequest>,
+}
+
+#[derive(Debug, Serialize, PartialEq)]
+pub struct OnboardingPromptOptionRequest {
+    pub id: String,
+    pub title: String,
+    pub description: Option<String>,
+    pub emoji_name: Option<String>,
+    pub role_ids: Vec<String>,
+    pub channel_ids: Vec<String>,
+}
+
+impl OnboardingRequest {
+    pub fn from(
+        onboarding: &AwaitingOnboarding,
+        roles: &RolesList<ExistingRole>,
+        channels: &ChannelsList<ExistingChannel>,
+    ) -> Self {
+        let mut ids = IdGenerator::new();
+
+        let prompts = onboarding
+            .prompts
+            .iter()
+            .map(|prompt| OnboardingPromptRequest {
+                id: ids.next(),
+                _type: match prompt.prompt_type {
+                    OnboardingPromptType::MULTIPLE_CHOICE => 0,
+                    OnboardingPromptType::DROPDOWN => 1,
+                },
+                title: prompt.title.clone(),
+                single_select: prompt.single_select,
+                required: prompt.required,
+                in_onboarding: prompt.in_onboarding,
+                options: prompt
+                    .options
+                    .iter()
+                    .map(|option| OnboardingPromptOptionRequest {
+                        id: ids.next(),
+                        title: option.title.clone(),
+                        description: option.description.clone(),
+                        emoji_name: option.emoji.clone(),
+                        role_ids: role_ids(&option.roles, roles),
+                        channel_ids: channel_ids(&option.channels, channels),
+                    })
+                    .collect(),
+            })
+            .collect();
+
+        Self {
+            prompts,
+            default_channel_ids: channel_ids(&onboarding.default_channels, channels),
+            enabled: onboarding.enabled,
+            mode: match onboarding.mode {
+                OnboardingMode::DEFAULT => 0,
+                OnboardingMode::ADVANCED => 1,
+            },
+        }
+    }
+}
+
+/// Discord requires prompts and options to have snowflake ids, even new ones.
+struct IdGenerator {
+    timestamp: u128,
+    increment: u128,
+}
+
+impl IdGenerator {
+    fn new() -> Self {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_millis();
+
+        Self {
+            timestamp: now - DISCORD_EPOCH_MILLIS,
+            increment: 0,
+        }
+    }
+
+    fn next(&mut self) -> String {
+        self.increment += 1;
+        ((self.timestamp << 22) + self.increment).to_string()
+    }
+}
+
+fn role_ids(awaiting: &RolesList<AwaitingRole>, roles: &RolesList<ExistingRole>) -> Vec<String> {
+    awaiting
+        .to_list()
+        .into_iter()
+        .map(|role| {
+            roles
+                .find_by_name(&role.name)
+                .unwrap_or_else(|| {
+                    panic!(
+                        "Cannot create onboarding request with non-existant role {}.",
+                        &role.name
+                    )
+                })
+                .id
+                .clone()
+        })
+        .collect()
+}
+
+fn channel_ids(
+    awaiting: &ChannelsList<AwaitingChannel>,
+    channels: &ChannelsList<ExistingChannel>,
+) -> Vec<String> {
+    awaiting
+        .to_list()
+        .into_iter()
+        .map(|channel| {
+            channels
+                .find_by_unique_name(&channel.unique_name())
+                .unwrap_or_else(|| {
+                    panic!(
+                        "Cannot create onboarding request with non-existant channel {}.",
+                        channel.unique_name()
+                    )
+                })
+                .id
+                .clone()
+        })
+        .collect()
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct OnboardingResponse {
+    pub prompts: Vec<OnboardingPromptResponse>,
+    pub default_channel_ids: Vec<String>,
+    pub enabled: bool,
+    pub mode: u8,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct OnboardingPromptResponse {
+    #[serde(rename = "type")]
+    pub _type: u8,
+    pub title: String,
+    pub single_select: bool,
+    pub required: bool,
+    pub in_onboarding: bool,
+    pub options: Vec<OnboardingPromptOptionResponse>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct OnboardingPromptOptionResponse {
+    pub title: String,
+    pub description: Option<String>,
+    pub emoji: Option<EmojiResponse>,
+    pub role_ids: Vec<String>,
+    pub channel_ids: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct EmojiResponse {
+    pub name: Option<String>,
+}
+
+impl OnboardingResponse {
+    pub fn into(
+        self,
+        roles: &RolesList<ExistingRole>,
+        channels: &ChannelsList<ExistingChannel>,
+    ) -> ExistingOnboarding {
+        let prompts = self
+            .prompts
+            .into_iter()
+            .map(|prompt| OnboardingPrompt {
+                prompt_type: match prompt._type {
+                    1 => OnboardingPromptType::DROPDOWN,
+                    _ => OnboardingPromptType::MULTIPLE_CHOICE,
+                },
+                single_select: prompt.single_select,
+                required: prompt.required,
+                in_onboarding: prompt.in_onboarding,
+                options: prompt
+                    .options
+                    .into_iter()
+                    .map(|option| OnboardingPromptOption {
+                        roles: find_roles(&option.role_ids, roles, &option.title),
+                        channels: find_channels(&option.channel_ids, channels, &option.title),
+                        title: option.title,
+                        description: option.description.filter(|value| !value.is_empty()),
+                        emoji: option.emoji.and_then(|emoji| emoji.name),
+                    })
+                    .collect(),
+                title: prompt.title,
+            })
+            .collect();
+
+        ExistingOnboarding {
+            enabled: self.enabled,
+            mode: match self.mode {
+                1 => OnboardingMode::ADVANCED,
+                _ => OnboardingMode::DEFAULT,
+            },
+            default_channels: find_channels(&self.default_channel_ids, channels, "onboarding"),
+            prompts,
+        }
+    }
+}
+
+fn find_roles(
+    ids: &[String],
+    roles: &RolesList<ExistingRole>,
+    context: &str,
+) -> RolesList<ExistingRole> {
+    ids.iter()
+        .filter_map(|id| {
+            let role = roles.find_by_id(id).cloned();
+            if role.is_none() {
+                eprintln!("Ignoring unknown role with id '{id}' in '{context}'");
+            }
+            role
+        })
+        .collect::<Vec<ExistingRole>>()
+        .into()
+}
+
+fn find_channels(
+    ids: &[String],
+    channels: &ChannelsList<ExistingChannel>,
+    context: &str,
+) -> ChannelsList<ExistingChannel> {
+    ids.iter()
+        .filter_map(|id| {
+            let channel = channels.find_by_id(id).cloned();
+            if channel.is_none() {
+                eprintln!("Ignoring unsupported channel with id '{id}' in '{context}'");
+            }
+            channel
+        })
+        .collect::<Vec<ExistingChannel>>()
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    mod request {
+        use crate::{
+            channel::ChannelsList,
+            impls::discord::dtos::onboarding::OnboardingRequest,
+            onboarding::{AwaitingOnboarding, OnboardingPrompt, OnboardingPromptType},
+            role::RolesList,
+            tests::fixtures::{awaiting::AwaitingChannelFixture, existing::ExistingChannelFixture},
+        };
+
+        #[test]
+        fn can_be_created_from_awaiting_onboarding() {
+            let existing_channel = ExistingChannelFixture::new().with_name("welcome").build();
+            let onboarding = AwaitingOnboarding {
+                enabled: true,
+                default_channels: ChannelsList::from(vec![AwaitingChannelFixture::new()
+                    .with_name("welcome")
+                    .build()]),
+                prompts: vec![OnboardingPrompt {
+                    title: "prompt".to_string(),
+                    prompt_type: OnboardingPromptType::DROPDOWN,
+                    single_select: true,
+                    required: false,
+                    in_onboarding: true,
+                    options: vec![],
+                }],
+                ..Default::default()
+            };
+
+            let request = OnboardingRequest::from(
+                &onboarding,
+                &RolesList::new(),
+                &ChannelsList::from(vec![existing_channel.clone()]),
+            );
+
+            assert_eq!(request.default_channel_ids, vec![existing_channel.id]);
+            assert_eq!(request.prompts[0]._type, 1);
+            assert_eq!(request.mode, 0);
+        }
+    }
+
+    mod response {
+        use crate::{
+            channel::ChannelsList,
+            impls::discord::dtos::onboarding::{
+                EmojiResponse, OnboardingPromptOptionResponse, OnboardingPromptResponse,
+                OnboardingResponse,
+            },
+            role::RolesList,
+            tests::fixtures::existing::ExistingRoleFixture,
+        };
+
+        #[test]
+        fn can_convert_into_existing_onboarding_ignoring_unknown_ids() {
+            let role = ExistingRoleFixture::new().build();
+            let response = OnboardingResponse {
+                prompts: vec![OnboardingPromptResponse {
+                    _type: 0,
+                    title: "prompt".to_string(),
+                    single_select: false,
+                    required: true,
+                    in_onboarding: true,
+                    options: vec![OnboardingPromptOptionResponse {
+                        title: "option".to_string(),
+                        description: None,
+                        emoji: Some(EmojiResponse {
+                            name: Some("🎮".to_string()),
+                        }),
+                        role_ids: vec![role.id.clone()],
+                        channel_ids: vec!["unknown".to_string()],
+                    }],
+                }],
+                default_channel_ids: vec![],
+                enabled: true,
+                mode: 0,
+            };
+
+            let onboarding =
+                response.into(&RolesList::from(vec![role.clone()]), &ChannelsList::new());
+
+            let option = &onboarding.prompts[0].options[0];
+            assert_eq!(option.roles, RolesList::from(vec![role]));
+            assert_eq!(option.channels, ChannelsList::new());
+            assert_eq!(option.emoji, Some("🎮".to_string()));
+        }
+    }
+}
@@ -37,6 +37,8 @@ pub struct RoleResponse {
     pub color: u32,
     pub hoist: bool,
     pub mentionable: bool,
+    #[serde(default)]
+    pub managed: bool,
 }
 
 impl Into<ExistingRole> for RoleResponse {
@@ -53,6 +55,7 @@ impl Into<ExistingRole> for RoleResponse {
             color,
             is_mentionable: self.mentionable,
             show_in_sidebar: self.hoist,
+            is_managed: self.managed,
         }
     }
 }
@@ -104,6 +107,7 @@ mod tests {
                 color: 16463434,
                 hoist: true,
                 mentionable: false,
+                managed: false,
             };
 
             let expected_role = ExistingRole {
@@ -113,6 +117,7 @@ mod tests {
                 color: Some("fb364a".to_string()),
                 is_mentionable: false,
                 show_in_sidebar: true,
+                is_managed: false,
             };
 
             let role: ExistingRole = response.into();
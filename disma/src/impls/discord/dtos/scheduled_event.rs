@@ -0,0 +1,231 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    channel::{Channel, ChannelsList, ExistingChannel},
+    scheduled_event::{AwaitingScheduledEvent, ExistingScheduledEvent, ScheduledEventLocation},
+    utils::time,
+};
+
+const GUILD_ONLY_PRIVACY_LEVEL: u8 = 2;
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct ScheduledEventMetadataDto {
+    pub location: Option<String>,
+}
+
+#[derive(Debug, Serialize, PartialEq)]
+pub struct ScheduledEventRequest {
+    pub name: String,
+    pub description: Option<String>,
+    pub channel_id: Option<String>,
+    pub entity_metadata: Option<ScheduledEventMetadataDto>,
+    pub entity_type: u8,
+    pub privacy_level: u8,
+    pub scheduled_start_time: String,
+    pub scheduled_end_time: Option<String>,
+}
+
+impl ScheduledEventRequest {
+    pub fn from_event(
+        event: &AwaitingScheduledEvent,
+        channels: &ChannelsList<ExistingChannel>,
+    ) -> Self {
+        let find_channel_id = |channel: &dyn Channel| {
+            channels
+                .find_by_unique_name(&channel.unique_name())
+                .unwrap_or_else(|| {
+                    panic!(
+                        "Cannot create scheduled event request with non-existant channel {}.",
+                        channel.unique_name()
+                    )
+                })
+                .id
+                .clone()
+        };
+
+        let (entity_type, channel_id, entity_metadata) = match &event.location {
+            ScheduledEventLocation::Stage { channel } => (1, Some(find_channel_id(channel)), None),
+            ScheduledEventLocation::Voice { channel } => (2, Some(find_channel_id(channel)), None),
+            ScheduledEventLocation::External { location } => (
+                3,
+                None,
+                Some(ScheduledEventMetadataDto {
+                    location: Some(location.clone()),
+                }),
+            ),
+        };
+
+        Self {
+            name: event.name.clone(),
+            description: event.description.clone(),
+            channel_id,
+            entity_metadata,
+            entity_type,
+            privacy_level: GUILD_ONLY_PRIVACY_LEVEL,
+            scheduled_start_time: event.start_time.clone(),
+            scheduled_end_time: event.end_time.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct ScheduledEventResponse {
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub channel_id: Option<String>,
+    pub entity_metadata: Option<ScheduledEventMetadataDto>,
+    pub entity_type: u8,
+    pub scheduled_start_time: String,
+    pub scheduled_end_time: Option<String>,
+}
+
+impl ScheduledEventResponse {
+    pub fn _try_into(
+        self,
+        channels: &ChannelsList<ExistingChannel>,
+    ) -> Result<ExistingScheduledEvent, String> {
+        let find_channel = |id: Option<String>| {
+            let id = id.unwrap_or_default();
+            channels
+                .find_by_id(&id)
+                .cloned()
+                .ok_or_else(|| format!("Unsupported or non-existant channel with id '{id}'"))
+        };
+
+        let location = match self.entity_type {
+            1 => ScheduledEventLocation::Stage {
+                channel: find_channel(self.channel_id)?,
+            },
+            2 => ScheduledEventLocation::Voice {
+                channel: find_channel(self.channel_id)?,
+            },
+            3 => ScheduledEventLocation::External {
+                location: self
+                    .entity_metadata
+                    .and_then(|metadata| metadata.location)
+                    .unwrap_or_default(),
+            },
+            other => return Err(format!("Unsupported entity type {other}")),
+        };
+
+        Ok(ExistingScheduledEvent {
+            id: self.id,
+            name: self.name,
+            description: self
+                .description
+                .filter(|description| !description.is_empty()),
+            location,
+            start_time: normalize_timestamp(self.scheduled_start_time),
+            end_time: self.scheduled_end_time.map(normalize_timestamp),
+        })
+    }
+}
+
+/// Discord returns timestamps as `2030-01-01T18:00:00+00:00`, while configs
+/// are normalized to `2030-01-01T18:00:00Z`.
+fn normalize_timestamp(timestamp: String) -> String {
+    time::normalize_timestamp(&timestamp).unwrap_or(timestamp)
+}
+
+#[cfg(test)]
+mod tests {
+    mod request {
+        use crate::{
+            channel::ChannelsList,
+            impls::discord::dtos::scheduled_event::ScheduledEventRequest,
+            scheduled_event::ScheduledEventLocation,
+            tests::fixtures::{
+                awaiting::{AwaitingChannelFixture, AwaitingScheduledEventFixture},
+                existing::ExistingChannelFixture,
+            },
+        };
+
+        #[test]
+        fn can_be_created_from_voice_event() {
+            let existing_channel = ExistingChannelFixture::new().with_name("gaming").build();
+            let event = AwaitingScheduledEventFixture::new()
+                .with_location(ScheduledEventLocation::Voice {
+                    channel: AwaitingChannelFixture::new().with_name("gaming").build(),
+                })
+                .build();
+
+            let request = ScheduledEventRequest::from_event(
+                &event,
+                &ChannelsList::from(vec![existing_channel.clone()]),
+            );
+
+            assert_eq!(request.entity_type, 2);
+            assert_eq!(request.channel_id, Some(existing_channel.id));
+            assert_eq!(request.entity_metadata, None);
+        }
+
+        #[test]
+        fn can_be_created_from_external_event() {
+            let event = AwaitingScheduledEventFixture::new().build();
+
+            let request = ScheduledEventRequest::from_event(&event, &ChannelsList::new());
+
+            assert_eq!(request.entity_type, 3);
+            assert_eq!(request.channel_id, None);
+            assert_eq!(
+                request.entity_metadata.unwrap().location,
+                Some("Online".to_string())
+            );
+        }
+    }
+
+    mod response {
+        use crate::{
+            channel::ChannelsList,
+            impls::discord::dtos::scheduled_event::{
+                ScheduledEventMetadataDto, ScheduledEventResponse,
+            },
+            scheduled_event::{ExistingScheduledEvent, ScheduledEventLocation},
+        };
+
+        fn given_response(entity_type: u8) -> ScheduledEventResponse {
+            ScheduledEventResponse {
+                id: "abc-123".to_string(),
+                name: "Meetup".to_string(),
+                description: Some("".to_string()),
+                channel_id: None,
+                entity_metadata: Some(ScheduledEventMetadataDto {
+                    location: Some("Montreal".to_string()),
+                }),
+                entity_type,
+                scheduled_start_time: "2030-01-01T18:00:00+00:00".to_string(),
+                scheduled_end_time: None,
+            }
+        }
+
+        #[test]
+        fn can_convert_into_existing_event() {
+            let response = given_response(3);
+            let expected_event = ExistingScheduledEvent {
+                id: "abc-123".to_string(),
+                name: "Meetup".to_string(),
+                description: None,
+                location: ScheduledEventLocation::External {
+                    location: "Montreal".to_string(),
+                },
+                start_time: "2030-01-01T18:00:00Z".to_string(),
+                end_time: None,
+            };
+
+            let event = response._try_into(&ChannelsList::new());
+
+            assert_eq!(event, Ok(expected_event));
+        }
+
+        #[test]
+        fn given_unknown_channel_it_returns_error() {
+            let mut response = given_response(2);
+            response.channel_id = Some("unknown".to_string());
+
+            let event = response._try_into(&ChannelsList::new());
+
+            assert!(event.is_err());
+        }
+    }
+}
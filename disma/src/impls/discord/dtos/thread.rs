@@ -0,0 +1,241 @@
+use serde::{Deserialize, Serialize};
+
+use crate::channel::{AwaitingThread, ExistingThread, ThreadType};
+
+const PUBLIC_THREAD_TYPE: u8 = 11;
+const PRIVATE_THREAD_TYPE: u8 = 12;
+
+#[derive(Debug, Serialize, PartialEq)]
+pub struct ThreadRequest {
+    pub name: String,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub _type: Option<u8>,
+    pub auto_archive_duration: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invitable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<ThreadMessageRequest>,
+}
+
+#[derive(Debug, Serialize, PartialEq)]
+pub struct ThreadMessageRequest {
+    pub content: String,
+}
+
+impl From<&AwaitingThread> for ThreadRequest {
+    fn from(thread: &AwaitingThread) -> Self {
+        // Forum posts are created with a message and no type
+        if let Some(content) = &thread.message {
+            return Self {
+                name: thread.name.clone(),
+                _type: None,
+                auto_archive_duration: thread.auto_archive_duration,
+                invitable: None,
+                message: Some(ThreadMessageRequest {
+                    content: content.clone(),
+                }),
+            };
+        }
+
+        let (_type, invitable) = match thread.thread_type {
+            ThreadType::PUBLIC => (PUBLIC_THREAD_TYPE, None),
+            ThreadType::PRIVATE => (PRIVATE_THREAD_TYPE, Some(thread.invitable)),
+        };
+
+        Self {
+            name: thread.name.clone(),
+            _type: Some(_type),
+            auto_archive_duration: thread.auto_archive_duration,
+            invitable,
+            message: None,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, PartialEq, Default)]
+pub struct ThreadUpdateRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub archived: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_archive_duration: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locked: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invitable: Option<bool>,
+}
+
+impl ThreadUpdateRequest {
+    pub fn archive() -> Self {
+        Self {
+            archived: Some(true),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<&AwaitingThread> for ThreadUpdateRequest {
+    fn from(thread: &AwaitingThread) -> Self {
+        Self {
+            archived: Some(false),
+            auto_archive_duration: Some(thread.auto_archive_duration),
+            locked: Some(thread.locked),
+            invitable: match thread.thread_type {
+                ThreadType::PUBLIC => None,
+                ThreadType::PRIVATE => Some(thread.invitable),
+            },
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct ThreadMetadataResponse {
+    pub archived: bool,
+    pub auto_archive_duration: u32,
+    pub archive_timestamp: String,
+    pub locked: bool,
+    pub invitable: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct ThreadResponse {
+    pub id: String,
+    pub parent_id: Option<String>,
+    pub name: String,
+    #[serde(rename = "type")]
+    pub _type: u8,
+    pub owner_id: Option<String>,
+    pub thread_metadata: ThreadMetadataResponse,
+}
+
+impl ThreadResponse {
+    pub fn _try_into(self, bot_user_id: &str) -> Result<ExistingThread, String> {
+        let thread_type = match self._type {
+            PUBLIC_THREAD_TYPE => ThreadType::PUBLIC,
+            PRIVATE_THREAD_TYPE => ThreadType::PRIVATE,
+            other => return Err(format!("Unsupported thread type {other}")),
+        };
+
+        Ok(ExistingThread {
+            id: self.id,
+            name: self.name,
+            thread_type,
+            auto_archive_duration: self.thread_metadata.auto_archive_duration,
+            locked: self.thread_metadata.locked,
+            invitable: self.thread_metadata.invitable.unwrap_or(true),
+            archived: self.thread_metadata.archived,
+            is_managed: self.owner_id.as_deref() == Some(bot_user_id),
+        })
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct ActiveThreadsResponse {
+    pub threads: Vec<ThreadResponse>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct ArchivedThreadsResponse {
+    pub threads: Vec<ThreadResponse>,
+    pub has_more: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        channel::{ExistingThread, ThreadType},
+        impls::discord::dtos::thread::{
+            ThreadMessageRequest, ThreadMetadataResponse, ThreadRequest, ThreadResponse,
+        },
+        tests::fixtures::awaiting::AwaitingThreadFixture,
+    };
+
+    #[test]
+    fn can_convert_public_thread_into_request_without_invitable() {
+        let thread = AwaitingThreadFixture::new().build();
+        let expected_request = ThreadRequest {
+            name: thread.name.clone(),
+            _type: Some(11),
+            auto_archive_duration: thread.auto_archive_duration,
+            invitable: None,
+            message: None,
+        };
+
+        let request = ThreadRequest::from(&thread);
+
+        assert_eq!(request, expected_request);
+    }
+
+    #[test]
+    fn can_convert_forum_thread_into_request_with_message() {
+        let thread = AwaitingThreadFixture::new()
+            .with_message("Welcome!")
+            .build();
+        let expected_request = ThreadRequest {
+            name: thread.name.clone(),
+            _type: None,
+            auto_archive_duration: thread.auto_archive_duration,
+            invitable: None,
+            message: Some(ThreadMessageRequest {
+                content: "Welcome!".to_string(),
+            }),
+        };
+
+        let request = ThreadRequest::from(&thread);
+
+        assert_eq!(request, expected_request);
+    }
+
+    #[test]
+    fn can_convert_response_into_existing_thread() {
+        let response = ThreadResponse {
+            id: "123".to_string(),
+            parent_id: Some("456".to_string()),
+            name: "rules".to_string(),
+            _type: 12,
+            owner_id: Some("789".to_string()),
+            thread_metadata: ThreadMetadataResponse {
+                archived: true,
+                auto_archive_duration: 1440,
+                archive_timestamp: "2030-01-01T18:00:00+00:00".to_string(),
+                locked: true,
+                invitable: Some(false),
+            },
+        };
+        let expected_thread = ExistingThread {
+            id: "123".to_string(),
+            name: "rules".to_string(),
+            thread_type: ThreadType::PRIVATE,
+            auto_archive_duration: 1440,
+            locked: true,
+            invitable: false,
+            archived: true,
+            is_managed: true,
+        };
+
+        let thread = response._try_into("789");
+
+        assert_eq!(thread, Ok(expected_thread));
+    }
+
+    #[test]
+    fn given_announcement_thread_it_fails_to_convert() {
+        let response = ThreadResponse {
+            id: "123".to_string(),
+            parent_id: None,
+            name: "news".to_string(),
+            _type: 10,
+            owner_id: None,
+            thread_metadata: ThreadMetadataResponse {
+                archived: false,
+                auto_archive_duration: 60,
+                archive_timestamp: "2030-01-01T18:00:00+00:00".to_string(),
+                locked: false,
+                invitable: None,
+            },
+        };
+
+        let thread = response._try_into("789");
+
+        assert_eq!(thread, Err("Unsupported thread type 10".to_string()));
+    }
+}
@@ -0,0 +1,131 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    channel::{Channel, ChannelsList, ExistingChannel},
+    welcome_screen::{AwaitingWelcomeScreen, ExistingWelcomeScreen, WelcomeScreenChannel},
+};
+
+#[derive(Debug, Serialize, PartialEq)]
+pub struct WelcomeScreenRequest {
+    pub enabled: bool,
+    pub description: Option<String>,
+    pub welcome_channels: Vec<WelcomeScreenChannelDto>,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct WelcomeScreenChannelDto {
+    pub channel_id: String,
+    pub description: String,
+    pub emoji_name: Option<String>,
+}
+
+impl WelcomeScreenRequest {
+    pub fn from(
+        welcome_screen: &AwaitingWelcomeScreen,
+        channels: &ChannelsList<ExistingChannel>,
+    ) -> Self {
+        let welcome_channels = welcome_screen
+            .channels
+            .iter()
+            .map(|welcome_channel| WelcomeScreenChannelDto {
+                channel_id: channels
+                    .find_by_unique_name(&welcome_channel.channel.unique_name())
+                    .unwrap_or_else(|| {
+                        panic!(
+                            "Cannot create welcome screen request with non-existant channel {}.",
+                            welcome_channel.channel.unique_name()
+                        )
+                    })
+                    .id
+                    .clone(),
+                description: welcome_channel.description.clone(),
+                emoji_name: welcome_channel.emoji.clone(),
+            })
+            .collect();
+
+        Self {
+            enabled: welcome_screen.enabled,
+            description: welcome_screen.description.clone(),
+            welcome_channels,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct WelcomeScreenResponse {
+    pub description: Option<String>,
+    pub welcome_channels: Vec<WelcomeScreenChannelDto>,
+}
+
+impl WelcomeScreenResponse {
+    /// Discord does not return whether the welcome screen is enabled, it is
+    /// exposed as the `WELCOME_SCREEN_ENABLED` guild feature instead.
+    pub fn into(
+        self,
+        enabled: bool,
+        channels: &ChannelsList<ExistingChannel>,
+    ) -> ExistingWelcomeScreen {
+        let welcome_channels = self
+            .welcome_channels
+            .into_iter()
+            .filter_map(
+                |welcome_channel| match channels.find_by_id(&welcome_channel.channel_id) {
+                    Some(channel) => Some(WelcomeScreenChannel {
+                        channel: channel.clone(),
+                        description: welcome_channel.description,
+                        emoji: welcome_channel.emoji_name,
+                    }),
+                    None => {
+                        eprintln!(
+                            "Ignoring unsupported channel with id '{}' in welcome screen",
+                            welcome_channel.channel_id
+                        );
+                        None
+                    }
+                },
+            )
+            .collect();
+
+        ExistingWelcomeScreen {
+            enabled,
+            description: self.description.filter(|value| !value.is_empty()),
+            channels: welcome_channels,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        channel::ChannelsList,
+        impls::discord::dtos::welcome_screen::{WelcomeScreenChannelDto, WelcomeScreenResponse},
+        tests::fixtures::existing::ExistingChannelFixture,
+        welcome_screen::{ExistingWelcomeScreen, WelcomeScreenChannel},
+    };
+
+    #[test]
+    fn can_convert_into_existing_welcome_screen() {
+        let channel = ExistingChannelFixture::new().build();
+        let response = WelcomeScreenResponse {
+            description: Some("".to_string()),
+            welcome_channels: vec![WelcomeScreenChannelDto {
+                channel_id: channel.id.clone(),
+                description: "Read the rules".to_string(),
+                emoji_name: None,
+            }],
+        };
+        let expected = ExistingWelcomeScreen {
+            enabled: true,
+            description: None,
+            channels: vec![WelcomeScreenChannel {
+                channel: channel.clone(),
+                description: "Read the rules".to_string(),
+                emoji: None,
+            }],
+        };
+
+        let welcome_screen = response.into(true, &ChannelsList::from(vec![channel]));
+
+        assert_eq!(welcome_screen, expected);
+    }
+}
@@ -1,15 +1,27 @@
 use std::sync::Arc;
 
 use crate::{
+    automod::{AwaitingAutoModRule, ExistingAutoModRule},
     category::{AwaitingCategory, CategoriesList, ExistingCategory},
-    channel::{AwaitingChannel, ExistingChannel},
+    channel::{AwaitingChannel, AwaitingThread, ChannelsList, ExistingChannel, ExistingThread},
     guild::GuildCommander,
+    onboarding::{AwaitingOnboarding, ExistingOnboarding},
     role::{AwaitingRole, ExistingRole, RolesList},
+    scheduled_event::{AwaitingScheduledEvent, ExistingScheduledEvent},
+    welcome_screen::{AwaitingWelcomeScreen, ExistingWelcomeScreen},
 };
 
 use super::{
     api::DiscordApi,
-    dtos::{channel::ChannelRequest, role::RoleRequest},
+    dtos::{
+        automod::AutoModRuleRequest,
+        channel::ChannelRequest,
+        onboarding::OnboardingRequest,
+        role::RoleRequest,
+        scheduled_event::ScheduledEventRequest,
+        thread::{ThreadRequest, ThreadUpdateRequest},
+        welcome_screen::WelcomeScreenRequest,
+    },
 };
 
 pub struct HttpGuildCommander {
@@ -113,4 +125,159 @@ impl GuildCommander for HttpGuildCommander {
             .map(|_| ())
             .map_err(|error| error.to_string())
     }
+
+    fn add_thread(
+        &self,
+        channel_id: &str,
+        thread: &AwaitingThread,
+    ) -> Result<ExistingThread, String> {
+        let response = self
+            .api
+            .add_thread(channel_id, ThreadRequest::from(thread))
+            .map_err(|error| error.to_string())?;
+
+        // Threads cannot be created locked, so they are locked afterwards
+        match thread.locked {
+            true => self.update_thread(&response.id, thread),
+            false => {
+                let bot_user_id = response.owner_id.clone().unwrap_or_default();
+                response._try_into(&bot_user_id)
+            }
+        }
+    }
+
+    fn update_thread(&self, id: &str, thread: &AwaitingThread) -> Result<ExistingThread, String> {
+        let bot_user_id = self
+            .api
+            .get_current_user()
+            .map_err(|error| error.to_string())?
+            .id;
+
+        self.api
+            .update_thread(id, ThreadUpdateRequest::from(thread))
+            .map_err(|error| error.to_string())
+            .and_then(|response| response._try_into(&bot_user_id))
+    }
+
+    fn archive_thread(&self, id: &str) -> Result<(), String> {
+        self.api
+            .update_thread(id, ThreadUpdateRequest::archive())
+            .map(|_| ())
+            .map_err(|error| error.to_string())
+    }
+
+    fn add_automod_rule(
+        &self,
+        rule: &AwaitingAutoModRule,
+        roles: &RolesList<ExistingRole>,
+        channels: &ChannelsList<ExistingChannel>,
+    ) -> Result<ExistingAutoModRule, String> {
+        self.api
+            .add_automod_rule(
+                &self.guild_id,
+                AutoModRuleRequest::from_rule(rule, roles, channels),
+            )
+            .map_err(|error| error.to_string())
+            .and_then(|response| response._try_into(roles, channels))
+    }
+
+    fn update_automod_rule(
+        &self,
+        id: &str,
+        rule: &AwaitingAutoModRule,
+        roles: &RolesList<ExistingRole>,
+        channels: &ChannelsList<ExistingChannel>,
+    ) -> Result<ExistingAutoModRule, String> {
+        self.api
+            .update_automod_rule(
+                &self.guild_id,
+                id,
+                AutoModRuleRequest::from_rule(rule, roles, channels).for_update(),
+            )
+            .map_err(|error| error.to_string())
+            .and_then(|response| response._try_into(roles, channels))
+    }
+
+    fn delete_automod_rule(&self, id: &str) -> Result<(), String> {
+        self.api
+            .delete_automod_rule(&self.guild_id, id)
+            .map_err(|error| error.to_string())
+    }
+
+    fn add_scheduled_event(
+        &self,
+        event: &AwaitingScheduledEvent,
+        channels: &ChannelsList<ExistingChannel>,
+    ) -> Result<ExistingScheduledEvent, String> {
+        self.api
+            .add_scheduled_event(
+                &self.guild_id,
+                ScheduledEventRequest::from_event(event, channels),
+            )
+            .map_err(|error| error.to_string())
+            .and_then(|response| response._try_into(channels))
+    }
+
+    fn update_scheduled_event(
+        &self,
+        id: &str,
+        event: &AwaitingScheduledEvent,
+        channels: &ChannelsList<ExistingChannel>,
+    ) -> Result<ExistingScheduledEvent, String> {
+        self.api
+            .update_scheduled_event(
+                &self.guild_id,
+                id,
+                ScheduledEventRequest::from_event(event, channels),
+            )
+            .map_err(|error| error.to_string())
+            .and_then(|response| response._try_into(channels))
+    }
+
+    fn delete_scheduled_event(&self, id: &str) -> Result<(), String> {
+        self.api
+            .delete_scheduled_event(&self.guild_id, id)
+            .map_err(|error| error.to_string())
+    }
+
+    fn add_member_role(&self, member_id: &str, role_id: &str) -> Result<(), String> {
+        self.api
+            .add_member_role(&self.guild_id, member_id, role_id)
+            .map_err(|error| error.to_string())
+    }
+
+    fn remove_member_role(&self, member_id: &str, role_id: &str) -> Result<(), String> {
+        self.api
+            .remove_member_role(&self.guild_id, member_id, role_id)
+            .map_err(|error| error.to_string())
+    }
+
+    fn update_onboarding(
+        &self,
+        onboarding: &AwaitingOnboarding,
+        roles: &RolesList<ExistingRole>,
+        channels: &ChannelsList<ExistingChannel>,
+    ) -> Result<ExistingOnboarding, String> {
+        self.api
+            .update_onboarding(
+                &self.guild_id,
+                OnboardingRequest::from(onboarding, roles, channels),
+            )
+            .map(|response| response.into(roles, channels))
+            .map_err(|error| error.to_string())
+    }
+
+    fn update_welcome_screen(
+        &self,
+        welcome_screen: &AwaitingWelcomeScreen,
+        channels: &ChannelsList<ExistingChannel>,
+    ) -> Result<ExistingWelcomeScreen, String> {
+        self.api
+            .update_welcome_screen(
+                &self.guild_id,
+                WelcomeScreenRequest::from(welcome_screen, channels),
+            )
+            .map(|response| response.into(welcome_screen.enabled, channels))
+            .map_err(|error| error.to_string())
+    }
 }
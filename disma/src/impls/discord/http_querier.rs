@@ -1,13 +1,18 @@
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 
 use crate::{
+    automod::{AutoModRulesList, ExistingAutoModRule},
     category::{CategoriesList, ExistingCategory},
-    channel::{ChannelsList, ExistingChannel},
+    channel::{ChannelType, ChannelsList, ExistingChannel, ExistingThread},
     guild::{ExistingGuild, GuildQuerier, GuildSummary},
+    member::{ExistingMember, MembersList},
+    onboarding::ExistingOnboarding,
     role::{ExistingRole, RolesList},
+    scheduled_event::{ExistingScheduledEvent, ScheduledEventsList},
+    welcome_screen::ExistingWelcomeScreen,
 };
 
-use super::api::DiscordApi;
+use super::api::{DiscordApi, DiscordError};
 
 pub struct HttpGuildQuerier {
     api: Arc<DiscordApi>,
@@ -41,12 +46,171 @@ impl GuildQuerier for HttpGuildQuerier {
 
         let channels: Vec<ExistingChannel> = channel_responses
             .iter()
-            .filter(|response| [0, 2].contains(&response._type))
+            .filter(|response| [0, 2, 13, 15].contains(&response._type))
             .map(|response| response.clone().into_channel(&roles_list, &categories_list))
             .collect();
         let channels_list = ChannelsList::from(channels);
 
-        ExistingGuild::new(roles_list, categories_list, channels_list)
+        ExistingGuild::new(
+            roles_list,
+            categories_list,
+            channels_list,
+            AutoModRulesList::new(),
+            ScheduledEventsList::new(),
+            ExistingOnboarding::default(),
+            ExistingWelcomeScreen::default(),
+        )
+    }
+
+    fn list_threads(
+        &self,
+        guild_id: &str,
+        channels: &ChannelsList<ExistingChannel>,
+    ) -> Result<HashMap<String, Vec<ExistingThread>>, String> {
+        let mut threads_by_channel: HashMap<String, Vec<ExistingThread>> = HashMap::new();
+        let fetch_error = |error: DiscordError| format!("Could not fetch threads. {error}");
+        let bot_user_id = self.api.get_current_user().map_err(fetch_error)?.id;
+
+        // Active threads first, for them to be found before archived ones
+        let mut responses = self
+            .api
+            .list_active_threads(guild_id)
+            .map_err(fetch_error)?
+            .threads;
+        for channel in channels.to_list() {
+            if ![ChannelType::TEXT, ChannelType::FORUM].contains(&channel.channel_type) {
+                continue;
+            }
+            responses.extend(
+                self.api
+                    .list_public_archived_threads(&channel.id)
+                    .map_err(fetch_error)?,
+            );
+            // Forum posts are all public
+            if channel.channel_type == ChannelType::TEXT {
+                responses.extend(
+                    self.api
+                        .list_private_archived_threads(&channel.id)
+                        .map_err(fetch_error)?,
+                );
+            }
+        }
+
+        for response in responses {
+            let thread_name = response.name.clone();
+            let parent_id = response.parent_id.clone().unwrap_or_default();
+            if channels.find_by_id(&parent_id).is_none() {
+                continue;
+            }
+            match response._try_into(&bot_user_id) {
+                Ok(thread) => threads_by_channel
+                    .entry(parent_id)
+                    .or_default()
+                    .push(thread),
+                Err(message) => eprintln!("Error while parsing thread '{thread_name}': {message}"),
+            }
+        }
+
+        Ok(threads_by_channel)
+    }
+
+    fn list_automod_rules(
+        &self,
+        guild_id: &str,
+        roles: &RolesList<ExistingRole>,
+        channels: &ChannelsList<ExistingChannel>,
+    ) -> Result<AutoModRulesList<ExistingAutoModRule>, String> {
+        let rules: Vec<ExistingAutoModRule> = self
+            .api
+            .list_automod_rules(guild_id)
+            .map_err(|error| format!("Could not fetch AutoMod rules. {error}"))?
+            .into_iter()
+            .filter_map(|response| {
+                let rule_name = response.name.clone();
+                match response._try_into(roles, channels) {
+                    Ok(rule) => Some(rule),
+                    Err(message) => {
+                        eprintln!("Error while parsing AutoMod rule '{rule_name}': {message}");
+                        None
+                    }
+                }
+            })
+            .collect();
+
+        Ok(AutoModRulesList::from(rules))
+    }
+
+    fn list_scheduled_events(
+        &self,
+        guild_id: &str,
+        channels: &ChannelsList<ExistingChannel>,
+    ) -> Result<ScheduledEventsList<ExistingScheduledEvent>, String> {
+        let events: Vec<ExistingScheduledEvent> = self
+            .api
+            .list_scheduled_events(guild_id)
+            .map_err(|error| format!("Could not fetch scheduled events. {error}"))?
+            .into_iter()
+            .filter_map(|response| {
+                let event_name = response.name.clone();
+                match response._try_into(channels) {
+                    Ok(event) => Some(event),
+                    Err(message) => {
+                        eprintln!("Error while parsing scheduled event '{event_name}': {message}");
+                        None
+                    }
+                }
+            })
+            .collect();
+
+        Ok(ScheduledEventsList::from_existing(events))
+    }
+
+    fn list_members(
+        &self,
+        guild_id: &str,
+        roles: &RolesList<ExistingRole>,
+    ) -> Result<MembersList<ExistingMember>, String> {
+        let members: Vec<ExistingMember> = self
+            .api
+            .list_members(guild_id)
+            .map_err(|error| format!("Could not fetch members. {error}"))?
+            .into_iter()
+            .map(|response| response.into(roles))
+            .collect();
+
+        Ok(MembersList::from(members))
+    }
+
+    fn get_onboarding(
+        &self,
+        guild_id: &str,
+        roles: &RolesList<ExistingRole>,
+        channels: &ChannelsList<ExistingChannel>,
+    ) -> Result<ExistingOnboarding, String> {
+        self.api
+            .get_onboarding(guild_id)
+            .map(|response| response.into(roles, channels))
+            .map_err(|error| format!("Could not fetch onboarding. {error}"))
+    }
+
+    fn get_welcome_screen(
+        &self,
+        guild_id: &str,
+        channels: &ChannelsList<ExistingChannel>,
+    ) -> Result<ExistingWelcomeScreen, String> {
+        let enabled = self
+            .api
+            .get_guild(guild_id)
+            .map_err(|error| format!("Could not fetch guild details. {error}"))?
+            .features
+            .contains(&"WELCOME_SCREEN_ENABLED".to_string());
+
+        match self.api.get_welcome_screen(guild_id) {
+            Ok(response) => Ok(response.into(enabled, channels)),
+            // Guilds without a configured welcome screen have none to be found
+            Err(DiscordError::Unknown(404, _)) => Ok(ExistingWelcomeScreen::default()),
+            Err(error) => Err(format!("Could not fetch welcome screen. {error}")),
+        }
     }
 
     fn list_guilds(&self) -> Vec<GuildSummary> {
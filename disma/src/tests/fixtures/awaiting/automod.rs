@@ -0,0 +1,72 @@
+use crate::{
+    automod::{AutoModAction, AutoModEventType, AutoModTrigger, AwaitingAutoModRule},
+    channel::{AwaitingChannel, ChannelsList},
+    role::{AwaitingRole, RolesList},
+};
+
+pub struct AwaitingAutoModRuleFixture {
+    name: String,
+    event_type: AutoModEventType,
+    trigger: AutoModTrigger,
+    actions: Vec<AutoModAction<AwaitingChannel>>,
+    enabled: bool,
+    exempt_roles: RolesList<AwaitingRole>,
+    exempt_channels: ChannelsList<AwaitingChannel>,
+}
+
+impl AwaitingAutoModRuleFixture {
+    pub fn new() -> Self {
+        Self {
+            name: "abc".to_string(),
+            event_type: AutoModEventType::MESSAGE_SEND,
+            trigger: AutoModTrigger::Keyword {
+                keywords: Vec::new(),
+                regex_patterns: Vec::new(),
+                allow_list: Vec::new(),
+            },
+            actions: vec![AutoModAction::BlockMessage {
+                custom_message: None,
+            }],
+            enabled: true,
+            exempt_roles: RolesList::from(Vec::new()),
+            exempt_channels: ChannelsList::from(Vec::new()),
+        }
+    }
+
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    pub fn with_trigger(mut self, trigger: AutoModTrigger) -> Self {
+        self.trigger = trigger;
+        self
+    }
+
+    pub fn with_actions(mut self, actions: Vec<AutoModAction<AwaitingChannel>>) -> Self {
+        self.actions = actions;
+        self
+    }
+
+    pub fn with_exempt_roles(mut self, roles: RolesList<AwaitingRole>) -> Self {
+        self.exempt_roles = roles;
+        self
+    }
+
+    pub fn disabled(mut self) -> Self {
+        self.enabled = false;
+        self
+    }
+
+    pub fn build(self) -> AwaitingAutoModRule {
+        AwaitingAutoModRule {
+            name: self.name,
+            event_type: self.event_type,
+            trigger: self.trigger,
+            actions: self.actions,
+            enabled: self.enabled,
+            exempt_roles: self.exempt_roles,
+            exempt_channels: self.exempt_channels,
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{
     category::AwaitingCategory,
-    channel::{AwaitingChannel, ChannelType},
+    channel::{AwaitingChannel, AwaitingThreadsList, ChannelType},
     permission::{PermissionsOverwrite, PermissionsOverwritesList},
     role::AwaitingRole,
 };
@@ -11,6 +11,7 @@ pub struct AwaitingChannelFixture {
     topic: Option<String>,
     channel_type: ChannelType,
     category: Option<AwaitingCategory>,
+    threads: Option<AwaitingThreadsList>,
 }
 
 impl AwaitingChannelFixture {
@@ -21,6 +22,7 @@ impl AwaitingChannelFixture {
             topic: None,
             channel_type: ChannelType::TEXT,
             category: None,
+            threads: None,
         }
     }
 
@@ -29,6 +31,11 @@ impl AwaitingChannelFixture {
         self
     }
 
+    pub fn with_type(mut self, channel_type: ChannelType) -> Self {
+        self.channel_type = channel_type;
+        self
+    }
+
     pub fn with_category(mut self, category: &AwaitingCategory) -> Self {
         self.category = Some(category.clone());
         self
@@ -49,6 +56,7 @@ impl AwaitingChannelFixture {
             topic: self.topic,
             channel_type: self.channel_type,
             category: self.category,
+            threads: self.threads,
         }
     }
 }
@@ -0,0 +1,123 @@
+use std::sync::Arc;
+
+use crate::{
+    automod::{
+        AutoModRulesList, AwaitingAutoModRule, AwaitingAutoModRulesList, KeepExtraAutoModRules,
+    },
+    category::{AwaitingCategoriesList, CategoriesList, KeepExtraCategories},
+    channel::{AwaitingChannel, AwaitingChannelsList, ChannelsList, KeepExtraChannels},
+    guild::AwaitingGuild,
+    member::{AwaitingMember, AwaitingMembersList, MembersList, RosterStrategy},
+    onboarding::AwaitingOnboarding,
+    role::{AwaitingRole, AwaitingRolesList, KeepExtraRoles, RolesList},
+    scheduled_event::{
+        AwaitingScheduledEvent, AwaitingScheduledEventsList, KeepExtraScheduledEvents,
+        ScheduledEventsList,
+    },
+    welcome_screen::AwaitingWelcomeScreen,
+};
+
+pub struct AwaitingGuildFixture {
+    roles: RolesList<AwaitingRole>,
+    channels: ChannelsList<AwaitingChannel>,
+    automod_rules: AutoModRulesList<AwaitingAutoModRule>,
+    scheduled_events: ScheduledEventsList<AwaitingScheduledEvent>,
+    members: Option<AwaitingMembersList>,
+    onboarding: Option<AwaitingOnboarding>,
+    welcome_screen: Option<AwaitingWelcomeScreen>,
+}
+
+impl AwaitingGuildFixture {
+    pub fn new() -> Self {
+        Self {
+            roles: RolesList::from(Vec::new()),
+            channels: ChannelsList::from(Vec::new()),
+            automod_rules: AutoModRulesList::from(Vec::new()),
+            scheduled_events: ScheduledEventsList::from(Vec::new()),
+            members: None,
+            onboarding: None,
+            welcome_screen: None,
+        }
+    }
+
+    pub fn with_role(mut self, role: AwaitingRole) -> Self {
+        self.roles.add(role);
+        self
+    }
+
+    pub fn with_channel(mut self, channel: AwaitingChannel) -> Self {
+        self.channels.add(channel);
+        self
+    }
+
+    pub fn with_automod_rule(mut self, rule: AwaitingAutoModRule) -> Self {
+        self.automod_rules.add(rule);
+        self
+    }
+
+    pub fn with_scheduled_event(mut self, event: AwaitingScheduledEvent) -> Self {
+        self.scheduled_events.add(event);
+        self
+    }
+
+    pub fn with_member(mut self, member: AwaitingMember) -> Self {
+        self.members
+            .get_or_insert_with(|| AwaitingMembersList {
+                items: MembersList::new(),
+                roster_strategy: RosterStrategy::ADDITIVE,
+            })
+            .items
+            .add(member);
+        self
+    }
+
+    pub fn with_roster_strategy(mut self, roster_strategy: RosterStrategy) -> Self {
+        self.members
+            .get_or_insert_with(|| AwaitingMembersList {
+                items: MembersList::new(),
+                roster_strategy: RosterStrategy::ADDITIVE,
+            })
+            .roster_strategy = roster_strategy;
+        self
+    }
+
+    pub fn with_onboarding(mut self, onboarding: AwaitingOnboarding) -> Self {
+        self.onboarding = Some(onboarding);
+        self
+    }
+
+    pub fn with_welcome_screen(mut self, welcome_screen: AwaitingWelcomeScreen) -> Self {
+        self.welcome_screen = Some(welcome_screen);
+        self
+    }
+
+    pub fn build(self) -> AwaitingGuild {
+        AwaitingGuild {
+            roles: AwaitingRolesList {
+                items: self.roles,
+                extra_items_strategy: Arc::from(KeepExtraRoles {}),
+            },
+            categories: AwaitingCategoriesList {
+                items: CategoriesList::from(Vec::new()),
+                extra_items_strategy: Arc::from(KeepExtraCategories {}),
+            },
+            channels: AwaitingChannelsList {
+                items: self.channels,
+                extra_items_strategy: Arc::from(KeepExtraChannels {}),
+                categories: CategoriesList::from(Vec::new()),
+            },
+            automod: AwaitingAutoModRulesList {
+                items: self.automod_rules,
+                extra_items_strategy: Arc::from(KeepExtraAutoModRules {}),
+            },
+            scheduled_events: AwaitingScheduledEventsList {
+                items: self.scheduled_events,
+                extra_items_strategy: Arc::from(KeepExtraScheduledEvents {}),
+            },
+            members: self.members,
+            onboarding: self.onboarding,
+            welcome_screen: self.welcome_screen,
+            ids: Arc::default(),
+        }
+    }
+}
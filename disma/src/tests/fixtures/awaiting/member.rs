@@ -0,0 +1,40 @@
+use fake::Fake;
+
+use crate::{
+    member::AwaitingMember,
+    role::{AwaitingRole, RolesList},
+};
+
+pub struct AwaitingMemberFixture {
+    id: String,
+
+    roles: RolesList<AwaitingRole>,
+}
+
+impl AwaitingMemberFixture {
+    pub fn new() -> Self {
+        Self {
+            id: fake::uuid::UUIDv4.fake(),
+
+            roles: RolesList::from(Vec::new()),
+        }
+    }
+
+    pub fn with_id(mut self, id: &str) -> Self {
+        self.id = id.to_string();
+        self
+    }
+
+    pub fn with_roles(mut self, roles: RolesList<AwaitingRole>) -> Self {
+        self.roles = roles;
+        self
+    }
+
+    pub fn build(self) -> AwaitingMember {
+        AwaitingMember {
+            id: self.id,
+
+            roles: self.roles,
+        }
+    }
+}
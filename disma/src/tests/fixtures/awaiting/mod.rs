@@ -6,3 +6,18 @@ pub use category::*;
 
 mod role;
 pub use role::*;
+
+mod automod;
+pub use automod::*;
+
+mod guild;
+pub use guild::*;
+
+mod scheduled_event;
+pub use scheduled_event::*;
+
+mod member;
+pub use member::*;
+
+mod thread;
+pub use thread::*;
@@ -26,6 +26,11 @@ impl AwaitingRoleFixture {
         self
     }
 
+    pub fn with_permissions(mut self, permissions: PermissionsList) -> Self {
+        self.permissions = permissions;
+        self
+    }
+
     pub fn build(self) -> AwaitingRole {
         AwaitingRole {
             name: self.name,
@@ -0,0 +1,51 @@
+use crate::{
+    channel::AwaitingChannel,
+    scheduled_event::{AwaitingScheduledEvent, ScheduledEventLocation},
+};
+
+pub struct AwaitingScheduledEventFixture {
+    name: String,
+    description: Option<String>,
+    location: ScheduledEventLocation<AwaitingChannel>,
+    start_time: String,
+    end_time: Option<String>,
+}
+
+impl AwaitingScheduledEventFixture {
+    pub fn new() -> Self {
+        Self {
+            name: "event".to_string(),
+            description: None,
+            location: ScheduledEventLocation::External {
+                location: "Online".to_string(),
+            },
+            start_time: "2030-01-01T18:00:00Z".to_string(),
+            end_time: Some("2030-01-01T20:00:00Z".to_string()),
+        }
+    }
+
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    pub fn with_location(mut self, location: ScheduledEventLocation<AwaitingChannel>) -> Self {
+        self.location = location;
+        self
+    }
+
+    pub fn with_start_time(mut self, start_time: &str) -> Self {
+        self.start_time = start_time.to_string();
+        self
+    }
+
+    pub fn build(self) -> AwaitingScheduledEvent {
+        AwaitingScheduledEvent {
+            name: self.name,
+            description: self.description,
+            location: self.location,
+            start_time: self.start_time,
+            end_time: self.end_time,
+        }
+    }
+}
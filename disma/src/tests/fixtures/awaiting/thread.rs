@@ -0,0 +1,39 @@
+use crate::channel::{AwaitingThread, ThreadType};
+
+pub struct AwaitingThreadFixture {
+    name: String,
+    thread_type: ThreadType,
+    auto_archive_duration: u32,
+    locked: bool,
+    invitable: bool,
+    message: Option<String>,
+}
+
+impl AwaitingThreadFixture {
+    pub fn new() -> Self {
+        Self {
+            name: "faq".to_string(),
+            thread_type: ThreadType::PUBLIC,
+            auto_archive_duration: 10080,
+            locked: false,
+            invitable: true,
+            message: None,
+        }
+    }
+
+    pub fn with_message(mut self, message: &str) -> Self {
+        self.message = Some(message.to_string());
+        self
+    }
+
+    pub fn build(self) -> AwaitingThread {
+        AwaitingThread {
+            name: self.name,
+            thread_type: self.thread_type,
+            auto_archive_duration: self.auto_archive_duration,
+            locked: self.locked,
+            invitable: self.invitable,
+            message: self.message,
+        }
+    }
+}
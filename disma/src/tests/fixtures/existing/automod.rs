@@ -0,0 +1,67 @@
+use fake::Fake;
+
+use crate::{
+    automod::{AutoModAction, AutoModEventType, AutoModTrigger, ExistingAutoModRule},
+    channel::{ChannelsList, ExistingChannel},
+    role::{ExistingRole, RolesList},
+};
+
+pub struct ExistingAutoModRuleFixture {
+    id: String,
+    name: String,
+    event_type: AutoModEventType,
+    trigger: AutoModTrigger,
+    actions: Vec<AutoModAction<ExistingChannel>>,
+    enabled: bool,
+    exempt_roles: RolesList<ExistingRole>,
+    exempt_channels: ChannelsList<ExistingChannel>,
+}
+
+impl ExistingAutoModRuleFixture {
+    pub fn new() -> Self {
+        Self {
+            id: fake::uuid::UUIDv4.fake(),
+            name: fake::faker::lorem::en::Word().fake(),
+            event_type: AutoModEventType::MESSAGE_SEND,
+            trigger: AutoModTrigger::Keyword {
+                keywords: Vec::new(),
+                regex_patterns: Vec::new(),
+                allow_list: Vec::new(),
+            },
+            actions: vec![AutoModAction::BlockMessage {
+                custom_message: None,
+            }],
+            enabled: true,
+            exempt_roles: RolesList::from(Vec::new()),
+            exempt_channels: ChannelsList::from(Vec::new()),
+        }
+    }
+
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    pub fn with_actions(mut self, actions: Vec<AutoModAction<ExistingChannel>>) -> Self {
+        self.actions = actions;
+        self
+    }
+
+    pub fn with_exempt_roles(mut self, roles: RolesList<ExistingRole>) -> Self {
+        self.exempt_roles = roles;
+        self
+    }
+
+    pub fn build(self) -> ExistingAutoModRule {
+        ExistingAutoModRule {
+            id: self.id,
+            name: self.name,
+            event_type: self.event_type,
+            trigger: self.trigger,
+            actions: self.actions,
+            enabled: self.enabled,
+            exempt_roles: self.exempt_roles,
+            exempt_channels: self.exempt_channels,
+        }
+    }
+}
@@ -2,8 +2,8 @@ use fake::Fake;
 
 use crate::{
     category::ExistingCategory,
-    channel::{ChannelType, ExistingChannel},
-    permission::PermissionsOverwritesList,
+    channel::{ChannelType, ExistingChannel, ExistingThread},
+    permission::{PermissionsOverwrite, PermissionsOverwritesList},
     role::ExistingRole,
 };
 
@@ -14,6 +14,7 @@ pub struct ExistingChannelFixture {
     topic: Option<String>,
     channel_type: ChannelType,
     category: Option<ExistingCategory>,
+    threads: Vec<ExistingThread>,
 }
 
 impl ExistingChannelFixture {
@@ -25,6 +26,7 @@ impl ExistingChannelFixture {
             topic: None,
             channel_type: ChannelType::TEXT,
             category: None,
+            threads: Vec::new(),
         }
     }
 
@@ -38,11 +40,29 @@ impl ExistingChannelFixture {
         self
     }
 
+    pub fn with_type(mut self, channel_type: ChannelType) -> Self {
+        self.channel_type = channel_type;
+        self
+    }
+
     pub fn with_category(mut self, category: &ExistingCategory) -> Self {
         self.category = Some(category.clone());
         self
     }
 
+    pub fn with_permissions_overwrites(
+        mut self,
+        overwrites: Vec<PermissionsOverwrite<ExistingRole>>,
+    ) -> Self {
+        self.overwrites = PermissionsOverwritesList::from(overwrites);
+        self
+    }
+
+    pub fn with_threads(mut self, threads: Vec<ExistingThread>) -> Self {
+        self.threads = threads;
+        self
+    }
+
     pub fn build(self) -> ExistingChannel {
         ExistingChannel {
             id: self.id,
@@ -51,6 +71,7 @@ impl ExistingChannelFixture {
             topic: self.topic,
             channel_type: self.channel_type,
             category: self.category,
+            threads: self.threads,
         }
     }
 }
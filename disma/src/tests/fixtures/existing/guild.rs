@@ -1,14 +1,22 @@
 use crate::{
+    automod::{AutoModRulesList, ExistingAutoModRule},
     category::{CategoriesList, ExistingCategory},
     channel::{ChannelsList, ExistingChannel},
     guild::ExistingGuild,
+    member::{ExistingMember, MembersList},
+    onboarding::ExistingOnboarding,
     role::{ExistingRole, RolesList},
+    scheduled_event::{ExistingScheduledEvent, ScheduledEventsList},
+    welcome_screen::ExistingWelcomeScreen,
 };
 
 pub struct ExistingGuildFixture {
     roles: RolesList<ExistingRole>,
     categories: CategoriesList<ExistingCategory>,
     channels: ChannelsList<ExistingChannel>,
+    automod_rules: AutoModRulesList<ExistingAutoModRule>,
+    scheduled_events: ScheduledEventsList<ExistingScheduledEvent>,
+    members: MembersList<ExistingMember>,
 }
 
 impl ExistingGuildFixture {
@@ -17,6 +25,9 @@ impl ExistingGuildFixture {
             roles: RolesList::from(Vec::new()),
             categories: CategoriesList::from(Vec::new()),
             channels: ChannelsList::from(Vec::new()),
+            automod_rules: AutoModRulesList::from(Vec::new()),
+            scheduled_events: ScheduledEventsList::from(Vec::new()),
+            members: MembersList::from(Vec::new()),
         }
     }
 
@@ -35,7 +46,41 @@ impl ExistingGuildFixture {
         self
     }
 
+    pub fn with_automod_rule(mut self, rule: ExistingAutoModRule) -> Self {
+        self.automod_rules.add(rule);
+        self
+    }
+
+    pub fn with_scheduled_events(
+        mut self,
+        events: ScheduledEventsList<ExistingScheduledEvent>,
+    ) -> Self {
+        self.scheduled_events = events;
+        self
+    }
+
+    pub fn with_scheduled_event(mut self, event: ExistingScheduledEvent) -> Self {
+        self.scheduled_events.add(event);
+        self
+    }
+
+    pub fn with_member(mut self, member: ExistingMember) -> Self {
+        self.members.add(member);
+        self
+    }
+
     pub fn build(self) -> ExistingGuild {
-        ExistingGuild::new(self.roles, self.categories, self.channels)
+        let mut guild = ExistingGuild::new(
+            self.roles,
+            self.categories,
+            self.channels,
+            self.automod_rules,
+            self.scheduled_events,
+            ExistingOnboarding::default(),
+            ExistingWelcomeScreen::default(),
+        );
+        guild.replace_members(self.members);
+
+        guild
     }
 }
@@ -0,0 +1,40 @@
+use fake::Fake;
+
+use crate::{
+    member::ExistingMember,
+    role::{ExistingRole, RolesList},
+};
+
+pub struct ExistingMemberFixture {
+    id: String,
+    username: String,
+    roles: RolesList<ExistingRole>,
+}
+
+impl ExistingMemberFixture {
+    pub fn new() -> Self {
+        Self {
+            id: fake::uuid::UUIDv4.fake(),
+            username: fake::faker::internet::en::Username().fake(),
+            roles: RolesList::from(Vec::new()),
+        }
+    }
+
+    pub fn with_id(mut self, id: &str) -> Self {
+        self.id = id.to_string();
+        self
+    }
+
+    pub fn with_roles(mut self, roles: RolesList<ExistingRole>) -> Self {
+        self.roles = roles;
+        self
+    }
+
+    pub fn build(self) -> ExistingMember {
+        ExistingMember {
+            id: self.id,
+            username: self.username,
+            roles: self.roles,
+        }
+    }
+}
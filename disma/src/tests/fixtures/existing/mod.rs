@@ -9,3 +9,15 @@ pub use guild::*;
 
 mod role;
 pub use role::*;
+
+mod automod;
+pub use automod::*;
+
+mod scheduled_event;
+pub use scheduled_event::*;
+
+mod member;
+pub use member::*;
+
+mod thread;
+pub use thread::*;
@@ -9,6 +9,7 @@ pub struct ExistingRoleFixture {
     color: Option<String>,
     is_mentionable: bool,
     show_in_sidebar: bool,
+    is_managed: bool,
 }
 
 impl ExistingRoleFixture {
@@ -20,6 +21,7 @@ impl ExistingRoleFixture {
             color: None,
             is_mentionable: false,
             show_in_sidebar: false,
+            is_managed: false,
         }
     }
 
@@ -28,6 +30,16 @@ impl ExistingRoleFixture {
         self
     }
 
+    pub fn with_permissions(mut self, permissions: PermissionsList) -> Self {
+        self.permissions = permissions;
+        self
+    }
+
+    pub fn managed(mut self) -> Self {
+        self.is_managed = true;
+        self
+    }
+
     pub fn build(self) -> ExistingRole {
         ExistingRole {
             id: self.id,
@@ -36,6 +48,7 @@ impl ExistingRoleFixture {
             color: self.color,
             is_mentionable: self.is_mentionable,
             show_in_sidebar: self.show_in_sidebar,
+            is_managed: self.is_managed,
         }
     }
 }
@@ -0,0 +1,55 @@
+use crate::{
+    channel::ExistingChannel,
+    scheduled_event::{ExistingScheduledEvent, ScheduledEventLocation},
+};
+use fake::Fake;
+
+pub struct ExistingScheduledEventFixture {
+    id: String,
+    name: String,
+    description: Option<String>,
+    location: ScheduledEventLocation<ExistingChannel>,
+    start_time: String,
+    end_time: Option<String>,
+}
+
+impl ExistingScheduledEventFixture {
+    pub fn new() -> Self {
+        Self {
+            id: fake::uuid::UUIDv4.fake(),
+            name: fake::faker::lorem::en::Word().fake(),
+            description: None,
+            location: ScheduledEventLocation::External {
+                location: "Online".to_string(),
+            },
+            start_time: "2030-01-01T18:00:00Z".to_string(),
+            end_time: Some("2030-01-01T20:00:00Z".to_string()),
+        }
+    }
+
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    pub fn with_start_time(mut self, start_time: &str) -> Self {
+        self.start_time = start_time.to_string();
+        self
+    }
+
+    pub fn with_location(mut self, location: ScheduledEventLocation<ExistingChannel>) -> Self {
+        self.location = location;
+        self
+    }
+
+    pub fn build(self) -> ExistingScheduledEvent {
+        ExistingScheduledEvent {
+            id: self.id,
+            name: self.name,
+            description: self.description,
+            location: self.location,
+            start_time: self.start_time,
+            end_time: self.end_time,
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use fake::Fake;
+
+use crate::channel::{ExistingThread, ThreadType};
+
+pub struct ExistingThreadFixture {
+    id: String,
+    name: String,
+    thread_type: ThreadType,
+    auto_archive_duration: u32,
+    locked: bool,
+    invitable: bool,
+    archived: bool,
+    is_managed: bool,
+}
+
+impl ExistingThreadFixture {
+    pub fn new() -> Self {
+        Self {
+            id: fake::uuid::UUIDv4.fake(),
+            name: fake::faker::lorem::en::Word().fake(),
+            thread_type: ThreadType::PUBLIC,
+            auto_archive_duration: 10080,
+            locked: false,
+            invitable: true,
+            archived: false,
+            is_managed: true,
+        }
+    }
+
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    pub fn archived(mut self) -> Self {
+        self.archived = true;
+        self
+    }
+
+    pub fn started_by_member(mut self) -> Self {
+        self.is_managed = false;
+        self
+    }
+
+    pub fn build(self) -> ExistingThread {
+        ExistingThread {
+            id: self.id,
+            name: self.name,
+            thread_type: self.thread_type,
+            auto_archive_duration: self.auto_archive_duration,
+            locked: self.locked,
+            invitable: self.invitable,
+            archived: self.archived,
+            is_managed: self.is_managed,
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use crate::api::params::channel::{
     ChannelParams, ChannelParamsChannelType, ChannelParamsPermissionsOverwritesStrategy,
+    ThreadsParamsList,
 };
 
 pub struct ChannelParamsFixture {
@@ -8,6 +9,7 @@ pub struct ChannelParamsFixture {
     _type: ChannelParamsChannelType,
     topic: Option<String>,
     category: Option<String>,
+    threads: Option<ThreadsParamsList>,
 }
 
 impl ChannelParamsFixture {
@@ -20,6 +22,7 @@ impl ChannelParamsFixture {
             _type: ChannelParamsChannelType::TEXT,
             topic: None,
             category: None,
+            threads: None,
         }
     }
 
@@ -38,6 +41,16 @@ impl ChannelParamsFixture {
         self
     }
 
+    pub fn with_permissions_overwrites_from_category(mut self) -> Self {
+        self.permissions_overwrites = ChannelParamsPermissionsOverwritesStrategy::FromCategory;
+        self
+    }
+
+    pub fn with_threads(mut self, threads: ThreadsParamsList) -> Self {
+        self.threads = Some(threads);
+        self
+    }
+
     pub fn build(self) -> ChannelParams {
         ChannelParams {
             name: self.name,
@@ -45,6 +58,7 @@ impl ChannelParamsFixture {
             _type: self._type,
             topic: self.topic,
             category: self.category,
+            threads: self.threads,
         }
     }
 }
@@ -1,14 +1,21 @@
+use std::collections::BTreeMap;
+
 use crate::api::params::{
+    automod::AutoModRulesParamsList,
     category::{CategoriesParamsList, CategoryParams, CategoryParamsExtraItemsStrategy},
     channel::{ChannelParams, ChannelParamsExtraItemsStrategy, ChannelsParamsList},
     guild::GuildParams,
+    member::MembersParamsList,
     role::{RoleParams, RoleParamsExtraItemsStrategy, RolesParamsList},
+    scheduled_event::ScheduledEventsParamsList,
 };
 
 pub struct GuildParamsFixture {
     roles: RolesParamsList,
     categories: CategoriesParamsList,
     channels: ChannelsParamsList,
+    automod: AutoModRulesParamsList,
+    members: Option<MembersParamsList>,
 }
 
 impl GuildParamsFixture {
@@ -17,6 +24,8 @@ impl GuildParamsFixture {
             roles: RolesParamsList::default(),
             categories: CategoriesParamsList::default(),
             channels: ChannelsParamsList::default(),
+            automod: AutoModRulesParamsList::default(),
+            members: None,
         }
     }
 
@@ -50,11 +59,22 @@ impl GuildParamsFixture {
         self
     }
 
+    pub fn with_members(mut self, members: MembersParamsList) -> Self {
+        self.members = Some(members);
+        self
+    }
+
     pub fn build(self) -> GuildParams {
         GuildParams {
+            presets: BTreeMap::new(),
             roles: self.roles,
             categories: self.categories,
             channels: self.channels,
+            automod: self.automod,
+            scheduled_events: ScheduledEventsParamsList::default(),
+            members: self.members,
+            onboarding: None,
+            welcome_screen: None,
         }
     }
 }
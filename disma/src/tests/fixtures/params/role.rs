@@ -1,8 +1,8 @@
-use crate::{api::params::role::RoleParams, permission::Permission};
+use crate::api::params::{permission::PermissionParams, role::RoleParams};
 
 pub struct RoleParamsFixture {
     name: String,
-    permissions: Vec<Permission>,
+    permissions: Vec<PermissionParams>,
     color: Option<String>,
     show_in_sidebar: bool,
     is_mentionable: bool,
@@ -24,6 +24,11 @@ impl RoleParamsFixture {
         self
     }
 
+    pub fn with_permissions(mut self, permissions: Vec<PermissionParams>) -> Self {
+        self.permissions = permissions;
+        self
+    }
+
     pub fn with_color(mut self, color: &str) -> Self {
         self.color = Some(color.to_string());
         self
@@ -0,0 +1,49 @@
+/// Matches `text` against a glob `pattern`, where `*` matches any sequence of
+/// characters and `?` matches any single character.
+pub fn glob_matches(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+
+    let (mut pattern_index, mut text_index) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while text_index < text.len() {
+        match pattern.get(pattern_index) {
+            Some('*') => {
+                backtrack = Some((pattern_index, text_index));
+                pattern_index += 1;
+            }
+            Some(character) if *character == '?' || *character == text[text_index] => {
+                pattern_index += 1;
+                text_index += 1;
+            }
+            _ => match backtrack {
+                Some((star_index, star_text_index)) => {
+                    pattern_index = star_index + 1;
+                    text_index = star_text_index + 1;
+                    backtrack = Some((star_index, star_text_index + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+
+    pattern[pattern_index..]
+        .iter()
+        .all(|character| *character == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::glob_matches;
+
+    #[test]
+    fn glob_matches_wildcards() {
+        assert!(glob_matches("team-*", "team-alpha"));
+        assert!(glob_matches("*-old", "general-old"));
+        assert!(glob_matches("t?am", "team"));
+        assert!(glob_matches("*", ""));
+        assert!(!glob_matches("team-*", "teams"));
+        assert!(!glob_matches("t?am", "tam"));
+    }
+}
@@ -1,2 +1,4 @@
+pub mod glob;
 pub mod http;
 pub mod misc;
+pub mod time;
@@ -0,0 +1,178 @@
+/// RFC 3339 `timestamp` converted to UTC, as `2030-01-01T18:00:00Z`, for
+/// timestamps of different offsets or precisions to be compared. Fractional
+/// seconds are only kept when not zero.
+pub fn normalize_timestamp(timestamp: &str) -> Result<String, String> {
+    let invalid = || format!("Invalid RFC 3339 timestamp '{timestamp}'");
+
+    let (date, time) = timestamp.split_once(['T', 't', ' ']).ok_or_else(invalid)?;
+    let [year, month, day] = split_numbers::<3>(date, '-').ok_or_else(invalid)?;
+
+    let (time, offset_minutes) = match time.strip_suffix(['Z', 'z']) {
+        Some(time) => (time, 0),
+        None => {
+            let sign_position = time.rfind(['+', '-']).ok_or_else(invalid)?;
+            let (time, offset) = time.split_at(sign_position);
+            let [offset_hours, offset_minutes] =
+                split_numbers::<2>(&offset[1..], ':').ok_or_else(invalid)?;
+            if offset_hours > 23 || offset_minutes > 59 {
+                return Err(invalid());
+            }
+            let offset_minutes = (offset_hours * 60 + offset_minutes) as i64;
+            match offset.starts_with('-') {
+                true => (time, -offset_minutes),
+                false => (time, offset_minutes),
+            }
+        }
+    };
+    let (time, fraction) = match time.split_once('.') {
+        Some((time, fraction)) => (time, Some(fraction)),
+        None => (time, None),
+    };
+    let [hour, minute, second] = split_numbers::<3>(time, ':').ok_or_else(invalid)?;
+
+    if fraction.is_some_and(|fraction| {
+        fraction.is_empty() || !fraction.chars().all(|char| char.is_ascii_digit())
+    }) || !(1..=12).contains(&month)
+        || day == 0
+        || day > days_in_month(year, month)
+        || hour > 23
+        || minute > 59
+        || second > 60
+    {
+        return Err(invalid());
+    }
+
+    let local_minutes = days_from_civil(year, month, day) * 1440 + hour as i64 * 60 + minute as i64;
+    let utc_minutes = local_minutes - offset_minutes;
+    let (year, month, day) = civil_from_days(utc_minutes.div_euclid(1440));
+    let minutes_of_day = utc_minutes.rem_euclid(1440);
+
+    let fraction = fraction
+        .map(|fraction| fraction.trim_end_matches('0'))
+        .filter(|fraction| !fraction.is_empty())
+        .map(|fraction| format!(".{fraction}"))
+        .unwrap_or_default();
+
+    Ok(format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{second:02}{fraction}Z",
+        minutes_of_day / 60,
+        minutes_of_day % 60
+    ))
+}
+
+fn split_numbers<const N: usize>(text: &str, separator: char) -> Option<[u32; N]> {
+    let mut numbers = [0; N];
+    let mut parts = text.split(separator);
+
+    for number in numbers.iter_mut() {
+        let part = parts.next()?;
+        if part.is_empty() || !part.chars().all(|char| char.is_ascii_digit()) {
+            return None;
+        }
+        *number = part.parse().ok()?;
+    }
+
+    parts.next().is_none().then_some(numbers)
+}
+
+fn days_in_month(year: u32, month: u32) -> u32 {
+    match month {
+        2 if year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400)) => {
+            29
+        }
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Days since the Unix epoch of a date of the proleptic Gregorian calendar.
+fn days_from_civil(year: u32, month: u32, day: u32) -> i64 {
+    let year = year as i64 - (month <= 2) as i64;
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month = month as i64;
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146097 + day_of_era - 719468
+}
+
+/// Date of the proleptic Gregorian calendar of days since the Unix epoch.
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719468;
+    let era = days.div_euclid(146097);
+    let day_of_era = days - era * 146097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+
+    (year_of_era + era * 400 + (month <= 2) as i64, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::normalize_timestamp;
+
+    #[test]
+    fn keeps_utc_timestamps() {
+        assert_eq!(
+            normalize_timestamp("2030-01-01T18:00:00Z"),
+            Ok("2030-01-01T18:00:00Z".to_string())
+        );
+    }
+
+    #[test]
+    fn converts_offsets_to_utc() {
+        assert_eq!(
+            normalize_timestamp("2030-01-01T18:00:00+00:00"),
+            Ok("2030-01-01T18:00:00Z".to_string())
+        );
+        assert_eq!(
+            normalize_timestamp("2030-01-01T13:00:00-05:00"),
+            Ok("2030-01-01T18:00:00Z".to_string())
+        );
+        assert_eq!(
+            normalize_timestamp("2030-01-01T01:30:00+02:00"),
+            Ok("2029-12-31T23:30:00Z".to_string())
+        );
+        assert_eq!(
+            normalize_timestamp("2028-02-28T23:00:00-02:00"),
+            Ok("2028-02-29T01:00:00Z".to_string())
+        );
+    }
+
+    #[test]
+    fn only_keeps_non_zero_fractional_seconds() {
+        assert_eq!(
+            normalize_timestamp("2030-01-01T18:00:00.000000+00:00"),
+            Ok("2030-01-01T18:00:00Z".to_string())
+        );
+        assert_eq!(
+            normalize_timestamp("2030-01-01T18:00:00.250Z"),
+            Ok("2030-01-01T18:00:00.25Z".to_string())
+        );
+    }
+
+    #[test]
+    fn given_invalid_timestamp_it_fails() {
+        for timestamp in [
+            "tomorrow",
+            "2030-01-01",
+            "2030-01-01T18:00:00",
+            "2030-02-30T18:00:00Z",
+            "2030-01-01T24:00:00Z",
+            "2030-01-01T18:00Z",
+            "2030-01-01T18:00:00+5",
+        ] {
+            assert!(normalize_timestamp(timestamp).is_err(), "{timestamp}");
+        }
+    }
+}